@@ -0,0 +1,50 @@
+//! Generation of unit tests with the `generate_tests` attribute.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{DeriveInput, Error, Result};
+
+/// Generate unit tests for a command if the `generate_tests` attribute is set.
+///
+/// The generated tests call the assertion functions of the
+/// `twilight_interactions::testing` module.
+pub fn generated_tests(
+    input: &DeriveInput,
+    generate_tests: Option<bool>,
+    span: Span,
+) -> Result<TokenStream> {
+    if generate_tests != Some(true) {
+        return Ok(TokenStream::new());
+    }
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            span,
+            "`generate_tests` cannot be used on generic types",
+        ));
+    }
+
+    let ident = &input.ident;
+    let module = format_ident!("__{}_generated_tests", ident);
+
+    Ok(quote! {
+        #[cfg(test)]
+        #[allow(non_snake_case)]
+        mod #module {
+            #[test]
+            fn create_parse_symmetry() {
+                ::twilight_interactions::testing::assert_create_parse_symmetry::<super::#ident>();
+            }
+
+            #[test]
+            fn limits() {
+                ::twilight_interactions::testing::assert_limits::<super::#ident>();
+            }
+
+            #[test]
+            fn localizations() {
+                ::twilight_interactions::testing::assert_localizations::<super::#ident>();
+            }
+        }
+    })
+}
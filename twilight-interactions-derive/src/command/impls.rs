@@ -40,6 +40,26 @@ pub fn dummy_command_model(ident: Ident, error: Error) -> TokenStream {
     }
 }
 
+/// Implementation of the `CommandModelRef` derive macro
+pub fn impl_command_model_ref(input: DeriveInput) -> Result<TokenStream> {
+    let span = input.span();
+
+    match input.data.clone() {
+        Data::Struct(data) => match data.fields {
+            Fields::Named(fields) => super::model::impl_command_model_ref(input, Some(fields)),
+            Fields::Unit => super::model::impl_command_model_ref(input, None),
+            _ => Err(Error::new(
+                span,
+                "`CommandModelRef` can only be applied to structs with named fields or unit structs",
+            )),
+        },
+        _ => Err(Error::new(
+            span,
+            "`CommandModelRef` can only be applied to structs",
+        )),
+    }
+}
+
 /// Implementation of the `CreateCommand` derive macro
 pub fn impl_create_command(input: DeriveInput) -> Result<TokenStream> {
     let span = input.span();
@@ -77,3 +97,77 @@ pub fn dummy_create_command(ident: Ident, error: Error) -> TokenStream {
         }
     }
 }
+
+/// Implementation of the `SlashCommand` derive macro
+///
+/// Both traits are implemented from the same input, so errors of both
+/// implementations are reported together.
+pub fn impl_slash_command(input: DeriveInput) -> Result<TokenStream> {
+    match (
+        impl_command_model(input.clone()),
+        impl_create_command(input),
+    ) {
+        (Ok(model), Ok(create)) => Ok(quote!(#model #create)),
+        (Err(mut error), Err(other)) => {
+            error.combine(other);
+            Err(error)
+        }
+        (Err(error), Ok(_)) | (Ok(_), Err(error)) => Err(error),
+    }
+}
+
+/// Dummy implementation of the `CommandModel` and `CreateCommand` traits in
+/// case of macro error
+pub fn dummy_slash_command(ident: Ident, error: Error) -> TokenStream {
+    let model = dummy_command_model(ident.clone(), error);
+
+    quote! {
+        #model
+
+        impl ::twilight_interactions::command::CreateCommand for #ident {
+            const NAME: &'static str = "";
+
+            fn create_command() -> ::twilight_interactions::command::ApplicationCommandData {
+                ::std::unimplemented!()
+            }
+        }
+    }
+}
+
+/// Implementation of the `CreateAutocomplete` derive macro
+pub fn impl_create_autocomplete(input: DeriveInput) -> Result<TokenStream> {
+    let span = input.span();
+
+    match input.data.clone() {
+        Data::Struct(data) => match data.fields {
+            Fields::Named(fields) => super::model::impl_create_autocomplete(input, Some(fields)),
+            Fields::Unit => super::model::impl_create_autocomplete(input, None),
+            _ => Err(Error::new(
+                span,
+                "`CreateAutocomplete` can only be applied to structs with named fields or unit structs",
+            )),
+        },
+        _ => Err(Error::new(
+            span,
+            "`CreateAutocomplete` can only be applied to structs",
+        )),
+    }
+}
+
+/// Dummy implementation of the `CreateAutocomplete` trait in case of macro error
+pub fn dummy_create_autocomplete(ident: Ident, error: Error) -> TokenStream {
+    let error = error.to_compile_error();
+
+    quote! {
+        #error
+
+        impl ::twilight_interactions::command::CreateAutocomplete for #ident {
+            fn autocomplete(
+                field: &str,
+                value: &str,
+            ) -> ::std::vec::Vec<::twilight_model::application::command::CommandOptionChoice> {
+                ::std::unimplemented!()
+            }
+        }
+    }
+}
@@ -1,11 +1,15 @@
 //! Implementation of `CommandModel` and `CreateCommand` derive macros.
 
+mod generate_tests;
 mod impls;
+mod option_names;
 
 mod model;
 mod subcommand;
 mod user_application;
 
 pub use impls::{
-    dummy_command_model, dummy_create_command, impl_command_model, impl_create_command,
+    dummy_command_model, dummy_create_autocomplete, dummy_create_command, dummy_slash_command,
+    impl_command_model, impl_command_model_ref, impl_create_autocomplete, impl_create_command,
+    impl_slash_command,
 };
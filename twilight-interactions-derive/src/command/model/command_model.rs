@@ -1,29 +1,74 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned};
-use syn::{DeriveInput, Error, FieldsNamed, Result};
+use syn::{
+    spanned::Spanned, DeriveInput, Error, FieldsNamed, GenericParam, Lifetime, LifetimeParam,
+    Result,
+};
 
 use super::parse::{FieldType, StructField, TypeAttribute};
 use crate::{
-    command::model::parse::{channel_type, command_option_value},
-    parse::syntax::{find_attr, optional},
+    command::model::parse::{channel_types_expr, command_option_value, ChannelTypes},
+    parse::syntax::{bounded_generics, find_attr, optional},
 };
 
 /// Implementation of `CommandModel` derive macro
 pub fn impl_command_model(input: DeriveInput, fields: Option<FieldsNamed>) -> Result<TokenStream> {
     let ident = &input.ident;
-    let generics = &input.generics;
-    let where_clause = &generics.where_clause;
     let fields = match fields {
-        Some(fields) => StructField::from_fields(fields)?,
+        Some(fields) => StructField::from_fields(fields, TypeAttribute::rename_all(&input.attrs)?)?,
         None => Vec::new(),
     };
 
-    let autocomplete = match find_attr(&input.attrs, "command") {
-        Some(attr) => TypeAttribute::parse(attr)?.autocomplete.unwrap_or(false),
-        None => false,
+    let option_types = fields
+        .iter()
+        .filter(|field| field.attributes.with.is_none() && field.is_option())
+        .map(|field| &field.ty);
+    let generics = bounded_generics(
+        &input.generics,
+        option_types,
+        quote!(::twilight_interactions::command::CommandOption),
+    );
+    let context_types = fields
+        .iter()
+        .filter(|field| field.attributes.ctx)
+        .map(|field| &field.ty);
+    let generics = bounded_generics(
+        &generics,
+        context_types,
+        quote!(::twilight_interactions::command::ContextField),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let (autocomplete, deny_unknown_fields, name) = match find_attr(&input.attrs, "command") {
+        Some(attr) => {
+            let attribute = TypeAttribute::parse(attr)?;
+            let deny_unknown_fields = attribute.deny_unknown_fields.unwrap_or(false);
+
+            if deny_unknown_fields && fields.iter().any(|field| field.attributes.rest) {
+                return Err(Error::new(
+                    attr.span(),
+                    "`deny_unknown_fields` cannot be used with a `rest` field",
+                ));
+            }
+
+            (
+                attribute.autocomplete.unwrap_or(false),
+                deny_unknown_fields,
+                attribute.name,
+            )
+        }
+        None => (false, false, None),
     };
 
-    for field in &fields {
+    let rest = fields.iter().position(|field| field.attributes.rest);
+    if let Some(index) = rest.filter(|index| index + 1 != fields.len()) {
+        return Err(Error::new(
+            fields[index].span,
+            "the field with the `rest` attribute must be the last field",
+        ));
+    }
+
+    for field in fields.iter().filter(|field| field.is_option()) {
         // If autocomplete, ensure all fields are either `AutocompleteValue` or `Option`s
         if autocomplete && ![FieldType::Autocomplete, FieldType::Optional].contains(&field.kind) {
             return Err(Error::new(
@@ -41,19 +86,165 @@ pub fn impl_command_model(input: DeriveInput, fields: Option<FieldsNamed>) -> Re
         }
     }
 
-    let field_unknown = field_unknown(autocomplete);
+    let field_unknown = match rest {
+        Some(index) => rest_field_push(&fields[index]),
+        None => field_unknown(autocomplete && !deny_unknown_fields),
+    };
+    let option_fields = fields.iter().filter(|field| field.is_option());
+    let fields_init = option_fields.clone().map(field_init);
+    let rest_init = rest.map(|index| rest_field_init(&fields[index]));
+    let fields_match_arms = option_fields.map(|field| field_match_arm(field, false));
+    let fields_constructor = fields.iter().map(|field| {
+        if field.attributes.ctx {
+            context_field_constructor(field)
+        } else if field.attributes.rest {
+            let ident = &field.ident;
+            quote!(#ident)
+        } else {
+            field_constructor(field)
+        }
+    });
+
+    let body = quote! {
+        #(#fields_init)*
+        #rest_init
+
+        for __opt in __data.options {
+            match &*__opt.name {
+                #(#fields_match_arms,)*
+                __other => #field_unknown
+            }
+        }
+
+        ::std::result::Result::Ok(Self { #(#fields_constructor),* })
+    };
+
+    // Parse failures are reported with the name of the command, or the name
+    // of the type if the model has no name
+    let command_name = match &name {
+        Some(name) => quote!(#name),
+        None => {
+            let name = ident.to_string();
+            quote!(#name)
+        }
+    };
+    let body = quote! {
+        ::twilight_interactions::command::internal::parse_command(#command_name, || { #body })
+    };
+
+    // Models with context fields parse the interaction with
+    // `from_interaction_context`, which receives the interaction
+    let methods = if fields.iter().any(|field| field.attributes.ctx) {
+        quote! {
+            fn from_interaction(
+                __data: ::twilight_interactions::command::CommandInputData,
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseError> {
+                Self::from_interaction_context(__data, ::std::option::Option::None)
+            }
+
+            fn from_interaction_context(
+                __data: ::twilight_interactions::command::CommandInputData,
+                __interaction: ::std::option::Option<&::twilight_model::application::interaction::Interaction>,
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseError> {
+                #body
+            }
+        }
+    } else {
+        quote! {
+            fn from_interaction(
+                __data: ::twilight_interactions::command::CommandInputData,
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseError> {
+                #body
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::twilight_interactions::command::CommandModel for #ident #ty_generics #where_clause {
+            #methods
+        }
+    })
+}
+
+/// Implementation of `CommandModelRef` derive macro
+pub fn impl_command_model_ref(
+    input: DeriveInput,
+    fields: Option<FieldsNamed>,
+) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let fields = match fields {
+        Some(fields) => StructField::from_fields(fields, TypeAttribute::rename_all(&input.attrs)?)?,
+        None => Vec::new(),
+    };
+
+    if let Some(attr) = find_attr(&input.attrs, "command") {
+        if TypeAttribute::parse(attr)?.autocomplete == Some(true) {
+            return Err(Error::new(
+                attr.span(),
+                "`CommandModelRef` does not support autocomplete models",
+            ));
+        }
+    }
+
+    for field in &fields {
+        if field.kind == FieldType::Autocomplete {
+            return Err(Error::new(
+                field.span,
+                "`AutocompleteValue` is not supported by `CommandModelRef`",
+            ));
+        }
+
+        if field.attributes.with.is_some() {
+            return Err(Error::new(
+                field.span,
+                "the `with` attribute is not supported by `CommandModelRef`",
+            ));
+        }
+
+        if field.attributes.ctx || field.attributes.rest {
+            return Err(Error::new(
+                field.span,
+                "the `ctx` and `rest` attributes are not supported by `CommandModelRef`",
+            ));
+        }
+    }
+
+    // Use the first lifetime of the type, or add one if there is none
+    let mut generics = input.generics.clone();
+    let lifetime = match generics.lifetimes().next() {
+        Some(param) => param.lifetime.clone(),
+        None => {
+            let lifetime = Lifetime::new("'__a", Span::call_site());
+            generics.params.insert(
+                0,
+                GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())),
+            );
+            lifetime
+        }
+    };
+
+    let generics = bounded_generics(
+        &generics,
+        fields.iter().map(|field| &field.ty),
+        quote!(::twilight_interactions::command::CommandOptionRef<#lifetime>),
+    );
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
     let fields_init = fields.iter().map(field_init);
-    let fields_match_arms = fields.iter().map(field_match_arm);
+    let fields_match_arms = fields.iter().map(|field| field_match_arm(field, true));
     let fields_constructor = fields.iter().map(field_constructor);
+    let field_unknown = field_unknown(false);
 
     Ok(quote! {
-        impl #generics ::twilight_interactions::command::CommandModel for #ident #generics #where_clause {
-            fn from_interaction(
-                __data: ::twilight_interactions::command::CommandInputData,
+        impl #impl_generics ::twilight_interactions::command::CommandModelRef<#lifetime> for #ident #ty_generics #where_clause {
+            fn from_interaction_ref(
+                __options: &#lifetime [::twilight_model::application::interaction::application_command::CommandDataOption],
+                __resolved: ::std::option::Option<&#lifetime ::twilight_model::application::interaction::InteractionDataResolved>,
             ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseError> {
                 #(#fields_init)*
 
-                for __opt in __data.options {
+                for __opt in __options {
                     match &*__opt.name {
                         #(#fields_match_arms,)*
                         __other => #field_unknown
@@ -73,45 +264,90 @@ fn field_init(field: &StructField) -> TokenStream {
 }
 
 /// Generate field match arm
-fn field_match_arm(field: &StructField) -> TokenStream {
+///
+/// Borrowed fields are parsed with the `CommandOptionRef` trait.
+fn field_match_arm(field: &StructField, borrowed: bool) -> TokenStream {
     let ident = &field.ident;
     let span = field.span;
 
-    let name = field.attributes.name_default(ident.to_string());
-    let max_value = command_option_value(field.attributes.max_value);
-    let min_value = command_option_value(field.attributes.min_value);
-    let max_length = optional(field.attributes.max_length);
-    let min_length = optional(field.attributes.min_length);
+    let name = field.attributes.name(ident);
+    let option_data = option_data(field);
 
-    let channel_types = if field.attributes.channel_types.is_empty() {
-        quote! { ::std::option::Option::None }
-    } else {
-        let items = field.attributes.channel_types.iter().map(channel_type);
-        quote! { ::std::option::Option::Some(::std::vec![#(#items),*]) }
+    let parse = match &field.attributes.with {
+        Some(path) => quote_spanned! {span=>
+            ::twilight_interactions::command::internal::parse_option_with(
+                #name,
+                #path(__opt.value, __data.resolved.as_deref()),
+            )
+        },
+        None if borrowed => quote_spanned! {span=>
+            ::twilight_interactions::command::internal::parse_option_ref(#name, &__opt.value, #option_data, __resolved)
+        },
+        None => quote_spanned! {span=>
+            ::twilight_interactions::command::internal::parse_option(#name, __opt.value, #option_data, __data.resolved.as_deref())
+        },
     };
 
+    let validate = field.attributes.validate.as_ref().map(|path| {
+        let ty = &field.ty;
+        // The type is required for deref coercion of the function argument
+        let value_ty = match field.kind {
+            FieldType::Autocomplete => {
+                quote!(::twilight_interactions::command::AutocompleteValue<#ty>)
+            }
+            FieldType::Optional | FieldType::Required => quote!(#ty),
+        };
+
+        quote_spanned! {span=>
+            let __value: #value_ty = __value;
+            ::twilight_interactions::command::internal::validate_option(#name, #path(&__value))?;
+        }
+    });
+
     quote_spanned! {span=>
         #name => {
-            let __option_data = ::twilight_interactions::command::internal::CommandOptionData {
-                channel_types: #channel_types,
-                max_value: #max_value,
-                min_value: #min_value,
-                max_length: #max_length,
-                min_length: #min_length,
-            };
-
-            match ::twilight_interactions::command::CommandOption::from_option(__opt.value, __option_data, __data.resolved.as_deref()) {
-                ::std::result::Result::Ok(__value) => #ident = Some(__value),
-                ::std::result::Result::Err(__kind) => {
-                    return ::std::result::Result::Err(
-                        ::twilight_interactions::error::ParseError::Option(
-                            ::twilight_interactions::error::ParseOptionError {
-                                field: ::std::convert::From::from(#name),
-                                kind: __kind,
-                        })
-                    )
-                }
-            }
+            let __value = #parse?;
+            #validate
+            #ident = Some(__value)
+        }
+    }
+}
+
+/// Generate the constraints passed to the option parser
+///
+/// Options without constraints use the default value, which avoids
+/// generating the full struct for each field.
+fn option_data(field: &StructField) -> TokenStream {
+    let attributes = &field.attributes;
+    let channel_types = attributes.channel_types.as_ref().filter(
+        |channel_types| !matches!(channel_types, ChannelTypes::Names(names) if names.is_empty()),
+    );
+
+    if channel_types.is_none()
+        && attributes.max_value.is_none()
+        && attributes.min_value.is_none()
+        && attributes.max_length.is_none()
+        && attributes.min_length.is_none()
+        && attributes.format.is_none()
+    {
+        return quote!(::std::default::Default::default());
+    }
+
+    let channel_types = channel_types_expr(&attributes.channel_types);
+    let max_value = command_option_value(attributes.max_value.as_ref());
+    let min_value = command_option_value(attributes.min_value.as_ref());
+    let max_length = optional(attributes.max_length.as_ref());
+    let min_length = optional(attributes.min_length.as_ref());
+    let format = optional(attributes.format.as_deref());
+
+    quote! {
+        ::twilight_interactions::command::internal::CommandOptionData {
+            channel_types: #channel_types,
+            max_value: #max_value,
+            min_value: #min_value,
+            max_length: #max_length,
+            min_length: #min_length,
+            format: #format,
         }
     }
 }
@@ -123,14 +359,7 @@ fn field_constructor(field: &StructField) -> TokenStream {
 
     match field.kind {
         FieldType::Required => quote! {
-            #ident: match #ident {
-                Some(__value) => __value,
-                None => return Err(::twilight_interactions::error::ParseError::Option(
-                    ::twilight_interactions::error::ParseOptionError {
-                        field: ::std::convert::From::from(#ident_str),
-                        kind: ::twilight_interactions::error::ParseOptionErrorType::RequiredField
-                }))
-            }
+            #ident: ::twilight_interactions::command::internal::required_option(#ident_str, #ident)?
         },
         FieldType::Optional => quote!(#ident),
         FieldType::Autocomplete => quote! {
@@ -142,18 +371,54 @@ fn field_constructor(field: &StructField) -> TokenStream {
     }
 }
 
+/// Generate constructor of a field filled from the interaction
+fn context_field_constructor(field: &StructField) -> TokenStream {
+    let ident = &field.ident;
+    let ident_str = ident.to_string();
+    let ty = &field.ty;
+    let span = field.span;
+
+    let value = quote_spanned! {span=>
+        __interaction.and_then(<#ty as ::twilight_interactions::command::ContextField>::from_context)
+    };
+
+    match field.kind {
+        FieldType::Required => quote! {
+            #ident: ::twilight_interactions::command::internal::required_context(#ident_str, #value)?
+        },
+        FieldType::Optional | FieldType::Autocomplete => quote!(#ident: #value),
+    }
+}
+
+/// Generate initialization of the field collecting unknown options
+fn rest_field_init(field: &StructField) -> TokenStream {
+    let ident = &field.ident;
+    let ty = &field.ty;
+    let span = field.span;
+
+    quote_spanned!(span=> let mut #ident = <#ty as ::std::default::Default>::default();)
+}
+
+/// Generate unknown field match arm adding the option to the rest field
+fn rest_field_push(field: &StructField) -> TokenStream {
+    let ident = &field.ident;
+    let span = field.span;
+
+    quote_spanned! {span=>
+        ::twilight_interactions::command::internal::RestOptions::push_option(&mut #ident, __opt)
+    }
+}
+
 /// Generate unknown field match arm
-fn field_unknown(autocomplete: bool) -> TokenStream {
-    if autocomplete {
+///
+/// Unknown options are ignored if `lenient` is set.
+fn field_unknown(lenient: bool) -> TokenStream {
+    if lenient {
         quote!(continue)
     } else {
         quote! {
             return ::std::result::Result::Err(
-                ::twilight_interactions::error::ParseError::Option(
-                    ::twilight_interactions::error::ParseOptionError {
-                        field: ::std::convert::From::from(__other),
-                        kind: ::twilight_interactions::error::ParseOptionErrorType::UnknownField,
-                })
+                ::twilight_interactions::command::internal::unknown_option(__other)
             )
         }
     }
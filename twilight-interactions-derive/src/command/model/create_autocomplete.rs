@@ -0,0 +1,60 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{DeriveInput, Error, FieldsNamed, Result};
+
+use super::parse::{StructField, TypeAttribute};
+use crate::parse::syntax::find_attr;
+
+/// Implementation of `CreateAutocomplete` derive macro
+pub fn impl_create_autocomplete(
+    input: DeriveInput,
+    fields: Option<FieldsNamed>,
+) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let fields = match fields {
+        Some(fields) => StructField::from_fields(fields, TypeAttribute::rename_all(&input.attrs)?)?,
+        None => Vec::new(),
+    };
+
+    if let Some(attr) = find_attr(&input.attrs, "command") {
+        TypeAttribute::parse(attr)?;
+    }
+
+    let field_arms = fields
+        .iter()
+        .filter(|field| field.attributes.autocomplete)
+        .map(field_arm)
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #impl_generics ::twilight_interactions::command::CreateAutocomplete for #ident #ty_generics #where_clause {
+            fn autocomplete(
+                __field: &str,
+                __value: &str,
+            ) -> ::std::vec::Vec<::twilight_model::application::command::CommandOptionChoice> {
+                match __field {
+                    #(#field_arms)*
+                    _ => ::std::vec::Vec::new(),
+                }
+            }
+        }
+    })
+}
+
+/// Generate the match arm calling the provider function of a field
+fn field_arm(field: &StructField) -> Result<TokenStream> {
+    let Some(path) = &field.attributes.autocomplete_with else {
+        return Err(Error::new(
+            field.span,
+            "autocomplete field must have a function set with `autocomplete` or `autocomplete_with`",
+        ));
+    };
+
+    let name = field.attributes.name(&field.ident);
+    let span = field.span;
+
+    Ok(quote_spanned! {span=>
+        #name => #path(__value),
+    })
+}
@@ -2,25 +2,49 @@ use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
 use syn::{spanned::Spanned, DeriveInput, Error, FieldsNamed, Result};
 
-use super::parse::{channel_type, command_option_value, StructField, TypeAttribute};
+use super::parse::{
+    channel_types_expr, command_option_value, option_type, StructField, TypeAttribute,
+};
 use crate::{
+    command::generate_tests::generated_tests,
+    command::option_names::{names_module, NamesKind},
     command::user_application::{context, integration_type},
-    localization::{description_expr, name_expr},
-    parse::syntax::{find_attr, optional, parse_doc},
+    localization::{description_const, description_expr, name_expr},
+    parse::{
+        metadata::metadata_expr,
+        parsers::ConstOr,
+        syntax::{bounded_generics, find_attr, optional, parse_doc},
+    },
 };
 
 /// Implementation of `CreateCommand` derive macro
 pub fn impl_create_command(input: DeriveInput, fields: Option<FieldsNamed>) -> Result<TokenStream> {
     let ident = &input.ident;
-    let generics = &input.generics;
-    let where_clause = &generics.where_clause;
     let fields = match fields {
-        Some(fields) => StructField::from_fields(fields)?,
+        Some(fields) => StructField::from_fields(fields, TypeAttribute::rename_all(&input.attrs)?)?,
         None => Vec::new(),
     };
 
+    // Fields filled from the interaction or collecting unknown options are
+    // not command options
+    let mut fields: Vec<_> = fields.into_iter().filter(StructField::is_option).collect();
+
+    // Options are sorted by the `order` attribute, preserving the declaration
+    // order of fields with the same value
+    fields.sort_by_key(|field| field.attributes.order.unwrap_or_default());
     check_fields_order(&fields)?;
 
+    let option_types = fields
+        .iter()
+        .filter(|field| field.attributes.with.is_none())
+        .map(|field| &field.ty);
+    let generics = bounded_generics(
+        &input.generics,
+        option_types,
+        quote!(::twilight_interactions::command::CreateOption),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
     let capacity = fields.len();
     let (attributes, attr_span) = match find_attr(&input.attrs, "command") {
         Some(attr) => (TypeAttribute::parse(attr)?, attr.span()),
@@ -40,7 +64,7 @@ pub fn impl_create_command(input: DeriveInput, fields: Option<FieldsNamed>) -> R
     }
 
     let name = match attributes.name {
-        Some(name) => String::from(name),
+        Some(name) => name.checked_command(attributes.deny_reserved_name),
         None => return Err(Error::new(attr_span, "missing required attribute `name`")),
     };
 
@@ -48,6 +72,10 @@ pub fn impl_create_command(input: DeriveInput, fields: Option<FieldsNamed>) -> R
     let desc_expr = description_expr(&attributes.desc, &attributes.desc_localizations, || {
         parse_doc(&input.attrs, input.span())
     })?;
+    let desc_const = description_const(&attributes.desc, &attributes.desc_localizations, || {
+        parse_doc(&input.attrs, input.span())
+    })?;
+    let nsfw_const = attributes.nsfw.unwrap_or(false);
 
     let default_permissions = match &attributes.default_permissions {
         Some(path) => quote! { ::std::option::Option::Some(#path())},
@@ -55,10 +83,36 @@ pub fn impl_create_command(input: DeriveInput, fields: Option<FieldsNamed>) -> R
     };
     let dm_permission = optional(attributes.dm_permission);
     let nsfw = optional(attributes.nsfw);
+    let timeout = optional(attributes.timeout);
+    let cooldown = optional(attributes.cooldown);
+    let guild_only = attributes.guild_only.unwrap_or_default();
+    let owner_only = attributes.owner_only.unwrap_or_default();
+    let category_const = optional(attributes.category.as_ref());
+    let category = optional(
+        attributes
+            .category
+            .as_ref()
+            .map(|category| quote! { ::std::string::String::from(#category) }),
+    );
+    let metadata = metadata_expr(&attributes.metadata);
+    let generated_tests = generated_tests(&input, attributes.generate_tests, attr_span)?;
+    let names_module = attributes.names_module.then(|| {
+        names_module(
+            &input,
+            NamesKind::Options,
+            fields
+                .iter()
+                .filter_map(|field| match field.attributes.name(&field.ident) {
+                    ConstOr::Value(name) => Some((name, &field.ident)),
+                    ConstOr::Const(_) => None,
+                }),
+        )
+    });
 
+    let autocomplete_all = attributes.autocomplete_all.unwrap_or_default();
     let field_options = fields
         .iter()
-        .map(field_option)
+        .map(|field| field_option(field, autocomplete_all))
         .collect::<Result<Vec<_>>>()?;
 
     let contexts = if let Some(items) = attributes.contexts {
@@ -76,10 +130,17 @@ pub fn impl_create_command(input: DeriveInput, fields: Option<FieldsNamed>) -> R
     };
 
     Ok(quote! {
-        impl #generics ::twilight_interactions::command::CreateCommand for #ident #generics #where_clause {
+        impl #impl_generics ::twilight_interactions::command::CreateCommand for #ident #ty_generics #where_clause {
             const NAME: &'static str = #name;
+            const DESCRIPTION: &'static str = #desc_const;
+            const NSFW: bool = #nsfw_const;
+            const CATEGORY: ::std::option::Option<&'static str> = #category_const;
 
             fn create_command() -> ::twilight_interactions::command::ApplicationCommandData {
+                ::twilight_interactions::command::internal::localize_global(Self::create_command_base())
+            }
+
+            fn create_command_base() -> ::twilight_interactions::command::ApplicationCommandData {
                 let mut __command_options = ::std::vec::Vec::with_capacity(#capacity);
 
                 #(#field_options)*
@@ -99,18 +160,27 @@ pub fn impl_create_command(input: DeriveInput, fields: Option<FieldsNamed>) -> R
                     group: false,
                     contexts: #contexts,
                     integration_types: #integration_types,
+                    timeout: #timeout,
+                    cooldown: #cooldown,
+                    guild_only: #guild_only,
+                    owner_only: #owner_only,
+                    category: #category,
+                    metadata: #metadata,
                 }
             }
         }
+
+        #generated_tests
+        #names_module
     })
 }
 
 /// Generate field option code
-fn field_option(field: &StructField) -> Result<TokenStream> {
+fn field_option(field: &StructField, autocomplete_all: bool) -> Result<TokenStream> {
     let ty = &field.ty;
     let span = field.span;
 
-    let name = field.attributes.name_default(field.ident.to_string());
+    let name = field.attributes.name_checked(&field.ident);
     let name_expr = name_expr(&name, &field.attributes.name_localizations);
 
     let desc_expr = description_expr(
@@ -121,39 +191,73 @@ fn field_option(field: &StructField) -> Result<TokenStream> {
 
     let required = field.kind.required();
     let autocomplete = field.attributes.autocomplete;
-    let max_value = command_option_value(field.attributes.max_value);
-    let min_value = command_option_value(field.attributes.min_value);
-    let max_length = optional(field.attributes.max_length);
-    let min_length = optional(field.attributes.min_length);
+    let max_value = command_option_value(field.attributes.max_value.as_ref());
+    let min_value = command_option_value(field.attributes.min_value.as_ref());
+    let (min_length, max_length) = field.attributes.length_checked();
+    let format = optional(field.attributes.format.as_deref());
 
-    let channel_types = if field.attributes.channel_types.is_empty() {
-        quote! { ::std::option::Option::None }
+    let channel_types = channel_types_expr(&field.attributes.channel_types);
+
+    let create_option = match (&field.attributes.with, &field.attributes.option_type) {
+        (None, _) => quote_spanned! {span=>
+            <#ty as ::twilight_interactions::command::CreateOption>::create_option(__option_data)
+        },
+        (Some(_), Some(kind)) => {
+            let kind = option_type(kind);
+            quote_spanned!(span=> __option_data.into_option(#kind))
+        }
+        (Some(_), None) => {
+            return Err(Error::new(
+                span,
+                "fields using `with` require the `option_type` attribute",
+            ))
+        }
+    };
+
+    let set_choices = field.attributes.choices.as_ref().map(|choices| {
+        quote_spanned!(span=> __option.choices = ::std::option::Option::Some(#choices());)
+    });
+
+    // The option type is only known at runtime
+    let enable_autocomplete = autocomplete_all.then(|| {
+        quote_spanned! {span=>
+            ::twilight_interactions::command::internal::enable_autocomplete(&mut __option);
+        }
+    });
+
+    let push_option = if set_choices.is_some() || enable_autocomplete.is_some() {
+        quote_spanned! {span=>
+            let mut __option = #create_option;
+            #set_choices
+            #enable_autocomplete
+            __command_options.push(__option);
+        }
     } else {
-        let items = field.attributes.channel_types.iter().map(channel_type);
-        quote! { ::std::option::Option::Some(::std::vec![#(#items),*]) }
+        quote_spanned!(span=> __command_options.push(#create_option);)
     };
 
     Ok(quote_spanned! {span => {
         let __field_desc = #desc_expr;
         let __field_name = #name_expr;
 
-        __command_options.push(<#ty as ::twilight_interactions::command::CreateOption>::create_option(
-            ::twilight_interactions::command::internal::CreateOptionData {
-                name: __field_name.fallback,
-                name_localizations: __field_name.localizations,
-                description: __field_desc.fallback,
-                description_localizations: __field_desc.localizations,
-                required: ::std::option::Option::Some(#required),
-                autocomplete: #autocomplete,
-                data: ::twilight_interactions::command::internal::CommandOptionData {
-                    channel_types: #channel_types,
-                    max_value: #max_value,
-                    min_value: #min_value,
-                    max_length: #max_length,
-                    min_length: #min_length,
-                },
-            }
-        ));
+        let __option_data = ::twilight_interactions::command::internal::CreateOptionData {
+            name: __field_name.fallback,
+            name_localizations: __field_name.localizations,
+            description: __field_desc.fallback,
+            description_localizations: __field_desc.localizations,
+            required: ::std::option::Option::Some(#required),
+            autocomplete: #autocomplete,
+            data: ::twilight_interactions::command::internal::CommandOptionData {
+                channel_types: #channel_types,
+                max_value: #max_value,
+                min_value: #min_value,
+                max_length: #max_length,
+                min_length: #min_length,
+                format: #format,
+            },
+        };
+
+        #push_option
     }})
 }
 
@@ -169,7 +273,7 @@ fn check_fields_order(fields: &[StructField]) -> Result<()> {
         if optional_option_added && field.kind.required() {
             return Err(Error::new(
                 field.span,
-                "required options should be added before optional",
+                "required options must be placed before optional ones (move the field or set its `order` attribute)",
             ));
         }
     }
@@ -2,8 +2,10 @@
 //! named fields.
 
 mod command_model;
+mod create_autocomplete;
 mod create_command;
 mod parse;
 
-pub use command_model::impl_command_model;
+pub use command_model::{impl_command_model, impl_command_model_ref};
+pub use create_autocomplete::impl_create_autocomplete;
 pub use create_command::impl_create_command;
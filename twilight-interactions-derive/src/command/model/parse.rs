@@ -1,15 +1,18 @@
 //! Parsing of struct fields and attributes
 
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote;
-use syn::{spanned::Spanned, Attribute, Error, Lit, Result, Type};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{spanned::Spanned, Attribute, Error, Lit, Path, Result, Type};
 
 use crate::{
     command::user_application::{ApplicationIntegrationType, InteractionContextType},
     parse::{
         attribute::{NamedAttrs, ParseAttribute, ParseSpanned},
-        parsers::{CommandDescription, CommandName, FunctionPath},
-        syntax::{extract_generic, find_attr},
+        parsers::{
+            CommandCooldown, CommandDescription, CommandName, CommandRenameRule, CommandTimeout,
+            ConstOr, FunctionPath, RenameRule,
+        },
+        syntax::{check_unique, extract_generic, find_attr, optional},
     },
 };
 
@@ -33,7 +36,10 @@ pub enum FieldType {
 
 impl StructField {
     /// Parse a [`syn::Field`] as a [`StructField`]
-    pub fn from_field(field: syn::Field) -> Result<Self> {
+    ///
+    /// The rename rule is applied to the name of the field if it has no
+    /// `rename` attribute.
+    pub fn from_field(field: syn::Field, rename_all: Option<RenameRule>) -> Result<Self> {
         let (kind, ty) = match extract_generic(&field.ty, "Option") {
             Some(ty) => match extract_generic(&ty, "AutocompleteValue") {
                 Some(_) => {
@@ -50,11 +56,25 @@ impl StructField {
             },
         };
 
-        let attributes = match find_attr(&field.attrs, "command") {
-            Some(attr) => FieldAttribute::parse(attr)?,
+        let mut attributes = match find_attr(&field.attrs, "command") {
+            Some(attr) => FieldAttribute::parse(attr, &ty)?,
             None => FieldAttribute::default(),
         };
 
+        if attributes.ctx && kind == FieldType::Autocomplete {
+            return Err(Error::new_spanned(
+                &field.ty,
+                "`AutocompleteValue` cannot be used with the `ctx` attribute",
+            ));
+        }
+
+        if attributes.rest && kind != FieldType::Required {
+            return Err(Error::new_spanned(
+                &field.ty,
+                "fields with the `rest` attribute cannot be optional",
+            ));
+        }
+
         let Some(ident) = field.ident else {
             return Err(Error::new_spanned(
                 field,
@@ -62,6 +82,11 @@ impl StructField {
             ));
         };
 
+        if let (None, Some(rule)) = (&attributes.rename, rename_all) {
+            let name = rule.apply_snake(&ident.to_string());
+            attributes.rename = Some(ConstOr::Value(CommandName::new(&name, ident.span())?));
+        }
+
         Ok(Self {
             span: field.ty.span(),
             ident,
@@ -72,9 +97,37 @@ impl StructField {
         })
     }
 
+    /// Whether the field is parsed from a command option
+    pub fn is_option(&self) -> bool {
+        !self.attributes.ctx && !self.attributes.rest
+    }
+
     /// Parse [`syn::FieldsNamed`] as a [`Vec<StructField>`]
-    pub fn from_fields(fields: syn::FieldsNamed) -> Result<Vec<Self>> {
-        fields.named.into_iter().map(Self::from_field).collect()
+    ///
+    /// An error is returned if several options have the same name.
+    pub fn from_fields(
+        fields: syn::FieldsNamed,
+        rename_all: Option<RenameRule>,
+    ) -> Result<Vec<Self>> {
+        let fields = fields
+            .named
+            .into_iter()
+            .map(|field| Self::from_field(field, rename_all))
+            .collect::<Result<Vec<_>>>()?;
+
+        check_unique(
+            fields
+                .iter()
+                .filter(|field| field.is_option())
+                // Names provided as constants cannot be compared by the macro
+                .filter_map(|field| match field.attributes.name(&field.ident) {
+                    ConstOr::Value(name) => Some((name, field.ident.span())),
+                    ConstOr::Const(_) => None,
+                }),
+            "option name",
+        )?;
+
+        Ok(fields)
     }
 }
 
@@ -92,11 +145,11 @@ pub struct TypeAttribute {
     /// Whether the model is an autocomplete interaction model.
     pub autocomplete: Option<bool>,
     /// Command name.
-    pub name: Option<CommandName>,
+    pub name: Option<ConstOr<CommandName>>,
     /// Localization dictionary for the command name.
     pub name_localizations: Option<FunctionPath>,
     /// Command description.
-    pub desc: Option<CommandDescription>,
+    pub desc: Option<ConstOr<CommandDescription>>,
     /// Localization dictionary for the command description.
     pub desc_localizations: Option<FunctionPath>,
     /// Default permissions required for a member to run the command.
@@ -109,6 +162,31 @@ pub struct TypeAttribute {
     pub contexts: Option<Vec<InteractionContextType>>,
     /// Installation contexts where the command is available.
     pub integration_types: Option<Vec<ApplicationIntegrationType>>,
+    /// Maximum execution time of the command handler.
+    pub timeout: Option<CommandTimeout>,
+    /// Cooldown of the command.
+    pub cooldown: Option<CommandCooldown>,
+    /// Whether the command can only be used in guilds.
+    pub guild_only: Option<bool>,
+    /// Whether the command can only be used by the owners of the bot.
+    pub owner_only: Option<bool>,
+    /// Category of the command.
+    pub category: Option<String>,
+    /// Whether to generate unit tests for the command.
+    pub generate_tests: Option<bool>,
+    /// Whether to enable autocomplete on all eligible fields.
+    pub autocomplete_all: Option<bool>,
+    /// Whether to reject unknown options in autocomplete models.
+    pub deny_unknown_fields: Option<bool>,
+    /// Rename rule applied to option names.
+    pub rename_all: Option<CommandRenameRule>,
+    /// Entries of the `meta` attribute.
+    pub metadata: Vec<(String, String)>,
+    /// Whether to reject names of built-in Discord commands.
+    pub deny_reserved_name: bool,
+    /// Whether to generate the module with the names of options or
+    /// subcommands.
+    pub names_module: bool,
 }
 
 impl TypeAttribute {
@@ -123,12 +201,24 @@ impl TypeAttribute {
         "nsfw",
         "contexts",
         "integration_types",
+        "timeout",
+        "cooldown",
+        "guild_only",
+        "owner_only",
+        "category",
+        "generate_tests",
+        "deny_reserved_name",
+        "names_module",
+        "autocomplete_all",
+        "deny_unknown_fields",
+        "rename_all",
+        "meta",
+        "crate",
     ];
 
     pub fn parse(attr: &Attribute) -> Result<Self> {
         let mut parser = NamedAttrs::parse(attr, Self::VALID_ATTRIBUTES)?;
-
-        Ok(Self {
+        let attribute = Self {
             autocomplete: parser.optional("autocomplete")?,
             name: parser.optional("name")?,
             name_localizations: parser.optional("name_localizations")?,
@@ -139,7 +229,33 @@ impl TypeAttribute {
             nsfw: parser.optional("nsfw")?,
             contexts: parser.optional("contexts")?,
             integration_types: parser.optional("integration_types")?,
-        })
+            timeout: parser.optional("timeout")?,
+            cooldown: parser.optional("cooldown")?,
+            guild_only: parser.optional("guild_only")?,
+            owner_only: parser.optional("owner_only")?,
+            category: parser.optional("category")?,
+            generate_tests: parser.optional("generate_tests")?,
+            metadata: parser.metadata()?,
+            deny_reserved_name: parser.optional("deny_reserved_name")?.unwrap_or_default(),
+            names_module: parser.optional("names_module")?.unwrap_or_default(),
+            autocomplete_all: parser.optional("autocomplete_all")?,
+            deny_unknown_fields: parser.optional("deny_unknown_fields")?,
+            rename_all: parser.optional("rename_all")?,
+        };
+
+        if let Some(ConstOr::Value(name)) = &attribute.name {
+            name.check_reserved(attribute.deny_reserved_name, attr.span())?;
+        }
+
+        Ok(attribute)
+    }
+
+    /// Get the rename rule of option names from the type attributes.
+    pub fn rename_all(attrs: &[Attribute]) -> Result<Option<RenameRule>> {
+        match find_attr(attrs, "command") {
+            Some(attr) => Ok(Self::parse(attr)?.rename_all.map(|rule| rule.0)),
+            None => Ok(None),
+        }
     }
 }
 
@@ -147,25 +263,43 @@ impl TypeAttribute {
 #[derive(Default)]
 pub struct FieldAttribute {
     /// Rename the field to the given name
-    pub rename: Option<CommandName>,
+    pub rename: Option<ConstOr<CommandName>>,
     /// Localization dictionary for the field name.
     pub name_localizations: Option<FunctionPath>,
     /// Overwrite the field description
-    pub desc: Option<CommandDescription>,
+    pub desc: Option<ConstOr<CommandDescription>>,
     /// Localization dictionary for the command description.
     pub desc_localizations: Option<FunctionPath>,
     /// Whether the field supports autocomplete
     pub autocomplete: bool,
+    /// Function providing autocomplete suggestions for the field
+    pub autocomplete_with: Option<FunctionPath>,
+    /// Function returning the choices of the option
+    pub choices: Option<FunctionPath>,
     /// Limit to specific channel types
-    pub channel_types: Vec<ChannelType>,
+    pub channel_types: Option<ChannelTypes>,
     /// Maximum value permitted
-    pub max_value: Option<CommandOptionValue>,
+    pub max_value: Option<BoundValue>,
     /// Minimum value permitted
-    pub min_value: Option<CommandOptionValue>,
+    pub min_value: Option<BoundValue>,
     /// Maximum string length
-    pub max_length: Option<u16>,
+    pub max_length: Option<ConstOr<u16>>,
     /// Minimum string length
-    pub min_length: Option<u16>,
+    pub min_length: Option<ConstOr<u16>>,
+    /// Format used to parse date and time values
+    pub format: Option<String>,
+    /// Function used to parse the option instead of the `CommandOption` trait
+    pub with: Option<FunctionPath>,
+    /// Type of the created option when using a custom parsing function
+    pub option_type: Option<OptionType>,
+    /// Function used to validate the parsed value
+    pub validate: Option<FunctionPath>,
+    /// Position of the option relative to other options
+    pub order: Option<i32>,
+    /// Whether the field is filled from the interaction
+    pub ctx: bool,
+    /// Whether the field collects unknown options
+    pub rest: bool,
 }
 
 impl FieldAttribute {
@@ -175,35 +309,206 @@ impl FieldAttribute {
         "desc",
         "desc_localizations",
         "autocomplete",
+        "autocomplete_with",
+        "choices",
         "channel_types",
         "max_value",
         "min_value",
         "max_length",
         "min_length",
+        "format",
+        "with",
+        "option_type",
+        "validate",
+        "order",
+        "ctx",
+        "rest",
     ];
 
+    /// Maximum value of the `min_length` and `max_length` attributes.
+    const MAX_LENGTH: u16 = 6000;
+
     /// Parse a single [`Attribute`]
-    pub fn parse(attr: &Attribute) -> Result<Self> {
+    ///
+    /// The type of the field is used to check that `min_value` and `max_value`
+    /// bounds have the right type.
+    pub fn parse(attr: &Attribute, ty: &Type) -> Result<Self> {
         let mut parser = NamedAttrs::parse(attr, Self::VALID_ATTRIBUTES)?;
 
+        let ctx = Self::parse_exclusive(&mut parser, "ctx")?;
+        let rest = Self::parse_exclusive(&mut parser, "rest")?;
+
+        let max_value: Option<ParseSpanned<ConstOr<CommandOptionValue>>> =
+            parser.optional("max_value")?;
+        let min_value: Option<ParseSpanned<ConstOr<CommandOptionValue>>> =
+            parser.optional("min_value")?;
+        let max_length: Option<ParseSpanned<ConstOr<u16>>> = parser.optional("max_length")?;
+        let min_length: Option<ParseSpanned<ConstOr<u16>>> = parser.optional("min_length")?;
+
+        let value_kind = ValueKind::from_type(ty);
+        let max_value = max_value.map(|value| value_kind.bound(value)).transpose()?;
+        let min_value = min_value.map(|value| value_kind.bound(value)).transpose()?;
+
+        if let (Some(BoundValue::Literal(min)), Some(BoundValue::Literal(max))) =
+            (&min_value, &max_value)
+        {
+            if min.inner.as_f64() > max.inner.as_f64() {
+                return Err(min.error("`min_value` must be less than or equal to `max_value`"));
+            }
+        }
+
+        for length in max_length.iter().chain(&min_length) {
+            if let ConstOr::Value(value) = length.inner {
+                if value > Self::MAX_LENGTH {
+                    return Err(length.error(format!(
+                        "length must be less than or equal to {}",
+                        Self::MAX_LENGTH
+                    )));
+                }
+            }
+        }
+
+        if let (Some(min), Some(max)) = (&min_length, &max_length) {
+            if let (ConstOr::Value(min_inner), ConstOr::Value(max_inner)) = (&min.inner, &max.inner)
+            {
+                if min_inner > max_inner {
+                    return Err(
+                        min.error("`min_length` must be less than or equal to `max_length`")
+                    );
+                }
+            }
+        }
+
+        let with: Option<FunctionPath> = parser.optional("with")?;
+        let option_type: Option<ParseSpanned<OptionType>> = parser.optional("option_type")?;
+
+        if let (None, Some(option_type)) = (&with, &option_type) {
+            return Err(option_type.error("`option_type` can only be used with `with`"));
+        }
+
+        let autocomplete: Option<ParseSpanned<Autocomplete>> = parser.optional("autocomplete")?;
+        let autocomplete_with: Option<FunctionPath> = parser.optional("autocomplete_with")?;
+
+        let (autocomplete, autocomplete_with) = match (autocomplete, autocomplete_with) {
+            (None, with) => (with.is_some(), with),
+            (Some(spanned), with) => match (spanned.inner, with) {
+                (Autocomplete::Enabled(false), Some(_)) => {
+                    return Err(Error::new(
+                        spanned.span,
+                        "`autocomplete_with` requires autocomplete",
+                    ))
+                }
+                (Autocomplete::With(_), Some(_)) => {
+                    return Err(Error::new(
+                        spanned.span,
+                        "`autocomplete_with` cannot be used with an `autocomplete` function",
+                    ))
+                }
+                (Autocomplete::Enabled(enabled), with) => (enabled || with.is_some(), with),
+                (Autocomplete::With(path), None) => (true, Some(path)),
+            },
+        };
+
+        let choices: Option<ParseSpanned<FunctionPath>> = parser.optional("choices")?;
+        if let (Some(choices), true) = (&choices, autocomplete) {
+            return Err(choices.error("`choices` cannot be used with autocomplete"));
+        }
+
         Ok(Self {
             rename: parser.optional("rename")?,
             name_localizations: parser.optional("name_localizations")?,
             desc: parser.optional("desc")?,
             desc_localizations: parser.optional("desc_localizations")?,
-            autocomplete: parser.optional("autocomplete")?.unwrap_or_default(),
-            channel_types: parser.optional("channel_types")?.unwrap_or_default(),
-            max_value: parser.optional("max_value")?,
-            min_value: parser.optional("min_value")?,
-            max_length: parser.optional("max_length")?,
-            min_length: parser.optional("min_length")?,
+            autocomplete,
+            autocomplete_with,
+            choices: choices.map(|choices| choices.inner),
+            channel_types: parser.optional("channel_types")?,
+            max_value,
+            min_value,
+            max_length: max_length.map(|length| length.inner),
+            min_length: min_length.map(|length| length.inner),
+            format: parser.optional("format")?,
+            with,
+            option_type: option_type.map(|option_type| option_type.inner),
+            validate: parser.optional("validate")?,
+            order: parser.optional("order")?,
+            ctx,
+            rest,
         })
     }
 
-    pub fn name_default(&self, default: String) -> String {
+    /// Parse a flag that cannot be combined with other attributes
+    fn parse_exclusive(parser: &mut NamedAttrs, name: &str) -> Result<bool> {
+        let flag: Option<ParseSpanned<bool>> = parser.optional(name)?;
+
+        match flag {
+            Some(flag) if flag.inner && !parser.is_empty() => {
+                Err(flag.error(format!("`{name}` cannot be combined with other attributes")))
+            }
+            Some(flag) => Ok(flag.inner),
+            None => Ok(false),
+        }
+    }
+
+    /// Get the name of the option, which defaults to the field identifier.
+    ///
+    /// The returned value can be used both as an expression and a pattern.
+    pub fn name(&self, ident: &Ident) -> ConstOr<String> {
         match &self.rename {
-            Some(name) => name.clone().into(),
-            None => default,
+            Some(ConstOr::Value(name)) => ConstOr::Value(name.clone().into()),
+            Some(ConstOr::Const(path)) => ConstOr::Const(path.clone()),
+            None => ConstOr::Value(ident.to_string()),
+        }
+    }
+
+    /// Generate the name of the option, checking constants at compile time.
+    pub fn name_checked(&self, ident: &Ident) -> TokenStream {
+        match &self.rename {
+            Some(name) => name.checked(),
+            None => ident.to_string().to_token_stream(),
+        }
+    }
+
+    /// Generate the `min_length` and `max_length` of the option, checking
+    /// constants at compile time.
+    pub fn length_checked(&self) -> (TokenStream, TokenStream) {
+        let min = self.min_length.as_ref().map(ConstOr::<u16>::checked);
+        let max = self.max_length.as_ref().map(ConstOr::<u16>::checked);
+
+        // Literal bounds are already compared by the macro
+        let range_check = match (&self.min_length, &self.max_length) {
+            (Some(ConstOr::Value(_)), Some(ConstOr::Value(_))) | (None, _) | (_, None) => None,
+            (Some(min), Some(max)) => Some(quote! {
+                const _: () = ::twilight_interactions::command::internal::check_length_range(#min, #max);
+            }),
+        };
+
+        let min = match (min, range_check) {
+            (Some(min), Some(range_check)) => Some(quote!({ #range_check #min })),
+            (min, _) => min,
+        };
+
+        (optional(min), optional(max))
+    }
+}
+
+/// Value of the `autocomplete` field attribute
+pub enum Autocomplete {
+    /// Whether autocomplete is enabled
+    Enabled(bool),
+    /// Function providing autocomplete suggestions, which enables autocomplete
+    With(FunctionPath),
+}
+
+impl ParseAttribute for Autocomplete {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        match input {
+            Lit::Bool(lit) => Ok(Self::Enabled(lit.value)),
+            Lit::Str(_) => FunctionPath::parse_attribute(input).map(Self::With),
+            _ => Err(Error::new_spanned(
+                input,
+                "expected boolean or string literal",
+            )),
         }
     }
 }
@@ -216,7 +521,6 @@ pub enum ChannelType {
     Group,
     GuildCategory,
     GuildAnnouncement,
-    GuildStore,
     AnnouncementThread,
     PublicThread,
     PrivateThread,
@@ -226,7 +530,17 @@ pub enum ChannelType {
     GuildMedia,
 }
 
-impl ParseAttribute for Vec<ChannelType> {
+/// Value of the `channel_types` attribute
+pub enum ChannelTypes {
+    /// Channel type names separated by spaces, like `"guild_text private"`
+    Names(Vec<ChannelType>),
+    /// List of `ChannelType` variants, like `channel_types(GuildText, Private)`
+    Variants(Vec<Path>),
+    /// Path to a constant slice of channel types
+    Const(Path),
+}
+
+impl ParseAttribute for ChannelTypes {
     fn parse_attribute(input: Lit) -> Result<Self> {
         let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
 
@@ -234,7 +548,16 @@ impl ParseAttribute for Vec<ChannelType> {
             .inner
             .split_ascii_whitespace()
             .map(|value| ChannelType::parse(value, spanned.span))
-            .collect()
+            .collect::<Result<_>>()
+            .map(Self::Names)
+    }
+
+    fn parse_path(path: Path) -> Result<Self> {
+        Ok(Self::Const(path))
+    }
+
+    fn parse_list(paths: Vec<Path>, _span: Span) -> Result<Self> {
+        Ok(Self::Variants(paths))
     }
 }
 
@@ -247,7 +570,10 @@ impl ChannelType {
             "group" => Ok(Self::Group),
             "guild_category" => Ok(Self::GuildCategory),
             "guild_announcement" | "guild_news" => Ok(Self::GuildAnnouncement),
-            "guild_store" => Ok(Self::GuildStore),
+            "guild_store" => Err(Error::new(
+                span,
+                "store channels are no longer supported by Discord",
+            )),
             "announcement_thread" | "guild_news_thread" => Ok(Self::AnnouncementThread),
             "public_thread" | "guild_public_thread" => Ok(Self::PublicThread),
             "private_thread" | "guild_private_thread" => Ok(Self::PrivateThread),
@@ -263,6 +589,38 @@ impl ChannelType {
     }
 }
 
+/// Parsed command option type
+pub enum OptionType {
+    String,
+    Integer,
+    Boolean,
+    User,
+    Channel,
+    Role,
+    Mentionable,
+    Number,
+    Attachment,
+}
+
+impl ParseAttribute for OptionType {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
+
+        match &*spanned.inner {
+            "string" => Ok(Self::String),
+            "integer" => Ok(Self::Integer),
+            "boolean" => Ok(Self::Boolean),
+            "user" => Ok(Self::User),
+            "channel" => Ok(Self::Channel),
+            "role" => Ok(Self::Role),
+            "mentionable" => Ok(Self::Mentionable),
+            "number" => Ok(Self::Number),
+            "attachment" => Ok(Self::Attachment),
+            invalid => Err(spanned.error(format!("`{invalid}` is not a valid option type"))),
+        }
+    }
+}
+
 /// Parsed command option value
 #[derive(Clone, Copy)]
 pub enum CommandOptionValue {
@@ -283,8 +641,107 @@ impl ParseAttribute for CommandOptionValue {
     }
 }
 
+impl CommandOptionValue {
+    fn as_f64(self) -> f64 {
+        match self {
+            Self::Integer(inner) => inner as f64,
+            Self::Number(inner) => inner,
+        }
+    }
+}
+
+/// Kind of value expected by a field, used to check `min_value` and `max_value`
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ValueKind {
+    Integer,
+    Number,
+    /// The kind cannot be inferred from the type (e.g. custom option types)
+    Unknown,
+}
+
+impl ValueKind {
+    fn from_type(ty: &Type) -> Self {
+        let Type::Path(path) = ty else {
+            return Self::Unknown;
+        };
+
+        match path.path.segments.last() {
+            Some(segment)
+                if ["i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64"]
+                    .iter()
+                    .any(|ty| segment.ident == ty) =>
+            {
+                Self::Integer
+            }
+            Some(segment) if segment.ident == "NonZeroI64" => Self::Integer,
+            Some(segment) if segment.ident == "NonZeroU64" => Self::Integer,
+            Some(segment) if segment.ident == "f64" => Self::Number,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Check the type of a `max_value` or `min_value` attribute.
+    fn bound(self, value: ParseSpanned<ConstOr<CommandOptionValue>>) -> Result<BoundValue> {
+        let span = value.span;
+
+        match (self, value.inner) {
+            (Self::Integer, ConstOr::Value(CommandOptionValue::Number(_))) => Err(Error::new(
+                span,
+                "expected integer literal for an integer field",
+            )),
+            (Self::Number, ConstOr::Value(CommandOptionValue::Integer(_))) => Err(Error::new(
+                span,
+                "expected floating point literal for a number field",
+            )),
+            (_, ConstOr::Value(inner)) => Ok(BoundValue::Literal(ParseSpanned { span, inner })),
+            (Self::Integer, ConstOr::Const(path)) => Ok(BoundValue::Integer(path)),
+            (Self::Number, ConstOr::Const(path)) => Ok(BoundValue::Number(path)),
+            (Self::Unknown, ConstOr::Const(_)) => Err(Error::new(
+                span,
+                "constants can only be used on integer or `f64` fields",
+            )),
+        }
+    }
+}
+
+/// Value of the `max_value` and `min_value` attributes
+pub enum BoundValue {
+    Literal(ParseSpanned<CommandOptionValue>),
+    /// Path to an integer constant
+    Integer(Path),
+    /// Path to a floating point constant
+    Number(Path),
+}
+
+/// Generate the value of the `channel_types` field of command options
+pub fn channel_types_expr(channel_types: &Option<ChannelTypes>) -> TokenStream {
+    let channel_types = match channel_types {
+        None => return quote!(::std::option::Option::None),
+        Some(ChannelTypes::Names(names)) if names.is_empty() => {
+            return quote!(::std::option::Option::None)
+        }
+        Some(ChannelTypes::Names(names)) => {
+            let items = names.iter().map(channel_type);
+            quote!(::std::vec![#(#items),*])
+        }
+        Some(ChannelTypes::Variants(paths)) => {
+            // Single identifiers are resolved as `ChannelType` variants
+            let items = paths.iter().map(|path| match path.get_ident() {
+                Some(ident) => quote!(::twilight_model::channel::ChannelType::#ident),
+                None => quote!(#path),
+            });
+            quote!(::std::vec![#(#items),*])
+        }
+        Some(ChannelTypes::Const(path)) => quote_spanned! {path.span()=>
+            <[::twilight_model::channel::ChannelType]>::to_vec(&#path)
+        },
+    };
+
+    quote!(::std::option::Option::Some(#channel_types))
+}
+
 /// Convert a [`ChannelType`] into a [`TokenStream`]
-pub fn channel_type(kind: &ChannelType) -> TokenStream {
+fn channel_type(kind: &ChannelType) -> TokenStream {
     match kind {
         ChannelType::GuildText => quote!(::twilight_model::channel::ChannelType::GuildText),
         ChannelType::Private => quote!(::twilight_model::channel::ChannelType::Private),
@@ -294,7 +751,6 @@ pub fn channel_type(kind: &ChannelType) -> TokenStream {
         ChannelType::GuildAnnouncement => {
             quote!(::twilight_model::channel::ChannelType::GuildAnnouncement)
         }
-        ChannelType::GuildStore => quote!(::twilight_model::channel::ChannelType::GuildStore),
         ChannelType::AnnouncementThread => {
             quote!(::twilight_model::channel::ChannelType::AnnouncementThread)
         }
@@ -315,15 +771,50 @@ pub fn channel_type(kind: &ChannelType) -> TokenStream {
     }
 }
 
-/// Convert a [`Option<CommandOptionValue>`] into a [`TokenStream`]
-pub fn command_option_value(value: Option<CommandOptionValue>) -> TokenStream {
-    match value {
-        None => quote!(::std::option::Option::None),
-        Some(CommandOptionValue::Integer(inner)) => {
-            quote!(::std::option::Option::Some(::twilight_model::application::command::CommandOptionValue::Integer(#inner)))
+/// Convert a [`OptionType`] into a [`TokenStream`]
+pub fn option_type(kind: &OptionType) -> TokenStream {
+    match kind {
+        OptionType::String => {
+            quote!(::twilight_model::application::command::CommandOptionType::String)
+        }
+        OptionType::Integer => {
+            quote!(::twilight_model::application::command::CommandOptionType::Integer)
+        }
+        OptionType::Boolean => {
+            quote!(::twilight_model::application::command::CommandOptionType::Boolean)
         }
-        Some(CommandOptionValue::Number(inner)) => {
-            quote!(::std::option::Option::Some(::twilight_model::application::command::CommandOptionValue::Number(#inner)))
+        OptionType::User => quote!(::twilight_model::application::command::CommandOptionType::User),
+        OptionType::Channel => {
+            quote!(::twilight_model::application::command::CommandOptionType::Channel)
+        }
+        OptionType::Role => quote!(::twilight_model::application::command::CommandOptionType::Role),
+        OptionType::Mentionable => {
+            quote!(::twilight_model::application::command::CommandOptionType::Mentionable)
+        }
+        OptionType::Number => {
+            quote!(::twilight_model::application::command::CommandOptionType::Number)
+        }
+        OptionType::Attachment => {
+            quote!(::twilight_model::application::command::CommandOptionType::Attachment)
         }
     }
 }
+
+/// Convert a [`Option<BoundValue>`] into a [`TokenStream`]
+pub fn command_option_value(value: Option<&BoundValue>) -> TokenStream {
+    let (variant, inner) = match value {
+        None => return quote!(::std::option::Option::None),
+        Some(BoundValue::Literal(value)) => match value.inner {
+            CommandOptionValue::Integer(inner) => (quote!(Integer), quote!(#inner)),
+            CommandOptionValue::Number(inner) => (quote!(Number), quote!(#inner)),
+        },
+        Some(BoundValue::Integer(path)) => {
+            (quote!(Integer), quote!(::std::convert::From::from(#path)))
+        }
+        Some(BoundValue::Number(path)) => {
+            (quote!(Number), quote!(::std::convert::From::from(#path)))
+        }
+    };
+
+    quote!(::std::option::Option::Some(::twilight_model::application::command::CommandOptionValue::#variant(#inner)))
+}
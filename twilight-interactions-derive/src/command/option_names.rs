@@ -0,0 +1,94 @@
+//! Generation of the module with the option names of a command.
+
+use std::collections::HashSet;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::DeriveInput;
+
+use crate::parse::parsers::RenameRule;
+
+/// Kind of names exposed by the generated module.
+#[derive(Clone, Copy)]
+pub enum NamesKind {
+    /// Option names of a struct model, in an `options` module.
+    Options,
+    /// Subcommand names of an enum model, in a `subcommands` module.
+    Subcommands,
+}
+
+/// Generate the companion module of a command containing a constant for each
+/// option or subcommand name.
+///
+/// The module is named after the type in `snake_case` and has the same
+/// visibility. Constants are named after the option names in
+/// `SCREAMING_SNAKE_CASE`, or after the field or variant identifier if the
+/// name is not a valid identifier. Names provided as constants are omitted,
+/// since the constant can be used directly.
+pub fn names_module<'a>(
+    input: &DeriveInput,
+    kind: NamesKind,
+    names: impl IntoIterator<Item = (String, &'a Ident)>,
+) -> TokenStream {
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let module = format_ident!("{}", RenameRule::Snake.apply(&unraw(ident)));
+    let (inner, item) = match kind {
+        NamesKind::Options => (format_ident!("options"), "option"),
+        NamesKind::Subcommands => (format_ident!("subcommands"), "subcommand"),
+    };
+
+    let module_doc = format!("Names of the {item}s of the `{ident}` command.");
+    let mut used = HashSet::new();
+    let consts = names.into_iter().filter_map(|(name, ident)| {
+        let const_ident = [const_name(&name), Some(fallback_name(ident, kind))]
+            .into_iter()
+            .flatten()
+            .find(|const_ident| !used.contains(const_ident))?;
+        used.insert(const_ident.clone());
+
+        let doc = format!("Name of the `{name}` {item}.");
+        Some(quote! {
+            #[doc = #doc]
+            pub const #const_ident: &str = #name;
+        })
+    });
+
+    // Items of the module are public, their visibility is restricted by the
+    // module itself
+    quote! {
+        #[doc = #module_doc]
+        #[allow(dead_code)]
+        #vis mod #module {
+            #[doc = #module_doc]
+            pub mod #inner {
+                #(#consts)*
+            }
+        }
+    }
+}
+
+/// Constant name derived from an option name, if it is a valid identifier.
+fn const_name(name: &str) -> Option<Ident> {
+    let const_name = name.replace('-', "_").to_uppercase();
+    syn::parse_str::<Ident>(&const_name).ok()
+}
+
+/// Constant name derived from the field or variant identifier.
+fn fallback_name(ident: &Ident, kind: NamesKind) -> Ident {
+    let name = match kind {
+        NamesKind::Options => unraw(ident).to_uppercase(),
+        NamesKind::Subcommands => RenameRule::ScreamingSnake.apply(&unraw(ident)),
+    };
+
+    Ident::new(&name, Span::call_site())
+}
+
+/// Identifier without the `r#` prefix of raw identifiers.
+fn unraw(ident: &Ident) -> String {
+    let ident = ident.to_string();
+    match ident.strip_prefix("r#") {
+        Some(ident) => ident.to_owned(),
+        None => ident,
+    }
+}
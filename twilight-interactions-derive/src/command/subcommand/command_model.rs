@@ -1,8 +1,11 @@
+use std::collections::BTreeMap;
+
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
-use syn::{spanned::Spanned, DeriveInput, Result, Variant};
+use syn::{spanned::Spanned, DeriveInput, Result, Type, Variant};
 
-use super::parse::ParsedVariant;
+use super::parse::{ParsedVariant, TypeAttribute};
+use crate::parse::syntax::{bounded_generics, find_attr};
 
 /// Implementation of `CommandModel` derive macro
 pub fn impl_command_model(
@@ -10,40 +13,110 @@ pub fn impl_command_model(
     variants: impl IntoIterator<Item = Variant>,
 ) -> Result<TokenStream> {
     let ident = &input.ident;
-    let generics = &input.generics;
-    let where_clause = &generics.where_clause;
-    let variants = ParsedVariant::from_variants(variants, input.span())?;
+    let variants = ParsedVariant::from_variants(variants, &input.attrs, input.span())?;
+
+    let variant_types: Vec<_> = variants
+        .iter()
+        .map(|variant| Type::Path(variant.inner.clone()))
+        .collect();
+    let generics = bounded_generics(
+        &input.generics,
+        &variant_types,
+        quote!(::twilight_interactions::command::CommandModel),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let variants_match_arms = variants.iter().map(variant_match_arm);
+    let name_match = name_match(&variants);
+
+    // Parse failures are reported with the name of the command, or the name
+    // of the type if the model has no name
+    let command_name = match find_attr(&input.attrs, "command") {
+        Some(attr) => TypeAttribute::name(attr)?,
+        None => None,
+    };
+    let command_name = match command_name {
+        Some(name) => quote!(#name),
+        None => {
+            let name = ident.to_string();
+            quote!(#name)
+        }
+    };
 
     Ok(quote! {
-        impl #generics ::twilight_interactions::command::CommandModel for #ident #generics #where_clause {
+        impl #impl_generics ::twilight_interactions::command::CommandModel for #ident #ty_generics #where_clause {
             fn from_interaction(
                 __data: ::twilight_interactions::command::CommandInputData,
             ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseError> {
-                if __data.options.is_empty() {
-                    return std::result::Result::Err(twilight_interactions::error::ParseError::EmptyOptions);
-                }
-
-                let mut __options = __data.options;
-                let __opt = __options.swap_remove(0);
-
-                match &*__opt.name {
-                    #(#variants_match_arms,)*
-                    __other => ::std::result::Result::Err(
-                        ::twilight_interactions::error::ParseError::Option(
-                            ::twilight_interactions::error::ParseOptionError {
-                                field: ::std::convert::From::from(__other),
-                                kind: twilight_interactions::error::ParseOptionErrorType::UnknownSubcommand,
-                            }
-                        )
-                    )
-                }
+                Self::from_interaction_context(__data, ::std::option::Option::None)
+            }
+
+            fn from_interaction_context(
+                __data: ::twilight_interactions::command::CommandInputData,
+                __interaction: ::std::option::Option<&::twilight_model::application::interaction::Interaction>,
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseError> {
+                ::twilight_interactions::command::internal::parse_command(#command_name, || {
+                    let __opt = ::twilight_interactions::command::internal::take_subcommand(__data.options)?;
+
+                    #name_match
+                })
             }
         }
     })
 }
 
+/// Minimum number of variants for which names are matched by length first.
+const BUCKETED_MATCH_THRESHOLD: usize = 8;
+
+/// Generate the match on the received subcommand name
+///
+/// For enums with many variants, names are first matched by length so that
+/// only the names with the same length are compared, instead of comparing the
+/// received name with every variant.
+fn name_match(variants: &[ParsedVariant]) -> TokenStream {
+    let unknown = quote! {
+        ::std::result::Result::Err(
+            ::twilight_interactions::command::internal::unknown_subcommand(&__opt.name)
+        )
+    };
+
+    if variants.len() < BUCKETED_MATCH_THRESHOLD {
+        let arms = variants.iter().map(variant_match_arm);
+
+        return quote! {
+            match &*__opt.name {
+                #(#arms,)*
+                _ => #unknown
+            }
+        };
+    }
+
+    let mut buckets: BTreeMap<usize, Vec<&ParsedVariant>> = BTreeMap::new();
+    for variant in variants {
+        buckets
+            .entry(variant.attribute.name.as_str().len())
+            .or_default()
+            .push(variant);
+    }
+
+    let bucket_arms = buckets.into_iter().map(|(len, variants)| {
+        let arms = variants.into_iter().map(variant_match_arm);
+
+        quote! {
+            #len => match &*__opt.name {
+                #(#arms,)*
+                _ => #unknown
+            }
+        }
+    });
+
+    quote! {
+        match __opt.name.len() {
+            #(#bucket_arms,)*
+            _ => #unknown
+        }
+    }
+}
+
 /// Generate variant match arm
 fn variant_match_arm(variant: &ParsedVariant) -> TokenStream {
     let name = &variant.attribute.name;
@@ -51,22 +124,12 @@ fn variant_match_arm(variant: &ParsedVariant) -> TokenStream {
     let span = variant.span;
 
     quote_spanned! {span=>
-        #name => {
-            let __input = match ::twilight_interactions::command::CommandInputData::from_option(__opt.value, __data.resolved.as_deref()) {
-                Ok(__value) => __value,
-                Err(__error) => return ::std::result::Result::Err(
-                    ::twilight_interactions::error::ParseError::Option(
-                        ::twilight_interactions::error::ParseOptionError {
-                            field: ::std::convert::From::from(#name),
-                            kind: __error,
-                        }
-                    )
-                )
-            };
-
-            Ok(Self::#ident(
-                ::twilight_interactions::command::CommandModel::from_interaction(__input)?
-            ))
-        }
+        #name => ::twilight_interactions::command::internal::parse_subcommand(
+            #name,
+            __opt.value,
+            __data.resolved.as_deref(),
+            __interaction,
+        )
+        .map(Self::#ident)
     }
 }
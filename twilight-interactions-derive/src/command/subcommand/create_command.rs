@@ -1,12 +1,17 @@
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
-use syn::{spanned::Spanned, DeriveInput, Error, Result, Variant};
+use syn::{spanned::Spanned, DeriveInput, Error, Result, Type, Variant};
 
 use super::parse::{ParsedVariant, TypeAttribute};
 use crate::{
+    command::generate_tests::generated_tests,
+    command::option_names::{names_module, NamesKind},
     command::user_application::{context, integration_type},
-    localization::{description_expr, name_expr},
-    parse::syntax::{find_attr, optional, parse_doc},
+    localization::{description_const, description_expr, name_expr},
+    parse::{
+        metadata::metadata_expr,
+        syntax::{bounded_generics, find_attr, optional, parse_doc},
+    },
 };
 
 /// Implementation of `CreateCommand` derive macro
@@ -15,12 +20,21 @@ pub fn impl_create_command(
     variants: impl IntoIterator<Item = Variant>,
 ) -> Result<TokenStream> {
     let ident = &input.ident;
-    let generics = &input.generics;
-    let where_clause = &generics.where_clause;
 
-    let variants = ParsedVariant::from_variants(variants, input.span())?;
-    let attributes = match find_attr(&input.attrs, "command") {
-        Some(attr) => TypeAttribute::parse(attr)?,
+    let variants = ParsedVariant::from_variants(variants, &input.attrs, input.span())?;
+
+    let variant_types: Vec<_> = variants
+        .iter()
+        .map(|variant| Type::Path(variant.inner.clone()))
+        .collect();
+    let generics = bounded_generics(
+        &input.generics,
+        &variant_types,
+        quote!(::twilight_interactions::command::CreateCommand),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (attributes, attr_span) = match find_attr(&input.attrs, "command") {
+        Some(attr) => (TypeAttribute::parse(attr)?, attr.span()),
         None => {
             return Err(Error::new_spanned(
                 input,
@@ -29,12 +43,18 @@ pub fn impl_create_command(
         }
     };
 
-    let name = String::from(attributes.name);
+    let name = attributes
+        .name
+        .checked_command(attributes.deny_reserved_name);
     let name_expr = name_expr(&name, &attributes.name_localizations);
 
     let desc_expr = description_expr(&attributes.desc, &attributes.desc_localizations, || {
         parse_doc(&input.attrs, input.span())
     })?;
+    let desc_const = description_const(&attributes.desc, &attributes.desc_localizations, || {
+        parse_doc(&input.attrs, input.span())
+    })?;
+    let nsfw_const = attributes.nsfw.unwrap_or(false);
 
     let capacity = variants.len();
     let default_permissions = match &attributes.default_permissions {
@@ -43,8 +63,31 @@ pub fn impl_create_command(
     };
     let dm_permission = optional(attributes.dm_permission);
     let nsfw = optional(attributes.nsfw);
+    let timeout = optional(attributes.timeout);
+    let cooldown = optional(attributes.cooldown);
+    let guild_only = attributes.guild_only.unwrap_or_default();
+    let owner_only = attributes.owner_only.unwrap_or_default();
+    let category_const = optional(attributes.category.as_ref());
+    let category = optional(
+        attributes
+            .category
+            .as_ref()
+            .map(|category| quote! { ::std::string::String::from(#category) }),
+    );
+    let metadata = metadata_expr(&attributes.metadata);
+    let generated_tests = generated_tests(&input, attributes.generate_tests, attr_span)?;
+    let names_module = attributes.names_module.then(|| {
+        names_module(
+            &input,
+            NamesKind::Subcommands,
+            variants
+                .iter()
+                .map(|variant| (variant.attribute.name.as_str().to_owned(), &variant.ident)),
+        )
+    });
 
     let variant_options = variants.iter().map(variant_option);
+    let variant_types = variants.iter().map(|variant| &variant.inner);
 
     let contexts = if let Some(items) = attributes.contexts {
         let items = items.iter().map(context);
@@ -61,10 +104,21 @@ pub fn impl_create_command(
     };
 
     Ok(quote! {
-        impl #generics ::twilight_interactions::command::CreateCommand for #ident #generics #where_clause {
+        impl #impl_generics ::twilight_interactions::command::CreateCommand for #ident #ty_generics #where_clause {
             const NAME: &'static str = #name;
+            const DESCRIPTION: &'static str = #desc_const;
+            const NSFW: bool = #nsfw_const;
+            const CATEGORY: ::std::option::Option<&'static str> = #category_const;
+            const GROUP: bool = true;
+            const SUBCOMMAND_DEPTH: u8 = ::twilight_interactions::command::internal::subcommand_depth(&[
+                #(<#variant_types as ::twilight_interactions::command::CreateCommand>::SUBCOMMAND_DEPTH),*
+            ]);
 
             fn create_command() -> ::twilight_interactions::command::ApplicationCommandData {
+                ::twilight_interactions::command::internal::localize_global(Self::create_command_base())
+            }
+
+            fn create_command_base() -> ::twilight_interactions::command::ApplicationCommandData {
                 let __command_name = #name_expr;
                 let __command_desc = #desc_expr;
                 let mut __command_options = ::std::vec::Vec::with_capacity(#capacity);
@@ -83,9 +137,18 @@ pub fn impl_create_command(
                     group: true,
                     contexts: #contexts,
                     integration_types: #integration_types,
+                    timeout: #timeout,
+                    cooldown: #cooldown,
+                    guild_only: #guild_only,
+                    owner_only: #owner_only,
+                    category: #category,
+                    metadata: #metadata,
                 }
             }
         }
+
+        #generated_tests
+        #names_module
     })
 }
 
@@ -95,8 +158,14 @@ fn variant_option(variant: &ParsedVariant) -> TokenStream {
     let span = variant.span;
 
     quote_spanned! {span=>
+        const {
+            ::twilight_interactions::command::internal::check_subcommand_depth(
+                <#ty as ::twilight_interactions::command::CreateCommand>::SUBCOMMAND_DEPTH
+            );
+        }
+
         __command_options.push(::std::convert::From::from(
-            <#ty as ::twilight_interactions::command::CreateCommand>::create_command()
+            <#ty as ::twilight_interactions::command::CreateCommand>::create_command_base()
         ));
     }
 }
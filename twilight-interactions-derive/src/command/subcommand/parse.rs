@@ -5,8 +5,11 @@ use crate::{
     command::user_application::{ApplicationIntegrationType, InteractionContextType},
     parse::{
         attribute::NamedAttrs,
-        parsers::{CommandDescription, CommandName, FunctionPath},
-        syntax::find_attr,
+        parsers::{
+            CommandCooldown, CommandDescription, CommandName, CommandRenameRule, CommandTimeout,
+            ConstOr, FunctionPath, RenameRule,
+        },
+        syntax::{check_unique, find_attr},
     },
 };
 
@@ -20,8 +23,12 @@ pub struct ParsedVariant {
 
 impl ParsedVariant {
     /// Parse an iterator of syn [`Variant`].
+    ///
+    /// The `rename_all` attribute of the enum is read from its attributes. An
+    /// error is returned if several subcommands have the same name.
     pub fn from_variants(
         variants: impl IntoIterator<Item = Variant>,
+        attrs: &[Attribute],
         input_span: Span,
     ) -> Result<Vec<Self>> {
         let variants: Vec<_> = variants.into_iter().collect();
@@ -33,11 +40,29 @@ impl ParsedVariant {
             ));
         }
 
-        variants.into_iter().map(Self::from_variant).collect()
+        let rename_all = match find_attr(attrs, "command") {
+            Some(attr) => TypeAttribute::rename_all(attr)?,
+            None => None,
+        };
+
+        let variants = variants
+            .into_iter()
+            .map(|variant| Self::from_variant(variant, rename_all))
+            .collect::<Result<Vec<_>>>()?;
+
+        check_unique(
+            variants.iter().map(|variant| {
+                let name = variant.attribute.name.clone().into();
+                (name, variant.ident.span())
+            }),
+            "subcommand name",
+        )?;
+
+        Ok(variants)
     }
 
     /// Parse a single syn [`Variant`].
-    fn from_variant(variant: Variant) -> Result<Self> {
+    fn from_variant(variant: Variant, rename_all: Option<RenameRule>) -> Result<Self> {
         let span = variant.span();
         let Fields::Unnamed(fields) = variant.fields else {
             return Err(Error::new(span, "variant must be an unnamed variant"));
@@ -61,9 +86,18 @@ impl ParsedVariant {
             }
         };
 
-        let attribute = match find_attr(&variant.attrs, "command") {
-            Some(attr) => VariantAttribute::parse(attr)?,
-            None => {
+        let renamed = match rename_all {
+            Some(rule) => Some(CommandName::new(
+                &rule.apply(&variant.ident.to_string()),
+                variant.ident.span(),
+            )?),
+            None => None,
+        };
+
+        let attribute = match (find_attr(&variant.attrs, "command"), renamed) {
+            (Some(attr), renamed) => VariantAttribute::parse(attr, renamed)?,
+            (None, Some(name)) => VariantAttribute { name },
+            (None, None) => {
                 return Err(Error::new(
                     span,
                     "missing required #[command(..)] attribute",
@@ -87,23 +121,30 @@ pub struct VariantAttribute {
 }
 
 impl VariantAttribute {
-    pub fn parse(attr: &Attribute) -> Result<Self> {
+    /// Parse the attribute of a variant.
+    ///
+    /// The `name` attribute is only required if no name is derived from the
+    /// `rename_all` attribute of the enum.
+    pub fn parse(attr: &Attribute, renamed: Option<CommandName>) -> Result<Self> {
         let mut parser = NamedAttrs::parse(attr, &["name"])?;
 
-        Ok(Self {
-            name: parser.required("name")?,
-        })
+        let name = match renamed {
+            Some(renamed) => parser.optional("name")?.unwrap_or(renamed),
+            None => parser.required("name")?,
+        };
+
+        Ok(Self { name })
     }
 }
 
 /// Parsed type attribute
 pub struct TypeAttribute {
     /// Name of the command
-    pub name: CommandName,
+    pub name: ConstOr<CommandName>,
     /// Localization dictionary for the command name.
     pub name_localizations: Option<FunctionPath>,
     /// Description of the command
-    pub desc: Option<CommandDescription>,
+    pub desc: Option<ConstOr<CommandDescription>>,
     /// Localization dictionary for the command description.
     pub desc_localizations: Option<FunctionPath>,
     /// Default permissions required for a member to run the command.
@@ -116,6 +157,25 @@ pub struct TypeAttribute {
     pub contexts: Option<Vec<InteractionContextType>>,
     /// Installation contexts where the command is available.
     pub integration_types: Option<Vec<ApplicationIntegrationType>>,
+    /// Maximum execution time of the command handler.
+    pub timeout: Option<CommandTimeout>,
+    /// Cooldown of the command.
+    pub cooldown: Option<CommandCooldown>,
+    /// Whether the command can only be used in guilds.
+    pub guild_only: Option<bool>,
+    /// Whether the command can only be used by the owners of the bot.
+    pub owner_only: Option<bool>,
+    /// Category of the command.
+    pub category: Option<String>,
+    /// Whether to generate unit tests for the command.
+    pub generate_tests: Option<bool>,
+    /// Entries of the `meta` attribute.
+    pub metadata: Vec<(String, String)>,
+    /// Whether to reject names of built-in Discord commands.
+    pub deny_reserved_name: bool,
+    /// Whether to generate the module with the names of options or
+    /// subcommands.
+    pub names_module: bool,
 }
 
 impl TypeAttribute {
@@ -129,12 +189,22 @@ impl TypeAttribute {
         "nsfw",
         "contexts",
         "integration_types",
+        "timeout",
+        "cooldown",
+        "guild_only",
+        "owner_only",
+        "category",
+        "generate_tests",
+        "deny_reserved_name",
+        "names_module",
+        "rename_all",
+        "meta",
+        "crate",
     ];
 
     pub fn parse(attr: &Attribute) -> Result<Self> {
         let mut parser = NamedAttrs::parse(attr, Self::VALID_ATTRIBUTES)?;
-
-        Ok(Self {
+        let attribute = Self {
             name: parser.required("name")?,
             name_localizations: parser.optional("name_localizations")?,
             desc: parser.optional("desc")?,
@@ -144,6 +214,40 @@ impl TypeAttribute {
             nsfw: parser.optional("nsfw")?,
             contexts: parser.optional("contexts")?,
             integration_types: parser.optional("integration_types")?,
-        })
+            timeout: parser.optional("timeout")?,
+            cooldown: parser.optional("cooldown")?,
+            guild_only: parser.optional("guild_only")?,
+            owner_only: parser.optional("owner_only")?,
+            category: parser.optional("category")?,
+            generate_tests: parser.optional("generate_tests")?,
+            metadata: parser.metadata()?,
+            deny_reserved_name: parser.optional("deny_reserved_name")?.unwrap_or_default(),
+            names_module: parser.optional("names_module")?.unwrap_or_default(),
+        };
+
+        if let ConstOr::Value(name) = &attribute.name {
+            name.check_reserved(attribute.deny_reserved_name, attr.span())?;
+        }
+
+        Ok(attribute)
+    }
+
+    /// Get the name of the command.
+    ///
+    /// Other attributes are not validated, since they are not required by the
+    /// `CommandModel` derive macro.
+    pub fn name(attr: &Attribute) -> Result<Option<ConstOr<CommandName>>> {
+        NamedAttrs::parse(attr, Self::VALID_ATTRIBUTES)?.optional("name")
+    }
+
+    /// Get the rename rule of subcommand names.
+    ///
+    /// Other attributes are not validated, since they are not required by the
+    /// `CommandModel` derive macro.
+    pub fn rename_all(attr: &Attribute) -> Result<Option<RenameRule>> {
+        let mut parser = NamedAttrs::parse(attr, Self::VALID_ATTRIBUTES)?;
+        let rename_all: Option<CommandRenameRule> = parser.optional("rename_all")?;
+
+        Ok(rename_all.map(|rule| rule.0))
     }
 }
@@ -0,0 +1,176 @@
+//! Implementation of the `CustomId` derive macro.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Lit, Result};
+
+use crate::parse::{
+    attribute::{NamedAttrs, ParseAttribute, ParseSpanned},
+    syntax::find_attr,
+};
+
+/// Maximum length of a custom id
+const MAX_LENGTH: usize = 100;
+
+/// Implementation of the `CustomId` derive macro
+pub fn impl_custom_id(input: DeriveInput) -> Result<TokenStream> {
+    let span = input.span();
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            span,
+            "`CustomId` can only be applied to structs",
+        ));
+    };
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "`CustomId` cannot be applied to generic types",
+        ));
+    }
+
+    let Some(attr) = find_attr(&input.attrs, "custom_id") else {
+        return Err(Error::new(
+            span,
+            "missing required #[custom_id(...)] attribute",
+        ));
+    };
+    let mut parser = NamedAttrs::parse(attr, &["prefix", "encoding", "crate"])?;
+    let prefix: ParseSpanned<String> = parser.required("prefix")?;
+    let encoding = parser.optional("encoding")?.unwrap_or(Encoding::Text);
+
+    if prefix.inner.is_empty() || prefix.inner.contains([':', '\\']) {
+        return Err(prefix.error("prefix must not be empty or contain `:` and `\\` characters"));
+    }
+
+    let prefix_length = prefix.inner.chars().count();
+    if prefix_length > MAX_LENGTH {
+        return Err(prefix.error(format!("prefix must be at most {MAX_LENGTH} characters")));
+    }
+
+    let ident = &input.ident;
+    let prefix = prefix.inner;
+    let count = data.fields.len();
+    let types = data.fields.iter().map(|field| &field.ty);
+    let (writer, reader, max_length, length_const) = match encoding {
+        Encoding::Text => (
+            quote!(CustomIdWriter),
+            quote!(CustomIdReader::new(Self::PREFIX, __custom_id, #count)),
+            quote!(max_length),
+            quote!(MAX_LENGTH),
+        ),
+        Encoding::Compact => (
+            quote!(CompactWriter),
+            quote!(CompactReader::new(Self::PREFIX, __custom_id)),
+            quote!(max_compact_length),
+            quote!(MAX_BYTES),
+        ),
+    };
+    let finish_reader = match encoding {
+        Encoding::Text => quote!(),
+        Encoding::Compact => quote!(__reader.finish()?;),
+    };
+    let length_error = format!("custom id of `{ident}` may exceed {MAX_LENGTH} characters");
+
+    let write_fields = data.fields.iter().enumerate().map(|(index, field)| {
+        let span = field.span();
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = syn::Index::from(index);
+                quote!(#index)
+            }
+        };
+
+        quote_spanned!(span=> __writer.field(&self.#member);)
+    });
+
+    let read_fields = data.fields.iter().map(|field| {
+        let span = field.span();
+        let read = quote_spanned!(span=> __reader.field()?);
+
+        match &field.ident {
+            Some(ident) => quote!(#ident: #read),
+            None => read,
+        }
+    });
+    let value = match &data.fields {
+        Fields::Named(_) => quote!(Self { #(#read_fields),* }),
+        Fields::Unnamed(_) => quote!(Self(#(#read_fields),*)),
+        Fields::Unit => quote!(Self),
+    };
+
+    Ok(quote! {
+        impl ::twilight_interactions::component::CustomId for #ident {
+            const PREFIX: &'static str = #prefix;
+
+            fn to_custom_id(&self) -> ::std::result::Result<::std::string::String, ::twilight_interactions::error::CustomIdError> {
+                let mut __writer = ::twilight_interactions::component::internal::#writer::new(Self::PREFIX);
+
+                #(#write_fields)*
+
+                __writer.finish()
+            }
+
+            fn from_custom_id(__custom_id: &str) -> ::std::result::Result<Self, ::twilight_interactions::error::CustomIdError> {
+                let mut __reader = ::twilight_interactions::component::internal::#reader?;
+                let __value = #value;
+                #finish_reader
+
+                ::std::result::Result::Ok(__value)
+            }
+        }
+
+        const _: () = {
+            let __fields: &[::std::option::Option<usize>] = &[
+                #(<#types as ::twilight_interactions::component::CustomIdField>::#length_const),*
+            ];
+
+            if let ::std::option::Option::Some(__length) =
+                ::twilight_interactions::component::internal::#max_length(#prefix_length, __fields)
+            {
+                ::std::assert!(__length <= #MAX_LENGTH, #length_error);
+            }
+        };
+    })
+}
+
+/// Encoding of the fields of a custom id
+#[derive(Clone, Copy)]
+enum Encoding {
+    Text,
+    Compact,
+}
+
+impl ParseAttribute for Encoding {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
+
+        match spanned.inner.as_str() {
+            "text" => Ok(Self::Text),
+            "compact" => Ok(Self::Compact),
+            _ => Err(spanned.error("invalid encoding (expected one of text, compact)")),
+        }
+    }
+}
+
+/// Dummy implementation of the `CustomId` trait in case of macro error
+pub fn dummy_custom_id(ident: Ident, error: Error) -> TokenStream {
+    let error = error.to_compile_error();
+
+    quote! {
+        #error
+
+        impl ::twilight_interactions::component::CustomId for #ident {
+            const PREFIX: &'static str = "";
+
+            fn to_custom_id(&self) -> ::std::result::Result<::std::string::String, ::twilight_interactions::error::CustomIdError> {
+                ::std::unimplemented!()
+            }
+
+            fn from_custom_id(custom_id: &str) -> ::std::result::Result<Self, ::twilight_interactions::error::CustomIdError> {
+                ::std::unimplemented!()
+            }
+        }
+    }
+}
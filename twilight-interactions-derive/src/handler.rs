@@ -0,0 +1,143 @@
+//! Implementation of the `command_handler` attribute macro.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    spanned::Spanned, Error, FnArg, ItemFn, Pat, PatType, Result, ReturnType, Type, Visibility,
+};
+
+use crate::parse::parsers::pascal_case;
+
+/// Implementation of the `command_handler` attribute macro
+pub fn impl_command_handler(attr: TokenStream, mut item: ItemFn) -> Result<TokenStream> {
+    let sig = &item.sig;
+
+    if sig.asyncness.is_none() {
+        return Err(Error::new(
+            sig.fn_token.span(),
+            "command handlers must be async functions",
+        ));
+    }
+
+    if !sig.generics.params.is_empty() {
+        return Err(Error::new(
+            sig.generics.span(),
+            "command handlers cannot be generic",
+        ));
+    }
+
+    let mut inputs = item.sig.inputs.iter_mut();
+    match inputs.next() {
+        Some(FnArg::Typed(_)) => (),
+        Some(FnArg::Receiver(receiver)) => {
+            return Err(Error::new(
+                receiver.span(),
+                "command handlers cannot take `self`",
+            ))
+        }
+        None => {
+            return Err(Error::new(
+                item.sig.paren_token.span.join(),
+                "command handlers must take the interaction as first argument",
+            ))
+        }
+    }
+
+    let mut fields = Vec::new();
+    for input in inputs {
+        let FnArg::Typed(input) = input else {
+            return Err(Error::new(input.span(), "unexpected `self` argument"));
+        };
+
+        fields.push(HandlerField::from_input(input)?);
+    }
+
+    let vis = &item.vis;
+    let fn_ident = &item.sig.ident;
+    let model = format_ident!("{}Command", pascal_case(&fn_ident.to_string()));
+    let output = match &item.sig.output {
+        ReturnType::Default => quote!(()),
+        ReturnType::Type(_, ty) => quote!(#ty),
+    };
+
+    // Documentation of the function is used as command description
+    let docs = item.attrs.iter().filter(|attr| attr.path().is_ident("doc"));
+    let field_defs = fields.iter().map(|field| field.definition(vis));
+    let field_idents = fields.iter().map(|field| &field.ident);
+
+    Ok(quote! {
+        #item
+
+        #[derive(
+            ::twilight_interactions::command::CommandModel,
+            ::twilight_interactions::command::CreateCommand,
+        )]
+        #[command(#attr)]
+        #(#docs)*
+        #vis struct #model {
+            #(#field_defs),*
+        }
+
+        impl #model {
+            /// Register the command and its handler to a
+            /// [`CommandRegistry`](::twilight_interactions::registry::CommandRegistry).
+            #vis fn register(
+                registry: ::twilight_interactions::registry::CommandRegistry<#output>,
+            ) -> ::twilight_interactions::registry::CommandRegistry<#output> {
+                registry.register(|__command: Self, __interaction| {
+                    #fn_ident(__interaction, #(__command.#field_idents),*)
+                })
+            }
+        }
+    })
+}
+
+/// Remove `#[command]` attributes from the arguments of a function
+///
+/// Used to emit the original function alongside a compile error, since these
+/// attributes are not valid on function arguments.
+pub fn strip_attributes(item: &mut ItemFn) {
+    for input in &mut item.sig.inputs {
+        if let FnArg::Typed(input) = input {
+            input.attrs.retain(|attr| !attr.path().is_ident("command"));
+        }
+    }
+}
+
+/// Command option parsed from a function argument
+struct HandlerField {
+    ident: Ident,
+    ty: Type,
+    attrs: Vec<syn::Attribute>,
+}
+
+impl HandlerField {
+    /// Parse a function argument, removing its `#[command]` attributes
+    fn from_input(input: &mut PatType) -> Result<Self> {
+        let Pat::Ident(pat) = &*input.pat else {
+            return Err(Error::new(
+                input.pat.span(),
+                "command handler arguments must be identifiers",
+            ));
+        };
+
+        let (attrs, other) = input
+            .attrs
+            .drain(..)
+            .partition(|attr| attr.path().is_ident("command"));
+        input.attrs = other;
+
+        Ok(Self {
+            ident: pat.ident.clone(),
+            ty: (*input.ty).clone(),
+            attrs,
+        })
+    }
+
+    /// Generate the definition of the model field
+    fn definition(&self, vis: &Visibility) -> TokenStream {
+        let Self { ident, ty, attrs } = self;
+
+        quote!(#(#attrs)* #vis #ident: #ty)
+    }
+}
@@ -6,12 +6,18 @@
 //! information.
 
 mod command;
+mod custom_id;
+mod handler;
 mod localization;
 mod option;
 mod parse;
+mod router;
+mod select;
+mod select_menu;
 
+use parse::crate_path::{apply_crate_path, find_crate_path};
 use proc_macro::TokenStream;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, parse_quote, Attribute, DeriveInput, ItemFn};
 
 /// Derive macro for the `CommandModel` trait.
 ///
@@ -21,11 +27,31 @@ use syn::{parse_macro_input, DeriveInput};
 pub fn command_model(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let ident = input.ident.clone();
+    let crate_path = find_crate_path(&input.attrs, "command");
 
-    match command::impl_command_model(input) {
-        Ok(output) => output.into(),
-        Err(error) => command::dummy_command_model(ident, error).into(),
-    }
+    let output = match command::impl_command_model(input) {
+        Ok(output) => output,
+        Err(error) => command::dummy_command_model(ident, error),
+    };
+
+    apply_crate_path(crate_path, output).into()
+}
+
+/// Derive macro for the `CommandModelRef` trait.
+///
+/// See the documentation of the trait for more information about usage of this
+/// macro.
+#[proc_macro_derive(CommandModelRef, attributes(command))]
+pub fn command_model_ref(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let crate_path = find_crate_path(&input.attrs, "command");
+
+    let output = match command::impl_command_model_ref(input) {
+        Ok(output) => output,
+        Err(error) => error.to_compile_error(),
+    };
+
+    apply_crate_path(crate_path, output).into()
 }
 
 /// Derive macro for the `CreateCommand` trait.
@@ -36,11 +62,51 @@ pub fn command_model(input: TokenStream) -> TokenStream {
 pub fn create_command(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let ident = input.ident.clone();
+    let crate_path = find_crate_path(&input.attrs, "command");
+
+    let output = match command::impl_create_command(input) {
+        Ok(output) => output,
+        Err(error) => command::dummy_create_command(ident, error),
+    };
+
+    apply_crate_path(crate_path, output).into()
+}
+
+/// Derive macro for both the `CommandModel` and `CreateCommand` traits.
+///
+/// This is equivalent to deriving both traits, with a single set of
+/// attributes. See the documentation of the `CreateCommand` trait for more
+/// information about usage of this macro.
+#[proc_macro_derive(SlashCommand, attributes(command))]
+pub fn slash_command(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = input.ident.clone();
+    let crate_path = find_crate_path(&input.attrs, "command");
+
+    let output = match command::impl_slash_command(input) {
+        Ok(output) => output,
+        Err(error) => command::dummy_slash_command(ident, error),
+    };
+
+    apply_crate_path(crate_path, output).into()
+}
+
+/// Derive macro for the `CreateAutocomplete` trait.
+///
+/// See the documentation of the trait for more information about usage of this
+/// macro.
+#[proc_macro_derive(CreateAutocomplete, attributes(command))]
+pub fn create_autocomplete(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = input.ident.clone();
+    let crate_path = find_crate_path(&input.attrs, "command");
 
-    match command::impl_create_command(input) {
-        Ok(output) => output.into(),
-        Err(error) => command::dummy_create_command(ident, error).into(),
-    }
+    let output = match command::impl_create_autocomplete(input) {
+        Ok(output) => output,
+        Err(error) => command::dummy_create_autocomplete(ident, error),
+    };
+
+    apply_crate_path(crate_path, output).into()
 }
 
 /// Derive macro for the `CommandOption` trait.
@@ -51,11 +117,14 @@ pub fn create_command(input: TokenStream) -> TokenStream {
 pub fn command_option(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let ident = input.ident.clone();
+    let crate_path = find_crate_path(&input.attrs, "option");
+
+    let output = match option::impl_command_option(input) {
+        Ok(output) => output,
+        Err(error) => option::dummy_command_option(ident, error),
+    };
 
-    match option::impl_command_option(input) {
-        Ok(output) => output.into(),
-        Err(error) => option::dummy_command_option(ident, error).into(),
-    }
+    apply_crate_path(crate_path, output).into()
 }
 
 /// Derive macro for the `CreateOption` trait.
@@ -66,9 +135,114 @@ pub fn command_option(input: TokenStream) -> TokenStream {
 pub fn create_option(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let ident = input.ident.clone();
+    let crate_path = find_crate_path(&input.attrs, "option");
+
+    let output = match option::impl_create_option(input) {
+        Ok(output) => output,
+        Err(error) => option::dummy_create_option(ident, error),
+    };
+
+    apply_crate_path(crate_path, output).into()
+}
+
+/// Derive macro for the `CommandRouter` trait.
+///
+/// See the documentation of the trait for more information about usage of this
+/// macro.
+#[proc_macro_derive(CommandRouter, attributes(command))]
+pub fn command_router(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = input.ident.clone();
+    let crate_path = find_crate_path(&input.attrs, "command");
+
+    let output = match router::impl_command_router(input) {
+        Ok(output) => output,
+        Err(error) => router::dummy_command_router(ident, error),
+    };
+
+    apply_crate_path(crate_path, output).into()
+}
+
+/// Derive macro for the `SelectOption` trait.
+///
+/// See the documentation of the trait for more information about usage of this
+/// macro.
+#[proc_macro_derive(SelectOption, attributes(select))]
+pub fn select_option(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = input.ident.clone();
+    let crate_path = find_crate_path(&input.attrs, "select");
+
+    let output = match select::impl_select_option(input) {
+        Ok(output) => output,
+        Err(error) => select::dummy_select_option(ident, error),
+    };
+
+    apply_crate_path(crate_path, output).into()
+}
+
+/// Derive macro for the `SelectMenuModel` trait.
+///
+/// See the documentation of the trait for more information about usage of this
+/// macro.
+#[proc_macro_derive(SelectMenuModel, attributes(select))]
+pub fn select_menu_model(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = input.ident.clone();
+    let crate_path = find_crate_path(&input.attrs, "select");
+
+    let output = match select_menu::impl_select_menu_model(input) {
+        Ok(output) => output,
+        Err(error) => select_menu::dummy_select_menu_model(ident, error),
+    };
+
+    apply_crate_path(crate_path, output).into()
+}
+
+/// Derive macro for the `CustomId` trait.
+///
+/// See the documentation of the trait for more information about usage of this
+/// macro.
+#[proc_macro_derive(CustomId, attributes(custom_id))]
+pub fn custom_id(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = input.ident.clone();
+    let crate_path = find_crate_path(&input.attrs, "custom_id");
+
+    let output = match custom_id::impl_custom_id(input) {
+        Ok(output) => output,
+        Err(error) => custom_id::dummy_custom_id(ident, error),
+    };
+
+    apply_crate_path(crate_path, output).into()
+}
+
+/// Attribute macro generating a command from an async function.
+///
+/// The first argument of the function receives the `Interaction`, and other
+/// arguments are command options. A `<Name>Command` struct implementing
+/// `CommandModel` and `CreateCommand` is generated along with a `register`
+/// function adding the command to a `CommandRegistry`.
+///
+/// See the documentation of the `registry` module for more information about
+/// usage of this macro.
+#[proc_macro_attribute]
+pub fn command_handler(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as ItemFn);
+    let attr = proc_macro2::TokenStream::from(attr);
+    let command_attr: Attribute = parse_quote!(#[command(#attr)]);
+    let crate_path = find_crate_path(&[command_attr], "command");
+
+    let output = match handler::impl_command_handler(attr, item.clone()) {
+        Ok(output) => output,
+        Err(error) => {
+            let mut item = item;
+            handler::strip_attributes(&mut item);
+
+            let error = error.to_compile_error();
+            quote::quote!(#error #item)
+        }
+    };
 
-    match option::impl_create_option(input) {
-        Ok(output) => output.into(),
-        Err(error) => option::dummy_create_option(ident, error).into(),
-    }
+    apply_crate_path(crate_path, output).into()
 }
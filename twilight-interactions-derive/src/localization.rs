@@ -1,4 +1,4 @@
-use crate::parse::parsers::{CommandDescription, FunctionPath};
+use crate::parse::parsers::{CommandDescription, ConstOr, FunctionPath};
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned, ToTokens};
 use syn::{spanned::Spanned, Error, Result};
@@ -11,14 +11,14 @@ use syn::{spanned::Spanned, Error, Result};
 /// If no description is found, the documentation comment is parsed from the
 /// item attributes.
 pub fn description_expr(
-    desc: &Option<CommandDescription>,
+    desc: &Option<ConstOr<CommandDescription>>,
     localizations: &Option<FunctionPath>,
     default: impl FnOnce() -> Result<String>,
 ) -> Result<TokenStream> {
     let localizations_span = localizations.span();
 
     let description = match (desc, localizations) {
-        (Some(desc), None) => desc.to_token_stream(),
+        (Some(desc), None) => desc.checked(),
         (None, Some(path)) => quote! { #path()},
         (None, None) => default()?.to_token_stream(),
         (Some(_), Some(_)) => {
@@ -34,11 +34,29 @@ pub fn description_expr(
     })
 }
 
-pub fn name_expr(name: &str, name_localizations: &Option<FunctionPath>) -> TokenStream {
+/// Generate the value of the `DESCRIPTION` constant of `CreateCommand`.
+///
+/// Descriptions returned by a `desc_localizations` function are only known at
+/// runtime, so the constant is empty.
+pub fn description_const(
+    desc: &Option<ConstOr<CommandDescription>>,
+    localizations: &Option<FunctionPath>,
+    default: impl FnOnce() -> Result<String>,
+) -> Result<TokenStream> {
+    match (desc, localizations) {
+        (Some(desc), _) => Ok(desc.to_token_stream()),
+        (None, Some(_)) => Ok(quote!("")),
+        (None, None) => Ok(default()?.to_token_stream()),
+    }
+}
+
+pub fn name_expr(name: impl ToTokens, name_localizations: &Option<FunctionPath>) -> TokenStream {
     let localizations_span = name_localizations.span();
     let name_localizations = match name_localizations {
         Some(path) => quote! { ::std::option::Option::Some(#path())},
-        None => quote! { ::std::option::Option::None },
+        None => quote! {
+            ::std::option::Option::None::<::twilight_interactions::command::NameLocalizations>
+        },
     };
 
     quote_spanned! { localizations_span =>
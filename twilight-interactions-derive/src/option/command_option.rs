@@ -2,34 +2,71 @@ use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned, ToTokens};
 use syn::{spanned::Spanned, DataEnum, DeriveInput, Error, Ident, Result};
 
-use super::parse::{ChoiceKind, ChoiceValue, ParsedVariant};
+use super::parse::{
+    display_variants, ChoiceKind, ChoiceValue, OtherVariant, ParsedVariant, TypeAttribute,
+};
 
 /// Implementation of the `CommandOption` derive macro
 pub fn impl_command_option(input: DeriveInput) -> Result<TokenStream> {
     let ident = &input.ident;
     let input_span = input.span();
+    let type_attribute = TypeAttribute::parse(&input.attrs)?;
 
-    let (variants, kind) = match input.data {
-        syn::Data::Enum(DataEnum { variants, .. }) => {
-            ParsedVariant::from_variants(variants, input_span)?
+    if type_attribute.transparent {
+        return super::transparent::impl_command_option(&input);
+    }
+
+    let (variants, kind, other) = match input.data {
+        syn::Data::Enum(DataEnum { variants, .. }) if type_attribute.display => {
+            let variants = display_variants(variants, input_span)?;
+            return Ok(impl_display_command_option(ident, &variants));
         }
-        _ => {
-            return Err(Error::new(
-                input_span,
-                "`#[derive(CommandOption)] can only be applied to enums",
-            ))
+        syn::Data::Enum(DataEnum { variants, .. }) => {
+            ParsedVariant::from_variants(variants, input_span, &type_attribute)?
         }
+        _ => return Err(Error::new(
+            input_span,
+            "`#[derive(CommandOption)]` can only be applied to enums, or to newtype structs with \
+                 `#[option(transparent)]`",
+        )),
     };
 
+    let case_insensitive = type_attribute.case_insensitive(&variants, kind)?;
     let parsed_init = parsed_init(kind);
-    let match_expr = match_expr(kind);
-    let match_arms = variants.iter().map(variant_match_arm);
+    let match_expr = match_expr(kind, case_insensitive);
+    let match_arms = variants
+        .iter()
+        .map(|variant| variant_match_arm(variant, case_insensitive));
+    let other_match_arm = other_match_arm(other.as_ref());
 
     let value_match_arms = variants.iter().map(value_match_arm);
-    let choice_ty = match kind {
-        ChoiceKind::String => quote! { &'static str },
-        ChoiceKind::Integer => quote! { i64 },
-        ChoiceKind::Number => quote! { f64 },
+    let name_match_arms = variants
+        .iter()
+        .map(|variant| name_match_arm(variant, other.is_some()));
+    let variant_idents = variants.iter().map(|variant| &variant.ident);
+    let choice_ty = match (kind, &other) {
+        (ChoiceKind::String, None) => quote! { &'static str },
+        (ChoiceKind::String, Some(_)) => quote! { &str },
+        (ChoiceKind::Integer, _) => quote! { i64 },
+        (ChoiceKind::Number, _) => quote! { f64 },
+    };
+    let name_ty = match &other {
+        Some(_) => quote! { ::std::option::Option<&'static str> },
+        None => quote! { &'static str },
+    };
+    let (other_name_arm, other_value_arm) = match &other {
+        Some(OtherVariant { ident, span }) => {
+            let value = match kind {
+                ChoiceKind::String => quote! { __value },
+                ChoiceKind::Integer | ChoiceKind::Number => quote! { *__value },
+            };
+
+            (
+                quote_spanned! {*span=> Self::#ident(_) => ::std::option::Option::None, },
+                quote_spanned! {*span=> Self::#ident(__value) => #value, },
+            )
+        }
+        None => (quote!(), quote!()),
     };
 
     Ok(quote! {
@@ -43,16 +80,31 @@ pub fn impl_command_option(input: DeriveInput) -> Result<TokenStream> {
 
                 match #match_expr {
                     #(#match_arms,)*
-                    __other => ::std::result::Result::Err(
-                        ::twilight_interactions::error::ParseOptionErrorType::InvalidChoice(
-                            ::std::string::ToString::to_string(__other)
-                        )
-                    )
+                    #other_match_arm
                 }
             }
         }
 
         impl #ident {
+            /// List of all choice variants, in declaration order.
+            ///
+            /// This constant is automatically generated by the [`CommandOption`] derive macro.
+            ///
+            /// [`CommandOption`]: twilight_interactions::command::CommandOption
+            pub const VARIANTS: &'static [Self] = &[#(Self::#variant_idents),*];
+
+            /// Get the choice name corresponding to the current variant.
+            ///
+            /// This method is automatically generated by the [`CommandOption`] derive macro.
+            ///
+            /// [`CommandOption`]: twilight_interactions::command::CommandOption
+            pub fn name(&self) -> #name_ty {
+                match self {
+                    #(#name_match_arms,)*
+                    #other_name_arm
+                }
+            }
+
             /// Get the value corresponding to the current variant.
             ///
             /// This method is automatically generated by the [`CommandOption`] derive macro.
@@ -61,12 +113,48 @@ pub fn impl_command_option(input: DeriveInput) -> Result<TokenStream> {
             pub fn value(&self) -> #choice_ty {
                 match self {
                     #(#value_match_arms,)*
+                    #other_value_arm
                 }
             }
         }
     })
 }
 
+/// Implementation of the `CommandOption` trait for choices derived from the
+/// `Display` implementation of the enum
+fn impl_display_command_option(ident: &Ident, variants: &[Ident]) -> TokenStream {
+    quote! {
+        impl ::twilight_interactions::command::CommandOption for #ident {
+            fn from_option(
+                __value: ::twilight_model::application::interaction::application_command::CommandOptionValue,
+                __data: ::twilight_interactions::command::internal::CommandOptionData,
+                __resolved: ::std::option::Option<&::twilight_model::application::interaction::InteractionDataResolved>
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseOptionErrorType> {
+                let __parsed: ::std::string::String = ::twilight_interactions::command::CommandOption::from_option(__value, ::std::default::Default::default(), __resolved)?;
+
+                #(
+                    if ::std::string::ToString::to_string(&Self::#variants) == __parsed {
+                        return ::std::result::Result::Ok(Self::#variants);
+                    }
+                )*
+
+                ::std::result::Result::Err(
+                    ::twilight_interactions::error::ParseOptionErrorType::InvalidChoice(__parsed)
+                )
+            }
+        }
+
+        impl #ident {
+            /// List of all variants, in declaration order.
+            ///
+            /// This constant is automatically generated by the [`CommandOption`] derive macro.
+            ///
+            /// [`CommandOption`]: twilight_interactions::command::CommandOption
+            pub const VARIANTS: &'static [Self] = &[#(Self::#variants),*];
+        }
+    }
+}
+
 /// Dummy implementation of the `CommandOption` trait in case of macro error
 pub fn dummy_command_option(ident: Ident, error: Error) -> TokenStream {
     let error = error.to_compile_error();
@@ -102,8 +190,10 @@ fn parsed_init(kind: ChoiceKind) -> TokenStream {
 }
 
 /// Expression in match block
-fn match_expr(kind: ChoiceKind) -> TokenStream {
-    if kind == ChoiceKind::String {
+fn match_expr(kind: ChoiceKind, case_insensitive: bool) -> TokenStream {
+    if case_insensitive {
+        quote! { __parsed.to_lowercase().as_str() }
+    } else if kind == ChoiceKind::String {
         quote! { __parsed.as_str() }
     } else {
         quote! { &__parsed }
@@ -111,10 +201,13 @@ fn match_expr(kind: ChoiceKind) -> TokenStream {
 }
 
 /// Generate match arm for a variant
-fn variant_match_arm(variant: &ParsedVariant) -> TokenStream {
+///
+/// String values are lowercased if matched ignoring case.
+fn variant_match_arm(variant: &ParsedVariant, case_insensitive: bool) -> TokenStream {
     let ident = &variant.ident;
     let span = variant.span;
     let value = match &variant.attribute.value {
+        ChoiceValue::String(val) if case_insensitive => val.to_lowercase().to_token_stream(),
         ChoiceValue::String(val) => val.to_token_stream(),
         ChoiceValue::Int(val) => val.to_token_stream(),
         // https://stackoverflow.com/questions/45875142/what-are-the-alternatives-to-pattern-matching-floating-point-numbers
@@ -127,6 +220,40 @@ fn variant_match_arm(variant: &ParsedVariant) -> TokenStream {
     }
 }
 
+/// Generate fallback match arm for values not matching any choice
+fn other_match_arm(other: Option<&OtherVariant>) -> TokenStream {
+    match other {
+        Some(OtherVariant { ident, span }) => quote_spanned! {*span=>
+            _ => ::std::result::Result::Ok(Self::#ident(__parsed))
+        },
+        None => quote! {
+            _ => ::std::result::Result::Err(
+                ::twilight_interactions::error::ParseOptionErrorType::InvalidChoice(
+                    ::std::string::ToString::to_string(&__parsed)
+                )
+            )
+        },
+    }
+}
+
+/// Generate match arm for a variant in name method
+///
+/// Names are wrapped in `Some` if the enum has a catch-all variant.
+fn name_match_arm(variant: &ParsedVariant, has_other: bool) -> TokenStream {
+    let ident = &variant.ident;
+    let span = variant.span;
+    let name = &variant.attribute.name;
+    let name = if has_other {
+        quote! { ::std::option::Option::Some(#name) }
+    } else {
+        quote! { #name }
+    };
+
+    quote_spanned! {span=>
+        Self::#ident => #name
+    }
+}
+
 /// Generate match arm for a variant in value method
 fn value_match_arm(variant: &ParsedVariant) -> TokenStream {
     let ident = &variant.ident;
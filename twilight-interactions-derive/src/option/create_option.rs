@@ -4,22 +4,30 @@ use syn::{spanned::Spanned, DeriveInput, Error, Ident, Result};
 
 use crate::localization::name_expr;
 
-use super::parse::{ChoiceKind, ChoiceValue, ParsedVariant};
+use super::parse::{display_variants, ChoiceKind, ChoiceValue, ParsedVariant, TypeAttribute};
 
 pub fn impl_create_option(input: DeriveInput) -> Result<TokenStream> {
     let ident = &input.ident;
     let input_span = input.span();
+    let type_attribute = TypeAttribute::parse(&input.attrs)?;
 
-    let (variants, kind) = match input.data {
-        syn::Data::Enum(syn::DataEnum { variants, .. }) => {
-            ParsedVariant::from_variants(variants, input_span)?
+    if type_attribute.transparent {
+        return super::transparent::impl_create_option(&input);
+    }
+
+    let (variants, kind, _) = match input.data {
+        syn::Data::Enum(syn::DataEnum { variants, .. }) if type_attribute.display => {
+            let variants = display_variants(variants, input_span)?;
+            return Ok(impl_display_create_option(ident, &variants));
         }
-        _ => {
-            return Err(Error::new(
-                input_span,
-                "`#[derive(CommandOption)] can only be applied to enums",
-            ))
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => {
+            ParsedVariant::from_variants(variants, input_span, &type_attribute)?
         }
+        _ => return Err(Error::new(
+            input_span,
+            "`#[derive(CreateOption)]` can only be applied to enums, or to newtype structs with \
+                 `#[option(transparent)]`",
+        )),
     };
 
     let vec_capacity = variants.len();
@@ -41,6 +49,37 @@ pub fn impl_create_option(input: DeriveInput) -> Result<TokenStream> {
     })
 }
 
+/// Implementation of the `CreateOption` trait for choices derived from the
+/// `Display` implementation of the enum
+fn impl_display_create_option(ident: &Ident, variants: &[Ident]) -> TokenStream {
+    let vec_capacity = variants.len();
+
+    quote! {
+        impl ::twilight_interactions::command::CreateOption for #ident {
+            fn create_option(
+                __data: ::twilight_interactions::command::internal::CreateOptionData,
+            ) -> ::twilight_model::application::command::CommandOption {
+                let mut __choices = ::std::vec::Vec::with_capacity(#vec_capacity);
+
+                #( {
+                    let __value = ::std::string::ToString::to_string(&#ident::#variants);
+                    __choices.push(
+                        ::twilight_model::application::command::CommandOptionChoice {
+                            name: ::std::clone::Clone::clone(&__value),
+                            name_localizations: ::std::option::Option::None,
+                            value: ::twilight_model::application::command::CommandOptionChoiceValue::String(__value),
+                        });
+                } )*
+
+                __data
+                    .builder(::twilight_model::application::command::CommandOptionType::String)
+                    .choices(__choices)
+                    .build()
+            }
+        }
+    }
+}
+
 pub fn dummy_create_option(ident: Ident, error: Error) -> TokenStream {
     let error = error.to_compile_error();
 
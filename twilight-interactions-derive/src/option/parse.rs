@@ -1,10 +1,15 @@
+use std::{collections::HashSet, fmt};
+
 use proc_macro2::{Ident, Span};
-use syn::{spanned::Spanned, Attribute, Error, Fields, Lit, Result, Variant};
+use syn::{
+    spanned::Spanned, Attribute, Error, Expr, ExprLit, ExprUnary, Fields, Lit, Result, UnOp,
+    Variant,
+};
 
 use crate::parse::{
     attribute::{NamedAttrs, ParseAttribute, ParseSpanned},
-    parsers::{ChoiceName, FunctionPath},
-    syntax::find_attr,
+    parsers::{ChoiceName, FunctionPath, RenameRule},
+    syntax::{check_unique, find_attr},
 };
 
 /// Parsed enum variants.
@@ -18,16 +23,36 @@ pub struct ParsedVariant {
 impl ParsedVariant {
     /// Parse an iterator of syn [`Variant`].
     ///
-    /// The inferred [`OptionKind`] is also returned.
+    /// The inferred [`OptionKind`] and the catch-all variant, if any, are
+    /// also returned.
     pub fn from_variants(
         variants: impl IntoIterator<Item = Variant>,
         input_span: Span,
-    ) -> Result<(Vec<Self>, ChoiceKind)> {
-        let mut iter = variants.into_iter();
+        type_attribute: &TypeAttribute,
+    ) -> Result<(Vec<Self>, ChoiceKind, Option<OtherVariant>)> {
+        let rename_all = type_attribute.rename_all;
+
+        // Separate the catch-all variant from choices
+        let mut other = None;
+        let mut choices = Vec::new();
+        for variant in variants {
+            if !OtherVariant::is_other(&variant)? {
+                choices.push(variant);
+            } else if other.is_some() {
+                return Err(Error::new(
+                    variant.span(),
+                    "only one variant can be marked with `other`",
+                ));
+            } else {
+                other = Some(OtherVariant::from_variant(variant)?);
+            }
+        }
+
+        let mut iter = choices.into_iter();
 
         // Parse the fist variant to infer the type
         let first = match iter.next() {
-            Some(variant) => Self::from_variant(variant, None)?,
+            Some(variant) => Self::from_variant(variant, None, rename_all)?,
             None => {
                 return Err(Error::new(
                     input_span,
@@ -40,16 +65,35 @@ impl ParsedVariant {
         // Parse other variants
         let mut variants = vec![first];
         for variant in iter {
-            variants.push(Self::from_variant(variant, Some(choice_kind))?);
+            variants.push(Self::from_variant(variant, Some(choice_kind), rename_all)?);
         }
 
-        Ok((variants, choice_kind))
+        check_unique(
+            variants.iter().map(|variant| {
+                let name = variant.attribute.name.clone().into();
+                (name, variant.ident.span())
+            }),
+            "choice name",
+        )?;
+        check_unique(
+            variants.iter().map(|variant| {
+                let value = variant.attribute.value.to_string();
+                (value, variant.ident.span())
+            }),
+            "choice value",
+        )?;
+
+        Ok((variants, choice_kind, other))
     }
 
     /// Parse a single syn [`Variant`].
     ///
     /// If no [`ChoiceKind`] is provided, the type is inferred from value.
-    fn from_variant(variant: Variant, kind: Option<ChoiceKind>) -> Result<Self> {
+    fn from_variant(
+        variant: Variant,
+        kind: Option<ChoiceKind>,
+        rename_all: Option<RenameRule>,
+    ) -> Result<Self> {
         if !matches!(variant.fields, Fields::Unit) {
             return Err(Error::new_spanned(
                 variant,
@@ -57,9 +101,16 @@ impl ParsedVariant {
             ));
         }
 
-        let attribute = match find_attr(&variant.attrs, "option") {
-            Some(attr) => VariantAttribute::parse(attr, kind)?,
-            None => {
+        let discriminant = variant.discriminant.as_ref().map(|(_, expr)| expr);
+
+        let attribute = match (find_attr(&variant.attrs, "option"), rename_all) {
+            (Some(attr), _) => {
+                VariantAttribute::parse(attr, kind, &variant.ident, rename_all, discriminant)?
+            }
+            (None, Some(rename_all)) => {
+                VariantAttribute::from_ident(&variant.ident, kind, rename_all, discriminant)?
+            }
+            (None, None) => {
                 return Err(Error::new(
                     variant.span(),
                     "missing required #[option(...)] attribute",
@@ -76,6 +127,88 @@ impl ParsedVariant {
     }
 }
 
+/// Parse the variants of an enum whose choices are derived from its `Display`
+/// implementation.
+///
+/// Variants must be unit variants without `#[option(...)]` attribute.
+pub fn display_variants(
+    variants: impl IntoIterator<Item = Variant>,
+    input_span: Span,
+) -> Result<Vec<Ident>> {
+    let mut idents = Vec::new();
+    for variant in variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "variant must be a unit variant",
+            ));
+        }
+
+        if let Some(attr) = find_attr(&variant.attrs, "option") {
+            return Err(Error::new_spanned(
+                attr,
+                "variant attributes cannot be used with `display`",
+            ));
+        }
+
+        idents.push(variant.ident);
+    }
+
+    if idents.is_empty() {
+        return Err(Error::new(
+            input_span,
+            "enum must have at least one variant",
+        ));
+    }
+
+    Ok(idents)
+}
+
+/// Catch-all variant receiving values that do not match any choice.
+pub struct OtherVariant {
+    pub span: Span,
+    pub ident: Ident,
+}
+
+impl OtherVariant {
+    /// Whether the variant is marked with `#[option(other)]`.
+    fn is_other(variant: &Variant) -> Result<bool> {
+        let Some(attr) = find_attr(&variant.attrs, "option") else {
+            return Ok(false);
+        };
+
+        let mut parser =
+            NamedAttrs::parse(attr, &["name", "name_localizations", "value", "other"])?;
+        let flag: Option<ParseSpanned<bool>> = parser.optional("other")?;
+
+        match flag {
+            Some(flag) if flag.inner && !parser.is_empty() => {
+                Err(flag.error("`other` cannot be combined with other attributes"))
+            }
+            Some(flag) => Ok(flag.inner),
+            None => Ok(false),
+        }
+    }
+
+    /// Parse a variant marked with `#[option(other)]`.
+    fn from_variant(variant: Variant) -> Result<Self> {
+        match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => (),
+            _ => {
+                return Err(Error::new_spanned(
+                    variant,
+                    "`other` variant must have a single unnamed field",
+                ))
+            }
+        }
+
+        Ok(Self {
+            span: variant.span(),
+            ident: variant.ident,
+        })
+    }
+}
+
 /// Parsed variant attribute
 pub struct VariantAttribute {
     /// Name of the choice (shown to users)
@@ -89,27 +222,202 @@ pub struct VariantAttribute {
 impl VariantAttribute {
     /// Parse a single [`Attribute`].
     ///
-    /// If no [`ChoiceKind`] is provided, the type is inferred from value.
-    pub fn parse(attr: &Attribute, kind: Option<ChoiceKind>) -> Result<Self> {
+    /// If no [`ChoiceKind`] is provided, the type is inferred from value. The
+    /// name and value are derived from the variant identifier if omitted and
+    /// a [`RenameRule`] is provided. The discriminant of the variant, if any,
+    /// is used as value if omitted.
+    pub fn parse(
+        attr: &Attribute,
+        kind: Option<ChoiceKind>,
+        ident: &Ident,
+        rename_all: Option<RenameRule>,
+        discriminant: Option<&Expr>,
+    ) -> Result<Self> {
         let mut parser = NamedAttrs::parse(attr, &["name", "name_localizations", "value"])?;
+        let ident = ident.to_string();
 
-        // Ensure the parsed type is the same as the inferred one
-        let value: ParseSpanned<ChoiceValue> = parser.required("value")?;
-        if let Some(kind) = kind {
-            if value.inner.kind() != kind {
-                return Err(Error::new(
-                    value.span,
-                    format!("invalid attribute type, expected {}", kind.name()),
-                ));
-            }
-        }
+        let value = match (parser.optional("value")?, discriminant, rename_all) {
+            (Some(value), _, _) => value,
+            (None, Some(expr), _) => discriminant_value(expr)?,
+            (None, None, Some(rename_all)) => ParseSpanned {
+                span: attr.span(),
+                inner: ChoiceValue::String(rename_all.apply(&ident)),
+            },
+            (None, None, None) => parser.required("value")?,
+        };
+        let name = match rename_all {
+            Some(rename_all) => parser
+                .optional("name")?
+                .unwrap_or_else(|| rename_all.choice_name(&ident)),
+            None => parser.required("name")?,
+        };
+
+        check_kind(&value, kind)?;
 
         Ok(Self {
-            name: parser.required("name")?,
+            name,
             name_localizations: parser.optional("name_localizations")?,
             value: value.inner,
         })
     }
+
+    /// Derive the attribute of a variant without `#[option(...)]` attribute
+    /// from its identifier.
+    fn from_ident(
+        ident: &Ident,
+        kind: Option<ChoiceKind>,
+        rename_all: RenameRule,
+        discriminant: Option<&Expr>,
+    ) -> Result<Self> {
+        let value = match discriminant {
+            Some(expr) => discriminant_value(expr)?,
+            None => ParseSpanned {
+                span: ident.span(),
+                inner: ChoiceValue::String(rename_all.apply(&ident.to_string())),
+            },
+        };
+
+        check_kind(&value, kind)?;
+
+        Ok(Self {
+            name: rename_all.choice_name(&ident.to_string()),
+            name_localizations: None,
+            value: value.inner,
+        })
+    }
+}
+
+/// Parse the discriminant of a variant as an integer choice value.
+fn discriminant_value(expr: &Expr) -> Result<ParseSpanned<ChoiceValue>> {
+    let digits = match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_digits().to_owned(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => match &**expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(lit), ..
+            }) => format!("-{}", lit.base10_digits()),
+            _ => return Err(invalid_discriminant(expr)),
+        },
+        _ => return Err(invalid_discriminant(expr)),
+    };
+
+    let value = digits
+        .parse()
+        .map_err(|_| Error::new_spanned(expr, "discriminant must fit in an `i64`"))?;
+
+    Ok(ParseSpanned {
+        span: expr.span(),
+        inner: ChoiceValue::Int(value),
+    })
+}
+
+fn invalid_discriminant(expr: &Expr) -> Error {
+    Error::new_spanned(
+        expr,
+        "discriminant must be an integer literal to be used as choice value",
+    )
+}
+
+/// Ensure the parsed type is the same as the inferred one.
+fn check_kind(value: &ParseSpanned<ChoiceValue>, kind: Option<ChoiceKind>) -> Result<()> {
+    match kind {
+        Some(kind) if value.inner.kind() != kind => Err(Error::new(
+            value.span,
+            format!("invalid attribute type, expected {}", kind.name()),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Parsed type attribute
+#[derive(Default)]
+pub struct TypeAttribute {
+    /// Casing used to derive choice names and values from variant identifiers
+    pub rename_all: Option<RenameRule>,
+    /// Whether string values are matched ignoring case
+    pub case_insensitive: Option<ParseSpanned<bool>>,
+    /// Whether choices are derived from the `Display` implementation
+    pub display: bool,
+    /// Whether the type is a newtype delegating to its inner type
+    pub transparent: bool,
+}
+
+impl TypeAttribute {
+    /// Parse the `#[option(...)]` attribute of the type, if any.
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let Some(attr) = find_attr(attrs, "option") else {
+            return Ok(Self::default());
+        };
+
+        let mut parser = NamedAttrs::parse(
+            attr,
+            &[
+                "rename_all",
+                "case_insensitive",
+                "display",
+                "transparent",
+                "crate",
+            ],
+        )?;
+
+        let transparent: Option<ParseSpanned<bool>> = parser.optional("transparent")?;
+        let transparent = match transparent {
+            Some(flag) if flag.inner && !parser.is_empty() => {
+                return Err(flag.error("`transparent` cannot be combined with other attributes"))
+            }
+            Some(flag) => flag.inner,
+            None => false,
+        };
+
+        let display: Option<ParseSpanned<bool>> = parser.optional("display")?;
+        let display = match display {
+            Some(flag) if flag.inner && !parser.is_empty() => {
+                return Err(flag.error("`display` cannot be combined with other attributes"))
+            }
+            Some(flag) => flag.inner,
+            None => false,
+        };
+
+        Ok(Self {
+            rename_all: parser.optional("rename_all")?,
+            case_insensitive: parser.optional("case_insensitive")?,
+            display,
+            transparent,
+        })
+    }
+
+    /// Whether string values are matched ignoring case.
+    ///
+    /// An error is returned if the choices are not strings or if two values
+    /// only differ by their case.
+    pub fn case_insensitive(&self, variants: &[ParsedVariant], kind: ChoiceKind) -> Result<bool> {
+        let Some(flag) = self.case_insensitive.as_ref().filter(|flag| flag.inner) else {
+            return Ok(false);
+        };
+
+        if kind != ChoiceKind::String {
+            return Err(flag.error("`case_insensitive` can only be used with string choices"));
+        }
+
+        let mut values = HashSet::new();
+        for variant in variants {
+            if let ChoiceValue::String(value) = &variant.attribute.value {
+                if !values.insert(value.to_lowercase()) {
+                    return Err(Error::new(
+                        variant.span,
+                        format!("choice value `{value}` conflicts with another value when ignoring case"),
+                    ));
+                }
+            }
+        }
+
+        Ok(true)
+    }
 }
 
 /// Value of a parsed choice
@@ -131,6 +439,16 @@ impl ChoiceValue {
     }
 }
 
+impl fmt::Display for ChoiceValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChoiceValue::String(value) => f.write_str(value),
+            ChoiceValue::Int(value) => value.fmt(f),
+            ChoiceValue::Number(value) => value.fmt(f),
+        }
+    }
+}
+
 impl ParseAttribute for ChoiceValue {
     fn parse_attribute(input: Lit) -> Result<Self> {
         let parsed = match input {
@@ -0,0 +1,82 @@
+//! Implementation of the option derive macros for transparent newtypes.
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Result, Type};
+
+use crate::parse::syntax::bounded_generics;
+
+/// Implementation of the `CommandOption` trait for a newtype struct marked
+/// with `#[option(transparent)]`.
+pub fn impl_command_option(input: &DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let (ty, constructor) = inner_field(input)?;
+
+    let bound = quote!(::twilight_interactions::command::CommandOption);
+    let generics = bounded_generics(&input.generics, [ty], bound);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::twilight_interactions::command::CommandOption for #ident #ty_generics #where_clause {
+            fn from_option(
+                __value: ::twilight_model::application::interaction::application_command::CommandOptionValue,
+                __data: ::twilight_interactions::command::internal::CommandOptionData,
+                __resolved: ::std::option::Option<&::twilight_model::application::interaction::InteractionDataResolved>
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseOptionErrorType> {
+                <#ty as ::twilight_interactions::command::CommandOption>::from_option(__value, __data, __resolved)
+                    .map(|__value| #constructor)
+            }
+        }
+    })
+}
+
+/// Implementation of the `CreateOption` trait for a newtype struct marked
+/// with `#[option(transparent)]`.
+pub fn impl_create_option(input: &DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let (ty, _) = inner_field(input)?;
+
+    let bound = quote!(::twilight_interactions::command::CreateOption);
+    let generics = bounded_generics(&input.generics, [ty], bound);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::twilight_interactions::command::CreateOption for #ident #ty_generics #where_clause {
+            fn create_option(
+                __data: ::twilight_interactions::command::internal::CreateOptionData,
+            ) -> ::twilight_model::application::command::CommandOption {
+                <#ty as ::twilight_interactions::command::CreateOption>::create_option(__data)
+            }
+        }
+    })
+}
+
+/// Get the type of the single field of a newtype struct, along with the
+/// expression building the struct from a `__value` variable.
+fn inner_field(input: &DeriveInput) -> Result<(&Type, TokenStream)> {
+    let error = || {
+        Error::new(
+            input.span(),
+            "`#[option(transparent)]` can only be applied to structs with a single field",
+        )
+    };
+
+    let Data::Struct(data) = &input.data else {
+        return Err(error());
+    };
+
+    let mut fields = data.fields.iter();
+    let (Some(field), None) = (fields.next(), fields.next()) else {
+        return Err(error());
+    };
+
+    let constructor = match &data.fields {
+        Fields::Named(_) => {
+            let ident = field.ident.to_token_stream();
+            quote!(Self { #ident: __value })
+        }
+        _ => quote!(Self(__value)),
+    };
+
+    Ok((&field.ty, constructor))
+}
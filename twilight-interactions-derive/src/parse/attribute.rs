@@ -9,15 +9,42 @@
 use std::fmt::Display;
 
 use proc_macro2::{Ident, Span};
-use syn::{meta::ParseNestedMeta, spanned::Spanned, Attribute, Error, Lit, Result};
+use syn::{
+    ext::IdentExt,
+    meta::ParseNestedMeta,
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token, Attribute, Error, Lit, LitBool, LitFloat, LitInt, LitStr, Path, Result, Token,
+};
+
+use super::metadata::MetadataValue;
 
 /// Parse a list of named attributes like `#[command(rename = "name")]`.
 ///
-/// This only support `(ident) = (literal)` syntax for simplicity. Collected
-/// values can be parsed using the `optional` and `required` methods.
+/// This only support `(ident) = (literal)` syntax for simplicity, and `(ident)`
+/// alone as a shorthand for `(ident) = true`. Negative numbers like `-100` are
+/// parsed as a single literal, and `(ident) = (path)` is accepted for values
+/// that can refer to constants (see [`ParseAttribute::parse_path`]). Lists of
+/// paths like `(ident)(A, B)` are accepted by some values (see
+/// [`ParseAttribute::parse_list`]). Collected values can be parsed
+/// using the `optional` and `required` methods.
+///
+/// The `crate` argument is ignored if it is valid, since it is handled by the
+/// [`crate_path`](super::crate_path) module. Entries of the `meta(key = value)`
+/// argument are collected with the `metadata` method if `meta` is valid.
 pub struct NamedAttrs {
     attr_span: Span,
-    values: Vec<(Ident, Lit)>,
+    values: Vec<(Ident, AttrValue)>,
+    metadata: Vec<(String, Lit)>,
+}
+
+/// Value of a named attribute
+enum AttrValue {
+    Lit(Lit),
+    Path(Path),
+    List(Vec<Path>, Span),
 }
 
 impl NamedAttrs {
@@ -28,6 +55,7 @@ impl NamedAttrs {
         let mut parser = Self {
             attr_span: attr.span(),
             values: Vec::new(),
+            metadata: Vec::new(),
         };
 
         attr.parse_nested_meta(|meta| parser.parse_meta(meta, valid))?;
@@ -36,18 +64,75 @@ impl NamedAttrs {
     }
 
     fn parse_meta(&mut self, meta: ParseNestedMeta, valid: &[&str]) -> Result<()> {
-        let is_valid = |ident| valid.iter().any(|name| ident == name);
+        if valid.contains(&"meta") && meta.path.is_ident("meta") {
+            return self.parse_entries(meta);
+        }
 
-        let Some(ident) = meta.path.get_ident().filter(|i| is_valid(*i)) else {
-            let expected = valid.join(", ");
+        let Some(ident) = meta
+            .path
+            .get_ident()
+            .filter(|i| valid.iter().any(|name| i == name))
+        else {
             return Err(Error::new_spanned(
                 meta.path,
-                format!("invalid argument name (expected one of {expected})"),
+                format!(
+                    "invalid argument name (expected one of {})",
+                    valid.join(", ")
+                ),
             ));
         };
 
-        let lit: Lit = meta.value()?.parse()?;
-        self.values.push((ident.clone(), lit));
+        let value = if meta.input.is_empty() || meta.input.peek(Token![,]) {
+            AttrValue::Lit(Lit::Bool(LitBool::new(true, ident.span())))
+        } else if meta.input.peek(token::Paren) {
+            let content;
+            let paren = parenthesized!(content in meta.input);
+            let paths = Punctuated::<Path, Token![,]>::parse_terminated_with(
+                &content,
+                Path::parse_mod_style,
+            )?;
+
+            AttrValue::List(paths.into_iter().collect(), paren.span.join())
+        } else {
+            let value = meta.value()?;
+
+            if value.peek(Token![-]) {
+                let minus: Token![-] = value.parse()?;
+                AttrValue::Lit(negate_literal(minus, value.parse()?)?)
+            } else if value.peek(Lit) {
+                AttrValue::Lit(value.parse()?)
+            } else {
+                AttrValue::Path(value.call(Path::parse_mod_style)?)
+            }
+        };
+
+        // The `crate` argument is read before expanding the derive macros (see
+        // the `crate_path` module).
+        if ident == "crate" {
+            return Ok(());
+        }
+
+        self.values.push((ident.clone(), value));
+
+        Ok(())
+    }
+
+    /// Parse the entries of a `meta(key = value, ...)` argument.
+    fn parse_entries(&mut self, meta: ParseNestedMeta) -> Result<()> {
+        let content;
+        parenthesized!(content in meta.input);
+        let entries = Punctuated::<MetaEntry, Token![,]>::parse_terminated(&content)?;
+
+        for entry in entries {
+            if self.metadata.iter().any(|(key, _)| *key == entry.key) {
+                return Err(Error::new(
+                    entry.span,
+                    format!("duplicate `{}` entry", entry.key),
+                ));
+            }
+
+            self.metadata.push((entry.key, entry.value));
+        }
 
         Ok(())
     }
@@ -58,8 +143,11 @@ impl NamedAttrs {
             return Ok(None);
         };
 
-        let (_, lit) = self.values.remove(index);
-        let parsed = T::parse_attribute(lit)?;
+        let parsed = match self.values.remove(index).1 {
+            AttrValue::Lit(lit) => T::parse_attribute(lit)?,
+            AttrValue::Path(path) => T::parse_path(path)?,
+            AttrValue::List(paths, span) => T::parse_list(paths, span)?,
+        };
 
         Ok(Some(parsed))
     }
@@ -77,11 +165,85 @@ impl NamedAttrs {
 
         Ok(parsed)
     }
+
+    /// Whether all the collected values have been parsed.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Parse the entries of the `meta` argument, in declaration order.
+    pub fn metadata(&mut self) -> Result<Vec<(String, String)>> {
+        self.metadata
+            .drain(..)
+            .map(|(key, lit)| {
+                let MetadataValue(value) = MetadataValue::parse_attribute(lit)?;
+
+                Ok((key, value))
+            })
+            .collect()
+    }
+}
+
+/// Entry of a `meta(key = value, ...)` argument.
+///
+/// Keys are identifiers (including keywords) or string literals, so that keys
+/// like `"feature-flag"` can be used.
+struct MetaEntry {
+    key: String,
+    span: Span,
+    value: Lit,
+}
+
+impl Parse for MetaEntry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let (key, span) = if input.peek(LitStr) {
+            let key: LitStr = input.parse()?;
+            (key.value(), key.span())
+        } else {
+            let key = input.call(Ident::parse_any)?;
+            (key.unraw().to_string(), key.span())
+        };
+
+        input.parse::<Token![=]>()?;
+        let value = if input.peek(Token![-]) {
+            let minus: Token![-] = input.parse()?;
+            negate_literal(minus, input.parse()?)?
+        } else {
+            input.parse()?
+        };
+
+        Ok(Self { key, span, value })
+    }
+}
+
+/// Negate a numeric literal preceded by a `-` sign.
+fn negate_literal(minus: Token![-], lit: Lit) -> Result<Lit> {
+    match lit {
+        Lit::Int(lit) => Ok(Lit::Int(LitInt::new(&format!("-{lit}"), lit.span()))),
+        Lit::Float(lit) => Ok(Lit::Float(LitFloat::new(&format!("-{lit}"), lit.span()))),
+        _ => Err(Error::new(
+            minus.span.join(lit.span()).unwrap_or(minus.span),
+            "only integer and float literals can be negative",
+        )),
+    }
 }
 
 /// Parse an attribute literal into a concrete type.
 pub trait ParseAttribute: Sized {
     fn parse_attribute(input: Lit) -> Result<Self>;
+
+    /// Parse an attribute whose value is a path, like `max_value = MAX`.
+    ///
+    /// Only types that can refer to constants accept paths.
+    fn parse_path(path: Path) -> Result<Self> {
+        Err(Error::new_spanned(path, "expected literal"))
+    }
+
+    /// Parse an attribute whose value is a list of paths, like
+    /// `channel_types(GuildText, Private)`.
+    fn parse_list(_paths: Vec<Path>, span: Span) -> Result<Self> {
+        Err(Error::new(span, "expected literal"))
+    }
 }
 
 impl ParseAttribute for String {
@@ -104,6 +266,16 @@ impl ParseAttribute for bool {
     }
 }
 
+impl ParseAttribute for i32 {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let Lit::Int(lit) = input else {
+            return Err(Error::new_spanned(input, "expected integer literal"));
+        };
+
+        lit.base10_parse()
+    }
+}
+
 impl ParseAttribute for u16 {
     fn parse_attribute(input: Lit) -> Result<Self> {
         let Lit::Int(lit) = input else {
@@ -133,4 +305,17 @@ impl<T: ParseAttribute> ParseAttribute for ParseSpanned<T> {
 
         Ok(Self { span, inner })
     }
+
+    fn parse_path(path: Path) -> Result<Self> {
+        let span = path.span();
+        let inner = T::parse_path(path)?;
+
+        Ok(Self { span, inner })
+    }
+
+    fn parse_list(paths: Vec<Path>, span: Span) -> Result<Self> {
+        let inner = T::parse_list(paths, span)?;
+
+        Ok(Self { span, inner })
+    }
 }
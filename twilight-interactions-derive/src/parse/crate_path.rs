@@ -0,0 +1,101 @@
+//! Support of the `crate` attribute.
+//!
+//! Generated code refers to items of the library with absolute paths starting
+//! with `::twilight_interactions`, which do not resolve when the crate is
+//! renamed or re-exported from another crate. The `crate` argument of type
+//! attributes overrides this path: [`find_crate_path`] reads the argument and
+//! [`apply_crate_path`] rewrites the generated code to use it.
+//!
+//! Parsers of type attributes accept the `crate` argument, but its value is
+//! ignored by [`NamedAttrs`](super::attribute::NamedAttrs).
+
+use proc_macro2::{Group, Spacing, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{Attribute, LitStr, Path, Result, Token};
+
+use super::syntax::find_attr;
+
+/// Name of the crate used in generated paths.
+const CRATE_NAME: &str = "twilight_interactions";
+
+/// Find the path set with the `crate` argument of the attribute with the
+/// provided name.
+///
+/// Other arguments of the attribute are skipped, since they are validated by
+/// the derive macros.
+pub fn find_crate_path(attrs: &[Attribute], name: &str) -> Result<Option<Path>> {
+    let Some(attr) = find_attr(attrs, name) else {
+        return Ok(None);
+    };
+
+    let mut path = None;
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("crate") {
+            let value: LitStr = meta.value()?.parse()?;
+            path = Some(value.parse()?);
+        } else {
+            while !meta.input.is_empty() && !meta.input.peek(Token![,]) {
+                meta.input.parse::<TokenTree>()?;
+            }
+        }
+
+        Ok(())
+    })?;
+
+    Ok(path)
+}
+
+/// Rewrite the generated code to use the path found with [`find_crate_path`].
+///
+/// Errors returned when reading the path are appended to the output.
+pub fn apply_crate_path(path: Result<Option<Path>>, output: TokenStream) -> TokenStream {
+    match path {
+        Ok(Some(path)) => replace_crate_path(output, &path.into_token_stream()),
+        Ok(None) => output,
+        Err(error) => {
+            let error = error.to_compile_error();
+            quote!(#output #error)
+        }
+    }
+}
+
+/// Replace the `::twilight_interactions` prefix of absolute paths.
+fn replace_crate_path(tokens: TokenStream, path: &TokenStream) -> TokenStream {
+    let tokens: Vec<_> = tokens.into_iter().collect();
+    let mut output = Vec::with_capacity(tokens.len());
+    let mut index = 0;
+
+    while index < tokens.len() {
+        if is_crate_path(&tokens[index..]) {
+            output.extend(path.clone());
+            index += 3;
+            continue;
+        }
+
+        output.push(match &tokens[index] {
+            TokenTree::Group(group) => {
+                let mut replaced =
+                    Group::new(group.delimiter(), replace_crate_path(group.stream(), path));
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced)
+            }
+            other => other.clone(),
+        });
+        index += 1;
+    }
+
+    output.into_iter().collect()
+}
+
+/// Whether the tokens start with `::twilight_interactions`.
+fn is_crate_path(tokens: &[TokenTree]) -> bool {
+    let [TokenTree::Punct(first), TokenTree::Punct(second), TokenTree::Ident(ident), ..] = tokens
+    else {
+        return false;
+    };
+
+    first.as_char() == ':'
+        && first.spacing() == Spacing::Joint
+        && second.as_char() == ':'
+        && ident == CRATE_NAME
+}
@@ -0,0 +1,48 @@
+//! Metadata of commands.
+//!
+//! Entries of the type-level `#[command(meta(key = value, ...))]` attribute
+//! are stored in the metadata of the created command.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Lit, Result};
+
+use super::attribute::ParseAttribute;
+
+/// Value of a metadata entry.
+///
+/// String, integer, float and boolean literals are accepted and converted
+/// into their string representation.
+pub struct MetadataValue(pub String);
+
+impl ParseAttribute for MetadataValue {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let value = match &input {
+            Lit::Str(lit) => lit.value(),
+            Lit::Int(lit) => lit.base10_digits().to_owned(),
+            Lit::Float(lit) => lit.base10_digits().to_owned(),
+            Lit::Bool(lit) => lit.value.to_string(),
+            _ => {
+                return Err(Error::new_spanned(
+                    input,
+                    "expected string, integer, float or boolean literal",
+                ))
+            }
+        };
+
+        Ok(Self(value))
+    }
+}
+
+/// Generate the metadata map of a command.
+pub fn metadata_expr(metadata: &[(String, String)]) -> TokenStream {
+    if metadata.is_empty() {
+        return quote! { ::std::collections::HashMap::new() };
+    }
+
+    let items = metadata.iter().map(|(key, value)| {
+        quote! { (::std::string::String::from(#key), ::std::string::String::from(#value)) }
+    });
+
+    quote! { ::std::collections::HashMap::from([#(#items),*]) }
+}
@@ -1,5 +1,7 @@
 //! Logic used to parse macro input across the crate.
 
 pub mod attribute;
+pub mod crate_path;
+pub mod metadata;
 pub mod parsers;
 pub mod syntax;
@@ -1,5 +1,7 @@
-use proc_macro2::TokenStream;
-use quote::ToTokens;
+use std::time::Duration;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
 use syn::{Error, Lit, Path, Result};
 
 use super::attribute::{ParseAttribute, ParseSpanned};
@@ -8,6 +10,83 @@ use super::attribute::{ParseAttribute, ParseSpanned};
 #[derive(Clone)]
 pub struct FunctionPath(Path);
 
+/// Attribute value that is either a literal or a path to a constant, like
+/// `max_value = limits::MAX_AMOUNT`.
+///
+/// Constants are resolved in the generated code, so they cannot be validated
+/// by the macro. Names, descriptions and lengths are instead checked by const
+/// assertions in the generated code.
+#[derive(Clone)]
+pub enum ConstOr<T> {
+    Value(T),
+    Const(Path),
+}
+
+impl<T: ParseAttribute> ParseAttribute for ConstOr<T> {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        T::parse_attribute(input).map(Self::Value)
+    }
+
+    fn parse_path(path: Path) -> Result<Self> {
+        Ok(Self::Const(path))
+    }
+}
+
+impl<T: ToTokens> ToTokens for ConstOr<T> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Value(value) => value.to_tokens(tokens),
+            Self::Const(path) => path.to_tokens(tokens),
+        }
+    }
+}
+
+impl<T: ToTokens> ConstOr<T> {
+    /// Generate the value, checking constants at compile time with the
+    /// provided function of the `internal` module.
+    fn checked_with(&self, check: TokenStream) -> TokenStream {
+        match self {
+            Self::Value(value) => value.to_token_stream(),
+            Self::Const(path) => quote! {{
+                const _: () = ::twilight_interactions::command::internal::#check(#path);
+                #path
+            }},
+        }
+    }
+}
+
+impl ConstOr<CommandName> {
+    /// Generate the name, checking constants at compile time.
+    pub fn checked(&self) -> TokenStream {
+        self.checked_with(quote!(check_name))
+    }
+
+    /// Generate the name of a command, checking constants at compile time.
+    ///
+    /// Names of built-in Discord commands are rejected if `deny_reserved` is
+    /// set, like literal names checked with [`CommandName::check_reserved`].
+    pub fn checked_command(&self, deny_reserved: bool) -> TokenStream {
+        match deny_reserved {
+            true => self.checked_with(quote!(check_unreserved_name)),
+            false => self.checked(),
+        }
+    }
+}
+
+impl ConstOr<u16> {
+    /// Generate the string length, checking constants at compile time.
+    pub fn checked(&self) -> TokenStream {
+        self.checked_with(quote!(check_length))
+    }
+}
+
+impl ConstOr<CommandDescription> {
+    /// Generate the description, checking constants at compile time.
+    pub fn checked(&self) -> TokenStream {
+        self.checked_with(quote!(check_description))
+    }
+}
+
 impl ParseAttribute for FunctionPath {
     fn parse_attribute(input: Lit) -> Result<Self> {
         let Lit::Str(lit) = input else {
@@ -40,29 +119,84 @@ pub struct CommandName(String);
 impl ParseAttribute for CommandName {
     fn parse_attribute(input: Lit) -> Result<Self> {
         let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
-        let value = spanned.inner.trim();
 
+        Self::new(spanned.inner.trim(), spanned.span)
+    }
+}
+
+impl CommandName {
+    /// Validate a command name.
+    pub fn new(value: &str, span: Span) -> Result<Self> {
         match value.chars().count() {
             1..=32 => (),
-            _ => return Err(spanned.error("name must be between 1 and 32 characters")),
+            _ => return Err(Error::new(span, "name must be between 1 and 32 characters")),
         }
 
         for char in value.chars() {
             if !char.is_alphanumeric() && char != '-' && char != '_' {
-                return Err(spanned.error(format!(
-                    "name must only contain word characters, found invalid character `{char}`"
-                )));
+                return Err(Error::new(
+                    span,
+                    format!(
+                        "name must only contain word characters, found invalid character `{char}`"
+                    ),
+                ));
             }
 
             if char.to_lowercase().to_string() != char.to_string() {
-                return Err(spanned.error(format!(
-                    "name must be in lowercase, found invalid character `{char}`"
-                )));
+                return Err(Error::new(
+                    span,
+                    format!("name must be in lowercase, found invalid character `{char}`"),
+                ));
             }
         }
 
         Ok(Self(value.to_owned()))
     }
+
+    /// Get the name as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Names of the built-in commands of the Discord client.
+    ///
+    /// Commands with these names are accepted by Discord but are displayed
+    /// alongside the built-in command, which is confusing for users. They are
+    /// only rejected with the `deny_reserved_name` attribute. This list must be
+    /// kept in sync with `audit::RESERVED_NAMES`, which is checked by the
+    /// `audit` tests of the main crate.
+    const RESERVED_NAMES: &'static [&'static str] = &[
+        "ban",
+        "giphy",
+        "kick",
+        "me",
+        "msg",
+        "nick",
+        "shrug",
+        "spoiler",
+        "tableflip",
+        "tenor",
+        "thread",
+        "timeout",
+        "tts",
+        "unflip",
+    ];
+
+    /// Return an error if the name is reserved and `deny` is set.
+    pub fn check_reserved(&self, deny: bool, span: Span) -> Result<()> {
+        if !deny || !Self::RESERVED_NAMES.contains(&&*self.0) {
+            return Ok(());
+        }
+
+        Err(Error::new(
+            span,
+            format!(
+                "`{}` is the name of a built-in Discord command, remove `deny_reserved_name` \
+                 to use it anyway",
+                self.0
+            ),
+        ))
+    }
 }
 
 impl ToTokens for CommandName {
@@ -103,12 +237,6 @@ impl ToTokens for CommandDescription {
     }
 }
 
-impl From<CommandDescription> for String {
-    fn from(value: CommandDescription) -> Self {
-        value.0
-    }
-}
-
 /// Slash command choice name.
 ///
 /// This validate that the choice is between 1 and 100 characters.
@@ -141,3 +269,222 @@ impl From<ChoiceName> for String {
         value.0
     }
 }
+
+/// Casing applied to variant identifiers with the `rename_all` attribute.
+///
+/// The supported rules are the same as the `serde` crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    /// Apply the rule to a variant identifier written in `PascalCase`.
+    pub fn apply(self, ident: &str) -> String {
+        match self {
+            Self::Lower => ident.to_lowercase(),
+            Self::Upper => ident.to_uppercase(),
+            Self::Pascal => ident.to_owned(),
+            Self::Camel => {
+                let mut chars = ident.chars();
+                match chars.next() {
+                    Some(first) => first.to_lowercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            }
+            Self::Snake => {
+                let mut snake = String::with_capacity(ident.len());
+                for (i, char) in ident.char_indices() {
+                    if i > 0 && char.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.extend(char.to_lowercase());
+                }
+                snake
+            }
+            Self::ScreamingSnake => Self::Snake.apply(ident).to_uppercase(),
+            Self::Kebab => Self::Snake.apply(ident).replace('_', "-"),
+            Self::ScreamingKebab => Self::ScreamingSnake.apply(ident).replace('_', "-"),
+        }
+    }
+
+    /// Apply the rule to a field identifier written in `snake_case`.
+    pub fn apply_snake(self, ident: &str) -> String {
+        self.apply(&pascal_case(ident))
+    }
+
+    /// Get a [`ChoiceName`] from a variant identifier.
+    ///
+    /// Identifiers are always between 1 and 100 characters, so the length is
+    /// not validated.
+    pub fn choice_name(self, ident: &str) -> ChoiceName {
+        ChoiceName(self.apply(ident))
+    }
+}
+
+/// Rename rule of command and option names.
+///
+/// Only rules producing lowercase names are accepted, since Discord requires
+/// lowercase names for chat input commands.
+#[derive(Clone, Copy)]
+pub struct CommandRenameRule(pub RenameRule);
+
+impl ParseAttribute for CommandRenameRule {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let spanned: ParseSpanned<RenameRule> = ParseAttribute::parse_attribute(input)?;
+
+        match spanned.inner {
+            RenameRule::Lower | RenameRule::Snake | RenameRule::Kebab => Ok(Self(spanned.inner)),
+            _ => Err(spanned.error(
+                "command names must be lowercase (expected one of lowercase, snake_case or \
+                 kebab-case)",
+            )),
+        }
+    }
+}
+
+/// Convert a `snake_case` identifier into `PascalCase`.
+pub fn pascal_case(ident: &str) -> String {
+    ident
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+impl ParseAttribute for RenameRule {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
+
+        match spanned.inner.as_str() {
+            "lowercase" => Ok(Self::Lower),
+            "UPPERCASE" => Ok(Self::Upper),
+            "PascalCase" => Ok(Self::Pascal),
+            "camelCase" => Ok(Self::Camel),
+            "snake_case" => Ok(Self::Snake),
+            "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnake),
+            "kebab-case" => Ok(Self::Kebab),
+            "SCREAMING-KEBAB-CASE" => Ok(Self::ScreamingKebab),
+            invalid => Err(spanned.error(format!(
+                "`{invalid}` is not a valid rename rule (expected one of lowercase, UPPERCASE, \
+                 PascalCase, camelCase, snake_case, SCREAMING_SNAKE_CASE, kebab-case or \
+                 SCREAMING-KEBAB-CASE)"
+            ))),
+        }
+    }
+}
+
+/// Maximum execution time of a command handler.
+///
+/// The duration is parsed using the `humantime` format (e.g. `10s` or `1m 30s`)
+/// and must be greater than zero.
+#[derive(Clone, Copy, Debug)]
+pub struct CommandTimeout(Duration);
+
+impl ParseAttribute for CommandTimeout {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
+
+        let duration = humantime::parse_duration(spanned.inner.trim())
+            .map_err(|error| spanned.error(format!("invalid timeout duration: {error}")))?;
+
+        if duration.is_zero() {
+            return Err(spanned.error("timeout must be greater than zero"));
+        }
+
+        Ok(Self(duration))
+    }
+}
+
+impl ToTokens for CommandTimeout {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let secs = self.0.as_secs();
+        let nanos = self.0.subsec_nanos();
+
+        tokens.extend(quote! { ::std::time::Duration::new(#secs, #nanos) });
+    }
+}
+
+/// Cooldown of a command, like `5s per user`.
+///
+/// The duration is parsed using the `humantime` format and must be greater
+/// than zero. It is followed by an optional scope (`user`, `channel`, `guild`
+/// or `global`), which defaults to `user`.
+#[derive(Clone, Copy, Debug)]
+pub struct CommandCooldown {
+    duration: Duration,
+    scope: CooldownScope,
+}
+
+/// Scope of a [`CommandCooldown`].
+#[derive(Clone, Copy, Debug)]
+enum CooldownScope {
+    User,
+    Channel,
+    Guild,
+    Global,
+}
+
+impl ParseAttribute for CommandCooldown {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
+        let value = spanned.inner.trim();
+
+        let (duration, scope) = match value.split_once(" per ") {
+            Some((duration, scope)) => (duration, Some(scope.trim())),
+            None => (value, None),
+        };
+
+        let duration = humantime::parse_duration(duration.trim())
+            .map_err(|error| spanned.error(format!("invalid cooldown duration: {error}")))?;
+
+        if duration.is_zero() {
+            return Err(spanned.error("cooldown must be greater than zero"));
+        }
+
+        let scope = match scope {
+            None | Some("user") => CooldownScope::User,
+            Some("channel") => CooldownScope::Channel,
+            Some("guild") => CooldownScope::Guild,
+            Some("global") => CooldownScope::Global,
+            Some(scope) => {
+                return Err(spanned.error(format!(
+                    "invalid cooldown scope `{scope}` (expected user, channel, guild or global)"
+                )))
+            }
+        };
+
+        Ok(Self { duration, scope })
+    }
+}
+
+impl ToTokens for CommandCooldown {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let duration = CommandTimeout(self.duration);
+        let scope = match self.scope {
+            CooldownScope::User => quote!(User),
+            CooldownScope::Channel => quote!(Channel),
+            CooldownScope::Guild => quote!(Guild),
+            CooldownScope::Global => quote!(Global),
+        };
+
+        tokens.extend(quote! {
+            ::twilight_interactions::cooldown::Cooldown::new(
+                #duration,
+                ::twilight_interactions::cooldown::CooldownScope::#scope,
+            )
+        });
+    }
+}
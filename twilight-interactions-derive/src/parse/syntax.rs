@@ -1,8 +1,13 @@
 //! Rust syntax parsing helpers.
 
-use proc_macro2::{Span, TokenStream};
+use std::collections::HashMap;
+
+use proc_macro2::{Span, TokenStream, TokenTree};
 use quote::{quote, ToTokens};
-use syn::{Attribute, Error, Expr, GenericArgument, Lit, PathArguments, Result};
+use syn::{
+    parse_quote, Attribute, Error, Expr, GenericArgument, Generics, Ident, Lit, PathArguments,
+    Result, Type,
+};
 
 /// Find the first attribute with a specific name.
 pub fn find_attr<'a>(attrs: &'a [Attribute], name: &str) -> Option<&'a Attribute> {
@@ -78,6 +83,27 @@ pub fn parse_doc(attrs: &[Attribute], span: Span) -> Result<String> {
     }
 }
 
+/// Ensure that names or values of options, subcommands or choices are unique.
+///
+/// The `kind` is used in the error message, which points to both items using
+/// the same value.
+pub fn check_unique(values: impl IntoIterator<Item = (String, Span)>, kind: &str) -> Result<()> {
+    let mut seen: HashMap<String, Span> = HashMap::new();
+
+    for (value, span) in values {
+        if let Some(first) = seen.get(&value) {
+            let mut error = Error::new(span, format!("duplicate {kind} `{value}`"));
+            error.combine(Error::new(*first, format!("`{value}` is first used here")));
+
+            return Err(error);
+        }
+
+        seen.insert(value, span);
+    }
+
+    Ok(())
+}
+
 /// Convert an [`Option<T>`] into a [`TokenStream`]
 pub fn optional<T>(value: Option<T>) -> TokenStream
 where
@@ -88,3 +114,40 @@ where
         None => quote! {::std::option::Option::None },
     }
 }
+
+/// Add a `where` predicate bounding each type that uses a generic type
+/// parameter with the provided trait.
+///
+/// For example, with the `T: CommandOption` bound, a field of type `Vec<T>`
+/// adds the `Vec<T>: CommandOption` predicate. Types that do not use generic
+/// parameters are ignored.
+pub fn bounded_generics<'a>(
+    generics: &Generics,
+    types: impl IntoIterator<Item = &'a Type>,
+    bound: TokenStream,
+) -> Generics {
+    let params: Vec<_> = generics.type_params().map(|param| &param.ident).collect();
+    let mut generics = generics.clone();
+
+    if params.is_empty() {
+        return generics;
+    }
+
+    let where_clause = generics.make_where_clause();
+    for ty in types {
+        if uses_params(ty.to_token_stream(), &params) {
+            where_clause.predicates.push(parse_quote!(#ty: #bound));
+        }
+    }
+
+    generics
+}
+
+/// Check whether a token stream contains any of the provided identifiers.
+fn uses_params(tokens: TokenStream, params: &[&Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => params.contains(&&ident),
+        TokenTree::Group(group) => uses_params(group.stream(), params),
+        _ => false,
+    })
+}
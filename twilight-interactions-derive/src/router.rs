@@ -0,0 +1,172 @@
+//! Implementation of the `CommandRouter` derive macro.
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Result, Type, Variant};
+
+use crate::parse::{attribute::NamedAttrs, parsers::CommandName, syntax::find_attr};
+
+/// Implementation of the `CommandRouter` derive macro
+pub fn impl_command_router(input: DeriveInput) -> Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new(
+            input.span(),
+            "`CommandRouter` can only be applied to enums",
+        ));
+    };
+
+    if data.variants.is_empty() {
+        return Err(Error::new(
+            input.span(),
+            "enum must have at least one variant",
+        ));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let variants = data
+        .variants
+        .iter()
+        .map(RouterVariant::from_variant)
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut fallbacks = variants.iter().filter(|variant| variant.fallback);
+    let fallback = fallbacks.next();
+    if let Some(variant) = fallbacks.next() {
+        return Err(Error::new(
+            variant.span,
+            "only one variant can have the `fallback` attribute",
+        ));
+    }
+
+    let variant_branches = variants
+        .iter()
+        .filter(|variant| !variant.fallback)
+        .map(variant_branch);
+    let fallback_branch = match fallback {
+        Some(variant) => fallback_branch(variant),
+        None => quote! {
+            ::std::result::Result::Err(
+                ::twilight_interactions::error::ParseError::UnknownCommand(__data.name)
+            )
+        },
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::twilight_interactions::command::CommandRouter for #ident #ty_generics #where_clause {
+            fn route(
+                __data: ::twilight_model::application::interaction::application_command::CommandData,
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseError> {
+                #(#variant_branches)*
+
+                #fallback_branch
+            }
+        }
+    })
+}
+
+/// Dummy implementation of the `CommandRouter` trait in case of macro error
+pub fn dummy_command_router(ident: Ident, error: Error) -> TokenStream {
+    let error = error.to_compile_error();
+
+    quote! {
+        #error
+
+        impl ::twilight_interactions::command::CommandRouter for #ident {
+            fn route(
+                data: ::twilight_model::application::interaction::application_command::CommandData,
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseError> {
+                ::std::unimplemented!()
+            }
+        }
+    }
+}
+
+/// Parsed enum variant
+struct RouterVariant {
+    span: Span,
+    ident: Ident,
+    name: Option<CommandName>,
+    fallback: bool,
+    inner: Type,
+}
+
+impl RouterVariant {
+    /// Parse a single syn [`Variant`].
+    fn from_variant(variant: &Variant) -> Result<Self> {
+        let span = variant.span();
+        let Fields::Unnamed(fields) = &variant.fields else {
+            return Err(Error::new(span, "variant must be an unnamed variant"));
+        };
+
+        if fields.unnamed.len() != 1 {
+            return Err(Error::new(
+                span,
+                "variant must have exactly one unnamed field",
+            ));
+        }
+
+        let (name, fallback) = match find_attr(&variant.attrs, "command") {
+            Some(attr) => {
+                let mut parser = NamedAttrs::parse(attr, &["name", "fallback"])?;
+                let name: Option<CommandName> = parser.optional("name")?;
+                let fallback = parser.optional("fallback")?.unwrap_or(false);
+
+                if fallback && name.is_some() {
+                    return Err(Error::new(
+                        attr.span(),
+                        "`name` and `fallback` are mutually exclusive",
+                    ));
+                }
+
+                (name, fallback)
+            }
+            None => (None, false),
+        };
+
+        Ok(Self {
+            span,
+            ident: variant.ident.clone(),
+            name,
+            fallback,
+            inner: fields.unnamed[0].ty.clone(),
+        })
+    }
+}
+
+/// Generate the branch matching a variant
+fn variant_branch(variant: &RouterVariant) -> TokenStream {
+    let ident = &variant.ident;
+    let ty = &variant.inner;
+    let span = variant.span;
+
+    let name = match &variant.name {
+        Some(name) => quote! { #name },
+        None => quote! { <#ty as ::twilight_interactions::command::CreateCommand>::NAME },
+    };
+
+    quote_spanned! {span=>
+        if __data.name == #name {
+            return ::std::result::Result::Ok(Self::#ident(
+                <#ty as ::twilight_interactions::command::CommandModel>::from_interaction(
+                    ::std::convert::From::from(__data)
+                )?
+            ));
+        }
+    }
+}
+
+/// Generate the branch of the variant receiving unknown commands
+fn fallback_branch(variant: &RouterVariant) -> TokenStream {
+    let ident = &variant.ident;
+    let ty = &variant.inner;
+    let span = variant.span;
+
+    quote_spanned! {span=>
+        ::std::result::Result::Ok(Self::#ident(
+            <#ty as ::twilight_interactions::command::CommandModel>::from_interaction(
+                ::std::convert::From::from(__data)
+            )?
+        ))
+    }
+}
@@ -0,0 +1,312 @@
+//! Implementation of the `SelectOption` derive macro.
+
+use std::collections::HashSet;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Lit, Result, Variant};
+
+use crate::parse::{
+    attribute::{NamedAttrs, ParseAttribute, ParseSpanned},
+    parsers::RenameRule,
+    syntax::find_attr,
+};
+
+/// Maximum number of options in a select menu
+const MAX_OPTIONS: usize = 25;
+
+/// Maximum length of the label, value and description of an option
+const MAX_LENGTH: usize = 100;
+
+/// Implementation of the `SelectOption` derive macro
+pub fn impl_select_option(input: DeriveInput) -> Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new(
+            input.span(),
+            "`SelectOption` can only be applied to enums",
+        ));
+    };
+
+    if data.variants.is_empty() {
+        return Err(Error::new(
+            input.span(),
+            "enum must have at least one variant",
+        ));
+    }
+
+    if data.variants.len() > MAX_OPTIONS {
+        return Err(Error::new(
+            input.span(),
+            format!("enum must have at most {MAX_OPTIONS} variants"),
+        ));
+    }
+
+    let rename_all = match find_attr(&input.attrs, "select") {
+        Some(attr) => NamedAttrs::parse(attr, &["rename_all", "crate"])?.optional("rename_all")?,
+        None => None,
+    };
+
+    let variants = data
+        .variants
+        .iter()
+        .map(|variant| SelectVariant::from_variant(variant, rename_all))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut values = HashSet::new();
+    for variant in &variants {
+        if !values.insert(&variant.value) {
+            return Err(Error::new(
+                variant.span,
+                format!("duplicate option value `{}`", variant.value),
+            ));
+        }
+    }
+
+    let ident = &input.ident;
+    let capacity = variants.len();
+    let options = variants.iter().map(select_menu_option);
+    let from_value_arms = variants.iter().map(from_value_arm);
+    let value_arms = variants.iter().map(value_arm);
+
+    Ok(quote! {
+        impl ::twilight_interactions::component::SelectOption for #ident {
+            fn select_options() -> ::std::vec::Vec<::twilight_model::channel::message::component::SelectMenuOption> {
+                let mut __options = ::std::vec::Vec::with_capacity(#capacity);
+
+                #(#options)*
+
+                __options
+            }
+
+            fn from_value(__value: &str) -> ::std::option::Option<Self> {
+                match __value {
+                    #(#from_value_arms,)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            fn select_value(&self) -> &'static str {
+                match self {
+                    #(#value_arms,)*
+                }
+            }
+        }
+    })
+}
+
+/// Dummy implementation of the `SelectOption` trait in case of macro error
+pub fn dummy_select_option(ident: Ident, error: Error) -> TokenStream {
+    let error = error.to_compile_error();
+
+    quote! {
+        #error
+
+        impl ::twilight_interactions::component::SelectOption for #ident {
+            fn select_options() -> ::std::vec::Vec<::twilight_model::channel::message::component::SelectMenuOption> {
+                ::std::unimplemented!()
+            }
+
+            fn from_value(value: &str) -> ::std::option::Option<Self> {
+                ::std::unimplemented!()
+            }
+
+            fn select_value(&self) -> &'static str {
+                ::std::unimplemented!()
+            }
+        }
+    }
+}
+
+/// Parsed enum variant
+struct SelectVariant {
+    span: Span,
+    ident: Ident,
+    label: String,
+    value: String,
+    description: Option<String>,
+    emoji: Option<SelectEmoji>,
+    default: bool,
+}
+
+impl SelectVariant {
+    /// Parse a single syn [`Variant`].
+    fn from_variant(variant: &Variant, rename_all: Option<RenameRule>) -> Result<Self> {
+        let span = variant.span();
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(span, "variant must be a unit variant"));
+        }
+
+        let ident = variant.ident.to_string();
+        let mut parser = match find_attr(&variant.attrs, "select") {
+            Some(attr) => Some(NamedAttrs::parse(
+                attr,
+                &["label", "value", "description", "emoji", "default"],
+            )?),
+            None if rename_all.is_some() => None,
+            None => {
+                return Err(Error::new(
+                    span,
+                    "missing required #[select(...)] attribute",
+                ))
+            }
+        };
+
+        let (label, value) = match rename_all {
+            Some(rename_all) => {
+                let renamed = || spanned(span, rename_all.apply(&ident));
+
+                (
+                    optional(&mut parser, "label")?.unwrap_or_else(renamed),
+                    optional(&mut parser, "value")?.unwrap_or_else(renamed),
+                )
+            }
+            None => {
+                let parser = parser.as_mut().expect("attribute is required");
+
+                (parser.required("label")?, parser.required("value")?)
+            }
+        };
+        let description: Option<ParseSpanned<String>> = optional(&mut parser, "description")?;
+
+        for text in [Some(&label), Some(&value), description.as_ref()]
+            .into_iter()
+            .flatten()
+        {
+            if text.inner.is_empty() || text.inner.chars().count() > MAX_LENGTH {
+                return Err(text.error(format!("must be between 1 and {MAX_LENGTH} characters")));
+            }
+        }
+
+        Ok(Self {
+            span,
+            ident: variant.ident.clone(),
+            label: label.inner,
+            value: value.inner,
+            description: description.map(|description| description.inner),
+            emoji: optional(&mut parser, "emoji")?,
+            default: optional(&mut parser, "default")?.unwrap_or_default(),
+        })
+    }
+}
+
+/// Emoji of a select menu option
+enum SelectEmoji {
+    Unicode(String),
+    Custom {
+        animated: bool,
+        id: u64,
+        name: String,
+    },
+}
+
+impl ParseAttribute for SelectEmoji {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
+        let value = spanned.inner.trim();
+
+        // Custom emojis are written like `<:name:id>` or `<a:name:id>`
+        let Some(custom) = value.strip_prefix('<').and_then(|v| v.strip_suffix('>')) else {
+            if value.is_empty() {
+                return Err(spanned.error("emoji must not be empty"));
+            }
+
+            return Ok(Self::Unicode(value.to_owned()));
+        };
+
+        let mut parts = custom.split(':');
+        let (Some(prefix), Some(name), Some(id), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(spanned.error("custom emoji must be written like `<:name:id>`"));
+        };
+
+        let animated = match prefix {
+            "" => false,
+            "a" => true,
+            _ => return Err(spanned.error("custom emoji must be written like `<:name:id>`")),
+        };
+
+        match id.parse() {
+            Ok(id) if id != 0 => Ok(Self::Custom {
+                animated,
+                id,
+                name: name.to_owned(),
+            }),
+            _ => Err(spanned.error("invalid custom emoji id")),
+        }
+    }
+}
+
+/// Generate the code pushing the option of a variant
+fn select_menu_option(variant: &SelectVariant) -> TokenStream {
+    let span = variant.span;
+    let label = &variant.label;
+    let value = &variant.value;
+    let default = variant.default;
+    let description = match &variant.description {
+        Some(description) => {
+            quote! { ::std::option::Option::Some(::std::string::String::from(#description)) }
+        }
+        None => quote! { ::std::option::Option::None },
+    };
+    let emoji = match &variant.emoji {
+        Some(SelectEmoji::Unicode(name)) => quote! {
+            ::std::option::Option::Some(::twilight_model::channel::message::EmojiReactionType::Unicode {
+                name: ::std::string::String::from(#name),
+            })
+        },
+        Some(SelectEmoji::Custom { animated, id, name }) => quote! {
+            ::std::option::Option::Some(::twilight_model::channel::message::EmojiReactionType::Custom {
+                animated: #animated,
+                id: ::twilight_model::id::Id::new(#id),
+                name: ::std::option::Option::Some(::std::string::String::from(#name)),
+            })
+        },
+        None => quote! { ::std::option::Option::None },
+    };
+
+    quote_spanned! {span=>
+        __options.push(::twilight_model::channel::message::component::SelectMenuOption {
+            default: #default,
+            description: #description,
+            emoji: #emoji,
+            label: ::std::string::String::from(#label),
+            value: ::std::string::String::from(#value),
+        });
+    }
+}
+
+/// Generate the match arm parsing the value of a variant
+fn from_value_arm(variant: &SelectVariant) -> TokenStream {
+    let ident = &variant.ident;
+    let value = &variant.value;
+    let span = variant.span;
+
+    quote_spanned! {span=>
+        #value => ::std::option::Option::Some(Self::#ident)
+    }
+}
+
+/// Generate the match arm returning the value of a variant
+fn value_arm(variant: &SelectVariant) -> TokenStream {
+    let ident = &variant.ident;
+    let value = &variant.value;
+    let span = variant.span;
+
+    quote_spanned! {span=>
+        Self::#ident => #value
+    }
+}
+
+/// Parse an optional argument of a variant attribute, if present
+fn optional<T: ParseAttribute>(parser: &mut Option<NamedAttrs>, name: &str) -> Result<Option<T>> {
+    match parser {
+        Some(parser) => parser.optional(name),
+        None => Ok(None),
+    }
+}
+
+/// Wrap a value derived from a variant identifier in a [`ParseSpanned`]
+fn spanned<T>(span: Span, inner: T) -> ParseSpanned<T> {
+    ParseSpanned { span, inner }
+}
@@ -0,0 +1,78 @@
+//! Implementation of the `SelectMenuModel` derive macro.
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Result};
+
+use crate::parse::syntax::extract_generic;
+
+/// Implementation of the `SelectMenuModel` derive macro
+pub fn impl_select_menu_model(input: DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => struct_body(&data.fields, input.span())?,
+        Data::Enum(_) => quote! {
+            ::twilight_interactions::component::internal::parse_one(&__data.values)
+        },
+        Data::Union(_) => {
+            return Err(Error::new(
+                input.span(),
+                "`SelectMenuModel` can only be applied to structs or enums",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::twilight_interactions::component::SelectMenuModel for #ident #ty_generics #where_clause {
+            fn from_interaction(
+                __data: &::twilight_model::application::interaction::message_component::MessageComponentInteractionData,
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseSelectMenuError> {
+                #body
+            }
+        }
+    })
+}
+
+/// Dummy implementation of the `SelectMenuModel` trait in case of macro error
+pub fn dummy_select_menu_model(ident: Ident, error: Error) -> TokenStream {
+    let error = error.to_compile_error();
+
+    quote! {
+        #error
+
+        impl ::twilight_interactions::component::SelectMenuModel for #ident {
+            fn from_interaction(
+                data: &::twilight_model::application::interaction::message_component::MessageComponentInteractionData,
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseSelectMenuError> {
+                ::std::unimplemented!()
+            }
+        }
+    }
+}
+
+/// Generate the body parsing the single field of a struct
+fn struct_body(fields: &Fields, span: Span) -> Result<TokenStream> {
+    let mut iter = fields.iter();
+    let (Some(field), None) = (iter.next(), iter.next()) else {
+        return Err(Error::new(span, "struct must have exactly one field"));
+    };
+
+    let span = field.span();
+    let parse_fn = if extract_generic(&field.ty, "Vec").is_some() {
+        quote!(parse_many)
+    } else if extract_generic(&field.ty, "Option").is_some() {
+        quote!(parse_optional)
+    } else {
+        quote!(parse_one)
+    };
+    let value = quote_spanned! {span=>
+        ::twilight_interactions::component::internal::#parse_fn(&__data.values)?
+    };
+
+    Ok(match &field.ident {
+        Some(ident) => quote!(::std::result::Result::Ok(Self { #ident: #value })),
+        None => quote!(::std::result::Result::Ok(Self(#value))),
+    })
+}
@@ -0,0 +1,197 @@
+//! Audit of commands against user experience guidelines.
+//!
+//! Discord accepts some command definitions that result in a poor user
+//! experience, such as a single choice for an option or inconsistent
+//! punctuation in descriptions. The [`lint`] function reports these issues,
+//! and is intended to be called from a test to enforce a consistent style on
+//! all commands of a bot.
+//!
+//! ## Example
+//! ```
+//! use twilight_interactions::{audit::lint, command::CreateCommand};
+//!
+//! #[derive(CreateCommand)]
+//! #[command(name = "hello", desc = "Say hello")]
+//! struct HelloCommand {
+//!     /// The message to send
+//!     message: String,
+//! }
+//!
+//! // Typically called from a test
+//! assert_eq!(lint(&HelloCommand::create_command()), Vec::new());
+//! ```
+
+use std::fmt::{self, Display};
+
+use twilight_model::application::command::{CommandOption, CommandOptionType};
+
+use crate::command::ApplicationCommandData;
+
+/// Maximum number of required options before a [`LintKind::TooManyRequiredOptions`]
+/// warning is emitted.
+pub const MAX_REQUIRED_OPTIONS: usize = 10;
+
+/// Names of the built-in commands of the Discord client.
+///
+/// Commands with these names are accepted by Discord, but are displayed
+/// alongside the built-in command, which is confusing for users. They are
+/// reported by [`lint`], and rejected by the derive macros when the
+/// `deny_reserved_name` attribute is set.
+pub const RESERVED_NAMES: &[&str] = &[
+    "ban",
+    "giphy",
+    "kick",
+    "me",
+    "msg",
+    "nick",
+    "shrug",
+    "spoiler",
+    "tableflip",
+    "tenor",
+    "thread",
+    "timeout",
+    "tts",
+    "unflip",
+];
+
+/// Check a command against user experience guidelines.
+///
+/// The command and all of its options, including subcommands and their
+/// options, are checked. See [`LintKind`] for the list of checks.
+pub fn lint(command: &ApplicationCommandData) -> Vec<LintWarning> {
+    let mut linter = Linter {
+        period: command.description.ends_with('.'),
+        warnings: Vec::new(),
+    };
+
+    if RESERVED_NAMES.contains(&&*command.name) {
+        linter.warn(&command.name, LintKind::ReservedName);
+    }
+
+    linter.options(&command.name, &command.options);
+
+    linter.warnings
+}
+
+/// Warning returned by [`lint`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintWarning {
+    /// Path of the command or option the warning applies to.
+    ///
+    /// The path is made of the command name followed by the names of the
+    /// subcommands and option, separated by spaces (e.g. `config set value`).
+    pub path: String,
+    /// Kind of the warning.
+    pub kind: LintKind,
+}
+
+impl Display for LintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}`: {}", self.path, self.kind)
+    }
+}
+
+/// Kind of [`LintWarning`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LintKind {
+    /// The description does not use the same trailing period as the command
+    /// description.
+    InconsistentPunctuation {
+        /// Whether the command description ends with a period.
+        expected_period: bool,
+    },
+    /// More than [`MAX_REQUIRED_OPTIONS`] options are required.
+    TooManyRequiredOptions {
+        /// Number of required options.
+        count: usize,
+    },
+    /// The option has a single choice, leaving the user with nothing to pick.
+    SingleChoice,
+    /// Autocomplete is enabled on an option that has predefined choices or
+    /// whose type does not support autocomplete.
+    UnusedAutocomplete,
+    /// The command has the same name as a built-in command of the Discord
+    /// client (see [`RESERVED_NAMES`]).
+    ReservedName,
+}
+
+impl Display for LintKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LintKind::InconsistentPunctuation {
+                expected_period: true,
+            } => f.write_str("description should end with a period"),
+            LintKind::InconsistentPunctuation {
+                expected_period: false,
+            } => f.write_str("description should not end with a period"),
+            LintKind::TooManyRequiredOptions { count } => write!(
+                f,
+                "{count} required options (more than {MAX_REQUIRED_OPTIONS})"
+            ),
+            LintKind::SingleChoice => f.write_str("option has a single choice"),
+            LintKind::UnusedAutocomplete => f.write_str("autocomplete cannot be used"),
+            LintKind::ReservedName => f.write_str("name of a built-in Discord command"),
+        }
+    }
+}
+
+struct Linter {
+    /// Whether the command description ends with a period.
+    period: bool,
+    warnings: Vec<LintWarning>,
+}
+
+impl Linter {
+    fn warn(&mut self, path: &str, kind: LintKind) {
+        self.warnings.push(LintWarning {
+            path: path.to_owned(),
+            kind,
+        });
+    }
+
+    fn options(&mut self, parent: &str, options: &[CommandOption]) {
+        let required = options
+            .iter()
+            .filter(|option| option.required == Some(true))
+            .count();
+
+        if required > MAX_REQUIRED_OPTIONS {
+            self.warn(parent, LintKind::TooManyRequiredOptions { count: required });
+        }
+
+        for option in options {
+            self.option(&format!("{parent} {}", option.name), option);
+        }
+    }
+
+    fn option(&mut self, path: &str, option: &CommandOption) {
+        if option.description.ends_with('.') != self.period {
+            self.warn(
+                path,
+                LintKind::InconsistentPunctuation {
+                    expected_period: self.period,
+                },
+            );
+        }
+
+        if let Some(options) = &option.options {
+            self.options(path, options);
+        }
+
+        let choices = option.choices.as_deref().unwrap_or_default();
+
+        if choices.len() == 1 {
+            self.warn(path, LintKind::SingleChoice);
+        }
+
+        let autocomplete_type = matches!(
+            option.kind,
+            CommandOptionType::String | CommandOptionType::Integer | CommandOptionType::Number
+        );
+
+        if option.autocomplete == Some(true) && (!choices.is_empty() || !autocomplete_type) {
+            self.warn(path, LintKind::UnusedAutocomplete);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Autocomplete suggestions.
+//!
+//! The [`filter`] function selects the choices matching the partial input of
+//! the user, to respond to autocomplete interactions. Choices are matched
+//! case-insensitively, and sorted from the best to the worst match:
+//!
+//! 1. Names starting with the input.
+//! 2. Names containing a word starting with the input.
+//! 3. Names containing the input.
+//! 4. Names containing all the characters of the input in order (e.g. `gbr`
+//!    matches `Great Britain`).
+//!
+//! Choices with the same kind of match keep their original order.
+//!
+//! ## Example
+//! ```
+//! use twilight_interactions::{autocomplete, command::{AutocompleteValue, CommandModel}};
+//!
+//! #[derive(CommandModel)]
+//! #[command(autocomplete = true)]
+//! struct CountryAutocomplete {
+//!     country: AutocompleteValue<String>,
+//! }
+//!
+//! const COUNTRIES: &[(&str, &str)] = &[("France", "fr"), ("Germany", "de"), ("Great Britain", "gb")];
+//!
+//! let input = "gr";
+//! let choices = autocomplete::filter(COUNTRIES.iter().copied(), input);
+//!
+//! assert_eq!(choices[0].name, "Great Britain");
+//! assert_eq!(choices[1].name, "Germany");
+//! ```
+
+use twilight_model::application::command::{CommandOptionChoice, CommandOptionChoiceValue};
+
+/// Maximum number of choices in an autocomplete response.
+pub const MAX_CHOICES: usize = 25;
+
+/// Maximum length of a choice name, in characters.
+pub const MAX_NAME_LENGTH: usize = 100;
+
+/// Select the choices matching the partial input of the user.
+///
+/// Choices are provided as `(name, value)` pairs. At most [`MAX_CHOICES`]
+/// choices are returned, and names are truncated to [`MAX_NAME_LENGTH`]
+/// characters. All choices are matched by an empty input. See the [module
+/// documentation](self) for how choices are matched and sorted.
+pub fn filter<I, N, V>(choices: I, input: &str) -> Vec<CommandOptionChoice>
+where
+    I: IntoIterator<Item = (N, V)>,
+    N: Into<String>,
+    V: IntoChoiceValue,
+{
+    let input = input.trim().to_lowercase();
+
+    let mut matches: Vec<_> = choices
+        .into_iter()
+        .filter_map(|(name, value)| {
+            let name = name.into();
+            let score = score(&name.to_lowercase(), &input)?;
+
+            Some((score, name, value))
+        })
+        .collect();
+
+    // Stable sort, choices with the same score keep their order
+    matches.sort_by_key(|(score, _, _)| *score);
+
+    matches
+        .into_iter()
+        .take(MAX_CHOICES)
+        .map(|(_, name, value)| CommandOptionChoice {
+            name: truncate(name),
+            name_localizations: None,
+            value: value.into_choice_value(),
+        })
+        .collect()
+}
+
+/// Conversion into a [`CommandOptionChoiceValue`].
+///
+/// This trait is used by [`filter`] to accept choice values of different
+/// types.
+pub trait IntoChoiceValue {
+    /// Convert this type into a [`CommandOptionChoiceValue`].
+    fn into_choice_value(self) -> CommandOptionChoiceValue;
+}
+
+impl IntoChoiceValue for CommandOptionChoiceValue {
+    fn into_choice_value(self) -> CommandOptionChoiceValue {
+        self
+    }
+}
+
+impl IntoChoiceValue for String {
+    fn into_choice_value(self) -> CommandOptionChoiceValue {
+        CommandOptionChoiceValue::String(self)
+    }
+}
+
+impl IntoChoiceValue for &str {
+    fn into_choice_value(self) -> CommandOptionChoiceValue {
+        CommandOptionChoiceValue::String(self.to_owned())
+    }
+}
+
+impl IntoChoiceValue for i64 {
+    fn into_choice_value(self) -> CommandOptionChoiceValue {
+        CommandOptionChoiceValue::Integer(self)
+    }
+}
+
+impl IntoChoiceValue for f64 {
+    fn into_choice_value(self) -> CommandOptionChoiceValue {
+        CommandOptionChoiceValue::Number(self)
+    }
+}
+
+/// Compute how well a lowercase name matches a lowercase input, lower is
+/// better. Returns `None` if the name does not match.
+fn score(name: &str, input: &str) -> Option<u8> {
+    if name.starts_with(input) {
+        Some(0)
+    } else if name
+        .split(|c: char| !c.is_alphanumeric())
+        .any(|word| word.starts_with(input))
+    {
+        Some(1)
+    } else if name.contains(input) {
+        Some(2)
+    } else if is_subsequence(name, input) {
+        Some(3)
+    } else {
+        None
+    }
+}
+
+/// Returns `true` if all characters of `input` appear in `name` in order.
+fn is_subsequence(name: &str, input: &str) -> bool {
+    let mut name = name.chars();
+
+    input
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .all(|c| name.any(|other| other == c))
+}
+
+/// Truncate a name to [`MAX_NAME_LENGTH`] characters.
+fn truncate(mut name: String) -> String {
+    if let Some((index, _)) = name.char_indices().nth(MAX_NAME_LENGTH) {
+        name.truncate(index);
+    }
+
+    name
+}
@@ -1,15 +1,24 @@
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    num::{NonZeroI64, NonZeroU64},
+    ops::{Deref, DerefMut},
+    rc::Rc,
+    str::FromStr,
+    sync::Arc,
+};
 
 use twilight_model::{
     application::{
-        command::CommandOptionValue as NumberCommandOptionValue,
+        command::{CommandOptionType, CommandOptionValue as NumberCommandOptionValue},
         interaction::{
             application_command::{CommandData, CommandDataOption, CommandOptionValue},
-            InteractionChannel, InteractionDataResolved, InteractionMember,
+            Interaction, InteractionChannel, InteractionData, InteractionDataResolved,
+            InteractionMember,
         },
     },
-    channel::Attachment,
-    guild::Role,
+    channel::{thread::ThreadMetadata, Attachment, ChannelType},
+    guild::{Permissions, Role},
     id::{
         marker::{AttachmentMarker, ChannelMarker, GenericMarker, RoleMarker, UserMarker},
         Id,
@@ -17,7 +26,7 @@ use twilight_model::{
     user::User,
 };
 
-use super::internal::CommandOptionData;
+use super::ext::CommandOptionData;
 use crate::error::{ParseError, ParseOptionError, ParseOptionErrorType};
 
 /// Parse command data into a concrete type.
@@ -70,7 +79,8 @@ use crate::error::{ParseError, ParseOptionError, ParseOptionErrorType};
 /// - If a field has autocomplete enabled, its type must be [`AutocompleteValue`]
 ///   or the parsing will fail, since focused fields are sent as [`String`].
 /// - Autocomplete models are **partial**, which means that unknown fields
-///   will not make the parsing fail.
+///   will not make the parsing fail, unless the `deny_unknown_fields`
+///   attribute is set.
 /// - It is not possible to derive [`CreateCommand`] on autocomplete models.
 ///
 /// <div class="warning">
@@ -98,7 +108,9 @@ use crate::error::{ParseError, ParseOptionError, ParseOptionErrorType};
 /// name.
 ///
 /// Subcommand groups work the same way as regular subcommands, except the
-/// variant type is another enum implementing [`CommandModel`].
+/// variant type is another enum implementing [`CommandModel`]. Discord only
+/// supports one level of subcommand groups, so deriving [`CreateCommand`] on
+/// a group containing another group fails to compile.
 ///
 /// <div class="warning">
 ///
@@ -131,17 +143,43 @@ use crate::error::{ParseError, ParseOptionError, ParseOptionErrorType};
 /// }
 /// ```
 ///
+/// ## Public models
+/// Models exposed as part of a library public API can be marked as
+/// `#[non_exhaustive]`, so that adding options or subcommands later is not a
+/// breaking change. The derive macros generate code in the crate defining the
+/// model, which is allowed to construct and match non-exhaustive types. Fields
+/// with non-exhaustive types from other crates are also supported, since they
+/// are only constructed by their [`CommandOption`] or [`CommandModel`]
+/// implementation.
+///
+/// ```
+/// use twilight_interactions::command::CommandModel;
+///
+/// #[derive(CommandModel)]
+/// #[non_exhaustive]
+/// pub struct HelloCommand {
+///     pub message: String,
+/// }
+/// ```
+///
 ///
 /// ## Macro attributes
 /// The macro provides a `#[command]` attribute to configure generated code.
 ///
-/// | Attribute                  | Type           | Location             | Description                                                     |
-/// |----------------------------|----------------|----------------------|-----------------------------------------------------------------|
-/// | `name`                     | `str`          | Variant (subcommand) | Subcommand name (required).                                     |
-/// | `rename`                   | `str`          | Field                | Use a different name for the field when parsing.                |
-/// | `channel_types`            | `str`          | Field                | Restricts the channel choice to specific types.[^channel_types] |
-/// | `max_value`, `min_value`   | `i64` or `f64` | Field                | Maximum and/or minimum value permitted.                         |
-/// | `max_length`, `min_length` | `u16`          | Field                | Maximum and/or minimum string length permitted.                 |
+/// | Attribute                  | Type            | Location             | Description                                                     |
+/// |----------------------------|-----------------|----------------------|-----------------------------------------------------------------|
+/// | `name`                     | `str`           | Variant (subcommand) | Subcommand name (required).                                     |
+/// | `deny_unknown_fields`      | `bool`          | Type                 | Reject unknown options in autocomplete models.[^deny]           |
+/// | `rename`                   | `str`           | Field                | Use a different name for the field when parsing.                |
+/// | `rename_all`               | `str`           | Type                 | Derive option and subcommand names.[^rename_all]                |
+/// | `channel_types`            | `str`           | Field                | Restricts the channel choice to specific types.[^channel_types] |
+/// | `max_value`, `min_value`   | `i64` or `f64`  | Field                | Maximum and/or minimum value permitted.                         |
+/// | `max_length`, `min_length` | `u16`           | Field                | Maximum and/or minimum string length permitted.                 |
+/// | `format`                   | `str`           | Field                | Format used to parse date and time options.[^format]            |
+/// | `with`                     | `fn`[^with]     | Field                | Parse the option with a custom function.                        |
+/// | `validate`                 | `fn`[^validate] | Field                | Validate the parsed value with a custom function.               |
+/// | `ctx`                      | `bool`          | Field                | Fill the field from the interaction.[^ctx]                      |
+/// | `rest`                     | `bool`          | Field                | Collect options not matched by other fields.[^rest]             |
 ///
 /// ### Example
 /// ```
@@ -156,46 +194,162 @@ use crate::error::{ParseError, ParseOptionError, ParseOptionErrorType};
 /// }
 /// ```
 ///
+/// [^rename_all]: Rename rule applied to field names and subcommand variant
+///                names, one of `lowercase`, `snake_case` or `kebab-case`.
+///                The `rename` and `name` attributes take precedence, and the
+///                `name` attribute of variants becomes optional. Options or
+///                subcommands ending up with the same name fail to compile.
+///
+/// [^deny]: Can be written without value. Regular models always return a
+///          [`ParseOptionErrorType::UnknownField`] error for unknown options,
+///          while autocomplete models ignore them by default. This attribute
+///          cannot be used with a `rest` field.
+///
 /// [^channel_types]: List of [`ChannelType`] names in snake_case separated by spaces
 ///                   like `guild_text private`.
 ///
+/// [^format]: Format description used by `chrono` or `time` depending on the
+///            type of the field. Values are parsed as RFC 3339 timestamps by
+///            default. See the [module documentation](crate::command).
+///
+/// [^with]: Path to a function with the signature
+///          `fn(CommandOptionValue, Option<&InteractionDataResolved>) -> Result<T, E>`,
+///          where `E` is a [`String`] or a [`ValueError`], used instead of the
+///          [`CommandOption`] implementation of the field type. Errors are
+///          returned as [`ParseOptionErrorType::InvalidValue`].
+///
+/// [^validate]: Path to a function with the signature `fn(&T) -> Result<(), String>`,
+///              called with the parsed value (`&str` can be used for `String`
+///              fields). Errors are returned as
+///              [`ParseOptionErrorType::ValidationFailed`] with the name of the
+///              field, and can be reported to the user.
+///
+/// [^ctx]: Can be written without value. The field is filled from the
+///        received interaction instead of command options, and must implement
+///        [`ContextField`].
+///
+/// [^rest]: Can be written without value. Must be set on the last field, with
+///         a `HashMap<String, CommandOptionValue>` or `Vec<CommandDataOption>`
+///         type. Unknown options are collected instead of returning a
+///         [`ParseOptionErrorType::UnknownField`] error, which allows older
+///         models to parse commands with new options. The field does not
+///         create a command option.
+///
+/// [`ContextField`]: super::ContextField
 /// [`CreateCommand`]: super::CreateCommand
+/// [`ParseOptionErrorType::InvalidValue`]: crate::error::ParseOptionErrorType::InvalidValue
+/// [`ParseOptionErrorType::UnknownField`]: crate::error::ParseOptionErrorType::UnknownField
+/// [`ValueError`]: crate::error::ValueError
+/// [`ParseOptionErrorType::ValidationFailed`]: crate::error::ParseOptionErrorType::ValidationFailed
 /// [`ChannelType`]: twilight_model::channel::ChannelType
 pub trait CommandModel: Sized {
     /// Construct this type from [`CommandInputData`].
     fn from_interaction(data: CommandInputData) -> Result<Self, ParseError>;
-}
 
-impl<T: CommandModel> CommandModel for Box<T> {
-    fn from_interaction(data: CommandInputData) -> Result<Self, ParseError> {
-        T::from_interaction(data).map(Box::new)
+    /// Construct this type from [`CommandInputData`] and the interaction it
+    /// was received in, if available.
+    ///
+    /// The interaction is used to fill fields with the `ctx` attribute (see
+    /// [`ContextField`]). The default implementation ignores the interaction.
+    ///
+    /// [`ContextField`]: super::ContextField
+    fn from_interaction_context(
+        data: CommandInputData,
+        interaction: Option<&Interaction>,
+    ) -> Result<Self, ParseError> {
+        let _ = interaction;
+
+        Self::from_interaction(data)
     }
+
+    /// Construct this type from a received [`Interaction`].
+    ///
+    /// This method extracts the command data of application command and
+    /// autocomplete interactions. [`ParseError::InvalidInteraction`] is
+    /// returned for other kinds of interactions.
+    fn from_interaction_full(interaction: &Interaction) -> Result<Self, ParseError> {
+        match &interaction.data {
+            Some(InteractionData::ApplicationCommand(data)) => Self::from_interaction_context(
+                CommandInputData::from((**data).clone()),
+                Some(interaction),
+            ),
+            _ => Err(ParseError::InvalidInteraction(interaction.kind)),
+        }
+    }
+}
+
+/// Implement [`CommandModel`] for smart pointers, such as the variants of
+/// subcommand enums wrapped in a [`Box`], [`Arc`] or [`Rc`].
+macro_rules! smart_pointer {
+    ($($ty:ident),*) => {
+        $(
+            impl<T: CommandModel> CommandModel for $ty<T> {
+                fn from_interaction(data: CommandInputData) -> Result<Self, ParseError> {
+                    T::from_interaction(data).map($ty::new)
+                }
+
+                fn from_interaction_context(
+                    data: CommandInputData,
+                    interaction: Option<&Interaction>,
+                ) -> Result<Self, ParseError> {
+                    T::from_interaction_context(data, interaction).map($ty::new)
+                }
+            }
+        )*
+    };
 }
 
+smart_pointer!(Box, Arc, Rc);
+
 impl CommandModel for Vec<CommandDataOption> {
     fn from_interaction(data: CommandInputData) -> Result<Self, ParseError> {
         Ok(data.options)
     }
 }
 
+impl CommandModel for CommandInputData<'static> {
+    fn from_interaction(data: CommandInputData) -> Result<Self, ParseError> {
+        Ok(CommandInputData {
+            options: data.options,
+            resolved: data
+                .resolved
+                .map(|resolved| Cow::Owned(resolved.into_owned())),
+        })
+    }
+}
+
 /// Parse command option into a concrete type.
 ///
 /// This trait is used by the implementation of [`CommandModel`] generated
 /// by the derive macro. See the [module documentation](crate::command) for
 /// a list of implemented types.
 ///
+/// This trait can also be implemented manually to support custom types, using
+/// the types of the [`ext`](super::ext) module.
+///
 /// ## Option choices
 /// This trait can be derived on enums to represent command options with
 /// predefined choices. The `#[option]` attribute must be present on each
-/// variant.
+/// variant, unless the `rename_all` attribute is set on the enum.
 ///
 /// The corresponding slash command types are automatically inferred from
 /// the `value` attribute. In the example below, the inferred type would
 /// be `INTEGER`.
 ///
-/// A `value` method is also generated for each variant to obtain the
-/// value of the variant. This method is not described in the trait
-/// as it is only implemented for option choices.
+/// The following items are also generated on the enum, and can be used to
+/// reuse the choices in autocomplete or help messages. They are not described
+/// in the trait as they are only implemented for option choices.
+///
+/// - A `value` method to obtain the value of the variant.
+/// - A `name` method to obtain the choice name of the variant.
+/// - A `VARIANTS` constant with the list of all variants.
+///
+/// A variant with a single `String` or `i64` field can be marked with
+/// `#[option(other)]` to receive values that do not match any choice, instead
+/// of failing with [`ParseOptionErrorType::InvalidChoice`]. This keeps the
+/// model working when choices are updated before the bot is redeployed. Such a
+/// variant is not part of `VARIANTS`, its `name` is `None` (the method returns
+/// an `Option` in that case) and string values borrow from the variant.
 ///
 /// ### Example
 /// ```
@@ -212,15 +366,62 @@ impl CommandModel for Vec<CommandDataOption> {
 /// }
 ///
 /// assert_eq!(TimeUnit::Minute.value(), 60);
+/// assert_eq!(TimeUnit::Hour.name(), "Hour");
+/// assert_eq!(TimeUnit::VARIANTS.len(), 3);
+/// ```
+///
+/// ### Newtypes
+/// Structs with a single field can be marked with `#[option(transparent)]` to
+/// be parsed as their inner type. This allows using domain types as fields of
+/// command models. The [`CreateOption`](super::CreateOption) derive macro
+/// supports the same attribute.
+///
+/// ```
+/// use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+/// use twilight_model::id::{marker::UserMarker, Id};
+///
+/// #[derive(CommandOption, CreateOption)]
+/// #[option(transparent)]
+/// struct Tag(String);
+///
+/// #[derive(CommandOption, CreateOption)]
+/// #[option(transparent)]
+/// struct UserId(Id<UserMarker>);
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "tag", desc = "Tag a user")]
+/// struct TagCommand {
+///     /// Tag to add
+///     tag: Tag,
+///     /// User to tag
+///     user: UserId,
+/// }
 /// ```
 ///
+/// This attribute cannot be combined with other `#[option]` attributes.
+///
 /// ### Macro attributes
 /// The macro provides an `#[option]` attribute to configure the generated code.
 ///
-/// | Attribute | Type                  | Location | Description                                |
-/// |-----------|-----------------------|----------|--------------------------------------------|
-/// | `name`    | `str`                 | Variant  | Set the name of the command option choice. |
-/// | `value`   | `str`, `i64` or `f64` | Variant  | Value of the command option choice.        |
+/// | Attribute          | Type                  | Location | Description                                               |
+/// |--------------------|-----------------------|----------|-----------------------------------------------------------|
+/// | `rename_all`       | `str`[^rename_all]    | Type     | Derive omitted names and values from variant identifiers. |
+/// | `case_insensitive` | `bool`                | Type     | Match string values ignoring case.[^case]                 |
+/// | `transparent`      | `bool`                | Type     | Parse a newtype struct as its inner type.                 |
+/// | `name`             | `str`                 | Variant  | Set the name of the command option choice.                |
+/// | `value`            | `str`, `i64` or `f64` | Variant  | Value of the command option choice.[^value]               |
+/// | `other`            | `bool`                | Variant  | Receive values that do not match any choice.              |
+///
+/// [^rename_all]: Casing applied to variant identifiers. See the
+///                [`CreateOption`](super::CreateOption) documentation for the
+///                list of supported rules.
+///
+/// [^case]: Useful when values are also received from other sources than
+///           Discord, such as tests or migrations. Values that only differ by
+///           their case are rejected at compile time.
+///
+/// [^value]: The explicit discriminant of the variant (e.g. `Minute = 60`) is
+///           used as an `i64` value when the attribute is omitted.
 ///
 pub trait CommandOption: Sized {
     /// Convert a [`CommandOptionValue`] into this value.
@@ -231,6 +432,9 @@ pub trait CommandOption: Sized {
     ) -> Result<Self, ParseOptionErrorType>;
 }
 
+/// Version of the Discord API whose interaction payloads are parsed.
+const API_VERSION: u8 = 10;
+
 /// Data sent by Discord when receiving a command.
 ///
 /// This type is used in the [`CommandModel`] trait. It can be initialized
@@ -244,6 +448,18 @@ pub struct CommandInputData<'a> {
 }
 
 impl<'a> CommandInputData<'a> {
+    /// Get the version of the Discord API whose payloads are parsed.
+    ///
+    /// Command data sent by other versions of the API may contain options
+    /// or values that are not supported by this crate. Use
+    /// [`CommandRegistry::unknown_options`] to configure how options that are
+    /// not part of the registered command are handled.
+    ///
+    /// [`CommandRegistry::unknown_options`]: crate::registry::CommandRegistry::unknown_options
+    pub const fn api_version() -> u8 {
+        API_VERSION
+    }
+
     /// Parse a field from the command data.
     ///
     /// This method can be used to manually parse a field from
@@ -289,6 +505,7 @@ impl<'a> CommandInputData<'a> {
             Err(kind) => Err(ParseError::Option(ParseOptionError {
                 field: name.to_string(),
                 kind,
+                path: Vec::new(),
             })),
         }
     }
@@ -320,6 +537,30 @@ impl<'a> CommandInputData<'a> {
             .map(|option| &*option.name)
     }
 
+    /// Get the focused option, including its value and type.
+    ///
+    /// Unlike [`focused`], options of subcommands and subcommand groups are
+    /// also searched, which avoids traversing the options in autocomplete
+    /// handlers of commands with subcommands.
+    ///
+    /// [`focused`]: Self::focused
+    pub fn focused_option(&self) -> Option<FocusedOption> {
+        fn find(options: &[CommandDataOption]) -> Option<FocusedOption> {
+            options.iter().find_map(|option| match &option.value {
+                CommandOptionValue::Focused(value, kind) => Some(FocusedOption {
+                    name: option.name.clone(),
+                    value: value.clone(),
+                    kind: *kind,
+                }),
+                CommandOptionValue::SubCommand(options)
+                | CommandOptionValue::SubCommandGroup(options) => find(options),
+                _ => None,
+            })
+        }
+
+        find(&self.options)
+    }
+
     /// Parse a subcommand's [`CommandOptionValue`].
     ///
     /// This method's signature is the same as the [`CommandOption`] trait,
@@ -364,11 +605,101 @@ pub struct ResolvedUser {
     pub member: Option<InteractionMember>,
 }
 
+/// A resolved Discord guild member.
+///
+/// This struct implements [`CommandOption`] and can be used instead of
+/// [`ResolvedUser`] when the command is only used in guilds and the member
+/// data of the selected user is required. Parsing fails with a
+/// [`LookupFailed`] error if the selected user is not a member of the guild
+/// where the command was invoked.
+///
+/// [`LookupFailed`]: ParseOptionErrorType::LookupFailed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedMember {
+    /// The resolved user.
+    pub resolved: User,
+    /// The resolved member.
+    pub member: InteractionMember,
+}
+
+impl From<ResolvedMember> for ResolvedUser {
+    fn from(value: ResolvedMember) -> Self {
+        Self {
+            resolved: value.resolved,
+            member: Some(value.member),
+        }
+    }
+}
+
+/// A resolved Discord channel.
+///
+/// This struct implements [`CommandOption`] and can be used instead of
+/// [`InteractionChannel`] to obtain the resolved data of a channel, with the
+/// thread information grouped in a [`ResolvedThread`] if the channel is a
+/// thread.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedChannel {
+    /// ID of the channel.
+    pub id: Id<ChannelMarker>,
+    /// Type of the channel.
+    pub kind: ChannelType,
+    /// Name of the channel.
+    pub name: String,
+    /// ID of the parent channel.
+    ///
+    /// This is the category of a guild channel, or the channel a thread was
+    /// created in.
+    pub parent_id: Option<Id<ChannelMarker>>,
+    /// Computed permissions of the invoking user in the channel, including
+    /// overwrites.
+    pub permissions: Permissions,
+    /// Thread information, if the channel is a thread.
+    pub thread: Option<ResolvedThread>,
+}
+
+impl ResolvedChannel {
+    /// Returns `true` if the channel is a thread.
+    pub const fn is_thread(&self) -> bool {
+        self.thread.is_some()
+    }
+}
+
+impl From<InteractionChannel> for ResolvedChannel {
+    fn from(channel: InteractionChannel) -> Self {
+        let thread = match (channel.kind.is_thread(), channel.parent_id) {
+            (true, Some(parent_id)) => channel.thread_metadata.map(|metadata| ResolvedThread {
+                parent_id,
+                metadata,
+            }),
+            _ => None,
+        };
+
+        Self {
+            id: channel.id,
+            kind: channel.kind,
+            name: channel.name,
+            parent_id: channel.parent_id,
+            permissions: channel.permissions,
+            thread,
+        }
+    }
+}
+
+/// Thread information of a [`ResolvedChannel`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedThread {
+    /// ID of the channel the thread was created in.
+    pub parent_id: Id<ChannelMarker>,
+    /// Metadata of the thread.
+    pub metadata: ThreadMetadata,
+}
+
 /// A resolved mentionable.
 ///
 /// This struct implements [`CommandOption`] and can be used to obtain the
 /// resolved data from a mentionable ID, that can be either a user or a role.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
 pub enum ResolvedMentionable {
     /// User mention.
     User(ResolvedUser),
@@ -402,6 +733,93 @@ pub enum AutocompleteValue<T> {
     Completed(T),
 }
 
+/// The focused option of an autocomplete interaction.
+///
+/// This type implements [`CommandModel`] and can be used instead of an
+/// autocomplete model to handle the autocomplete of all fields of a command
+/// with a single handler, for example with the `autocomplete_all` attribute of
+/// the [`CreateCommand`] derive macro. Focused options of subcommands are also
+/// found, like with [`CommandInputData::focused_option`].
+///
+/// ```
+/// use twilight_interactions::command::{CommandModel, CreateCommand, FocusedOption};
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "travel", desc = "Plan a trip", autocomplete_all)]
+/// struct TravelCommand {
+///     /// Departure city.
+///     from: String,
+///     /// Arrival city.
+///     to: String,
+/// }
+///
+/// fn suggest(focused: FocusedOption) -> Vec<String> {
+///     // `focused.name` is either "from" or "to"
+///     vec![format!("{}ville", focused.value)]
+/// }
+/// ```
+///
+/// [`CreateCommand`]: super::CreateCommand
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FocusedOption {
+    /// Name of the focused option.
+    pub name: String,
+    /// Value typed by the user, which has not been validated by Discord.
+    pub value: String,
+    /// Type of the focused option.
+    pub kind: CommandOptionType,
+}
+
+impl CommandModel for FocusedOption {
+    fn from_interaction(data: CommandInputData) -> Result<Self, ParseError> {
+        data.focused_option().ok_or(ParseError::MissingFocused)
+    }
+}
+
+/// A string option parsed with [`FromStr`].
+///
+/// This type creates a `STRING` option, whose value is parsed with the
+/// [`FromStr`] implementation of `T`. If parsing fails, a
+/// [`ParseOptionErrorType::InvalidValue`] error containing the error message
+/// is returned.
+///
+/// ## Example
+/// ```
+/// use std::net::IpAddr;
+///
+/// use twilight_interactions::command::{CommandModel, CreateCommand, Parsed};
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "ping", desc = "Ping an IP address")]
+/// struct PingCommand {
+///     /// Address to ping.
+///     address: Parsed<IpAddr>,
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Parsed<T>(pub T);
+
+impl<T> Parsed<T> {
+    /// Get the parsed value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Parsed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Parsed<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
 macro_rules! lookup {
     ($resolved:ident.$cat:ident, $id:expr) => {
         $resolved
@@ -451,22 +869,61 @@ impl CommandOption for String {
             other => return Err(ParseOptionErrorType::InvalidType(other.kind())),
         };
 
-        if let Some(min) = data.min_length {
-            if value.len() < min.into() {
-                todo!()
-            }
-        }
-
-        if let Some(max) = data.max_length {
-            if value.len() > max.into() {
-                todo!()
-            }
-        }
+        data.check_length(&value)?;
 
         Ok(value)
     }
 }
 
+impl<T> CommandOption for Parsed<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let value = String::from_option(value, data, resolved)?;
+
+        value
+            .parse()
+            .map(Parsed)
+            .map_err(|error: T::Err| ParseOptionErrorType::InvalidValue(error.to_string().into()))
+    }
+}
+
+#[cfg(feature = "humantime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "humantime")))]
+impl CommandOption for std::time::Duration {
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let value = String::from_option(value, data, resolved)?;
+
+        humantime::parse_duration(value.trim()).map_err(ParseOptionErrorType::invalid_value)
+    }
+}
+
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+impl CommandOption for url::Url {
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let value = String::from_option(value, data, resolved)?;
+
+        url::Url::parse(value.trim()).map_err(|error| {
+            crate::error::ValueError::with_source(format!("invalid URL: {error}"), error).into()
+        })
+    }
+}
+
 impl CommandOption for Cow<'_, str> {
     fn from_option(
         value: CommandOptionValue,
@@ -477,6 +934,24 @@ impl CommandOption for Cow<'_, str> {
     }
 }
 
+macro_rules! shared_str {
+    ($($ty:ident),*) => {
+        $(
+            impl CommandOption for $ty<str> {
+                fn from_option(
+                    value: CommandOptionValue,
+                    data: CommandOptionData,
+                    resolved: Option<&InteractionDataResolved>,
+                ) -> Result<Self, ParseOptionErrorType> {
+                    String::from_option(value, data, resolved).map($ty::from)
+                }
+            }
+        )*
+    };
+}
+
+shared_str!(Box, Arc, Rc);
+
 impl CommandOption for i64 {
     fn from_option(
         value: CommandOptionValue,
@@ -488,22 +963,105 @@ impl CommandOption for i64 {
             other => return Err(ParseOptionErrorType::InvalidType(other.kind())),
         };
 
-        if let Some(NumberCommandOptionValue::Integer(min)) = data.min_value {
-            if value < min {
-                return Err(ParseOptionErrorType::IntegerOutOfRange(value));
-            }
-        }
+        let min = match data.min_value {
+            Some(NumberCommandOptionValue::Integer(min)) => Some(min),
+            _ => None,
+        };
+        let max = match data.max_value {
+            Some(NumberCommandOptionValue::Integer(max)) => Some(max),
+            _ => None,
+        };
 
-        if let Some(NumberCommandOptionValue::Integer(max)) = data.max_value {
-            if value > max {
-                return Err(ParseOptionErrorType::IntegerOutOfRange(value));
-            }
+        if min.is_some_and(|min| value < min) || max.is_some_and(|max| value > max) {
+            return Err(ParseOptionErrorType::OutOfRange {
+                value: NumberCommandOptionValue::Integer(value),
+                min: min.map(NumberCommandOptionValue::Integer),
+                max: max.map(NumberCommandOptionValue::Integer),
+            });
         }
 
         Ok(value)
     }
 }
 
+impl CommandOption for NonZeroI64 {
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let value = i64::from_option(value, data, resolved)?;
+
+        NonZeroI64::new(value).ok_or(ParseOptionErrorType::OutOfRange {
+            value: NumberCommandOptionValue::Integer(value),
+            min: None,
+            max: None,
+        })
+    }
+}
+
+impl CommandOption for NonZeroU64 {
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let value = i64::from_option(value, data, resolved)?;
+
+        u64::try_from(value).ok().and_then(NonZeroU64::new).ok_or(
+            ParseOptionErrorType::OutOfRange {
+                value: NumberCommandOptionValue::Integer(value),
+                min: Some(NumberCommandOptionValue::Integer(1)),
+                max: None,
+            },
+        )
+    }
+}
+
+/// Implement [`CommandOption`] for integer types smaller than `i64`.
+///
+/// Values are parsed as `i64` and rejected with an
+/// [`ParseOptionErrorType::OutOfRange`] error if they do not fit in the type.
+macro_rules! integer {
+    ($($ty:ty),*) => {
+        $(
+            impl CommandOption for $ty {
+                fn from_option(
+                    value: CommandOptionValue,
+                    data: CommandOptionData,
+                    resolved: Option<&InteractionDataResolved>,
+                ) -> Result<Self, ParseOptionErrorType> {
+                    let value = i64::from_option(value, data, resolved)?;
+
+                    <$ty>::try_from(value).map_err(|_| ParseOptionErrorType::OutOfRange {
+                        value: NumberCommandOptionValue::Integer(value),
+                        min: Some(NumberCommandOptionValue::Integer(<$ty>::MIN.into())),
+                        max: Some(NumberCommandOptionValue::Integer(<$ty>::MAX.into())),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+integer!(i8, i16, i32, u8, u16, u32);
+
+impl CommandOption for u64 {
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let value = i64::from_option(value, data, resolved)?;
+
+        u64::try_from(value).map_err(|_| ParseOptionErrorType::OutOfRange {
+            value: NumberCommandOptionValue::Integer(value),
+            min: Some(NumberCommandOptionValue::Integer(0)),
+            max: None,
+        })
+    }
+}
+
 impl CommandOption for f64 {
     fn from_option(
         value: CommandOptionValue,
@@ -515,16 +1073,21 @@ impl CommandOption for f64 {
             other => return Err(ParseOptionErrorType::InvalidType(other.kind())),
         };
 
-        if let Some(NumberCommandOptionValue::Number(min)) = data.min_value {
-            if value < min {
-                return Err(ParseOptionErrorType::NumberOutOfRange(value));
-            }
-        }
+        let min = match data.min_value {
+            Some(NumberCommandOptionValue::Number(min)) => Some(min),
+            _ => None,
+        };
+        let max = match data.max_value {
+            Some(NumberCommandOptionValue::Number(max)) => Some(max),
+            _ => None,
+        };
 
-        if let Some(NumberCommandOptionValue::Number(max)) = data.max_value {
-            if value > max {
-                return Err(ParseOptionErrorType::NumberOutOfRange(value));
-            }
+        if min.is_some_and(|min| value < min) || max.is_some_and(|max| value > max) {
+            return Err(ParseOptionErrorType::OutOfRange {
+                value: NumberCommandOptionValue::Number(value),
+                min: min.map(NumberCommandOptionValue::Number),
+                max: max.map(NumberCommandOptionValue::Number),
+            });
         }
 
         Ok(value)
@@ -657,6 +1220,24 @@ impl CommandOption for ResolvedUser {
     }
 }
 
+impl CommandOption for ResolvedMember {
+    fn from_option(
+        value: CommandOptionValue,
+        _data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let user_id = match value {
+            CommandOptionValue::User(value) => value,
+            other => return Err(ParseOptionErrorType::InvalidType(other.kind())),
+        };
+
+        Ok(Self {
+            resolved: lookup!(resolved.users, user_id)?,
+            member: lookup!(resolved.members, user_id)?,
+        })
+    }
+}
+
 impl CommandOption for ResolvedMentionable {
     fn from_option(
         value: CommandOptionValue,
@@ -708,6 +1289,16 @@ impl CommandOption for InteractionChannel {
     }
 }
 
+impl CommandOption for ResolvedChannel {
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        InteractionChannel::from_option(value, data, resolved).map(ResolvedChannel::from)
+    }
+}
+
 impl CommandOption for Role {
     fn from_option(
         value: CommandOptionValue,
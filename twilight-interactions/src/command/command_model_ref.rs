@@ -0,0 +1,212 @@
+use std::{
+    borrow::Cow,
+    num::{NonZeroI64, NonZeroU64},
+};
+
+use twilight_model::{
+    application::interaction::{
+        application_command::{CommandData, CommandDataOption, CommandOptionValue},
+        InteractionChannel, InteractionDataResolved,
+    },
+    channel::Attachment,
+    guild::Role,
+    id::{
+        marker::{AttachmentMarker, ChannelMarker, GenericMarker, RoleMarker, UserMarker},
+        Id,
+    },
+    user::User,
+};
+
+use super::{ext::CommandOptionData, CommandOption};
+use crate::error::{ParseError, ParseOptionErrorType};
+
+/// Parse command data into a type borrowing from it.
+///
+/// This trait is the borrowed counterpart of [`CommandModel`]: the model is
+/// parsed from a reference to the command data, which allows string options
+/// to be parsed as `&str` or [`Cow<str>`] without being cloned. This is useful
+/// for bots handling a high volume of commands with large string options.
+///
+/// A derive macro is provided to implement this trait automatically. It
+/// supports the same field attributes as the [`CommandModel`] macro, except
+/// for `with`. Subcommands and autocomplete models are not supported. All
+/// field types must implement the [`CommandOptionRef`] trait.
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use twilight_interactions::command::{CommandModelRef, ResolvedUser};
+///
+/// #[derive(CommandModelRef)]
+/// struct HelloCommand<'a> {
+///     #[command(max_length = 200)]
+///     message: &'a str,
+///     footer: Option<Cow<'a, str>>,
+///     repeat: Option<i64>,
+/// }
+/// ```
+///
+/// [`CommandModel`]: super::CommandModel
+/// [`Cow<str>`]: std::borrow::Cow
+pub trait CommandModelRef<'a>: Sized {
+    /// Construct this type from command options and resolved data.
+    fn from_interaction_ref(
+        options: &'a [CommandDataOption],
+        resolved: Option<&'a InteractionDataResolved>,
+    ) -> Result<Self, ParseError>;
+
+    /// Construct this type from a reference to [`CommandData`].
+    fn from_command_data(data: &'a CommandData) -> Result<Self, ParseError> {
+        Self::from_interaction_ref(&data.options, data.resolved.as_ref())
+    }
+}
+
+/// Parse command option into a type borrowing from it.
+///
+/// This trait is the borrowed counterpart of [`CommandOption`], used by the
+/// [`CommandModelRef`] trait. It is implemented for `&str` and [`Cow<str>`],
+/// references to resolved data ([`User`], [`Role`], [`InteractionChannel`]
+/// and [`Attachment`]) as well as types that are cheap to copy like integers
+/// and IDs.
+///
+/// [`Cow<str>`]: std::borrow::Cow
+pub trait CommandOptionRef<'a>: Sized {
+    /// Convert a [`CommandOptionValue`] reference into this value.
+    fn from_option_ref(
+        value: &'a CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&'a InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType>;
+}
+
+impl<'a> CommandOptionRef<'a> for &'a str {
+    fn from_option_ref(
+        value: &'a CommandOptionValue,
+        data: CommandOptionData,
+        _resolved: Option<&'a InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let value = match value {
+            CommandOptionValue::String(value) => value,
+            other => return Err(ParseOptionErrorType::InvalidType(other.kind())),
+        };
+
+        data.check_length(value)?;
+
+        Ok(value)
+    }
+}
+
+impl<'a> CommandOptionRef<'a> for Cow<'a, str> {
+    fn from_option_ref(
+        value: &'a CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&'a InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        <&str>::from_option_ref(value, data, resolved).map(Cow::Borrowed)
+    }
+}
+
+/// Implement [`CommandOptionRef`] for a type by copying the option value.
+macro_rules! copied {
+    ($($ty:ty),*) => {
+        $(
+            impl<'a> CommandOptionRef<'a> for $ty {
+                fn from_option_ref(
+                    value: &'a CommandOptionValue,
+                    data: CommandOptionData,
+                    resolved: Option<&'a InteractionDataResolved>,
+                ) -> Result<Self, ParseOptionErrorType> {
+                    <$ty as CommandOption>::from_option(value.clone(), data, resolved)
+                }
+            }
+        )*
+    };
+}
+
+copied!(
+    i8,
+    i16,
+    i32,
+    i64,
+    u8,
+    u16,
+    u32,
+    u64,
+    NonZeroI64,
+    NonZeroU64,
+    f64,
+    bool,
+    Id<UserMarker>,
+    Id<ChannelMarker>,
+    Id<RoleMarker>,
+    Id<GenericMarker>,
+    Id<AttachmentMarker>
+);
+
+macro_rules! lookup_ref {
+    ($resolved:ident.$cat:ident, $id:expr) => {
+        $resolved
+            .and_then(|resolved| resolved.$cat.get(&$id))
+            .ok_or_else(|| ParseOptionErrorType::LookupFailed($id.get()))
+    };
+}
+
+impl<'a> CommandOptionRef<'a> for &'a User {
+    fn from_option_ref(
+        value: &'a CommandOptionValue,
+        _data: CommandOptionData,
+        resolved: Option<&'a InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        match value {
+            CommandOptionValue::User(value) => lookup_ref!(resolved.users, value),
+            other => Err(ParseOptionErrorType::InvalidType(other.kind())),
+        }
+    }
+}
+
+impl<'a> CommandOptionRef<'a> for &'a Role {
+    fn from_option_ref(
+        value: &'a CommandOptionValue,
+        _data: CommandOptionData,
+        resolved: Option<&'a InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        match value {
+            CommandOptionValue::Role(value) => lookup_ref!(resolved.roles, value),
+            other => Err(ParseOptionErrorType::InvalidType(other.kind())),
+        }
+    }
+}
+
+impl<'a> CommandOptionRef<'a> for &'a Attachment {
+    fn from_option_ref(
+        value: &'a CommandOptionValue,
+        _data: CommandOptionData,
+        resolved: Option<&'a InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        match value {
+            CommandOptionValue::Attachment(value) => lookup_ref!(resolved.attachments, value),
+            other => Err(ParseOptionErrorType::InvalidType(other.kind())),
+        }
+    }
+}
+
+impl<'a> CommandOptionRef<'a> for &'a InteractionChannel {
+    fn from_option_ref(
+        value: &'a CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&'a InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        let resolved = match value {
+            CommandOptionValue::Channel(value) => lookup_ref!(resolved.channels, value)?,
+            other => return Err(ParseOptionErrorType::InvalidType(other.kind())),
+        };
+
+        if let Some(channel_types) = data.channel_types {
+            if !channel_types.contains(&resolved.kind) {
+                return Err(ParseOptionErrorType::InvalidChannelType(resolved.kind));
+            }
+        }
+
+        Ok(resolved)
+    }
+}
@@ -0,0 +1,85 @@
+use twilight_model::application::interaction::application_command::CommandData;
+
+use crate::error::ParseError;
+
+/// Route received command data to the corresponding command model.
+///
+/// This trait is implemented on enums where each variant wraps a type
+/// implementing [`CommandModel`]. It allows dispatching a received command
+/// with a single `match` on the returned enum instead of comparing command
+/// names manually. A derive macro is provided to implement this trait
+/// automatically.
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::command::{CommandModel, CommandRouter, CreateCommand};
+/// # use twilight_model::application::interaction::application_command::CommandData;
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "hello", desc = "Say hello")]
+/// struct HelloCommand {
+///     /// The message to send.
+///     message: String,
+/// }
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "ping", desc = "Ping the bot")]
+/// struct PingCommand;
+///
+/// #[derive(CommandRouter)]
+/// enum Commands {
+///     Hello(HelloCommand),
+///     Ping(PingCommand),
+/// }
+///
+/// # fn handle(data: CommandData) -> Result<(), Box<dyn std::error::Error>> {
+/// match Commands::route(data)? {
+///     Commands::Hello(command) => println!("hello {}", command.message),
+///     Commands::Ping(_) => println!("pong"),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ## Macro attributes
+/// The macro provides a `#[command]` attribute to configure variants.
+///
+/// | Attribute  | Type   | Location | Description                                   |
+/// |------------|--------|----------|-----------------------------------------------|
+/// | `name`     | `str`  | Variant  | Name of the command matched by this variant.  |
+/// | `fallback` | `bool` | Variant  | Receive commands not matching other variants. |
+///
+/// By default, the name of the command is obtained from the
+/// [`CreateCommand::NAME`] constant of the variant type. The `name` attribute
+/// is required if the variant type does not implement [`CreateCommand`].
+///
+/// If the received command does not match any variant, a
+/// [`ParseError::UnknownCommand`] error is returned, unless a variant has the
+/// `fallback` attribute. This variant is parsed from unknown commands, and is
+/// typically a [`Vec<CommandDataOption>`] or a boxed [`CommandInputData`] to
+/// handle the raw options of commands without a dedicated model.
+///
+/// ```
+/// use twilight_interactions::command::{CommandInputData, CommandModel, CommandRouter};
+///
+/// #[derive(CommandModel)]
+/// struct PingCommand;
+///
+/// #[derive(CommandRouter)]
+/// enum Commands {
+///     #[command(name = "ping")]
+///     Ping(PingCommand),
+///     #[command(fallback)]
+///     Other(Box<CommandInputData<'static>>),
+/// }
+/// ```
+///
+/// [`CommandModel`]: super::CommandModel
+/// [`CreateCommand`]: super::CreateCommand
+/// [`CreateCommand::NAME`]: super::CreateCommand::NAME
+/// [`Vec<CommandDataOption>`]: twilight_model::application::interaction::application_command::CommandDataOption
+/// [`CommandInputData`]: super::CommandInputData
+pub trait CommandRouter: Sized {
+    /// Parse command data into the model of the corresponding command.
+    fn route(data: CommandData) -> Result<Self, ParseError>;
+}
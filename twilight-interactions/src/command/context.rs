@@ -0,0 +1,118 @@
+use twilight_model::{
+    application::interaction::Interaction,
+    channel::Channel,
+    guild::PartialMember,
+    id::{
+        marker::{ApplicationMarker, ChannelMarker, GuildMarker, InteractionMarker, UserMarker},
+        Id,
+    },
+    user::User,
+};
+
+/// Extract a field of a command model from the received interaction.
+///
+/// This trait is used by fields with the `ctx` attribute of the
+/// [`CommandModel`] derive macro, which are filled from the interaction
+/// rather than from command options. These fields are ignored by the
+/// [`CreateCommand`] derive macro.
+///
+/// The trait is implemented for the following types:
+///
+/// | Type                    | Value                                              |
+/// |-------------------------|----------------------------------------------------|
+/// | `Id<GuildMarker>`       | ID of the guild the command was run in.            |
+/// | `Id<ChannelMarker>`     | ID of the channel the command was run in.          |
+/// | `Id<UserMarker>`        | ID of the user who ran the command.                |
+/// | `Id<InteractionMarker>` | ID of the interaction.                             |
+/// | `Id<ApplicationMarker>` | ID of the application the interaction was sent to. |
+/// | [`Channel`]             | Channel the command was run in.                    |
+/// | [`PartialMember`]       | Member who ran the command, in guilds.             |
+/// | [`User`]                | User who ran the command.                          |
+///
+/// Fields are only filled when parsing with
+/// [`CommandModel::from_interaction_full`] or by the [`CommandRegistry`]. A
+/// [`ParseError::MissingContext`] error is returned if a value is not
+/// available, unless the field is wrapped in an [`Option`].
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::command::CommandModel;
+/// use twilight_model::{
+///     guild::PartialMember,
+///     id::{marker::GuildMarker, Id},
+/// };
+///
+/// #[derive(CommandModel)]
+/// struct WarnCommand {
+///     reason: String,
+///     #[command(ctx)]
+///     guild_id: Option<Id<GuildMarker>>,
+///     #[command(ctx)]
+///     member: PartialMember,
+/// }
+/// ```
+///
+/// [`CommandModel`]: super::CommandModel
+/// [`CommandModel::from_interaction_full`]: super::CommandModel::from_interaction_full
+/// [`CreateCommand`]: super::CreateCommand
+/// [`CommandRegistry`]: crate::registry::CommandRegistry
+/// [`ParseError::MissingContext`]: crate::error::ParseError::MissingContext
+pub trait ContextField: Sized {
+    /// Extract the value from the interaction.
+    ///
+    /// Returns `None` if the value is not available.
+    fn from_context(interaction: &Interaction) -> Option<Self>;
+}
+
+impl ContextField for Id<GuildMarker> {
+    fn from_context(interaction: &Interaction) -> Option<Self> {
+        interaction.guild_id
+    }
+}
+
+impl ContextField for Id<ChannelMarker> {
+    fn from_context(interaction: &Interaction) -> Option<Self> {
+        #[allow(deprecated)]
+        interaction
+            .channel
+            .as_ref()
+            .map(|channel| channel.id)
+            .or(interaction.channel_id)
+    }
+}
+
+impl ContextField for Id<UserMarker> {
+    fn from_context(interaction: &Interaction) -> Option<Self> {
+        interaction.author_id()
+    }
+}
+
+impl ContextField for Id<InteractionMarker> {
+    fn from_context(interaction: &Interaction) -> Option<Self> {
+        Some(interaction.id)
+    }
+}
+
+impl ContextField for Id<ApplicationMarker> {
+    fn from_context(interaction: &Interaction) -> Option<Self> {
+        Some(interaction.application_id)
+    }
+}
+
+impl ContextField for Channel {
+    fn from_context(interaction: &Interaction) -> Option<Self> {
+        interaction.channel.clone()
+    }
+}
+
+impl ContextField for PartialMember {
+    fn from_context(interaction: &Interaction) -> Option<Self> {
+        interaction.member.clone()
+    }
+}
+
+impl ContextField for User {
+    fn from_context(interaction: &Interaction) -> Option<Self> {
+        interaction.author().cloned()
+    }
+}
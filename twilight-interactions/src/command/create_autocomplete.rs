@@ -0,0 +1,74 @@
+use twilight_model::application::command::CommandOptionChoice;
+
+use super::{CommandInputData, FocusedOption};
+
+/// Provide autocomplete suggestions for the fields of a command.
+///
+/// This trait is used to respond to autocomplete interactions with the
+/// choices matching the value typed by the user in the focused field. A derive
+/// macro is provided to automatically implement the trait.
+///
+/// ## Derive macro
+/// The derive macro calls the function set on the focused field with the
+/// `autocomplete` attribute (e.g. `#[command(autocomplete = "suggest")]`) or
+/// the `autocomplete_with` attribute, with the signature
+/// `fn(&str) -> Vec<CommandOptionChoice>`. Both attributes also enable
+/// autocomplete on the field, and a function is required on all fields with
+/// autocomplete enabled. Fields without autocomplete have no suggestions.
+///
+/// The macro can be used on the same type as the [`CreateCommand`] derive
+/// macro, or on an autocomplete model (see the [`CommandModel`] documentation).
+///
+/// ```
+/// use twilight_interactions::{
+///     autocomplete,
+///     command::{CommandModel, CreateAutocomplete, CreateCommand, FocusedOption},
+/// };
+/// use twilight_model::application::command::CommandOptionChoice;
+///
+/// #[derive(CommandModel, CreateCommand, CreateAutocomplete)]
+/// #[command(name = "travel", desc = "Plan a trip")]
+/// struct TravelCommand {
+///     /// Destination country.
+///     #[command(autocomplete = "suggest_country")]
+///     country: String,
+///     /// Number of days.
+///     days: i64,
+/// }
+///
+/// fn suggest_country(value: &str) -> Vec<CommandOptionChoice> {
+///     autocomplete::filter([("France", "fr"), ("Germany", "de"), ("Italy", "it")], value)
+/// }
+///
+/// let choices = TravelCommand::autocomplete("country", "ger");
+/// assert_eq!(choices[0].name, "Germany");
+/// ```
+///
+/// [`CreateCommand`]: super::CreateCommand
+/// [`CommandModel`]: super::CommandModel
+pub trait CreateAutocomplete {
+    /// Get the suggested choices for a field from the value typed by the
+    /// user.
+    ///
+    /// The field is identified by its option name. An empty list is returned
+    /// for unknown fields.
+    fn autocomplete(field: &str, value: &str) -> Vec<CommandOptionChoice>;
+
+    /// Get the suggested choices for the focused option of an autocomplete
+    /// interaction.
+    fn suggest(focused: &FocusedOption) -> Vec<CommandOptionChoice> {
+        Self::autocomplete(&focused.name, &focused.value)
+    }
+
+    /// Get the suggested choices for the focused option of the data of an
+    /// autocomplete interaction.
+    ///
+    /// Options of subcommands and subcommand groups are also searched, see
+    /// [`CommandInputData::focused_option`]. An empty list is returned if no
+    /// option is focused.
+    fn resolve_autocomplete(data: &CommandInputData<'_>) -> Vec<CommandOptionChoice> {
+        data.focused_option()
+            .map(|focused| Self::suggest(&focused))
+            .unwrap_or_default()
+    }
+}
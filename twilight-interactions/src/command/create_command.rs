@@ -1,9 +1,17 @@
-use std::{borrow::Cow, collections::HashMap};
+use std::{
+    any::TypeId,
+    borrow::Cow,
+    collections::HashMap,
+    num::{NonZeroI64, NonZeroU64},
+    rc::Rc,
+    sync::{Arc, OnceLock, RwLock},
+    time::Duration,
+};
 
 use twilight_model::{
     application::{
-        command::{Command, CommandOption, CommandOptionType, CommandType},
-        interaction::{InteractionChannel, InteractionContextType},
+        command::{Command, CommandOption, CommandOptionType, CommandOptionValue, CommandType},
+        interaction::{Interaction, InteractionChannel, InteractionContextType},
     },
     channel::Attachment,
     guild::{Permissions, Role},
@@ -15,7 +23,14 @@ use twilight_model::{
     user::User,
 };
 
-use super::{internal::CreateOptionData, ResolvedMentionable, ResolvedUser};
+use super::{
+    ext::CreateOptionData, localization::LocalizationProvider, Parsed, ResolvedChannel,
+    ResolvedMember, ResolvedMentionable, ResolvedUser,
+};
+use crate::{
+    cooldown::Cooldown,
+    error::{AccessDenied, AccessRestriction, CommandConversionError},
+};
 
 /// Create a slash command from a type.
 ///
@@ -55,6 +70,50 @@ use super::{internal::CreateOptionData, ResolvedMentionable, ResolvedUser};
 /// }
 /// ```
 ///
+/// ### Slash command derive
+/// Most commands derive both [`CommandModel`] and [`CreateCommand`]. The
+/// [`SlashCommand`] derive macro implements both traits from the same input,
+/// which ensures that the parsed and created commands have the same option
+/// names and optionality.
+///
+/// ```
+/// use twilight_interactions::command::{CreateCommand, SlashCommand};
+///
+/// #[derive(SlashCommand)]
+/// #[command(name = "hello", desc = "Say hello")]
+/// struct HelloCommand {
+///     /// The message to send.
+///     message: String,
+/// }
+///
+/// assert_eq!(HelloCommand::NAME, "hello");
+/// assert_eq!(HelloCommand::DESCRIPTION, "Say hello");
+/// ```
+///
+/// ### Option names
+/// With the `names_module` attribute, the derive macro generates a module
+/// named after the type in `snake_case`, with the same visibility. It contains
+/// an `options` module with a constant for each option name, or a
+/// `subcommands` module for enums. This avoids repeating names as strings, for
+/// example in autocomplete handlers.
+///
+/// ```
+/// use twilight_interactions::command::CreateCommand;
+///
+/// #[derive(CreateCommand)]
+/// #[command(name = "ban", desc = "Ban a member", names_module)]
+/// struct BanCommand {
+///     /// Member to ban
+///     #[command(rename = "member")]
+///     user: String,
+///     /// Days of messages to delete
+///     delete_days: Option<i64>,
+/// }
+///
+/// assert_eq!(ban_command::options::MEMBER, "member");
+/// assert_eq!(ban_command::options::DELETE_DAYS, "delete_days");
+/// ```
+///
 /// ## Macro attributes
 /// The macro provides a `#[command]` attribute to provide additional
 /// information.
@@ -67,14 +126,63 @@ use super::{internal::CreateOptionData, ResolvedMentionable, ResolvedUser};
 /// | `dm_permission`            | `bool`              | Type                   | Whether the command can be run in DMs.                                    |
 /// | `nsfw`                     | `bool`              | Type                   | Whether the command is age-restricted.                                    |
 /// | `rename`                   | `str`               | Field                  | Use a different option name than the field name.                          |
+/// | `rename_all`               | `str`               | Type                   | Derive option and subcommand names.[^rename_all]                          |
 /// | `name_localizations`       | `fn`[^localization] | Type / Field / Variant | Localized name of the command (optional).                                 |
 /// | `desc_localizations`       | `fn`[^localization] | Type / Field / Variant | Localized description of the command (optional).                          |
-/// | `autocomplete`             | `bool`              | Field                  | Enable autocomplete on this field.                                        |
-/// | `channel_types`            | `str`               | Field                  | Restricts the channel choice to specific types.[^channel_types]           |
+/// | `autocomplete`             | `bool` or `fn`      | Field                  | Enable autocomplete on this field.[^autocomplete_with]                    |
+/// | `autocomplete_all`         | `bool`              | Type                   | Enable autocomplete on all eligible fields.[^autocomplete_all]            |
+/// | `autocomplete_with`        | `fn`                | Field                  | Enable autocomplete with a suggestion function.[^autocomplete_with]       |
+/// | `channel_types`            | `str` or list       | Field                  | Restricts the channel choice to specific types.[^channel_types]           |
+/// | `choices`                  | `fn`                | Field                  | Set the choices of the option dynamically.[^choices]                      |
 /// | `max_value`, `min_value`   | `i64` or `f64`      | Field                  | Set the maximum and/or minimum value permitted.                           |
 /// | `max_length`, `min_length` | `u16`               | Field                  | Maximum and/or minimum string length permitted.                           |
+/// | `format`                   | `str`               | Field                  | Format used to parse date and time options.[^format]                      |
+/// | `order`                    | `i32`               | Field                  | Position of the option among other options.[^order]                       |
+/// | `option_type`              | `str`               | Field                  | Type of the option of fields parsed with `with`.[^option_type]            |
 /// | `contexts`                 | `str`               | Type                   | Interaction context(s) where the command can be used.[^contexts]          |
 /// | `integration_types`        | `str`               | Type                   | Installation contexts where the command is available.[^integration_types] |
+/// | `timeout`                  | `str`               | Type                   | Maximum execution time of the command handler.[^timeout]                  |
+/// | `generate_tests`           | `bool`              | Type                   | Generate unit tests for the command.[^generate_tests]                     |
+/// | `deny_reserved_name`       | `bool`              | Type                   | Reject the name of a built-in Discord command.[^reserved]                 |
+/// | `names_module`             | `bool`              | Type                   | Generate a module with the option names.[^names_module]                   |
+/// | `category`                 | `str`               | Type                   | Category of the command, used in help menus.[^category]                   |
+/// | `cooldown`                 | `str`               | Type                   | Minimum time between two uses of the command.[^cooldown]                  |
+/// | `guild_only`, `owner_only` | `bool`              | Type                   | Restrict who can use the command.[^access]                                |
+/// | `meta`                     | list                | Type                   | Arbitrary metadata of the command.[^meta]                                 |
+///
+/// ### Metadata
+/// In-house conventions like a billing tier can be attached to commands with
+/// the `meta` attribute, which accepts a list of `key = value` entries. Keys
+/// are identifiers or string literals. Values must be string, integer, float
+/// or boolean literals, and are stored as strings in the [`metadata`] of the
+/// command:
+///
+/// ```
+/// use twilight_interactions::command::CreateCommand;
+///
+/// #[derive(CreateCommand)]
+/// #[command(name = "beta", desc = "Try new features", meta(tier = 2, "feature-flag" = "beta"))]
+/// struct BetaCommand;
+///
+/// let command = BetaCommand::create_command();
+/// assert_eq!(command.metadata["tier"], "2");
+/// assert_eq!(command.metadata["feature-flag"], "beta");
+/// ```
+///
+/// Fields with the `ctx` or `rest` attributes of the [`CommandModel`] derive
+/// macro do not create command options.
+///
+/// The `name` and `desc` attributes of the command, as well as the `rename`,
+/// `desc`, `max_value`, `min_value`, `max_length` and `min_length` attributes
+/// of fields, also accept an unquoted path to a constant, such as
+/// `max_value = limits::MAX_AMOUNT`. This avoids duplicating values shared
+/// with other code. Constants are checked at compile time like literals, with
+/// only ASCII characters validated in names. Duplicate option names cannot be
+/// detected when they are provided as constants.
+///
+/// [^rename_all]: Rename rule applied to field names and subcommand variant
+/// names, one of `lowercase`, `snake_case` or `kebab-case`. See the
+/// [`CommandModel`] documentation.
 ///
 /// [^perms]: Path to a function that returns [`Permissions`]. Permissions can
 /// only be set on top-level commands
@@ -84,7 +192,11 @@ use super::{internal::CreateOptionData, ResolvedMentionable, ResolvedUser};
 /// learn more.
 ///
 /// [^channel_types]: List of [`ChannelType`] names in snake_case separated by spaces
-/// like `guild_text private`.
+/// like `guild_text private`. Former names of announcement and thread types,
+/// like `guild_news` or `guild_public_thread`, are also accepted. Variants can
+/// also be listed with `channel_types(GuildText, Private)`, or provided as a
+/// path to a constant slice, so that invalid types are reported by the
+/// compiler.
 ///
 /// [^contexts]: List of [`InteractionContextType`] names in snake_case separated by
 /// spaces like `guild private_channel`.
@@ -92,24 +204,212 @@ use super::{internal::CreateOptionData, ResolvedMentionable, ResolvedUser};
 /// [^integration_types]: List of [`ApplicationIntegrationType`] names in snake_case
 /// separated by spaces like `guild_install user_install`.
 ///
+/// [^timeout]: Duration in a human-readable format like `10s` or `1m 30s`. The
+/// timeout is only enforced by the [`CommandRegistry`] when the `tokio`
+/// feature is enabled, and can only be set on top-level commands.
+///
+/// [^format]: Format description used by `chrono` or `time` depending on the
+/// type of the field. Values are parsed as RFC 3339 timestamps by default, in
+/// which case length limits are set on the option.
+///
+/// [^order]: Options are sorted by this value, which defaults to `0`,
+/// keeping the declaration order of fields with the same value. Discord
+/// requires required options to be placed before optional ones, which is
+/// checked at compile time.
+///
+/// [^option_type]: Required on fields using the `with` attribute of
+/// [`CommandModel`], whose type does not implement [`CreateOption`]. One of
+/// `string`, `integer`, `number`, `boolean`, `user`, `channel`, `role`,
+/// `mentionable` or `attachment`.
+///
+/// [^generate_tests]: Can be written without value. The generated tests use the
+/// [`testing`](crate::testing) module, which requires the `testing` feature,
+/// and the type must also implement [`CommandModel`]. Tests are generated in
+/// a child module, so the type must be declared at module level rather than
+/// inside a function.
+///
+/// [^autocomplete_all]: Can be written without value. Autocomplete is enabled
+/// on `STRING`, `INTEGER` and `NUMBER` options without choices. Use the
+/// [`FocusedOption`] model to handle autocomplete for all fields with a single
+/// handler.
+///
+/// [^autocomplete_with]: Path to a function used by the [`CreateAutocomplete`]
+/// derive macro to suggest choices for the field. A function can also be set
+/// with the `autocomplete` attribute.
+///
+/// [^choices]: Path to a function returning a `Vec` of
+/// [`CommandOptionChoice`], called in [`create_command`]. This allows choices
+/// to be loaded from configuration without defining an enum. Values received
+/// in interactions are not checked against these choices. Cannot be combined
+/// with autocomplete.
+///
+/// [^reserved]: Can be written without value. Names of built-in commands of
+/// the Discord client, listed in [`RESERVED_NAMES`], are accepted by Discord
+/// but can be confusing for users. They are allowed by default and reported
+/// by [`audit::lint`](crate::audit::lint); this attribute turns them into a
+/// compile error.
+///
+/// [^names_module]: Can be written without value. See the
+/// [option names](#option-names) section.
+///
+/// [^category]: The category is stored in [`ApplicationCommandData::category`]
+/// and [`CreateCommand::CATEGORY`], and is not sent to Discord. Help commands
+/// can use it to group commands, such as the commands returned by
+/// [`CommandRegistry::command_data`].
+///
+/// [^cooldown]: The cooldown is a duration followed by an optional scope,
+/// such as `"5s per user"`. See the [`cooldown`](crate::cooldown) module for
+/// more information.
+///
+/// [^access]: Can be written without value. These restrictions are enforced
+/// by the bot, see [`ApplicationCommandData::check_access`].
+///
+/// [^meta]: Entries are stored in the [`metadata`] of the command, see the
+/// [metadata](#metadata) section. The same key cannot be provided twice.
+///
+/// [`metadata`]: ApplicationCommandData::metadata
 /// [`CommandModel`]: super::CommandModel
+/// [`SlashCommand`]: super::SlashCommand
+/// [`FocusedOption`]: super::FocusedOption
+/// [`CreateAutocomplete`]: super::CreateAutocomplete
+/// [`RESERVED_NAMES`]: crate::audit::RESERVED_NAMES
 /// [`ChannelType`]: twilight_model::channel::ChannelType
 /// [`InteractionContextType`]: twilight_model::application::interaction::InteractionContextType
 /// [`ApplicationIntegrationType`]: twilight_model::oauth::ApplicationIntegrationType
+/// [`CommandRegistry`]: crate::registry::CommandRegistry
+/// [`CommandRegistry::command_data`]: crate::registry::CommandRegistry::command_data
+/// [`CommandOptionChoice`]: twilight_model::application::command::CommandOptionChoice
+/// [`create_command`]: Self::create_command
 pub trait CreateCommand: Sized {
     /// Name of the command.
     const NAME: &'static str;
 
+    /// Description of the command.
+    ///
+    /// This is empty if the description is returned by a function set with
+    /// the `desc_localizations` attribute, since it is only known at runtime.
+    const DESCRIPTION: &'static str = "";
+
+    /// Whether the command is age-restricted.
+    const NSFW: bool = false;
+
+    /// Whether the command has subcommands, as in
+    /// [`ApplicationCommandData::group`].
+    const GROUP: bool = false;
+
+    /// Category of the command, as in [`ApplicationCommandData::category`].
+    const CATEGORY: Option<&'static str> = None;
+
+    /// Number of subcommand levels of the command.
+    ///
+    /// This is `0` for commands without subcommands, `1` for commands with
+    /// subcommands and `2` for commands with subcommand groups. The derive
+    /// macro uses it to reject subcommand groups nested in other groups at
+    /// compile time, since Discord only supports two levels of nesting.
+    const SUBCOMMAND_DEPTH: u8 = 0;
+
     /// Create an [`ApplicationCommandData`] for this type.
+    ///
+    /// Implementations generated by the derive macro apply the localizations
+    /// of the global [`LocalizationProvider`], if one is set with
+    /// [`set_localization_provider`].
+    ///
+    /// [`set_localization_provider`]: super::set_localization_provider
     fn create_command() -> ApplicationCommandData;
+
+    /// Create an [`ApplicationCommandData`] for this type, with the
+    /// localizations of a [`LocalizationProvider`].
+    ///
+    /// The global provider is applied first if set, and the localizations of
+    /// the provided one take precedence for locales set by both.
+    fn create_command_with(provider: &dyn LocalizationProvider) -> ApplicationCommandData {
+        let mut data = Self::create_command();
+        data.localize(provider);
+
+        data
+    }
+
+    /// Create an [`ApplicationCommandData`] for this type without applying
+    /// the global [`LocalizationProvider`].
+    ///
+    /// Used by the derive macro to create subcommands, which are localized
+    /// with the path of their parent command.
+    #[doc(hidden)]
+    fn create_command_base() -> ApplicationCommandData {
+        Self::create_command()
+    }
+
+    /// Get the [`ApplicationCommandData`] of this type, created once and
+    /// cached for the lifetime of the program.
+    ///
+    /// This avoids rebuilding the command each time it is needed, such as
+    /// when rendering help menus or command mentions. The cached command is
+    /// never freed, and only includes the localizations of the global
+    /// [`LocalizationProvider`] if it was set before the first call.
+    fn command() -> &'static ApplicationCommandData
+    where
+        Self: 'static,
+    {
+        cached_command::<Self>()
+    }
+}
+
+/// Implement [`CreateCommand`] for smart pointers, such as the variants of
+/// subcommand enums wrapped in a [`Box`], [`Arc`] or [`Rc`].
+macro_rules! smart_pointer {
+    ($($ty:ident),*) => {
+        $(
+            impl<T: CreateCommand> CreateCommand for $ty<T> {
+                const NAME: &'static str = T::NAME;
+                const DESCRIPTION: &'static str = T::DESCRIPTION;
+                const NSFW: bool = T::NSFW;
+                const GROUP: bool = T::GROUP;
+                const CATEGORY: Option<&'static str> = T::CATEGORY;
+                const SUBCOMMAND_DEPTH: u8 = T::SUBCOMMAND_DEPTH;
+
+                fn create_command() -> ApplicationCommandData {
+                    T::create_command()
+                }
+
+                fn create_command_base() -> ApplicationCommandData {
+                    T::create_command_base()
+                }
+
+                fn command() -> &'static ApplicationCommandData
+                where
+                    Self: 'static,
+                {
+                    T::command()
+                }
+            }
+        )*
+    };
 }
 
-impl<T: CreateCommand> CreateCommand for Box<T> {
-    const NAME: &'static str = T::NAME;
+smart_pointer!(Box, Arc, Rc);
 
-    fn create_command() -> ApplicationCommandData {
-        T::create_command()
+/// Get the cached command of a type, creating it if needed.
+///
+/// A single cache keyed by [`TypeId`] is used since statics declared in
+/// generic functions are shared by all types.
+fn cached_command<T: CreateCommand + 'static>() -> &'static ApplicationCommandData {
+    static CACHE: OnceLock<RwLock<HashMap<TypeId, &'static ApplicationCommandData>>> =
+        OnceLock::new();
+
+    let cache = CACHE.get_or_init(Default::default);
+    if let Some(command) = cache.read().unwrap().get(&TypeId::of::<T>()) {
+        return command;
     }
+
+    // The command is created without holding the lock, since implementations
+    // may call `command` for other types
+    let command = T::create_command();
+
+    cache
+        .write()
+        .unwrap()
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| Box::leak(Box::new(command)))
 }
 
 /// Create a command option from a type.
@@ -118,10 +418,14 @@ impl<T: CreateCommand> CreateCommand for Box<T> {
 /// by the derive macro. See the [module documentation](crate::command) for
 /// a list of implemented types.
 ///
+/// This trait can also be implemented manually to support custom types, using
+/// the types of the [`ext`](super::ext) module.
+///
 /// ## Option choices
 /// This trait can be derived on enums to represent command options with
 /// predefined choices. The `#[option]` attribute must be present on each
-/// variant.
+/// variant, unless the `rename_all` attribute is set on the enum. Choice names
+/// and values must be unique, otherwise the derive fails to compile.
 ///
 /// ### Example
 /// ```
@@ -136,19 +440,75 @@ impl<T: CreateCommand> CreateCommand for Box<T> {
 ///     #[option(name = "Day", value = 86400)]
 ///     Day,
 /// }
+///
+/// // Choices named "light-blue" and "dark-blue", with the same value
+/// #[derive(CreateOption)]
+/// #[option(rename_all = "kebab-case")]
+/// enum Color {
+///     LightBlue,
+///     DarkBlue,
+/// }
+/// ```
+///
+/// ### Display choices
+/// Enums that already implement [`Display`], for example with `strum`, can
+/// use the `#[option(display)]` attribute instead of annotating each variant.
+/// The string returned by [`Display`] is used as both name and value of the
+/// choices, and values received from Discord are matched against it.
+///
+/// ```
+/// use std::fmt;
+///
+/// use twilight_interactions::command::{CommandOption, CreateOption};
+///
+/// #[derive(CommandOption, CreateOption)]
+/// #[option(display)]
+/// enum Size {
+///     Small,
+///     Large,
+/// }
+///
+/// impl fmt::Display for Size {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         match self {
+///             Self::Small => f.write_str("small"),
+///             Self::Large => f.write_str("large"),
+///         }
+///     }
+/// }
 /// ```
 ///
+/// This attribute cannot be combined with other `#[option]` attributes.
+///
+/// [`Display`]: std::fmt::Display
+///
 /// ### Macro attributes
 /// The macro provides an `#[option]` attribute to configure the generated code.
 ///
-/// | Attribute            | Type                  | Location | Description                                  |
-/// |----------------------|-----------------------|----------|----------------------------------------------|
-/// | `name`               | `str`                 | Variant  | Set the name of the command option choice.   |
-/// | `name_localizations` | `fn`[^localization]   | Variant  | Localized name of the command option choice. |
-/// | `value`              | `str`, `i64` or `f64` | Variant  | Value of the command option choice.          |
+/// | Attribute            | Type                  | Location | Description                                               |
+/// |----------------------|-----------------------|----------|-----------------------------------------------------------|
+/// | `rename_all`         | `str`[^rename_all]    | Type     | Derive omitted names and values from variant identifiers. |
+/// | `display`            | `bool`                | Type     | Derive choices from the `Display` implementation.         |
+/// | `transparent`        | `bool`                | Type     | Use the option of the inner type.[^newtype]               |
+/// | `name`               | `str`                 | Variant  | Set the name of the command option choice.                |
+/// | `name_localizations` | `fn`[^localization]   | Variant  | Localized name of the command option choice.              |
+/// | `value`              | `str`, `i64` or `f64` | Variant  | Value of the command option choice.[^value]               |
+/// | `other`              | `bool`                | Variant  | Catch-all variant, not registered as a choice.            |
 ///
-/// [^localization]: Path to a function that returns a type that implements
-///                  `IntoIterator<Item = (ToString, ToString)>`. See the
+/// [^rename_all]: Casing applied to variant identifiers, using the same rules
+///                as `serde`: `lowercase`, `UPPERCASE`, `PascalCase`,
+///                `camelCase`, `snake_case`, `SCREAMING_SNAKE_CASE`,
+///                `kebab-case` or `SCREAMING-KEBAB-CASE`. Derived values are
+///                strings.
+///
+/// [^value]: Defaults to the explicit discriminant of the variant, if any.
+///
+/// [^newtype]: Only supported on structs with a single field. See the
+///             [`CommandOption`](super::CommandOption) documentation for an
+///             example.
+///
+/// [^localization]: Path to a function that returns a [`ChoiceLocalizations`]
+///                  (or a [`NameLocalizations`]). See the
 ///                  [module documentation](crate::command) to learn more.
 pub trait CreateOption: Sized {
     /// Create a [`CommandOption`] from this type.
@@ -185,6 +545,46 @@ impl NameLocalizations {
 
         Self { localizations }
     }
+
+    /// Resolve the localized name for a locale.
+    ///
+    /// See [`DescLocalizations::resolve`] for the fallback chain applied.
+    /// Returns `None` if no localization matches, in which case the default
+    /// name should be used.
+    pub fn resolve(&self, locale: &str) -> Option<&str> {
+        resolve_locale(&self.localizations, locale)
+    }
+
+    /// Merge with another [`NameLocalizations`].
+    ///
+    /// Localizations from `other` override the existing ones for the same
+    /// locale.
+    pub fn merge(mut self, other: NameLocalizations) -> Self {
+        self.localizations.extend(other.localizations);
+
+        self
+    }
+}
+
+impl<K, V> Extend<(K, V)> for NameLocalizations
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        self.localizations
+            .extend(iter.into_iter().map(|(k, v)| (k.into(), v.into())));
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for NameLocalizations
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self::new(iter)
+    }
 }
 
 /// Localization data for command descriptions.
@@ -192,6 +592,9 @@ impl NameLocalizations {
 /// This type is used in the `desc_localizations` attribute of the
 /// [`CreateCommand`] trait. See the [module documentation](crate::command) for
 /// more information.
+///
+/// Unlike [`NameLocalizations`], this type does not implement [`FromIterator`]
+/// since a fallback description is always required.
 #[derive(Debug, Clone, PartialEq)]
 pub struct DescLocalizations {
     pub(crate) fallback: String,
@@ -223,13 +626,217 @@ impl DescLocalizations {
             localizations,
         }
     }
+
+    /// Replace the fallback description.
+    ///
+    /// The fallback is used for locales without a localized description.
+    pub fn with_fallback(mut self, fallback: impl Into<String>) -> Self {
+        self.fallback = fallback.into();
+
+        self
+    }
+
+    /// Resolve the localized description for a locale.
+    ///
+    /// The following fallback chain is applied, to match how localized values
+    /// are displayed by Discord clients:
+    /// - the localization for the exact locale (e.g. `en-GB`)
+    /// - the localization for the language without region (e.g. `en`)
+    /// - the localization for the main region of the language (e.g. `en-US`)
+    /// - the localization for any other region of the language
+    /// - the fallback description
+    pub fn resolve(&self, locale: &str) -> &str {
+        resolve_locale(&self.localizations, locale).unwrap_or(&self.fallback)
+    }
+
+    /// Merge with another [`DescLocalizations`].
+    ///
+    /// Localizations from `other` override the existing ones for the same
+    /// locale. The fallback description of `self` is kept.
+    pub fn merge(mut self, other: DescLocalizations) -> Self {
+        self.localizations.extend(other.localizations);
+
+        self
+    }
+}
+
+impl<K, V> Extend<(K, V)> for DescLocalizations
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        self.localizations
+            .extend(iter.into_iter().map(|(k, v)| (k.into(), v.into())));
+    }
+}
+
+/// Localization data for command option choice names.
+///
+/// This type is used in the `name_localizations` attribute of the
+/// [`CreateOption`] trait. It can be created from a list of localizations
+/// with [`new`], or incrementally with [`builder`].
+///
+/// [`new`]: Self::new
+/// [`builder`]: Self::builder
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChoiceLocalizations {
+    pub(crate) localizations: HashMap<String, String>,
+}
+
+impl ChoiceLocalizations {
+    /// Create a new [`ChoiceLocalizations`].
+    ///
+    /// The localizations must be a tuple where the first element is a valid
+    /// [Discord locale] and the second element is the localized value.
+    ///
+    /// See [Localization] on Discord Developer Docs for more information.
+    ///
+    /// [Discord locale]: https://discord.com/developers/docs/reference#locales
+    /// [Localization]: https://discord.com/developers/docs/interactions/application-commands#localization
+    pub fn new(
+        localizations: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+    ) -> Self {
+        let localizations = localizations
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect();
+
+        Self { localizations }
+    }
+
+    /// Create a new [`ChoiceLocalizationsBuilder`].
+    pub fn builder() -> ChoiceLocalizationsBuilder {
+        ChoiceLocalizationsBuilder::default()
+    }
+
+    /// Resolve the localized choice name for a locale.
+    ///
+    /// See [`DescLocalizations::resolve`] for the fallback chain applied.
+    /// Returns `None` if no localization matches, in which case the default
+    /// choice name should be used.
+    pub fn resolve(&self, locale: &str) -> Option<&str> {
+        resolve_locale(&self.localizations, locale)
+    }
+
+    /// Merge with another [`ChoiceLocalizations`].
+    ///
+    /// Localizations from `other` override the existing ones for the same
+    /// locale.
+    pub fn merge(mut self, other: ChoiceLocalizations) -> Self {
+        self.localizations.extend(other.localizations);
+
+        self
+    }
+}
+
+impl<K, V> Extend<(K, V)> for ChoiceLocalizations
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        self.localizations
+            .extend(iter.into_iter().map(|(k, v)| (k.into(), v.into())));
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for ChoiceLocalizations
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// Builder for [`ChoiceLocalizations`].
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::command::ChoiceLocalizations;
+///
+/// let localizations = ChoiceLocalizations::builder()
+///     .localization("fr", "Minute")
+///     .localization("de", "Minute")
+///     .build();
+///
+/// assert_eq!(localizations, ChoiceLocalizations::new([("fr", "Minute"), ("de", "Minute")]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChoiceLocalizationsBuilder {
+    localizations: HashMap<String, String>,
+}
+
+impl ChoiceLocalizationsBuilder {
+    /// Add a localized choice name.
+    ///
+    /// The locale must be a valid [Discord locale]. Adding a locale that is
+    /// already present replaces its value.
+    ///
+    /// [Discord locale]: https://discord.com/developers/docs/reference#locales
+    pub fn localization(mut self, locale: impl Into<String>, name: impl Into<String>) -> Self {
+        self.localizations.insert(locale.into(), name.into());
+
+        self
+    }
+
+    /// Build the [`ChoiceLocalizations`].
+    pub fn build(self) -> ChoiceLocalizations {
+        ChoiceLocalizations {
+            localizations: self.localizations,
+        }
+    }
+}
+
+/// Main region of languages with multiple Discord locales.
+const MAIN_REGIONS: &[&str] = &["en-US", "es-ES", "pt-BR", "sv-SE", "zh-CN"];
+
+/// Find the localized value for a locale in a localization map.
+///
+/// See [`DescLocalizations::resolve`] for the fallback chain applied.
+fn resolve_locale<'a>(localizations: &'a HashMap<String, String>, locale: &str) -> Option<&'a str> {
+    if let Some(value) = localizations.get(locale) {
+        return Some(value);
+    }
+
+    let language = locale.split('-').next().unwrap_or(locale);
+    let is_region = |key: &str| {
+        key.strip_prefix(language)
+            .is_some_and(|region| region.starts_with('-'))
+    };
+
+    localizations
+        .get(language)
+        .or_else(|| {
+            MAIN_REGIONS
+                .iter()
+                .filter(|main| is_region(main))
+                .find_map(|main| localizations.get(*main))
+        })
+        .or_else(|| {
+            localizations
+                .iter()
+                .filter(|(key, _)| is_region(key))
+                .min_by_key(|(key, _)| *key)
+                .map(|(_, value)| value)
+        })
+        .map(String::as_str)
 }
 
 /// Data sent to Discord to create a command.
 ///
 /// This type is used in the [`CreateCommand`] trait.
 /// To convert it into a [`Command`], use the [From] (or [Into]) trait.
+///
+/// The [`Display`](std::fmt::Display) implementation renders the command as a
+/// tree of subcommands and options, which is useful for debugging.
+///
+/// When the `serde` feature is enabled, this type implements `Serialize` and
+/// `Deserialize`, which allows saving command definitions as JSON.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ApplicationCommandData {
     /// Name of the command. It must be 32 characters or less.
     pub name: String,
@@ -256,6 +863,133 @@ pub struct ApplicationCommandData {
     pub contexts: Option<Vec<InteractionContextType>>,
     /// Installation contexts where the command is available.
     pub integration_types: Option<Vec<ApplicationIntegrationType>>,
+    /// Maximum execution time of the command handler.
+    ///
+    /// This field is not sent to Discord and is only used by the
+    /// [`CommandRegistry`](crate::registry::CommandRegistry).
+    pub timeout: Option<Duration>,
+    /// Cooldown of the command.
+    ///
+    /// This field is not sent to Discord and is only used by the
+    /// [`CooldownTracker`](crate::cooldown::CooldownTracker).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cooldown: Option<Cooldown>,
+    /// Whether the command can only be used in guilds.
+    ///
+    /// This field is not sent to Discord, see [`check_access`].
+    ///
+    /// [`check_access`]: Self::check_access
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub guild_only: bool,
+    /// Whether the command can only be used by the owners of the bot.
+    ///
+    /// This field is not sent to Discord, see [`check_access`].
+    ///
+    /// [`check_access`]: Self::check_access
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub owner_only: bool,
+    /// Category of the command, used to group commands in help menus.
+    ///
+    /// This field is not sent to Discord.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub category: Option<String>,
+    /// Entries of the `meta` attribute of the command.
+    ///
+    /// This field is not sent to Discord. See the [`CreateCommand`]
+    /// documentation for more information.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub metadata: HashMap<String, String>,
+}
+
+impl ApplicationCommandData {
+    /// Convert a [`Command`] created with the twilight command builders.
+    ///
+    /// This allows using existing commands alongside commands created with
+    /// the [`CreateCommand`] trait, for instance with the [`sync`] module.
+    /// Fields populated by Discord, such as the command ID, are ignored. The
+    /// conversion fails if the command is not a chat input command, like the
+    /// [`TryFrom`] implementation.
+    ///
+    /// See the [`migrate`] module to generate equivalent command models.
+    ///
+    /// [`sync`]: crate::sync
+    /// [`migrate`]: crate::migrate
+    pub fn from_twilight_builder_output(command: Command) -> Result<Self, CommandConversionError> {
+        if command.kind != CommandType::ChatInput {
+            return Err(CommandConversionError {
+                name: command.name,
+                kind: command.kind,
+            });
+        }
+
+        // Commands with subcommands only have subcommand options
+        let group = command.options.iter().any(|option| {
+            matches!(
+                option.kind,
+                CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup
+            )
+        });
+
+        #[allow(deprecated)]
+        Ok(Self {
+            name: command.name,
+            name_localizations: command.name_localizations,
+            description: command.description,
+            description_localizations: command.description_localizations,
+            options: command.options,
+            dm_permission: command.dm_permission,
+            default_member_permissions: command.default_member_permissions,
+            group,
+            nsfw: command.nsfw,
+            contexts: command.contexts,
+            integration_types: command.integration_types,
+            timeout: None,
+            cooldown: None,
+            guild_only: false,
+            owner_only: false,
+            category: None,
+            metadata: HashMap::new(),
+        })
+    }
+
+    /// Check whether the command can be used with an interaction.
+    ///
+    /// Commands with [`guild_only`] set are rejected outside of guilds, and
+    /// commands with [`owner_only`] set are rejected unless used by one of the
+    /// provided owners. The [`CommandRegistry`] runs this check before
+    /// dispatching commands, with the owners set with
+    /// [`CommandRegistry::owners`].
+    ///
+    /// These restrictions are enforced by the bot. Discord can also hide
+    /// commands outside of guilds with the `contexts` attribute, or from
+    /// members without some permissions with `default_permissions`.
+    ///
+    /// [`guild_only`]: Self::guild_only
+    /// [`owner_only`]: Self::owner_only
+    /// [`CommandRegistry`]: crate::registry::CommandRegistry
+    /// [`CommandRegistry::owners`]: crate::registry::CommandRegistry::owners
+    pub fn check_access(
+        &self,
+        interaction: &Interaction,
+        owners: &[Id<UserMarker>],
+    ) -> Result<(), AccessDenied> {
+        let restriction = if self.guild_only && interaction.guild_id.is_none() {
+            AccessRestriction::GuildOnly
+        } else if self.owner_only
+            && !interaction
+                .author_id()
+                .is_some_and(|user_id| owners.contains(&user_id))
+        {
+            AccessRestriction::OwnerOnly
+        } else {
+            return Ok(());
+        };
+
+        Err(AccessDenied {
+            command: self.name.clone(),
+            restriction,
+        })
+    }
 }
 
 impl From<ApplicationCommandData> for Command {
@@ -281,6 +1015,18 @@ impl From<ApplicationCommandData> for Command {
     }
 }
 
+/// Convert a [`Command`] fetched from Discord.
+///
+/// Fields populated by Discord, such as the command ID and version, are
+/// dropped. The conversion fails if the command is not a chat input command.
+impl TryFrom<Command> for ApplicationCommandData {
+    type Error = CommandConversionError;
+
+    fn try_from(command: Command) -> Result<Self, Self::Error> {
+        Self::from_twilight_builder_output(command)
+    }
+}
+
 impl From<ApplicationCommandData> for CommandOption {
     fn from(item: ApplicationCommandData) -> Self {
         let data = CreateOptionData {
@@ -317,12 +1063,111 @@ impl CreateOption for Cow<'_, str> {
     }
 }
 
+macro_rules! shared_str {
+    ($($ty:ident),*) => {
+        $(
+            impl CreateOption for $ty<str> {
+                fn create_option(data: CreateOptionData) -> CommandOption {
+                    data.into_option(CommandOptionType::String)
+                }
+            }
+        )*
+    };
+}
+
+shared_str!(Box, Arc, Rc);
+
+impl<T> CreateOption for Parsed<T> {
+    fn create_option(data: CreateOptionData) -> CommandOption {
+        data.into_option(CommandOptionType::String)
+    }
+}
+
+#[cfg(feature = "humantime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "humantime")))]
+impl CreateOption for Duration {
+    fn create_option(data: CreateOptionData) -> CommandOption {
+        data.into_option(CommandOptionType::String)
+    }
+}
+
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+impl CreateOption for url::Url {
+    fn create_option(data: CreateOptionData) -> CommandOption {
+        data.into_option(CommandOptionType::String)
+    }
+}
+
 impl CreateOption for i64 {
     fn create_option(data: CreateOptionData) -> CommandOption {
         data.into_option(CommandOptionType::Integer)
     }
 }
 
+impl CreateOption for NonZeroI64 {
+    fn create_option(data: CreateOptionData) -> CommandOption {
+        data.into_option(CommandOptionType::Integer)
+    }
+}
+
+impl CreateOption for NonZeroU64 {
+    fn create_option(mut data: CreateOptionData) -> CommandOption {
+        // Only positive values can be parsed
+        let min = match data.data.min_value {
+            Some(CommandOptionValue::Integer(min)) => min.max(1),
+            _ => 1,
+        };
+        data.data.min_value = Some(CommandOptionValue::Integer(min));
+
+        data.into_option(CommandOptionType::Integer)
+    }
+}
+
+/// Implement [`CreateOption`] for integer types smaller than `i64`.
+///
+/// The `min_value` and `max_value` of the option are restricted to the range
+/// of the type, so that Discord rejects values that cannot be parsed.
+macro_rules! integer {
+    ($($ty:ty),*) => {
+        $(
+            impl CreateOption for $ty {
+                fn create_option(mut data: CreateOptionData) -> CommandOption {
+                    let (type_min, type_max) = (i64::from(<$ty>::MIN), i64::from(<$ty>::MAX));
+
+                    let min = match data.data.min_value {
+                        Some(CommandOptionValue::Integer(min)) => min.max(type_min),
+                        _ => type_min,
+                    };
+                    let max = match data.data.max_value {
+                        Some(CommandOptionValue::Integer(max)) => max.min(type_max),
+                        _ => type_max,
+                    };
+                    data.data.min_value = Some(CommandOptionValue::Integer(min));
+                    data.data.max_value = Some(CommandOptionValue::Integer(max));
+
+                    data.into_option(CommandOptionType::Integer)
+                }
+            }
+        )*
+    };
+}
+
+integer!(i8, i16, i32, u8, u16, u32);
+
+impl CreateOption for u64 {
+    fn create_option(mut data: CreateOptionData) -> CommandOption {
+        // Only non-negative values can be parsed
+        let min = match data.data.min_value {
+            Some(CommandOptionValue::Integer(min)) => min.max(0),
+            _ => 0,
+        };
+        data.data.min_value = Some(CommandOptionValue::Integer(min));
+
+        data.into_option(CommandOptionType::Integer)
+    }
+}
+
 impl CreateOption for f64 {
     fn create_option(data: CreateOptionData) -> CommandOption {
         data.into_option(CommandOptionType::Number)
@@ -383,6 +1228,12 @@ impl CreateOption for ResolvedUser {
     }
 }
 
+impl CreateOption for ResolvedMember {
+    fn create_option(data: CreateOptionData) -> CommandOption {
+        data.into_option(CommandOptionType::User)
+    }
+}
+
 impl CreateOption for ResolvedMentionable {
     fn create_option(data: CreateOptionData) -> CommandOption {
         data.into_option(CommandOptionType::Mentionable)
@@ -395,6 +1246,12 @@ impl CreateOption for InteractionChannel {
     }
 }
 
+impl CreateOption for ResolvedChannel {
+    fn create_option(data: CreateOptionData) -> CommandOption {
+        data.into_option(CommandOptionType::Channel)
+    }
+}
+
 impl CreateOption for Role {
     fn create_option(data: CreateOptionData) -> CommandOption {
         data.into_option(CommandOptionType::Role)
@@ -0,0 +1,112 @@
+//! Implementation of option traits for date and time types.
+//!
+//! Values are received as `STRING` options. They are parsed as [RFC 3339]
+//! timestamps by default, or with the format provided with the `format`
+//! attribute.
+//!
+//! [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339
+
+use twilight_model::application::{
+    command::{CommandOption, CommandOptionType},
+    interaction::{application_command::CommandOptionValue, InteractionDataResolved},
+};
+
+use super::{
+    ext::{CommandOptionData, CreateOptionData},
+    CommandOption as CommandOptionTrait, CreateOption,
+};
+use crate::error::ParseOptionErrorType;
+
+/// Minimum length of a RFC 3339 timestamp (`2024-01-01T00:00:00Z`).
+const RFC3339_MIN_LENGTH: u16 = 20;
+/// Maximum length of a RFC 3339 timestamp (`2024-01-01T00:00:00.000000000+00:00`).
+const RFC3339_MAX_LENGTH: u16 = 35;
+
+/// Extract the string value of an option.
+fn string_value(value: CommandOptionValue) -> Result<String, ParseOptionErrorType> {
+    match value {
+        CommandOptionValue::String(value) => Ok(value),
+        other => Err(ParseOptionErrorType::InvalidType(other.kind())),
+    }
+}
+
+/// Create a `STRING` option, with length limits if parsed as RFC 3339.
+fn create_datetime_option(mut data: CreateOptionData) -> CommandOption {
+    if data.data.format.is_none() {
+        data.data.min_length.get_or_insert(RFC3339_MIN_LENGTH);
+        data.data.max_length.get_or_insert(RFC3339_MAX_LENGTH);
+    }
+
+    data.into_option(CommandOptionType::String)
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl CommandOptionTrait for chrono::DateTime<chrono::Utc> {
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        _resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        use chrono::{DateTime, NaiveDateTime, Utc};
+
+        let value = string_value(value)?;
+        let parsed = match data.format {
+            Some(format) => DateTime::parse_from_str(&value, format)
+                .map(|datetime| datetime.with_timezone(&Utc))
+                .or_else(|_| NaiveDateTime::parse_from_str(&value, format).map(|n| n.and_utc())),
+            None => {
+                DateTime::parse_from_rfc3339(&value).map(|datetime| datetime.with_timezone(&Utc))
+            }
+        };
+
+        parsed.map_err(ParseOptionErrorType::invalid_value)
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl CreateOption for chrono::DateTime<chrono::Utc> {
+    fn create_option(data: CreateOptionData) -> CommandOption {
+        create_datetime_option(data)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl CommandOptionTrait for time::OffsetDateTime {
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        _resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        use time::{
+            format_description::{self, well_known::Rfc3339},
+            OffsetDateTime, PrimitiveDateTime,
+        };
+
+        let value = string_value(value)?;
+
+        match data.format {
+            Some(format) => {
+                let format = format_description::parse_borrowed::<1>(format)
+                    .map_err(ParseOptionErrorType::invalid_value)?;
+
+                OffsetDateTime::parse(&value, &format)
+                    .or_else(|_| PrimitiveDateTime::parse(&value, &format).map(|n| n.assume_utc()))
+                    .map_err(ParseOptionErrorType::invalid_value)
+            }
+            None => {
+                OffsetDateTime::parse(&value, &Rfc3339).map_err(ParseOptionErrorType::invalid_value)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl CreateOption for time::OffsetDateTime {
+    fn create_option(data: CreateOptionData) -> CommandOption {
+        create_datetime_option(data)
+    }
+}
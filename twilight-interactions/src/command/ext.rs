@@ -0,0 +1,320 @@
+//! Types used to implement option traits manually.
+//!
+//! The [`CreateOption`] and [`CommandOption`] traits can be implemented
+//! manually to support custom option types. This module contains the types
+//! used by these traits:
+//!
+//! - [`CreateOptionData`] holds the data used to create a command option. It
+//!   can be converted into a twilight command option with
+//!   [`CreateOptionData::into_option`] or [`CreateOptionData::builder`].
+//! - [`CommandOptionData`] holds the settings of an option used when parsing
+//!   its value, such as the minimum and maximum values.
+//!
+//! These types are covered by semver guarantees. New fields may be added in
+//! minor releases: use the provided constructors instead of struct literals
+//! when creating them.
+//!
+//! ## Example
+//! ```
+//! use twilight_interactions::{
+//!     command::{
+//!         ext::{CommandOptionData, CreateOptionData},
+//!         CommandOption, CreateOption,
+//!     },
+//!     error::{ParseOptionErrorType, ValueError},
+//! };
+//! use twilight_model::application::{
+//!     command::{CommandOption as TwilightCommandOption, CommandOptionType},
+//!     interaction::{application_command::CommandOptionValue, InteractionDataResolved},
+//! };
+//!
+//! /// Color parsed from an hexadecimal code like `#ff0000`.
+//! #[derive(Debug, PartialEq, Eq)]
+//! struct HexColor(u32);
+//!
+//! impl CommandOption for HexColor {
+//!     fn from_option(
+//!         value: CommandOptionValue,
+//!         data: CommandOptionData,
+//!         resolved: Option<&InteractionDataResolved>,
+//!     ) -> Result<Self, ParseOptionErrorType> {
+//!         let value = String::from_option(value, data, resolved)?;
+//!         let code = value.trim_start_matches('#');
+//!
+//!         u32::from_str_radix(code, 16)
+//!             .map(HexColor)
+//!             .map_err(|error| ValueError::with_source(format!("invalid color: {value}"), error).into())
+//!     }
+//! }
+//!
+//! impl CreateOption for HexColor {
+//!     fn create_option(data: CreateOptionData) -> TwilightCommandOption {
+//!         data.into_option(CommandOptionType::String)
+//!     }
+//! }
+//!
+//! let option = HexColor::create_option(
+//!     CreateOptionData::new("color", "Color to use")
+//!         .required(true)
+//!         .data(CommandOptionData::new().min_length(6).max_length(7)),
+//! );
+//! assert_eq!(option.max_length, Some(7));
+//!
+//! let color = HexColor::from_option(
+//!     CommandOptionValue::String("#ff0000".into()),
+//!     CommandOptionData::new(),
+//!     None,
+//! );
+//! assert_eq!(color, Ok(HexColor(0xff0000)));
+//! ```
+//!
+//! [`CreateOption`]: super::CreateOption
+//! [`CommandOption`]: super::CommandOption
+
+use std::collections::HashMap;
+
+use twilight_model::{
+    application::command::{
+        CommandOption, CommandOptionChoice, CommandOptionType, CommandOptionValue,
+    },
+    channel::ChannelType,
+};
+
+use super::{DescLocalizations, NameLocalizations};
+use crate::error::ParseOptionErrorType;
+
+/// Data to create a command option from.
+///
+/// This type is used in the [`CreateOption`] trait and contains a subset of
+/// twilight's [`CommandOption`] fields.
+///
+/// [`CreateOption`]: super::CreateOption
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateOptionData {
+    /// Name of the option. It must be 32 characters or less.
+    pub name: String,
+    /// Localization dictionary for the option name. Keys must be valid locales.
+    pub name_localizations: Option<HashMap<String, String>>,
+    /// Description of the option. It must be 100 characters or less.
+    pub description: String,
+    /// Localization dictionary for the option description. Keys must be valid
+    /// locales.
+    pub description_localizations: Option<HashMap<String, String>>,
+    /// Whether the option is required to be completed by a user.
+    pub required: Option<bool>,
+    /// Whether the command supports autocomplete. Only for `STRING`, `INTEGER`
+    /// and `NUMBER` option types.
+    pub autocomplete: bool,
+    /// Data of the command option.
+    pub data: CommandOptionData,
+}
+
+/// Data of a command option.
+///
+/// This type holds settings of a command option used when
+/// parsing the option. It is used in the [`CommandOption`]
+/// trait.
+///
+/// [`CommandOption`]: super::CommandOption
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommandOptionData {
+    /// Restricts the channel choice to specific types. Only for `CHANNEL`
+    /// option type.
+    pub channel_types: Option<Vec<ChannelType>>,
+    /// Maximum value permitted. Only for `INTEGER` and `NUMBER` option types.
+    pub max_value: Option<CommandOptionValue>,
+    /// Minimum value permitted. Only for `INTEGER` and `NUMBER` option types.
+    pub min_value: Option<CommandOptionValue>,
+    /// Maximum value length. Only for `STRING` option type.
+    pub max_length: Option<u16>,
+    /// Minimum value length. Only for `STRING` option type.
+    pub min_length: Option<u16>,
+    /// Format used to parse the value. Only for date and time types.
+    pub format: Option<&'static str>,
+}
+
+impl CommandOptionData {
+    /// Create a new empty [`CommandOptionData`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restrict the channel choice to specific types.
+    pub fn channel_types(mut self, channel_types: Vec<ChannelType>) -> Self {
+        self.channel_types = Some(channel_types);
+
+        self
+    }
+
+    /// Set the maximum value permitted.
+    pub fn max_value(mut self, value: CommandOptionValue) -> Self {
+        self.max_value = Some(value);
+
+        self
+    }
+
+    /// Set the minimum value permitted.
+    pub fn min_value(mut self, value: CommandOptionValue) -> Self {
+        self.min_value = Some(value);
+
+        self
+    }
+
+    /// Set the maximum value length.
+    pub fn max_length(mut self, length: u16) -> Self {
+        self.max_length = Some(length);
+
+        self
+    }
+
+    /// Set the minimum value length.
+    pub fn min_length(mut self, length: u16) -> Self {
+        self.min_length = Some(length);
+
+        self
+    }
+
+    /// Set the format used to parse the value.
+    pub fn format(mut self, format: &'static str) -> Self {
+        self.format = Some(format);
+
+        self
+    }
+
+    /// Check that a string value respects the minimum and maximum length.
+    ///
+    /// The length is counted in characters, like Discord does.
+    pub(crate) fn check_length(&self, value: &str) -> Result<(), ParseOptionErrorType> {
+        let length = value.chars().count();
+        let too_short = self.min_length.is_some_and(|min| length < min.into());
+        let too_long = self.max_length.is_some_and(|max| length > max.into());
+
+        if too_short || too_long {
+            return Err(ParseOptionErrorType::StringLengthOutOfRange(
+                value.to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Builder to convert a [`CreateOptionData`] into a [`CommandOption`].
+pub struct CreateOptionBuilder {
+    kind: CommandOptionType,
+    option: CreateOptionData,
+    choices: Option<Vec<CommandOptionChoice>>,
+    options: Option<Vec<CommandOption>>,
+}
+
+impl CreateOptionBuilder {
+    /// Create a new [`CreateOptionBuilder`].
+    pub fn new(option: CreateOptionData, kind: CommandOptionType) -> Self {
+        Self {
+            kind,
+            option,
+            choices: None,
+            options: None,
+        }
+    }
+
+    /// Set the option choices.
+    pub fn choices(mut self, choices: Vec<CommandOptionChoice>) -> Self {
+        self.choices = Some(choices);
+
+        self
+    }
+
+    /// Set the subcommand options.
+    pub fn options(mut self, options: Vec<CommandOption>) -> Self {
+        self.options = Some(options);
+
+        self
+    }
+
+    /// Build the [`CommandOption`].
+    pub fn build(self) -> CommandOption {
+        CommandOption {
+            autocomplete: Some(self.option.autocomplete),
+            channel_types: self.option.data.channel_types,
+            choices: self.choices,
+            description: self.option.description,
+            description_localizations: self.option.description_localizations,
+            kind: self.kind,
+            max_length: self.option.data.max_length,
+            max_value: self.option.data.max_value,
+            min_length: self.option.data.min_length,
+            min_value: self.option.data.min_value,
+            name: self.option.name,
+            name_localizations: self.option.name_localizations,
+            options: self.options,
+            required: self.option.required,
+        }
+    }
+}
+
+impl CreateOptionData {
+    /// Create a new [`CreateOptionData`] with a name and a description.
+    ///
+    /// The option is not required and does not support autocomplete by
+    /// default.
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            name_localizations: None,
+            description: description.into(),
+            description_localizations: None,
+            required: None,
+            autocomplete: false,
+            data: CommandOptionData::default(),
+        }
+    }
+
+    /// Set the localizations of the option name.
+    pub fn name_localizations(mut self, localizations: NameLocalizations) -> Self {
+        self.name_localizations = Some(localizations.localizations);
+
+        self
+    }
+
+    /// Set the description of the option and its localizations.
+    ///
+    /// This replaces the description set with [`CreateOptionData::new`].
+    pub fn description_localizations(mut self, localizations: DescLocalizations) -> Self {
+        self.description = localizations.fallback;
+        self.description_localizations = Some(localizations.localizations);
+
+        self
+    }
+
+    /// Set whether the option is required.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+
+        self
+    }
+
+    /// Set whether the option supports autocomplete.
+    pub fn autocomplete(mut self, autocomplete: bool) -> Self {
+        self.autocomplete = autocomplete;
+
+        self
+    }
+
+    /// Set the [`CommandOptionData`] of the option.
+    pub fn data(mut self, data: CommandOptionData) -> Self {
+        self.data = data;
+
+        self
+    }
+
+    /// Create a new [`CreateOptionBuilder`].
+    pub fn builder(self, kind: CommandOptionType) -> CreateOptionBuilder {
+        CreateOptionBuilder::new(self, kind)
+    }
+
+    /// Convert the data into a [`CommandOption`].
+    pub fn into_option(self, kind: CommandOptionType) -> CommandOption {
+        self.builder(kind).build()
+    }
+}
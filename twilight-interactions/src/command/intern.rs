@@ -0,0 +1,182 @@
+use std::{
+    borrow::Borrow,
+    collections::HashSet,
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::Deref,
+    sync::{Arc, Mutex, OnceLock},
+};
+
+use twilight_model::application::{
+    command::{CommandOption as CommandOptionModel, CommandOptionType},
+    interaction::{application_command::CommandOptionValue, InteractionDataResolved},
+};
+
+use super::{
+    ext::{CommandOptionData, CreateOptionData},
+    CommandOption, CommandOptionRef, CreateOption,
+};
+use crate::error::ParseOptionErrorType;
+
+/// Default maximum number of symbols stored in a [`SymbolTable`].
+pub const DEFAULT_MAX_SYMBOLS: usize = 4096;
+
+/// Table of interned strings.
+///
+/// Interning a string returns an [`Arc<str>`] shared with all previous
+/// occurrences of the same value, which avoids keeping many copies of the same
+/// string in memory. The table stores at most a fixed number of symbols, since
+/// option values are provided by users: once full, new values are still
+/// returned but not stored.
+///
+/// The [`Interned`] option type uses the [global](Self::global) table.
+///
+/// [`Arc<str>`]: std::sync::Arc
+#[derive(Debug)]
+pub struct SymbolTable {
+    symbols: Mutex<HashSet<Arc<str>>>,
+    max_symbols: usize,
+}
+
+impl SymbolTable {
+    /// Create a new empty [`SymbolTable`] storing at most `max_symbols`
+    /// symbols.
+    pub fn new(max_symbols: usize) -> Self {
+        Self {
+            symbols: Mutex::new(HashSet::new()),
+            max_symbols,
+        }
+    }
+
+    /// Get the global [`SymbolTable`], which stores at most
+    /// [`DEFAULT_MAX_SYMBOLS`] symbols.
+    pub fn global() -> &'static SymbolTable {
+        static GLOBAL: OnceLock<SymbolTable> = OnceLock::new();
+
+        GLOBAL.get_or_init(|| SymbolTable::new(DEFAULT_MAX_SYMBOLS))
+    }
+
+    /// Intern a string.
+    pub fn intern(&self, value: &str) -> Arc<str> {
+        let mut symbols = self.symbols.lock().unwrap();
+
+        if let Some(symbol) = symbols.get(value) {
+            return Arc::clone(symbol);
+        }
+
+        let symbol: Arc<str> = Arc::from(value);
+        if symbols.len() < self.max_symbols {
+            symbols.insert(Arc::clone(&symbol));
+        }
+
+        symbol
+    }
+
+    /// Get the number of stored symbols.
+    pub fn len(&self) -> usize {
+        self.symbols.lock().unwrap().len()
+    }
+
+    /// Returns `true` if the table does not store any symbol.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all stored symbols.
+    ///
+    /// Previously interned values are not affected, but they will no longer
+    /// be shared with values interned after this call.
+    pub fn clear(&self) {
+        self.symbols.lock().unwrap().clear();
+    }
+}
+
+impl Default for SymbolTable {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_SYMBOLS)
+    }
+}
+
+/// A string option interned in the global [`SymbolTable`].
+///
+/// This type creates a `STRING` option, whose value is shared with previously
+/// parsed options with the same value. It is useful for options whose values
+/// come from a small set, like choices that are not modeled as an enum, when
+/// parsed values are kept in memory.
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::command::{CommandModel, CreateCommand, Interned};
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "subscribe", desc = "Subscribe to a feed")]
+/// struct SubscribeCommand {
+///     /// Name of the feed.
+///     feed: Interned,
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interned(pub Arc<str>);
+
+impl Interned {
+    /// Intern a string in the global [`SymbolTable`].
+    pub fn new(value: &str) -> Self {
+        Self(SymbolTable::global().intern(value))
+    }
+
+    /// Get the interned string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for Interned {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Interned {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Interned {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Interned {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.0)
+    }
+}
+
+impl CommandOption for Interned {
+    fn from_option(
+        value: CommandOptionValue,
+        data: CommandOptionData,
+        _resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        <&str>::from_option_ref(&value, data, None).map(Interned::new)
+    }
+}
+
+impl<'a> CommandOptionRef<'a> for Interned {
+    fn from_option_ref(
+        value: &'a CommandOptionValue,
+        data: CommandOptionData,
+        resolved: Option<&'a InteractionDataResolved>,
+    ) -> Result<Self, ParseOptionErrorType> {
+        <&str>::from_option_ref(value, data, resolved).map(Interned::new)
+    }
+}
+
+impl CreateOption for Interned {
+    fn create_option(data: CreateOptionData) -> CommandOptionModel {
+        data.into_option(CommandOptionType::String)
+    }
+}
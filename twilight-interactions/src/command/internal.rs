@@ -7,14 +7,23 @@
 
 use std::collections::HashMap;
 
-use twilight_model::{
-    application::command::{
-        CommandOption, CommandOptionChoice, CommandOptionType, CommandOptionValue,
+use twilight_model::application::{
+    command::{CommandOption, CommandOptionType},
+    interaction::{
+        application_command::{CommandDataOption, CommandOptionValue},
+        Interaction, InteractionDataResolved,
     },
-    channel::ChannelType,
 };
 
-use super::{DescLocalizations, NameLocalizations};
+pub use super::ext::{CommandOptionData, CreateOptionBuilder, CreateOptionData};
+use super::{
+    localization::global_provider, ApplicationCommandData, ChoiceLocalizations, CommandInputData,
+    CommandModel, CommandOptionRef, DescLocalizations, NameLocalizations,
+};
+use crate::{
+    audit::RESERVED_NAMES,
+    error::{ParseError, ParseOptionError, ParseOptionErrorType, ValueError},
+};
 
 /// Internal representation of localization types ([`NameLocalizations`] and
 /// [`DescLocalizations`]).
@@ -47,6 +56,15 @@ impl IntoLocalizationsInternal for (&str, Option<NameLocalizations>) {
     }
 }
 
+impl IntoLocalizationsInternal for (&str, Option<ChoiceLocalizations>) {
+    fn into_localizations(self) -> LocalizationsInternal {
+        LocalizationsInternal {
+            fallback: self.0.to_owned(),
+            localizations: self.1.map(|v| v.localizations),
+        }
+    }
+}
+
 impl IntoLocalizationsInternal for &str {
     fn into_localizations(self) -> LocalizationsInternal {
         LocalizationsInternal {
@@ -56,116 +74,373 @@ impl IntoLocalizationsInternal for &str {
     }
 }
 
-/// Data to create a command option from.
+/// Enable autocomplete on an option if supported by its type.
 ///
-/// This type is used in the [`CreateOption`] trait and contains a subset of
-/// twilight's [`CommandOption`] fields.
+/// This is used by the `autocomplete_all` attribute of the [`CreateCommand`]
+/// derive macro. Autocomplete is only enabled on `STRING`, `INTEGER` and
+/// `NUMBER` options without choices.
 ///
-/// [`CreateOption`]: super::CreateOption
-#[derive(Debug, Clone, PartialEq)]
-pub struct CreateOptionData {
-    /// Name of the option. It must be 32 characters or less.
-    pub name: String,
-    /// Localization dictionary for the option name. Keys must be valid locales.
-    pub name_localizations: Option<HashMap<String, String>>,
-    /// Description of the option. It must be 100 characters or less.
-    pub description: String,
-    /// Localization dictionary for the option description. Keys must be valid
-    /// locales.
-    pub description_localizations: Option<HashMap<String, String>>,
-    /// Whether the option is required to be completed by a user.
-    pub required: Option<bool>,
-    /// Whether the command supports autocomplete. Only for `STRING`, `INTEGER`
-    /// and `NUMBER` option types.
-    pub autocomplete: bool,
-    /// Data of the command option.
-    pub data: CommandOptionData,
-}
-
-/// Data of a command option.
+/// [`CreateCommand`]: super::CreateCommand
+pub fn enable_autocomplete(option: &mut CommandOption) {
+    let eligible = matches!(
+        option.kind,
+        CommandOptionType::String | CommandOptionType::Integer | CommandOptionType::Number
+    );
+
+    if eligible && option.choices.as_ref().map_or(true, Vec::is_empty) {
+        option.autocomplete = Some(true);
+    }
+}
+
+/// Apply the global [`LocalizationProvider`] to a command created by the
+/// [`CreateCommand`] derive macro, if one is set.
 ///
-/// This type holds settings of a command option used when
-/// parsing the option. It is used in the [`CommandOption`]
-/// trait.
+/// [`LocalizationProvider`]: super::LocalizationProvider
+/// [`CreateCommand`]: super::CreateCommand
+pub fn localize_global(mut data: ApplicationCommandData) -> ApplicationCommandData {
+    if let Some(provider) = global_provider() {
+        data.localize(provider);
+    }
+
+    data
+}
+
+/// Check at compile time a command name provided as a constant to the derive
+/// macros.
 ///
-/// [`CommandOption`]: super::CommandOption
-#[derive(Debug, Clone, Default, PartialEq)]
-pub struct CommandOptionData {
-    /// Restricts the channel choice to specific types. Only for `CHANNEL`
-    /// option type.
-    pub channel_types: Option<Vec<ChannelType>>,
-    /// Maximum value permitted. Only for `INTEGER` and `NUMBER` option types.
-    pub max_value: Option<CommandOptionValue>,
-    /// Minimum value permitted. Only for `INTEGER` and `NUMBER` option types.
-    pub min_value: Option<CommandOptionValue>,
-    /// Minimum value length. Only for `STRING` option type.
-    pub max_length: Option<u16>,
-    /// Maximum value length. Only for `STRING` option type.
-    pub min_length: Option<u16>,
-}
-
-/// Builder to convert a [`CreateOptionData`] into a [`CommandOption`].
-pub struct CreateOptionBuilder {
-    kind: CommandOptionType,
-    option: CreateOptionData,
-    choices: Option<Vec<CommandOptionChoice>>,
-    options: Option<Vec<CommandOption>>,
-}
-
-impl CreateOptionBuilder {
-    /// Create a new [`CreateOptionBuilder`].
-    pub fn new(option: CreateOptionData, kind: CommandOptionType) -> Self {
-        Self {
-            kind,
-            option,
-            choices: None,
-            options: None,
+/// Unlike names written as literals, only the length and ASCII characters are
+/// checked.
+pub const fn check_name(name: &str) {
+    let length = char_count(name);
+    if length == 0 || length > 32 {
+        panic!("command name must be between 1 and 32 characters");
+    }
+
+    let bytes = name.as_bytes();
+    let mut index = 0;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        let valid =
+            byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'-' || byte == b'_';
+
+        if byte.is_ascii() && !valid {
+            panic!("command name must only contain lowercase word characters");
+        }
+
+        index += 1;
+    }
+}
+
+/// Check at compile time a command name provided as a constant to the derive
+/// macros with the `deny_reserved_name` attribute.
+///
+/// The name is checked like [`check_name`], and must not be one of the
+/// [`RESERVED_NAMES`].
+///
+/// [`RESERVED_NAMES`]: crate::audit::RESERVED_NAMES
+pub const fn check_unreserved_name(name: &str) {
+    check_name(name);
+
+    let mut index = 0;
+    while index < RESERVED_NAMES.len() {
+        if str_eq(name, RESERVED_NAMES[index]) {
+            panic!("command name is the name of a built-in Discord command");
         }
+
+        index += 1;
     }
+}
 
-    /// Set the option choices.
-    pub fn choices(mut self, choices: Vec<CommandOptionChoice>) -> Self {
-        self.choices = Some(choices);
+/// Check at compile time a `min_length` or `max_length` provided as a
+/// constant to the derive macros.
+pub const fn check_length(length: u16) {
+    assert!(length <= 6000, "length must be less than or equal to 6000");
+}
+
+/// Check at compile time `min_length` and `max_length` bounds when one of
+/// them is provided as a constant to the derive macros.
+pub const fn check_length_range(min: u16, max: u16) {
+    assert!(
+        min <= max,
+        "`min_length` must be less than or equal to `max_length`"
+    );
+}
 
-        self
+/// Check at compile time a command description provided as a constant to the
+/// derive macros.
+pub const fn check_description(description: &str) {
+    let length = char_count(description);
+    if length == 0 || length > 100 {
+        panic!("description must be between 1 and 100 characters");
     }
+}
 
-    /// Set the subcommand options.
-    pub fn options(mut self, options: Vec<CommandOption>) -> Self {
-        self.options = Some(options);
+/// Compute the [`SUBCOMMAND_DEPTH`] of a command from the depth of its
+/// subcommands.
+///
+/// [`SUBCOMMAND_DEPTH`]: super::CreateCommand::SUBCOMMAND_DEPTH
+pub const fn subcommand_depth(subcommands: &[u8]) -> u8 {
+    let mut depth = 0;
+    let mut index = 0;
+
+    while index < subcommands.len() {
+        if subcommands[index] > depth {
+            depth = subcommands[index];
+        }
 
-        self
+        index += 1;
     }
 
-    /// Build the [`CommandOption`].
-    pub fn build(self) -> CommandOption {
-        CommandOption {
-            autocomplete: Some(self.option.autocomplete),
-            channel_types: self.option.data.channel_types,
-            choices: self.choices,
-            description: self.option.description,
-            description_localizations: self.option.description_localizations,
-            kind: self.kind,
-            max_length: self.option.data.max_length,
-            max_value: self.option.data.max_value,
-            min_length: self.option.data.min_length,
-            min_value: self.option.data.min_value,
-            name: self.option.name,
-            name_localizations: self.option.name_localizations,
-            options: self.options,
-            required: self.option.required,
+    depth + 1
+}
+
+/// Check that a subcommand is not a subcommand group containing other
+/// subcommand groups.
+pub const fn check_subcommand_depth(depth: u8) {
+    assert!(
+        depth <= 1,
+        "subcommand groups cannot contain other subcommand groups"
+    );
+}
+
+/// Count the characters of a string in a const context.
+const fn char_count(value: &str) -> usize {
+    let bytes = value.as_bytes();
+    let mut count = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        // Skip UTF-8 continuation bytes
+        if bytes[index] & 0xC0 != 0x80 {
+            count += 1;
         }
+
+        index += 1;
     }
+
+    count
 }
 
-impl CreateOptionData {
-    /// Create a new [`CreateOptionBuilder`].
-    pub fn builder(self, kind: CommandOptionType) -> CreateOptionBuilder {
-        CreateOptionBuilder::new(self, kind)
+/// Compare two strings in a const context.
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut index = 0;
+    while index < a.len() {
+        if a[index] != b[index] {
+            return false;
+        }
+
+        index += 1;
     }
 
-    /// Convert the data into a [`CommandOption`].
-    pub fn into_option(self, kind: CommandOptionType) -> CommandOption {
-        self.builder(kind).build()
+    true
+}
+
+/// Convert the error returned by a `with` function of the [`CommandModel`]
+/// derive macro into a [`ParseOptionErrorType::InvalidValue`].
+///
+/// [`CommandModel`]: super::CommandModel
+pub fn invalid_value(error: impl Into<ValueError>) -> ParseOptionErrorType {
+    ParseOptionErrorType::InvalidValue(error.into())
+}
+
+// The following functions are called by the code generated by the
+// `CommandModel` and `CommandModelRef` derive macros. Keeping the logic here
+// instead of expanding it for each field reduces the amount of generated
+// code, which matters for bots with many commands.
+
+/// Parse the value of an option with its [`CommandOption`] implementation.
+///
+/// [`CommandOption`]: super::CommandOption
+pub fn parse_option<T: super::CommandOption>(
+    name: &str,
+    value: CommandOptionValue,
+    data: CommandOptionData,
+    resolved: Option<&InteractionDataResolved>,
+) -> Result<T, ParseError> {
+    T::from_option(value, data, resolved).map_err(|kind| option_error(name, kind))
+}
+
+/// Parse the value of an option with its [`CommandOptionRef`]
+/// implementation.
+pub fn parse_option_ref<'a, T: CommandOptionRef<'a>>(
+    name: &str,
+    value: &'a CommandOptionValue,
+    data: CommandOptionData,
+    resolved: Option<&'a InteractionDataResolved>,
+) -> Result<T, ParseError> {
+    T::from_option_ref(value, data, resolved).map_err(|kind| option_error(name, kind))
+}
+
+/// Convert the result of a `with` function into the value of an option.
+pub fn parse_option_with<T>(
+    name: &str,
+    result: Result<T, impl Into<ValueError>>,
+) -> Result<T, ParseError> {
+    result.map_err(|error| option_error(name, invalid_value(error)))
+}
+
+/// Convert the result of a `validate` function into a parsing error.
+pub fn validate_option(name: &str, result: Result<(), String>) -> Result<(), ParseError> {
+    result.map_err(|message| option_error(name, ParseOptionErrorType::ValidationFailed(message)))
+}
+
+/// Get the value of a required option, or return an error if it is missing.
+pub fn required_option<T>(name: &str, value: Option<T>) -> Result<T, ParseError> {
+    value.ok_or_else(|| option_error(name, ParseOptionErrorType::RequiredField))
+}
+
+/// Get the value of a required field filled from the interaction, or return
+/// an error if it is not available.
+pub fn required_context<T>(name: &str, value: Option<T>) -> Result<T, ParseError> {
+    value.ok_or_else(|| ParseError::MissingContext(name.to_owned()))
+}
+
+/// Error returned for options not matching any field.
+pub fn unknown_option(name: &str) -> ParseError {
+    option_error(name, ParseOptionErrorType::UnknownField)
+}
+
+/// Take the subcommand of a command, or return an error if the options are
+/// empty.
+pub fn take_subcommand(
+    mut options: Vec<CommandDataOption>,
+) -> Result<CommandDataOption, ParseError> {
+    if options.is_empty() {
+        return Err(ParseError::EmptyOptions);
+    }
+
+    Ok(options.swap_remove(0))
+}
+
+/// Parse a subcommand with its [`CommandModel`] implementation.
+///
+/// Errors of the subcommand are nested in the subcommand name.
+pub fn parse_subcommand<T: CommandModel>(
+    name: &str,
+    value: CommandOptionValue,
+    resolved: Option<&InteractionDataResolved>,
+    interaction: Option<&Interaction>,
+) -> Result<T, ParseError> {
+    let input =
+        CommandInputData::from_option(value, resolved).map_err(|kind| option_error(name, kind))?;
+
+    T::from_interaction_context(input, interaction).map_err(|error| error.nested(name))
+}
+
+/// Error returned for subcommands not matching any variant.
+pub fn unknown_subcommand(name: &str) -> ParseError {
+    option_error(name, ParseOptionErrorType::UnknownSubcommand)
+}
+
+/// Create an error for the option with the provided name.
+fn option_error(name: &str, kind: ParseOptionErrorType) -> ParseError {
+    ParseError::Option(ParseOptionError {
+        field: name.to_owned(),
+        kind,
+        path: Vec::new(),
+    })
+}
+
+/// Parse a command model generated by the [`CommandModel`] derive macro.
+///
+/// When the `tracing` feature is enabled, an event is emitted if parsing
+/// fails. Models parsed by another model, such as subcommands, do not emit
+/// events, so that errors are reported once with the full option path.
+///
+/// [`CommandModel`]: super::CommandModel
+#[inline]
+pub fn parse_command<T>(
+    command: &'static str,
+    parse: impl FnOnce() -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    #[cfg(feature = "tracing")]
+    {
+        tracing::parse_command(command, parse)
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = command;
+        parse()
+    }
+}
+
+#[cfg(feature = "tracing")]
+mod tracing {
+    use std::cell::Cell;
+
+    use crate::error::ParseError;
+
+    thread_local! {
+        /// Number of command models being parsed on the current thread.
+        static DEPTH: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Decrement the parsing depth when dropped, even if parsing panics.
+    struct DepthGuard;
+
+    impl Drop for DepthGuard {
+        fn drop(&mut self) {
+            DEPTH.with(|depth| depth.set(depth.get() - 1));
+        }
+    }
+
+    pub(super) fn parse_command<T>(
+        command: &'static str,
+        parse: impl FnOnce() -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        let outermost = DEPTH.with(|depth| {
+            let current = depth.get();
+            depth.set(current + 1);
+
+            current == 0
+        });
+
+        let guard = DepthGuard;
+        let result = parse();
+        drop(guard);
+
+        if let (true, Err(error)) = (outermost, &result) {
+            let option = match error {
+                ParseError::Option(error) => Some(error.option_path()),
+                _ => None,
+            };
+
+            ::tracing::warn!(
+                command,
+                option = option.as_deref(),
+                kind = ?error.kind(),
+                error = %error,
+                "failed to parse command"
+            );
+        }
+
+        result
+    }
+}
+
+/// Collection of the options not matched by other fields, used by the `rest`
+/// attribute of the [`CommandModel`] derive macro.
+///
+/// [`CommandModel`]: super::CommandModel
+pub trait RestOptions: Default {
+    /// Add an unknown option to the collection.
+    fn push_option(&mut self, option: CommandDataOption);
+}
+
+impl RestOptions for HashMap<String, CommandOptionValue> {
+    fn push_option(&mut self, option: CommandDataOption) {
+        self.insert(option.name, option.value);
+    }
+}
+
+impl RestOptions for Vec<CommandDataOption> {
+    fn push_option(&mut self, option: CommandDataOption) {
+        self.push(option);
     }
 }
@@ -0,0 +1,176 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use twilight_model::application::command::{CommandOption, CommandOptionType};
+
+use super::ApplicationCommandData;
+
+/// Provider of command localizations loaded at runtime.
+///
+/// Localizations set with the `name_localizations` and `desc_localizations`
+/// attributes are functions compiled with the bot. This trait allows loading
+/// localizations from a runtime catalog instead, like a database or a
+/// translation service.
+///
+/// A provider is used either explicitly with
+/// [`CreateCommand::create_command_with`], or globally with
+/// [`set_localization_provider`]. Returned localizations are merged with the
+/// ones set with attributes, replacing existing locales. When both are set,
+/// the explicit provider is applied last and takes precedence.
+///
+/// ## Example
+/// ```
+/// use std::collections::HashMap;
+///
+/// use twilight_interactions::command::{
+///     CreateCommand, LocalizationKey, LocalizationProvider,
+/// };
+///
+/// struct Catalog;
+///
+/// impl LocalizationProvider for Catalog {
+///     fn name_localizations(&self, key: LocalizationKey<'_>) -> Option<HashMap<String, String>> {
+///         match (key.path, key.choice) {
+///             ("hello", None) => Some(HashMap::from([("fr".into(), "bonjour".into())])),
+///             _ => None,
+///         }
+///     }
+///
+///     fn desc_localizations(&self, key: LocalizationKey<'_>) -> Option<HashMap<String, String>> {
+///         match key.path {
+///             "hello" => Some(HashMap::from([("fr".into(), "Dis bonjour".into())])),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// #[derive(CreateCommand)]
+/// #[command(name = "hello", desc = "Say hello")]
+/// struct HelloCommand;
+///
+/// let command = HelloCommand::create_command_with(&Catalog);
+/// assert_eq!(command.name_localizations.unwrap()["fr"], "bonjour");
+/// ```
+///
+/// [`CreateCommand::create_command_with`]: super::CreateCommand::create_command_with
+pub trait LocalizationProvider: Send + Sync {
+    /// Get the localizations of the name of a command, option or choice.
+    fn name_localizations(&self, key: LocalizationKey<'_>) -> Option<HashMap<String, String>>;
+
+    /// Get the localizations of the description of a command or option.
+    fn desc_localizations(&self, key: LocalizationKey<'_>) -> Option<HashMap<String, String>>;
+}
+
+/// Key of a localized item, passed to a [`LocalizationProvider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocalizationKey<'a> {
+    /// Name of the command, followed by the names of the subcommand group,
+    /// subcommand and option if any (e.g. `config set value`).
+    pub path: &'a str,
+    /// Name of the choice, if the key refers to an option choice.
+    pub choice: Option<&'a str>,
+}
+
+static GLOBAL_PROVIDER: OnceLock<Box<dyn LocalizationProvider>> = OnceLock::new();
+
+/// Set the global [`LocalizationProvider`].
+///
+/// The global provider is applied by the implementations of
+/// [`CreateCommand::create_command`] generated by the derive macro, before
+/// any provider passed to [`CreateCommand::create_command_with`]. It can only
+/// be set once: the provider is returned as an error if a global provider is
+/// already set.
+///
+/// [`CreateCommand::create_command`]: super::CreateCommand::create_command
+/// [`CreateCommand::create_command_with`]: super::CreateCommand::create_command_with
+pub fn set_localization_provider<P>(provider: P) -> Result<(), P>
+where
+    P: LocalizationProvider + 'static,
+{
+    let mut provider = Some(provider);
+    GLOBAL_PROVIDER.get_or_init(|| Box::new(provider.take().unwrap()));
+
+    match provider {
+        Some(provider) => Err(provider),
+        None => Ok(()),
+    }
+}
+
+/// Get the global [`LocalizationProvider`], if set.
+pub(super) fn global_provider() -> Option<&'static dyn LocalizationProvider> {
+    GLOBAL_PROVIDER.get().map(|provider| &**provider)
+}
+
+impl ApplicationCommandData {
+    /// Apply the localizations of a [`LocalizationProvider`] to the command
+    /// and all its options.
+    pub fn localize(&mut self, provider: &dyn LocalizationProvider) {
+        let key = LocalizationKey {
+            path: &self.name,
+            choice: None,
+        };
+
+        merge(
+            &mut self.name_localizations,
+            provider.name_localizations(key),
+        );
+        merge(
+            &mut self.description_localizations,
+            provider.desc_localizations(key),
+        );
+
+        for option in &mut self.options {
+            localize_option(&self.name, option, provider);
+        }
+    }
+}
+
+/// Apply the localizations of a provider to an option and its sub-options.
+fn localize_option(parent: &str, option: &mut CommandOption, provider: &dyn LocalizationProvider) {
+    let path = format!("{parent} {}", option.name);
+    let key = LocalizationKey {
+        path: &path,
+        choice: None,
+    };
+
+    merge(
+        &mut option.name_localizations,
+        provider.name_localizations(key),
+    );
+    merge(
+        &mut option.description_localizations,
+        provider.desc_localizations(key),
+    );
+
+    for choice in option.choices.iter_mut().flatten() {
+        let key = LocalizationKey {
+            path: &path,
+            choice: Some(&choice.name),
+        };
+
+        merge(
+            &mut choice.name_localizations,
+            provider.name_localizations(key),
+        );
+    }
+
+    if matches!(
+        option.kind,
+        CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup
+    ) {
+        for sub_option in option.options.iter_mut().flatten() {
+            localize_option(&path, sub_option, provider);
+        }
+    }
+}
+
+/// Merge provided localizations into existing ones.
+fn merge(
+    localizations: &mut Option<HashMap<String, String>>,
+    provided: Option<HashMap<String, String>>,
+) {
+    if let Some(provided) = provided {
+        localizations
+            .get_or_insert_with(HashMap::new)
+            .extend(provided);
+    }
+}
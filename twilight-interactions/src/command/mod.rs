@@ -11,6 +11,10 @@
 //! - Support for subcommands and subcommand groups.
 //! - Command option choices with the [`CommandOption`] and [`CreateOption`]
 //!   traits.
+//! - Routing of received commands with the [`CommandRouter`] trait.
+//! - Autocomplete suggestions with the [`CreateAutocomplete`] trait.
+//! - Parsing without copying string options with the [`CommandModelRef`]
+//!   trait.
 //!
 //! Read the documentation of the [`CommandModel`] and [`CreateCommand`] traits
 //! for more information and the complete list of supported attributes.
@@ -44,10 +48,14 @@
 //!   default command name, and `name_localizations` with the name of a function
 //!   that returns a [`NameLocalizations`] struct.
 //!
-//! - For description, you should only provide the `name_localizations`
+//! - For description, you should only provide the `desc_localizations`
 //!   attribute with the name of a function that returns a [`DescLocalizations`]
 //!   struct.
 //!
+//! - For option choices, you should provide the `name` attribute with the
+//!   default choice name, and `name_localizations` with the name of a function
+//!   that returns a [`ChoiceLocalizations`] struct.
+//!
 //!   These structs take a list of tuples, where the first tuple element is a
 //!   valid [Discord locale] and the second tuple element is the localized
 //!   value.
@@ -76,21 +84,61 @@
 //! }
 //! ```
 //!
+//! Localizations can also be loaded at runtime, for example from a database,
+//! with a [`LocalizationProvider`].
+//!
+//! ## Generic commands
+//! The derive macros support generic types, which can be used to share the
+//! structure of similar commands. Bounds on the type parameters are added
+//! automatically from the types of fields or subcommands.
+//!
+//! ```
+//! use twilight_interactions::command::{CommandModel, CreateCommand};
+//!
+//! #[derive(CommandModel, CreateCommand)]
+//! #[command(name = "set", desc = "Set a value")]
+//! struct SetCommand<T> {
+//!     /// The value to set.
+//!     value: T,
+//! }
+//!
+//! let command = SetCommand::<i64>::create_command();
+//! ```
+//!
 //! ## Supported types
 //! The [`CommandOption`] and [`CreateOption`] traits are implemented for the
 //! following types:
 //!
-//! | Command option type | Provided implementations                       |
-//! |---------------------|------------------------------------------------|
-//! | `STRING`            | [`String`], [`Cow`]                            |
-//! | `INTEGER`           | [`i64`]                                        |
-//! | `NUMBER`            | [`f64`]                                        |
-//! | `BOOLEAN`           | [`bool`]                                       |
-//! | `USER`              | [`ResolvedUser`], [`User`], [`Id<UserMarker>`] |
-//! | `CHANNEL`           | [`InteractionChannel`], [`Id<ChannelMarker>`]  |
-//! | `ROLE`              | [`Role`], [`Id<RoleMarker>`]                   |
-//! | `MENTIONABLE`       | [`ResolvedMentionable`], [`Id<GenericMarker>`] |
-//! | `ATTACHMENT`        | [`Attachment`], [`Id<AttachmentMarker>`]       |
+//! | Command option type | Provided implementations                                           |
+//! |---------------------|--------------------------------------------------------------------|
+//! | `STRING`            | [`String`], [`Cow`], [`Parsed<T>`], [`Interned`]                   |
+//! | `INTEGER`           | [`i64`], [`NonZeroI64`], [`NonZeroU64`], `i8` to `u64`             |
+//! | `NUMBER`            | [`f64`]                                                            |
+//! | `BOOLEAN`           | [`bool`]                                                           |
+//! | `USER`              | [`ResolvedUser`], [`ResolvedMember`], [`User`], [`Id<UserMarker>`] |
+//! | `CHANNEL`           | [`ResolvedChannel`], [`InteractionChannel`], [`Id<ChannelMarker>`] |
+//! | `ROLE`              | [`Role`], [`Id<RoleMarker>`]                                       |
+//! | `MENTIONABLE`       | [`ResolvedMentionable`], [`Id<GenericMarker>`]                     |
+//! | `ATTACHMENT`        | [`Attachment`], [`Id<AttachmentMarker>`]                           |
+//!
+//! Immutable strings stored as `Box<str>`, `Rc<str>` or `Arc<str>` are also
+//! supported as `STRING` options.
+//!
+//! When the `humantime` feature is enabled, [`Duration`] is also supported as a
+//! `STRING` option parsed from a human-readable duration like `2h 30m`.
+//!
+//! When the `chrono` or `time` features are enabled, [`DateTime<Utc>`] and
+//! [`OffsetDateTime`] are supported as `STRING` options. Values are parsed as
+//! RFC 3339 timestamps, or with the format set with the `format` attribute.
+//! Values without timezone offset are assumed to be in UTC.
+//!
+//! When the `url` feature is enabled, [`Url`] is supported as a `STRING`
+//! option. Invalid URLs are rejected with a [`ParseOptionErrorType::InvalidValue`]
+//! error.
+//!
+//! Integer types other than `i64` restrict the `min_value` and `max_value` of
+//! the option to their range. Values that do not fit in the type are rejected
+//! with a [`ParseOptionErrorType::OutOfRange`] error.
 //!
 //! Option choices are supported for the `STRING`, `INTEGER` and `NUMBER` option
 //! types. See the [`CommandOption`] and [`CreateOption`] traits documentation
@@ -99,6 +147,17 @@
 //! [`from_interaction`]: CommandModel::from_interaction
 //!
 //! [`Cow`]: std::borrow::Cow
+//! [`Duration`]: std::time::Duration
+//! [`DateTime<Utc>`]: https://docs.rs/chrono/0.4/chrono/struct.DateTime.html
+//! [`OffsetDateTime`]: https://docs.rs/time/0.3/time/struct.OffsetDateTime.html
+//! [`Parsed<T>`]: Parsed
+//! [`Url`]: https://docs.rs/url/2/url/struct.Url.html
+//! [`ParseOptionErrorType::InvalidValue`]:
+//!     crate::error::ParseOptionErrorType::InvalidValue
+//! [`ParseOptionErrorType::OutOfRange`]:
+//!     crate::error::ParseOptionErrorType::OutOfRange
+//! [`NonZeroI64`]: std::num::NonZeroI64
+//! [`NonZeroU64`]: std::num::NonZeroU64
 //! [`User`]: twilight_model::user::User
 //! [`Id<UserMarker>`]: twilight_model::id::Id
 //! [`InteractionChannel`]:
@@ -111,18 +170,40 @@
 //! [`Id<AttachmentMarker>`]: twilight_model::id::Id
 
 mod command_model;
+mod command_model_ref;
+mod command_router;
+mod context;
+mod create_autocomplete;
 mod create_command;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod datetime;
+pub mod ext;
+mod intern;
+mod localization;
+mod policy;
+pub(crate) mod tree;
 
 #[doc(hidden)]
 pub mod internal;
 
 pub use command_model::{
-    AutocompleteValue, CommandInputData, CommandModel, CommandOption, ResolvedMentionable,
-    ResolvedUser,
+    AutocompleteValue, CommandInputData, CommandModel, CommandOption, FocusedOption, Parsed,
+    ResolvedChannel, ResolvedMember, ResolvedMentionable, ResolvedThread, ResolvedUser,
 };
+pub use command_model_ref::{CommandModelRef, CommandOptionRef};
+pub use command_router::CommandRouter;
+pub use context::ContextField;
+pub use create_autocomplete::CreateAutocomplete;
 pub use create_command::{
-    ApplicationCommandData, CreateCommand, CreateOption, DescLocalizations, NameLocalizations,
+    ApplicationCommandData, ChoiceLocalizations, ChoiceLocalizationsBuilder, CreateCommand,
+    CreateOption, DescLocalizations, NameLocalizations,
 };
+pub use intern::{Interned, SymbolTable, DEFAULT_MAX_SYMBOLS};
+pub use localization::{set_localization_provider, LocalizationKey, LocalizationProvider};
+pub use policy::CommandPolicy;
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
-pub use twilight_interactions_derive::{CommandModel, CommandOption, CreateCommand, CreateOption};
+pub use twilight_interactions_derive::{
+    command_handler, CommandModel, CommandModelRef, CommandOption, CommandRouter,
+    CreateAutocomplete, CreateCommand, CreateOption, SlashCommand,
+};
@@ -0,0 +1,59 @@
+use super::ApplicationCommandData;
+
+/// Policy applied to the settings of all commands.
+///
+/// A policy can override or fill the settings of commands, such as the
+/// contexts where they can be used or their default permissions, without
+/// setting the corresponding attribute on every command. Policies are applied
+/// to registered commands with [`CommandRegistry::policy`], or before
+/// comparing commands with [`sync::diff_with`].
+///
+/// This trait is implemented for closures taking a mutable reference to an
+/// [`ApplicationCommandData`].
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::{
+///     command::{ApplicationCommandData, CreateCommand},
+///     registry::CommandRegistry,
+/// };
+/// use twilight_model::{application::interaction::InteractionContextType, guild::Permissions};
+/// # use twilight_interactions::command::CommandModel;
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "purge", desc = "Delete recent messages")]
+/// struct PurgeCommand;
+///
+/// fn moderation_policy(command: &mut ApplicationCommandData) {
+///     command.contexts = Some(vec![InteractionContextType::Guild]);
+///
+///     // Moderation commands require the permission to manage messages
+///     if command.name == "purge" && command.default_member_permissions.is_none() {
+///         command.default_member_permissions = Some(Permissions::MANAGE_MESSAGES);
+///     }
+/// }
+///
+/// let registry = CommandRegistry::new()
+///     .register(|_: PurgeCommand, _interaction| async {})
+///     .policy(moderation_policy);
+///
+/// let command = registry.command_data().next().unwrap();
+/// assert_eq!(command.contexts, Some(vec![InteractionContextType::Guild]));
+/// assert_eq!(command.default_member_permissions, Some(Permissions::MANAGE_MESSAGES));
+/// ```
+///
+/// [`CommandRegistry::policy`]: crate::registry::CommandRegistry::policy
+/// [`sync::diff_with`]: crate::sync::diff_with
+pub trait CommandPolicy: Send + Sync {
+    /// Apply the policy to a command.
+    fn apply(&self, command: &mut ApplicationCommandData);
+}
+
+impl<F> CommandPolicy for F
+where
+    F: Fn(&mut ApplicationCommandData) + Send + Sync,
+{
+    fn apply(&self, command: &mut ApplicationCommandData) {
+        self(command)
+    }
+}
@@ -0,0 +1,173 @@
+//! Tree rendering of [`ApplicationCommandData`].
+
+use std::fmt::{self, Display, Formatter, Write};
+
+use twilight_model::application::command::{
+    CommandOption, CommandOptionChoiceValue, CommandOptionType, CommandOptionValue,
+};
+
+use super::ApplicationCommandData;
+
+/// Render the command as an indented tree.
+///
+/// Each line contains the name and description of the command, a subcommand
+/// or an option, along with its type and constraints. This is intended for
+/// debugging and reviewing changes in logs.
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::command::CreateCommand;
+///
+/// #[derive(CreateCommand)]
+/// #[command(name = "roll", desc = "Roll a dice")]
+/// struct RollCommand {
+///     /// Number of faces
+///     #[command(min_value = 2, max_value = 100)]
+///     faces: i64,
+///     /// Whether to show the result to everyone
+///     public: Option<bool>,
+/// }
+///
+/// assert_eq!(
+///     RollCommand::create_command().to_string(),
+///     "/roll: Roll a dice
+/// ├── faces (integer, required, 2..=100): Number of faces
+/// └── public (boolean): Whether to show the result to everyone"
+/// );
+/// ```
+impl Display for ApplicationCommandData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "/{}", self.name)?;
+
+        let mut details = Vec::new();
+        if self.nsfw == Some(true) {
+            details.push(String::from("nsfw"));
+        }
+        if let Some(permissions) = self.default_member_permissions {
+            let names: Vec<_> = permissions.iter_names().map(|(name, _)| name).collect();
+            if names.is_empty() {
+                details.push(String::from("permissions: none"));
+            } else {
+                details.push(format!("permissions: {}", names.join(" | ")));
+            }
+        }
+        write_details(f, &details)?;
+        write!(f, ": {}", self.description)?;
+
+        write_options(f, &self.options, "")
+    }
+}
+
+/// Write options as children of the previous line.
+fn write_options(f: &mut Formatter<'_>, options: &[CommandOption], prefix: &str) -> fmt::Result {
+    for (index, option) in options.iter().enumerate() {
+        let last = index + 1 == options.len();
+        let (branch, indent) = if last {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+
+        write!(f, "\n{prefix}{branch}{}", option.name)?;
+        write_details(f, &option_details(option))?;
+        write!(f, ": {}", option.description)?;
+
+        if let Some(options) = &option.options {
+            write_options(f, options, &format!("{prefix}{indent}"))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Write details between parentheses, if any.
+fn write_details(f: &mut Formatter<'_>, details: &[String]) -> fmt::Result {
+    if details.is_empty() {
+        return Ok(());
+    }
+
+    write!(f, " ({})", details.join(", "))
+}
+
+/// Type and constraints of an option.
+fn option_details(option: &CommandOption) -> Vec<String> {
+    let mut details = vec![String::from(option_type(option.kind))];
+
+    if option.required == Some(true) {
+        details.push(String::from("required"));
+    }
+    if option.autocomplete == Some(true) {
+        details.push(String::from("autocomplete"));
+    }
+
+    let value = |value: &CommandOptionValue| match value {
+        CommandOptionValue::Integer(value) => value.to_string(),
+        CommandOptionValue::Number(value) => value.to_string(),
+    };
+    if let Some(range) = range(option.min_value.as_ref(), option.max_value.as_ref(), value) {
+        details.push(range);
+    }
+
+    if let Some(range) = range(option.min_length, option.max_length, |length| {
+        length.to_string()
+    }) {
+        details.push(format!("length {range}"));
+    }
+
+    if let Some(channel_types) = option.channel_types.as_deref().filter(|c| !c.is_empty()) {
+        let channel_types: Vec<_> = channel_types.iter().map(|kind| kind.name()).collect();
+        details.push(format!("channels: {}", channel_types.join(" | ")));
+    }
+
+    if let Some(choices) = option.choices.as_deref().filter(|c| !c.is_empty()) {
+        let mut output = String::from("choices: ");
+        for (index, choice) in choices.iter().enumerate() {
+            if index > 0 {
+                output.push_str(" | ");
+            }
+
+            let _ = match &choice.value {
+                CommandOptionChoiceValue::String(value) => {
+                    write!(output, "{} = {value:?}", choice.name)
+                }
+                CommandOptionChoiceValue::Integer(value) => {
+                    write!(output, "{} = {value}", choice.name)
+                }
+                CommandOptionChoiceValue::Number(value) => {
+                    write!(output, "{} = {value}", choice.name)
+                }
+            };
+        }
+        details.push(output);
+    }
+
+    details
+}
+
+/// Format a range of values, if any bound is set.
+fn range<T>(min: Option<T>, max: Option<T>, format: impl Fn(T) -> String) -> Option<String> {
+    match (min.map(&format), max.map(&format)) {
+        (Some(min), Some(max)) => Some(format!("{min}..={max}")),
+        (Some(min), None) => Some(format!(">= {min}")),
+        (None, Some(max)) => Some(format!("<= {max}")),
+        (None, None) => None,
+    }
+}
+
+/// Name of an option type.
+pub(crate) fn option_type(kind: CommandOptionType) -> &'static str {
+    match kind {
+        CommandOptionType::SubCommand => "subcommand",
+        CommandOptionType::SubCommandGroup => "subcommand group",
+        CommandOptionType::String => "string",
+        CommandOptionType::Integer => "integer",
+        CommandOptionType::Boolean => "boolean",
+        CommandOptionType::User => "user",
+        CommandOptionType::Channel => "channel",
+        CommandOptionType::Role => "role",
+        CommandOptionType::Mentionable => "mentionable",
+        CommandOptionType::Number => "number",
+        CommandOptionType::Attachment => "attachment",
+        _ => "unknown",
+    }
+}
@@ -0,0 +1,323 @@
+use twilight_model::id::Id;
+
+use super::{internal, SelectOption};
+use crate::error::CustomIdError;
+
+/// Maximum length of the custom id of a component.
+pub const CUSTOM_ID_LENGTH: usize = 100;
+
+/// Store state in the custom id of a component.
+///
+/// This trait encodes the fields of a type into a compact custom id made of
+/// a prefix followed by the fields separated by `:`, and parses the custom id
+/// received in component interactions back into the type. A derive macro is
+/// provided to automatically implement the trait on structs whose fields
+/// implement [`CustomIdField`].
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::component::CustomId;
+/// use twilight_model::id::{marker::UserMarker, Id};
+///
+/// #[derive(CustomId, Debug, PartialEq, Eq)]
+/// #[custom_id(prefix = "vote")]
+/// struct VoteButton {
+///     poll: u32,
+///     user: Id<UserMarker>,
+///     choice: Option<String>,
+/// }
+///
+/// let button = VoteButton {
+///     poll: 42,
+///     user: Id::new(123),
+///     choice: Some("yes".into()),
+/// };
+/// let custom_id = button.to_custom_id().unwrap();
+///
+/// assert_eq!(custom_id, "vote:42:123:yes");
+/// assert!(VoteButton::matches(&custom_id));
+/// assert_eq!(VoteButton::from_custom_id(&custom_id), Ok(button));
+/// ```
+///
+/// ## Macro attributes
+/// The macro provides a `#[custom_id]` attribute to configure the custom id.
+///
+/// | Attribute  | Type  | Location | Description                                        |
+/// |------------|-------|----------|----------------------------------------------------|
+/// | `prefix`   | `str` | Type     | Prefix identifying the type (required).[^prefix]   |
+/// | `encoding` | `str` | Type     | Encoding of the fields (`text` or `compact`).[^enc] |
+///
+/// [^prefix]: The prefix must not be empty or contain `:` and `\` characters.
+///
+/// [^enc]: Fields are encoded as text by default. The `compact` encoding
+/// writes the fields in a binary format encoded in base64, which stores
+/// larger state within the length limit, such as multiple IDs, but is not
+/// human-readable.
+///
+/// ## Length limit
+/// Custom ids are limited to [`CUSTOM_ID_LENGTH`] characters. The derive
+/// macro fails to compile if the custom id of the type can exceed this limit
+/// in the selected encoding when the maximum length of all fields is known,
+/// like for integers and IDs. Otherwise, [`to_custom_id`] returns an error if the encoded custom id
+/// is too long.
+///
+/// Generic types are not supported by the derive macro.
+///
+/// [`to_custom_id`]: Self::to_custom_id
+pub trait CustomId: Sized {
+    /// Prefix identifying the type in custom ids.
+    const PREFIX: &'static str;
+
+    /// Encode the value into a custom id.
+    ///
+    /// An error is returned if the custom id exceeds [`CUSTOM_ID_LENGTH`].
+    fn to_custom_id(&self) -> Result<String, CustomIdError>;
+
+    /// Parse a custom id.
+    fn from_custom_id(custom_id: &str) -> Result<Self, CustomIdError>;
+
+    /// Whether a custom id has the prefix of this type.
+    ///
+    /// This can be used to route component interactions to the right type.
+    fn matches(custom_id: &str) -> bool {
+        internal::matches_prefix(Self::PREFIX, custom_id)
+    }
+}
+
+/// Field of a type implementing [`CustomId`].
+///
+/// This trait is implemented for integers, [`bool`], [`String`], [`Id`],
+/// [`SelectOption`] types, and [`Option`] of these types. Characters used as
+/// separator are escaped, so fields can contain any character.
+///
+/// With the text encoding, `None` is encoded as an empty field, which means
+/// that `Some` empty strings are parsed as `None`.
+///
+/// The compact encoding writes the text representation of the field prefixed
+/// by its length by default. Integers, [`bool`], [`Id`] and [`Option`] use a
+/// dedicated binary representation.
+pub trait CustomIdField: Sized {
+    /// Maximum length of the field in the text encoding, if known.
+    ///
+    /// This is used to check the length of custom ids at compile time. It
+    /// must include the escape characters of the field, if any.
+    const MAX_LENGTH: Option<usize> = None;
+
+    /// Maximum length of the field in the compact encoding, in bytes, if
+    /// known.
+    const MAX_BYTES: Option<usize> = None;
+
+    /// Write the field into the custom id.
+    fn write_field(&self, output: &mut String);
+
+    /// Parse the field from the custom id.
+    ///
+    /// Returns `None` if the field is invalid.
+    fn read_field(value: &str) -> Option<Self>;
+
+    /// Write the field in the compact encoding.
+    fn write_bytes(&self, output: &mut Vec<u8>) {
+        let mut value = String::new();
+        self.write_field(&mut value);
+
+        write_varint(output, value.len() as u64);
+        output.extend_from_slice(value.as_bytes());
+    }
+
+    /// Parse the field from the compact encoding, advancing the input.
+    ///
+    /// Returns `None` if the field is invalid.
+    fn read_bytes(input: &mut &[u8]) -> Option<Self> {
+        let length = usize::try_from(read_varint(input)?).ok()?;
+        if input.len() < length {
+            return None;
+        }
+
+        let (value, rest) = input.split_at(length);
+        *input = rest;
+
+        Self::read_field(std::str::from_utf8(value).ok()?)
+    }
+}
+
+impl CustomIdField for String {
+    fn write_field(&self, output: &mut String) {
+        output.push_str(self);
+    }
+
+    fn read_field(value: &str) -> Option<Self> {
+        Some(value.to_owned())
+    }
+}
+
+impl CustomIdField for bool {
+    const MAX_LENGTH: Option<usize> = Some(1);
+    const MAX_BYTES: Option<usize> = Some(1);
+
+    fn write_field(&self, output: &mut String) {
+        output.push(if *self { '1' } else { '0' });
+    }
+
+    fn read_field(value: &str) -> Option<Self> {
+        match value {
+            "1" => Some(true),
+            "0" => Some(false),
+            _ => None,
+        }
+    }
+
+    fn write_bytes(&self, output: &mut Vec<u8>) {
+        output.push(u8::from(*self));
+    }
+
+    fn read_bytes(input: &mut &[u8]) -> Option<Self> {
+        match u8::read_bytes(input)? {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl<T> CustomIdField for Id<T> {
+    const MAX_LENGTH: Option<usize> = <u64 as CustomIdField>::MAX_LENGTH;
+    const MAX_BYTES: Option<usize> = <u64 as CustomIdField>::MAX_BYTES;
+
+    fn write_field(&self, output: &mut String) {
+        self.get().write_field(output);
+    }
+
+    fn read_field(value: &str) -> Option<Self> {
+        value.parse().ok()
+    }
+
+    fn write_bytes(&self, output: &mut Vec<u8>) {
+        self.get().write_bytes(output);
+    }
+
+    fn read_bytes(input: &mut &[u8]) -> Option<Self> {
+        Id::new_checked(u64::read_bytes(input)?)
+    }
+}
+
+impl<T: CustomIdField> CustomIdField for Option<T> {
+    const MAX_LENGTH: Option<usize> = T::MAX_LENGTH;
+    const MAX_BYTES: Option<usize> = match T::MAX_BYTES {
+        Some(length) => Some(length + 1),
+        None => None,
+    };
+
+    fn write_field(&self, output: &mut String) {
+        if let Some(value) = self {
+            value.write_field(output);
+        }
+    }
+
+    fn read_field(value: &str) -> Option<Self> {
+        if value.is_empty() {
+            return Some(None);
+        }
+
+        T::read_field(value).map(Some)
+    }
+
+    fn write_bytes(&self, output: &mut Vec<u8>) {
+        match self {
+            Some(value) => {
+                output.push(1);
+                value.write_bytes(output);
+            }
+            None => output.push(0),
+        }
+    }
+
+    fn read_bytes(input: &mut &[u8]) -> Option<Self> {
+        match u8::read_bytes(input)? {
+            0 => Some(None),
+            1 => T::read_bytes(input).map(Some),
+            _ => None,
+        }
+    }
+}
+
+impl<T: SelectOption> CustomIdField for T {
+    fn write_field(&self, output: &mut String) {
+        output.push_str(self.select_value());
+    }
+
+    fn read_field(value: &str) -> Option<Self> {
+        T::from_value(value)
+    }
+}
+
+macro_rules! impl_integer_field {
+    ($($ty:ty),* ; $encode:expr, $decode:expr) => {
+        $(
+            impl CustomIdField for $ty {
+                const MAX_LENGTH: Option<usize> =
+                    Some(<$ty>::MAX.ilog10() as usize + 1 + (<$ty>::MIN != 0) as usize);
+                const MAX_BYTES: Option<usize> = Some((<$ty>::BITS as usize).div_ceil(7));
+
+                fn write_field(&self, output: &mut String) {
+                    output.push_str(&self.to_string());
+                }
+
+                fn read_field(value: &str) -> Option<Self> {
+                    value.parse().ok()
+                }
+
+                fn write_bytes(&self, output: &mut Vec<u8>) {
+                    write_varint(output, $encode(*self as i128));
+                }
+
+                fn read_bytes(input: &mut &[u8]) -> Option<Self> {
+                    <$ty>::try_from($decode(read_varint(input)?)).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_field!(u8, u16, u32, u64, usize; |value| value as u64, i128::from);
+impl_integer_field!(i8, i16, i32, i64, isize; zigzag_encode, zigzag_decode);
+
+/// Map signed integers to unsigned integers so that small negative values
+/// have a short varint representation.
+fn zigzag_encode(value: i128) -> u64 {
+    let value = value as i64;
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// Reverse of [`zigzag_encode`].
+fn zigzag_decode(value: u64) -> i128 {
+    i128::from((value >> 1) as i64 ^ -((value & 1) as i64))
+}
+
+/// Write an unsigned LEB128 integer.
+fn write_varint(output: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        output.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    output.push(value as u8);
+}
+
+/// Read an unsigned LEB128 integer, advancing the input.
+fn read_varint(input: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+
+    for (index, byte) in input.iter().enumerate() {
+        let shift = index * 7;
+        if shift >= 64 {
+            return None;
+        }
+
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            *input = &input[index + 1..];
+            return Some(value);
+        }
+    }
+
+    None
+}
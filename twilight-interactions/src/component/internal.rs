@@ -0,0 +1,318 @@
+//! Internal functions used by component traits.
+//!
+//! This module contains functions used by implementations generated by the
+//! derive macros of the [`component`] module.
+//!
+//! [`component`]: crate::component
+
+use super::{CustomIdField, SelectValue, CUSTOM_ID_LENGTH};
+use crate::error::{CustomIdError, ParseSelectError, ParseSelectMenuError};
+
+/// Separator between the prefix and fields of a custom id.
+const SEPARATOR: char = ':';
+
+/// Escape character of custom id fields.
+const ESCAPE: char = '\\';
+
+/// Parse exactly one selected value.
+pub fn parse_one<T: SelectValue>(values: &[String]) -> Result<T, ParseSelectMenuError> {
+    parse_optional(values)?.ok_or(ParseSelectMenuError::MissingValue)
+}
+
+/// Parse at most one selected value.
+pub fn parse_optional<T: SelectValue>(
+    values: &[String],
+) -> Result<Option<T>, ParseSelectMenuError> {
+    match values {
+        [] => Ok(None),
+        [value] => parse_value(value).map(Some),
+        _ => Err(ParseSelectMenuError::TooManyValues {
+            found: values.len(),
+        }),
+    }
+}
+
+/// Parse all selected values.
+pub fn parse_many<T: SelectValue>(values: &[String]) -> Result<Vec<T>, ParseSelectMenuError> {
+    values.iter().map(|value| parse_value(value)).collect()
+}
+
+fn parse_value<T: SelectValue>(value: &str) -> Result<T, ParseSelectMenuError> {
+    T::from_select_value(value).ok_or_else(|| {
+        ParseSelectMenuError::InvalidValue(ParseSelectError {
+            value: value.to_owned(),
+        })
+    })
+}
+
+/// Writer encoding the fields of a custom id.
+pub struct CustomIdWriter {
+    output: String,
+    buffer: String,
+}
+
+impl CustomIdWriter {
+    /// Create a new writer with the prefix of the custom id.
+    pub fn new(prefix: &str) -> Self {
+        Self {
+            output: prefix.to_owned(),
+            buffer: String::new(),
+        }
+    }
+
+    /// Write a field, escaping separator characters.
+    pub fn field<T: CustomIdField>(&mut self, value: &T) {
+        self.buffer.clear();
+        value.write_field(&mut self.buffer);
+
+        self.output.push(SEPARATOR);
+        for c in self.buffer.chars() {
+            if c == SEPARATOR || c == ESCAPE {
+                self.output.push(ESCAPE);
+            }
+            self.output.push(c);
+        }
+    }
+
+    /// Get the encoded custom id, checking its length.
+    pub fn finish(self) -> Result<String, CustomIdError> {
+        let length = self.output.chars().count();
+        if length > CUSTOM_ID_LENGTH {
+            return Err(CustomIdError::TooLong { length });
+        }
+
+        Ok(self.output)
+    }
+}
+
+/// Reader parsing the fields of a custom id.
+pub struct CustomIdReader {
+    fields: std::vec::IntoIter<String>,
+    index: usize,
+}
+
+impl CustomIdReader {
+    /// Split a custom id into fields, checking its prefix and the number of
+    /// fields.
+    pub fn new(prefix: &str, custom_id: &str, count: usize) -> Result<Self, CustomIdError> {
+        if !matches_prefix(prefix, custom_id) {
+            return Err(CustomIdError::PrefixMismatch);
+        }
+
+        let fields = split_fields(&custom_id[prefix.len()..]);
+        if fields.len() != count {
+            return Err(CustomIdError::FieldCount {
+                expected: count,
+                found: fields.len(),
+            });
+        }
+
+        Ok(Self {
+            fields: fields.into_iter(),
+            index: 0,
+        })
+    }
+
+    /// Parse the next field.
+    pub fn field<T: CustomIdField>(&mut self) -> Result<T, CustomIdError> {
+        let value = self.fields.next().unwrap_or_default();
+        let index = self.index;
+        self.index += 1;
+
+        T::read_field(&value).ok_or(CustomIdError::InvalidField { index, value })
+    }
+}
+
+/// Writer encoding the fields of a custom id with the compact encoding.
+pub struct CompactWriter {
+    prefix: String,
+    bytes: Vec<u8>,
+}
+
+impl CompactWriter {
+    /// Create a new writer with the prefix of the custom id.
+    pub fn new(prefix: &str) -> Self {
+        Self {
+            prefix: prefix.to_owned(),
+            bytes: Vec::new(),
+        }
+    }
+
+    /// Write a field.
+    pub fn field<T: CustomIdField>(&mut self, value: &T) {
+        value.write_bytes(&mut self.bytes);
+    }
+
+    /// Get the encoded custom id, checking its length.
+    pub fn finish(self) -> Result<String, CustomIdError> {
+        let mut output = self.prefix;
+        if !self.bytes.is_empty() {
+            output.push(SEPARATOR);
+            encode_base64(&self.bytes, &mut output);
+        }
+
+        let length = output.chars().count();
+        if length > CUSTOM_ID_LENGTH {
+            return Err(CustomIdError::TooLong { length });
+        }
+
+        Ok(output)
+    }
+}
+
+/// Reader parsing the fields of a custom id with the compact encoding.
+pub struct CompactReader {
+    bytes: Vec<u8>,
+    position: usize,
+}
+
+impl CompactReader {
+    /// Decode a custom id, checking its prefix.
+    pub fn new(prefix: &str, custom_id: &str) -> Result<Self, CustomIdError> {
+        if !matches_prefix(prefix, custom_id) {
+            return Err(CustomIdError::PrefixMismatch);
+        }
+
+        let encoded = custom_id[prefix.len()..]
+            .strip_prefix(SEPARATOR)
+            .unwrap_or_default();
+        let bytes = decode_base64(encoded).ok_or(CustomIdError::InvalidEncoding)?;
+
+        Ok(Self { bytes, position: 0 })
+    }
+
+    /// Parse the next field.
+    pub fn field<T: CustomIdField>(&mut self) -> Result<T, CustomIdError> {
+        let mut input = &self.bytes[self.position..];
+        let value = T::read_bytes(&mut input).ok_or(CustomIdError::InvalidEncoding)?;
+        self.position = self.bytes.len() - input.len();
+
+        Ok(value)
+    }
+
+    /// Check that all the fields have been read.
+    pub fn finish(self) -> Result<(), CustomIdError> {
+        if self.position != self.bytes.len() {
+            return Err(CustomIdError::InvalidEncoding);
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether a custom id starts with a prefix followed by a separator.
+pub fn matches_prefix(prefix: &str, custom_id: &str) -> bool {
+    match custom_id.strip_prefix(prefix) {
+        Some(rest) => rest.is_empty() || rest.starts_with(SEPARATOR),
+        None => false,
+    }
+}
+
+/// Compute the maximum length of a custom id, if the maximum length of all
+/// fields is known.
+pub const fn max_length(prefix: usize, fields: &[Option<usize>]) -> Option<usize> {
+    let mut length = prefix;
+    let mut index = 0;
+
+    while index < fields.len() {
+        match fields[index] {
+            Some(field) => length += field + 1,
+            None => return None,
+        }
+        index += 1;
+    }
+
+    Some(length)
+}
+
+/// Compute the maximum length of a custom id with the compact encoding, if
+/// the maximum length of all fields is known.
+pub const fn max_compact_length(prefix: usize, fields: &[Option<usize>]) -> Option<usize> {
+    let mut bytes = 0;
+    let mut index = 0;
+
+    while index < fields.len() {
+        match fields[index] {
+            Some(field) => bytes += field,
+            None => return None,
+        }
+        index += 1;
+    }
+
+    match bytes {
+        0 => Some(prefix),
+        _ => Some(prefix + 1 + (bytes * 4).div_ceil(3)),
+    }
+}
+
+/// Split the fields following the prefix of a custom id, removing escape
+/// characters.
+fn split_fields(value: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut chars = value.chars();
+
+    // The value is either empty or starts with a separator
+    if chars.next().is_none() {
+        return fields;
+    }
+
+    let mut field = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            ESCAPE => field.extend(chars.next()),
+            SEPARATOR => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+
+    fields
+}
+
+/// Alphabet of the URL-safe base64 encoding, which does not contain the
+/// separator character.
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encode bytes in base64 without padding.
+fn encode_base64(bytes: &[u8], output: &mut String) {
+    for chunk in bytes.chunks(3) {
+        let buffer = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |buffer, (index, byte)| {
+                buffer | u32::from(*byte) << (16 - index * 8)
+            });
+
+        for index in 0..=chunk.len() {
+            let sextet = (buffer >> (18 - index * 6)) & 0x3f;
+            output.push(char::from(BASE64_ALPHABET[sextet as usize]));
+        }
+    }
+}
+
+/// Decode base64 without padding.
+///
+/// Returns `None` if the input contains invalid characters or has an
+/// invalid length.
+fn decode_base64(input: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(input.len() * 3 / 4);
+
+    for chunk in input.as_bytes().chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+
+        let mut buffer = 0u32;
+        for (index, c) in chunk.iter().enumerate() {
+            let sextet = BASE64_ALPHABET.iter().position(|a| a == c)? as u32;
+            buffer |= sextet << (18 - index * 6);
+        }
+
+        for index in 0..chunk.len() - 1 {
+            bytes.push((buffer >> (16 - index * 8)) as u8);
+        }
+    }
+
+    Some(bytes)
+}
@@ -0,0 +1,32 @@
+//! Message component helpers.
+//!
+//! The [`SelectOption`] trait maps an enum to the options of a string select
+//! menu, and parses the values selected by the user back into the enum. It
+//! is the equivalent of the [`CreateOption`] and [`CommandOption`] traits for
+//! message components.
+//!
+//! The [`SelectMenuModel`] trait parses the values of a select menu
+//! interaction into a typed struct, like the [`CommandModel`] trait does for
+//! slash commands. Values are parsed with the [`SelectValue`] trait, which is
+//! implemented for [`SelectOption`] types and the IDs received from user,
+//! role, channel and mentionable select menus.
+//!
+//! The [`CustomId`] trait stores the state of a component in its custom id,
+//! and parses it back when the component is used.
+//!
+//! [`CommandModel`]: crate::command::CommandModel
+//! [`CreateOption`]: crate::command::CreateOption
+//! [`CommandOption`]: crate::command::CommandOption
+
+mod custom_id;
+mod select;
+
+#[doc(hidden)]
+pub mod internal;
+
+pub use custom_id::{CustomId, CustomIdField, CUSTOM_ID_LENGTH};
+pub use select::{SelectMenuModel, SelectOption, SelectValue};
+
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use twilight_interactions_derive::{CustomId, SelectMenuModel, SelectOption};
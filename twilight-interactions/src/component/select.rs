@@ -0,0 +1,181 @@
+use twilight_model::{
+    application::interaction::message_component::MessageComponentInteractionData,
+    channel::message::component::SelectMenuOption, id::Id,
+};
+
+use crate::error::{ParseSelectError, ParseSelectMenuError};
+
+/// Options of a string select menu.
+///
+/// This trait maps each variant of an enum to a [`SelectMenuOption`], and
+/// parses the values selected by the user back into the enum. A derive macro
+/// is provided to automatically implement the trait on fieldless enums.
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::component::SelectOption;
+///
+/// #[derive(SelectOption, Debug, PartialEq, Eq)]
+/// enum Language {
+///     #[select(label = "Rust", value = "rust", emoji = "🦀", default)]
+///     Rust,
+///     #[select(label = "Python", value = "python", description = "Batteries included")]
+///     Python,
+/// }
+///
+/// let options = Language::select_options();
+/// assert_eq!(options[0].label, "Rust");
+/// assert!(options[0].default);
+///
+/// let selected = Language::from_values(["python"]).unwrap();
+/// assert_eq!(selected, [Language::Python]);
+/// ```
+///
+/// ## Macro attributes
+/// The macro provides a `#[select]` attribute to configure the options.
+///
+/// | Attribute     | Type   | Location | Description                                             |
+/// |---------------|--------|----------|---------------------------------------------------------|
+/// | `label`       | `str`  | Variant  | Label of the option shown to users (required).          |
+/// | `value`       | `str`  | Variant  | Value of the option received when selected (required).  |
+/// | `description` | `str`  | Variant  | Additional description of the option.                   |
+/// | `emoji`       | `str`  | Variant  | Emoji shown next to the option.[^emoji]                 |
+/// | `default`     | `bool` | Variant  | Whether the option is selected by default.[^default]    |
+/// | `rename_all`  | `str`  | Type     | Derive labels and values from variant names.[^rename]   |
+///
+/// [^emoji]: Unicode emoji like `🦀`, or custom emoji written like
+/// `<:name:id>` or `<a:name:id>` for animated emojis.
+///
+/// [^default]: Can be written without value.
+///
+/// [^rename]: Same rules as the `rename_all` attribute of the
+/// [`CommandOption`] derive macro. The `label` and `value` attributes can be
+/// omitted on variants.
+///
+/// Labels, values and descriptions must be between 1 and 100 characters, and
+/// enums can have at most 25 variants, which is the maximum number of options
+/// of a select menu.
+///
+/// [`CommandOption`]: crate::command::CommandOption
+pub trait SelectOption: Sized {
+    /// Get the options of the select menu, in variant declaration order.
+    fn select_options() -> Vec<SelectMenuOption>;
+
+    /// Parse a selected value.
+    ///
+    /// Returns `None` if the value does not correspond to any option.
+    fn from_value(value: &str) -> Option<Self>;
+
+    /// Get the value of the option.
+    fn select_value(&self) -> &'static str;
+
+    /// Parse the values selected by the user.
+    ///
+    /// An error is returned if a value does not correspond to any option,
+    /// which can happen if the select menu has been sent with a previous
+    /// version of the bot.
+    fn from_values<I>(values: I) -> Result<Vec<Self>, ParseSelectError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        values
+            .into_iter()
+            .map(|value| {
+                let value = value.as_ref();
+
+                Self::from_value(value).ok_or_else(|| ParseSelectError {
+                    value: value.to_owned(),
+                })
+            })
+            .collect()
+    }
+
+    /// Parse the values selected in a message component interaction.
+    fn from_component(
+        data: &MessageComponentInteractionData,
+    ) -> Result<Vec<Self>, ParseSelectError> {
+        Self::from_values(&data.values)
+    }
+}
+
+/// Parse a select menu interaction into a type.
+///
+/// This trait is used to parse the values selected in a message component
+/// interaction. A derive macro is provided to automatically implement the
+/// trait on the following types:
+///
+/// - Structs with a single field, whose type is `T`, `Option<T>` or `Vec<T>`
+///   where `T` implements [`SelectValue`]. `T` requires exactly one selected
+///   value, `Option<T>` at most one, and `Vec<T>` accepts any number of
+///   values (multi-select menus).
+/// - Enums implementing [`SelectOption`], which require exactly one selected
+///   value.
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::component::{SelectMenuModel, SelectOption};
+/// use twilight_model::{
+///     application::interaction::message_component::MessageComponentInteractionData,
+///     channel::message::component::ComponentType,
+///     id::{marker::RoleMarker, Id},
+/// };
+///
+/// #[derive(SelectOption, SelectMenuModel, Debug, PartialEq, Eq)]
+/// #[select(rename_all = "snake_case")]
+/// enum Theme {
+///     Light,
+///     Dark,
+/// }
+///
+/// #[derive(SelectMenuModel)]
+/// struct RolesSelect {
+///     roles: Vec<Id<RoleMarker>>,
+/// }
+///
+/// let data = MessageComponentInteractionData {
+///     custom_id: "theme".into(),
+///     component_type: ComponentType::TextSelectMenu,
+///     resolved: None,
+///     values: vec!["dark".into()],
+/// };
+///
+/// assert_eq!(Theme::from_interaction(&data), Ok(Theme::Dark));
+/// assert!(RolesSelect::from_interaction(&data).is_err());
+/// ```
+pub trait SelectMenuModel: Sized {
+    /// Parse the values selected in a message component interaction.
+    fn from_interaction(
+        data: &MessageComponentInteractionData,
+    ) -> Result<Self, ParseSelectMenuError>;
+}
+
+/// Parse a single value of a select menu.
+///
+/// This trait is used by the [`SelectMenuModel`] derive macro. It is
+/// implemented for [`SelectOption`] types, [`String`] and [`Id`], whose
+/// values are sent by user, role, channel and mentionable select menus.
+pub trait SelectValue: Sized {
+    /// Parse a selected value.
+    ///
+    /// Returns `None` if the value is invalid.
+    fn from_select_value(value: &str) -> Option<Self>;
+}
+
+impl<T: SelectOption> SelectValue for T {
+    fn from_select_value(value: &str) -> Option<Self> {
+        T::from_value(value)
+    }
+}
+
+impl SelectValue for String {
+    fn from_select_value(value: &str) -> Option<Self> {
+        Some(value.to_owned())
+    }
+}
+
+impl<T> SelectValue for Id<T> {
+    fn from_select_value(value: &str) -> Option<Self> {
+        value.parse().ok()
+    }
+}
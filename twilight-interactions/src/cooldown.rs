@@ -0,0 +1,266 @@
+//! Command cooldowns.
+//!
+//! A cooldown limits how often a command can be used. It is set with the
+//! `#[command(cooldown = "...")]` attribute of the [`CreateCommand`] derive
+//! macro, which accepts a duration followed by an optional scope, such as
+//! `"5s per user"` or `"1m per guild"`. Cooldowns are per user by default.
+//!
+//! The cooldown is stored in [`ApplicationCommandData::cooldown`], and is not
+//! sent to Discord. Cooldowns are enforced by a [`CooldownTracker`], either
+//! set on a [`CommandRegistry`] with [`CommandRegistry::cooldowns`] or
+//! consulted manually before running handlers.
+//!
+//! ## Example
+//! ```
+//! use std::sync::Arc;
+//!
+//! use twilight_interactions::{
+//!     command::{CommandModel, CreateCommand},
+//!     cooldown::{CooldownScope, CooldownTracker},
+//!     registry::CommandRegistry,
+//! };
+//!
+//! #[derive(CommandModel, CreateCommand)]
+//! #[command(name = "roll", desc = "Roll a dice", cooldown = "5s per user")]
+//! struct RollCommand;
+//!
+//! let cooldown = RollCommand::create_command().cooldown.unwrap();
+//! assert_eq!(cooldown.scope, CooldownScope::User);
+//!
+//! let registry = CommandRegistry::new()
+//!     .register(|_: RollCommand, _interaction| async move { "You rolled a 4" })
+//!     .cooldowns(Arc::new(CooldownTracker::new()));
+//! ```
+//!
+//! [`CreateCommand`]: crate::command::CreateCommand
+//! [`CommandRegistry`]: crate::registry::CommandRegistry
+//! [`CommandRegistry::cooldowns`]: crate::registry::CommandRegistry::cooldowns
+
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use twilight_model::application::interaction::Interaction;
+
+use crate::{command::ApplicationCommandData, error::CommandOnCooldown};
+
+/// Cooldown of a command.
+///
+/// See the [module documentation](self) for more information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cooldown {
+    /// Minimum time between two uses of the command.
+    pub duration: Duration,
+    /// Scope the cooldown applies to.
+    pub scope: CooldownScope,
+}
+
+impl Cooldown {
+    /// Create a new [`Cooldown`].
+    pub const fn new(duration: Duration, scope: CooldownScope) -> Self {
+        Self { duration, scope }
+    }
+}
+
+/// Scope of a [`Cooldown`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum CooldownScope {
+    /// Each user has a separate cooldown.
+    #[default]
+    User,
+    /// Each channel has a separate cooldown.
+    ///
+    /// Interactions without a channel fall back to the user.
+    Channel,
+    /// Each guild has a separate cooldown.
+    ///
+    /// Interactions received outside of a guild fall back to the channel.
+    Guild,
+    /// A single cooldown is shared by all users.
+    Global,
+}
+
+impl CooldownScope {
+    /// ID of the user, channel or guild the cooldown applies to.
+    ///
+    /// Zero is returned for global cooldowns, and `None` if the interaction
+    /// does not contain the required information.
+    fn key(self, interaction: &Interaction) -> Option<u64> {
+        let user = || interaction.author_id().map(|id| id.get());
+        let channel = || {
+            interaction
+                .channel
+                .as_ref()
+                .map(|channel| channel.id.get())
+                .or_else(user)
+        };
+
+        match self {
+            Self::User => user(),
+            Self::Channel => channel(),
+            Self::Guild => interaction.guild_id.map(|id| id.get()).or_else(channel),
+            Self::Global => Some(0),
+        }
+    }
+}
+
+/// Tracker of the cooldowns of commands.
+///
+/// The tracker records when each command was last used in each scope, and
+/// rejects uses of a command that is still on cooldown. Expired entries are
+/// kept until [`remove_expired`] is called, which should be done periodically
+/// by bots with a large number of users.
+///
+/// See the [module documentation](self) for an example.
+///
+/// [`remove_expired`]: Self::remove_expired
+#[derive(Debug, Default)]
+pub struct CooldownTracker {
+    expirations: Mutex<HashMap<(String, u64), Instant>>,
+}
+
+impl CooldownTracker {
+    /// Create a new empty [`CooldownTracker`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check whether a command can be used, and start its cooldown if so.
+    ///
+    /// An error containing the remaining time is returned if the command is
+    /// still on cooldown for the scope of the interaction. Commands without
+    /// a cooldown are always accepted, as well as interactions missing the
+    /// user, channel or guild of the cooldown scope.
+    ///
+    /// Use [`reserve`](Self::reserve) to only start the cooldown once the
+    /// command has been successfully processed.
+    pub fn check(
+        &self,
+        command: &ApplicationCommandData,
+        interaction: &Interaction,
+    ) -> Result<(), CommandOnCooldown> {
+        self.check_at(command, interaction, Instant::now())
+    }
+
+    /// Check whether a command can be used at the given instant.
+    ///
+    /// This works like [`check`](Self::check), with a custom current time.
+    pub fn check_at(
+        &self,
+        command: &ApplicationCommandData,
+        interaction: &Interaction,
+        now: Instant,
+    ) -> Result<(), CommandOnCooldown> {
+        self.reserve_at(command, interaction, now)?.commit();
+
+        Ok(())
+    }
+
+    /// Check whether a command can be used, without starting its cooldown.
+    ///
+    /// This works like [`check`](Self::check), but the cooldown only starts
+    /// when the returned [`CooldownReservation`] is committed. This allows
+    /// ignoring uses of the command that fail, such as interactions that
+    /// cannot be parsed.
+    ///
+    /// Concurrent uses of the command are all accepted until one of them is
+    /// committed.
+    pub fn reserve(
+        &self,
+        command: &ApplicationCommandData,
+        interaction: &Interaction,
+    ) -> Result<CooldownReservation<'_>, CommandOnCooldown> {
+        self.reserve_at(command, interaction, Instant::now())
+    }
+
+    /// Check whether a command can be used at the given instant, without
+    /// starting its cooldown.
+    ///
+    /// This works like [`reserve`](Self::reserve), with a custom current
+    /// time.
+    pub fn reserve_at(
+        &self,
+        command: &ApplicationCommandData,
+        interaction: &Interaction,
+        now: Instant,
+    ) -> Result<CooldownReservation<'_>, CommandOnCooldown> {
+        let entry = command.cooldown.and_then(|cooldown| {
+            let key = cooldown.scope.key(interaction)?;
+
+            Some((cooldown, (command.name.clone(), key)))
+        });
+
+        let Some((cooldown, key)) = entry else {
+            return Ok(CooldownReservation {
+                tracker: self,
+                entry: None,
+            });
+        };
+
+        let expirations = self.expirations.lock().unwrap();
+
+        if let Some(expiration) = expirations
+            .get(&key)
+            .filter(|&&expiration| expiration > now)
+        {
+            return Err(CommandOnCooldown {
+                command: key.0,
+                scope: cooldown.scope,
+                remaining: *expiration - now,
+            });
+        }
+
+        Ok(CooldownReservation {
+            tracker: self,
+            entry: Some((key, now + cooldown.duration)),
+        })
+    }
+
+    /// Remove the cooldowns that expired before the given instant.
+    pub fn remove_expired(&self, now: Instant) {
+        self.expirations
+            .lock()
+            .unwrap()
+            .retain(|_, expiration| *expiration > now);
+    }
+
+    /// Reset the cooldowns of a command in all scopes.
+    pub fn reset(&self, command: &str) {
+        self.expirations
+            .lock()
+            .unwrap()
+            .retain(|(name, _), _| name != command);
+    }
+}
+
+/// Use of a command accepted by a [`CooldownTracker`].
+///
+/// The cooldown of the command starts when the reservation is committed, and
+/// is not started if the reservation is dropped.
+#[must_use = "the cooldown only starts when the reservation is committed"]
+#[derive(Debug)]
+pub struct CooldownReservation<'a> {
+    tracker: &'a CooldownTracker,
+    entry: Option<((String, u64), Instant)>,
+}
+
+impl CooldownReservation<'_> {
+    /// Start the cooldown of the command.
+    pub fn commit(self) {
+        if let Some((key, expiration)) = self.entry {
+            self.tracker
+                .expirations
+                .lock()
+                .unwrap()
+                .insert(key, expiration);
+        }
+    }
+}
@@ -1,11 +1,44 @@
 //! Error types used by the crate.
+//!
+//! All error types implement [`Error`] and return their cause from
+//! [`Error::source`], for example the error returned by the parser of an
+//! option value.
+//!
+//! Errors are displayed as a single line message by default. The alternate
+//! format (`{:#}`) also includes the messages of the causes of the error
+//! that are not already part of the message, which is useful for logging.
+//!
+//! ```
+//! use twilight_interactions::error::{ParseOptionErrorType, ValueError};
+//!
+//! let error = "abc".parse::<u32>().unwrap_err();
+//! let error = ParseOptionErrorType::InvalidValue(ValueError::with_source("invalid count", error));
+//!
+//! assert_eq!(format!("{error}"), "invalid value: invalid count");
+//! assert_eq!(
+//!     format!("{error:#}"),
+//!     "invalid value: invalid count: invalid digit found in string"
+//! );
+//! ```
 
 use std::{
     error::Error,
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{self, Display, Formatter, Result as FmtResult},
+    sync::Arc,
+    time::Duration,
 };
 
-use twilight_model::{application::command::CommandOptionType, channel::ChannelType};
+use twilight_model::{
+    application::{
+        command::{CommandOptionType, CommandOptionValue, CommandType},
+        interaction::InteractionType,
+    },
+    channel::{message::MessageFlags, ChannelType},
+    http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+    id::{marker::InteractionMarker, Id},
+};
+
+use crate::cooldown::CooldownScope;
 
 /// Error when parsing a command.
 ///
@@ -21,15 +54,95 @@ pub enum ParseError {
     EmptyOptions,
     /// Error when parsing a command option.
     Option(ParseOptionError),
+    /// Received a command that is not handled.
+    ///
+    /// This error is only returned by [`CommandRouter::route`].
+    ///
+    /// [`CommandRouter::route`]: crate::command::CommandRouter::route
+    UnknownCommand(String),
+    /// Received autocomplete data without focused option.
+    ///
+    /// This error is only returned when parsing a [`FocusedOption`].
+    ///
+    /// [`FocusedOption`]: crate::command::FocusedOption
+    MissingFocused,
+    /// Received an interaction without command data.
+    ///
+    /// This error is only returned by [`CommandModel::from_interaction_full`],
+    /// and contains the type of the received interaction.
+    ///
+    /// [`CommandModel::from_interaction_full`]: crate::command::CommandModel::from_interaction_full
+    InvalidInteraction(InteractionType),
+    /// A field filled from the interaction is not available.
+    ///
+    /// This error is returned when parsing fields with the `ctx` attribute,
+    /// and contains the name of the field.
+    MissingContext(String),
 }
 
-impl Error for ParseError {}
+impl ParseError {
+    /// Add the name of a parent subcommand or subcommand group to the path of
+    /// the option that caused the error.
+    ///
+    /// This method is used when parsing subcommands, and has no effect on
+    /// errors other than [`ParseError::Option`].
+    pub fn nested(self, subcommand: &str) -> Self {
+        match self {
+            ParseError::Option(mut error) => {
+                error.path.insert(0, subcommand.to_owned());
+                ParseError::Option(error)
+            }
+            other => other,
+        }
+    }
+
+    /// Get the [`ParseErrorKind`] of the error.
+    pub fn kind(&self) -> ParseErrorKind {
+        match self {
+            ParseError::EmptyOptions => ParseErrorKind::EmptyOptions,
+            ParseError::Option(error) => ParseErrorKind::from(&error.kind),
+            ParseError::UnknownCommand(_) => ParseErrorKind::UnknownCommand,
+            ParseError::MissingFocused => ParseErrorKind::MissingFocused,
+            ParseError::InvalidInteraction(_) => ParseErrorKind::InvalidInteraction,
+            ParseError::MissingContext(_) => ParseErrorKind::MissingContext,
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseError::Option(error) => Some(error),
+            ParseError::EmptyOptions
+            | ParseError::UnknownCommand(_)
+            | ParseError::MissingFocused
+            | ParseError::InvalidInteraction(_)
+            | ParseError::MissingContext(_) => None,
+        }
+    }
+}
 
 impl Display for ParseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate() {
+            return write_verbose(f, self);
+        }
+
         match self {
             ParseError::EmptyOptions => write!(f, "received an empty option list"),
             ParseError::Option(error) => error.fmt(f),
+            ParseError::UnknownCommand(name) => write!(f, "unknown command `{name}`"),
+            ParseError::MissingFocused => write!(f, "no focused option in autocomplete data"),
+            ParseError::InvalidInteraction(kind) => {
+                write!(
+                    f,
+                    "received {} interaction without command data",
+                    kind.kind()
+                )
+            }
+            ParseError::MissingContext(field) => {
+                write!(f, "field `{field}` is not available in the interaction")
+            }
         }
     }
 }
@@ -43,36 +156,48 @@ pub struct ParseOptionError {
     pub field: String,
     /// The type of the error.
     pub kind: ParseOptionErrorType,
+    /// Names of the subcommand group and subcommand containing the option,
+    /// from the outermost one.
+    ///
+    /// This is empty if the option is not part of a subcommand.
+    pub path: Vec<String>,
 }
 
-impl Error for ParseOptionError {}
+impl ParseOptionError {
+    /// Get the full path of the option that caused the error.
+    ///
+    /// The path contains the names of the subcommand group and subcommand
+    /// containing the option, followed by the name of the option (e.g.
+    /// `set welcome_message`).
+    pub fn option_path(&self) -> String {
+        let mut path = self.path.join(" ");
+        if !path.is_empty() {
+            path.push(' ');
+        }
+        path.push_str(&self.field);
+
+        path
+    }
+}
+
+impl Error for ParseOptionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.kind)
+    }
+}
 
 impl Display for ParseOptionError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "failed to parse option `{}`: ", self.field)?;
-
-        match &self.kind {
-            ParseOptionErrorType::InvalidType(ty) => write!(f, "invalid type, found {}", ty.kind()),
-            ParseOptionErrorType::InvalidChoice(choice) => {
-                write!(f, "invalid choice value, found `{choice}`")
-            }
-            ParseOptionErrorType::IntegerOutOfRange(val) => {
-                write!(f, "out of range integer, received `{val}`")
-            }
-            ParseOptionErrorType::NumberOutOfRange(val) => {
-                write!(f, "out of range number, received `{val}`")
-            }
-            ParseOptionErrorType::StringLengthOutOfRange(val) => {
-                write!(f, "out of range string length, received `{val}`")
-            }
-            ParseOptionErrorType::InvalidChannelType(kind) => {
-                write!(f, "invalid channel type, received `{}`", kind.name())
-            }
-            ParseOptionErrorType::LookupFailed(id) => write!(f, "failed to resolve `{id}`"),
-            ParseOptionErrorType::UnknownField => write!(f, "unknown field"),
-            ParseOptionErrorType::UnknownSubcommand => write!(f, "unknown subcommand"),
-            ParseOptionErrorType::RequiredField => write!(f, "missing required field"),
+        if f.alternate() {
+            return write_verbose(f, self);
         }
+
+        write!(
+            f,
+            "failed to parse option `{}`: {}",
+            self.option_path(),
+            self.kind
+        )
     }
 }
 
@@ -83,14 +208,39 @@ pub enum ParseOptionErrorType {
     InvalidType(CommandOptionType),
     /// Received an invalid value on choice option type.
     InvalidChoice(String),
-    /// Received an out of range integer.
-    IntegerOutOfRange(i64),
-    /// Received an out of range floating point number.
-    NumberOutOfRange(f64),
+    /// Received a value outside of the permitted range.
+    ///
+    /// This error is returned when an `INTEGER` or `NUMBER` option does not
+    /// respect the `min_value` and `max_value` of the option, or does not fit
+    /// in the type of the field (e.g. `300` for a `u8` field). The limits of
+    /// the permitted range are provided, if any.
+    OutOfRange {
+        /// The received value.
+        value: CommandOptionValue,
+        /// Minimum permitted value.
+        min: Option<CommandOptionValue>,
+        /// Maximum permitted value.
+        max: Option<CommandOptionValue>,
+    },
     /// Received an out of range string.
     StringLengthOutOfRange(String),
     /// Received an invalid channel type.
     InvalidChannelType(ChannelType),
+    /// Failed to parse the received value.
+    ///
+    /// This error contains the message of the error returned by the parser,
+    /// for example when using the [`Parsed`] type, and the error itself if
+    /// available.
+    ///
+    /// [`Parsed`]: crate::command::Parsed
+    InvalidValue(ValueError),
+    /// The parsed value was rejected by a validation function.
+    ///
+    /// This error contains the message returned by the function set with the
+    /// `validate` attribute of the [`CommandModel`] derive macro.
+    ///
+    /// [`CommandModel`]: crate::command::CommandModel
+    ValidationFailed(String),
     /// Failed to resolve data associated with an ID.
     LookupFailed(u64),
     /// Missing a required option field.
@@ -100,3 +250,824 @@ pub enum ParseOptionErrorType {
     /// Received an unknown subcommand.
     UnknownSubcommand,
 }
+
+impl ParseOptionErrorType {
+    /// Create an [`InvalidValue`] error from the error returned by a parser.
+    ///
+    /// [`InvalidValue`]: Self::InvalidValue
+    pub fn invalid_value(error: impl Error + Send + Sync + 'static) -> Self {
+        ParseOptionErrorType::InvalidValue(ValueError::from_error(error))
+    }
+}
+
+impl From<ValueError> for ParseOptionErrorType {
+    fn from(error: ValueError) -> Self {
+        ParseOptionErrorType::InvalidValue(error)
+    }
+}
+
+impl Error for ParseOptionErrorType {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseOptionErrorType::InvalidValue(error) => error.source(),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ParseOptionErrorType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate() {
+            return write_verbose(f, self);
+        }
+
+        match self {
+            ParseOptionErrorType::InvalidType(ty) => write!(f, "invalid type, found {}", ty.kind()),
+            ParseOptionErrorType::InvalidChoice(choice) => {
+                write!(f, "invalid choice value, found `{choice}`")
+            }
+            ParseOptionErrorType::OutOfRange { value, min, max } => {
+                write!(f, "out of range value, received `{}`", DisplayValue(value))?;
+
+                match (min, max) {
+                    (Some(min), Some(max)) => write!(
+                        f,
+                        " (expected between {} and {})",
+                        DisplayValue(min),
+                        DisplayValue(max)
+                    ),
+                    (Some(min), None) => write!(f, " (expected at least {})", DisplayValue(min)),
+                    (None, Some(max)) => write!(f, " (expected at most {})", DisplayValue(max)),
+                    (None, None) => Ok(()),
+                }
+            }
+            ParseOptionErrorType::StringLengthOutOfRange(val) => {
+                write!(f, "out of range string length, received `{val}`")
+            }
+            ParseOptionErrorType::InvalidChannelType(kind) => {
+                write!(f, "invalid channel type, received `{}`", kind.name())
+            }
+            ParseOptionErrorType::InvalidValue(message) => write!(f, "invalid value: {message}"),
+            ParseOptionErrorType::ValidationFailed(message) => {
+                write!(f, "validation failed: {message}")
+            }
+            ParseOptionErrorType::LookupFailed(id) => write!(f, "failed to resolve `{id}`"),
+            ParseOptionErrorType::UnknownField => write!(f, "unknown field"),
+            ParseOptionErrorType::UnknownSubcommand => write!(f, "unknown subcommand"),
+            ParseOptionErrorType::RequiredField => write!(f, "missing required field"),
+        }
+    }
+}
+
+/// Error returned by the parser of an option value.
+///
+/// This type is used by [`ParseOptionErrorType::InvalidValue`]. It contains
+/// a message and optionally the error that caused it, returned by
+/// [`Error::source`]. Errors are compared using their message only.
+///
+/// A [`ValueError`] can be created from a [`String`] or a `&str` with the
+/// [`From`] trait.
+#[derive(Clone)]
+pub struct ValueError {
+    message: String,
+    source: Option<Arc<dyn Error + Send + Sync>>,
+}
+
+impl ValueError {
+    /// Create a new [`ValueError`] with a message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            source: None,
+        }
+    }
+
+    /// Create a new [`ValueError`] with a message and the error that caused
+    /// it.
+    pub fn with_source(
+        message: impl Into<String>,
+        source: impl Error + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            message: message.into(),
+            source: Some(Arc::new(source)),
+        }
+    }
+
+    /// Create a new [`ValueError`] from an error, using its message.
+    ///
+    /// The error is returned by [`Error::source`] of the
+    /// [`ParseOptionErrorType`], so its message is not repeated by the
+    /// alternate format.
+    pub fn from_error(error: impl Error + Send + Sync + 'static) -> Self {
+        Self {
+            message: error.to_string(),
+            source: Some(Arc::new(error)),
+        }
+    }
+
+    /// Get the message of the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Error for ValueError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn Error + 'static))
+    }
+}
+
+impl Display for ValueError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.message)
+    }
+}
+
+impl fmt::Debug for ValueError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ValueError")
+            .field("message", &self.message)
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+impl PartialEq for ValueError {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+    }
+}
+
+impl Eq for ValueError {}
+
+impl From<String> for ValueError {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<&str> for ValueError {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+/// Write the message of an error followed by the messages of its causes that
+/// are not already part of it.
+///
+/// This function is used by the alternate format of error types.
+fn write_verbose(f: &mut Formatter<'_>, error: &dyn Error) -> FmtResult {
+    let mut message = error.to_string();
+    let mut source = error.source();
+
+    while let Some(error) = source {
+        let cause = error.to_string();
+        if !message.contains(&cause) {
+            message.push_str(": ");
+            message.push_str(&cause);
+        }
+
+        source = error.source();
+    }
+
+    f.write_str(&message)
+}
+
+/// Display implementation of [`CommandOptionValue`].
+struct DisplayValue<'a>(&'a CommandOptionValue);
+
+impl Display for DisplayValue<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.0 {
+            CommandOptionValue::Integer(value) => value.fmt(f),
+            CommandOptionValue::Number(value) => value.fmt(f),
+        }
+    }
+}
+
+/// Kind of a [`ParseError`], without associated data.
+///
+/// This type allows branching on the kind of an error, including the kind of
+/// [`ParseOptionError`]s. Each kind has a numeric [`code`] that is kept stable
+/// across releases, and can be used in logs or metrics.
+///
+/// [`code`]: Self::code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum ParseErrorKind {
+    /// [`ParseError::EmptyOptions`]
+    EmptyOptions = 1,
+    /// [`ParseError::UnknownCommand`]
+    UnknownCommand = 2,
+    /// [`ParseError::MissingFocused`]
+    MissingFocused = 3,
+    /// [`ParseError::InvalidInteraction`]
+    InvalidInteraction = 4,
+    /// [`ParseError::MissingContext`]
+    MissingContext = 5,
+    /// [`ParseOptionErrorType::InvalidType`]
+    InvalidType = 100,
+    /// [`ParseOptionErrorType::InvalidChoice`]
+    InvalidChoice = 101,
+    /// [`ParseOptionErrorType::OutOfRange`]
+    OutOfRange = 102,
+    /// [`ParseOptionErrorType::StringLengthOutOfRange`]
+    StringLengthOutOfRange = 103,
+    /// [`ParseOptionErrorType::InvalidChannelType`]
+    InvalidChannelType = 104,
+    /// [`ParseOptionErrorType::InvalidValue`]
+    InvalidValue = 105,
+    /// [`ParseOptionErrorType::ValidationFailed`]
+    ValidationFailed = 106,
+    /// [`ParseOptionErrorType::LookupFailed`]
+    LookupFailed = 107,
+    /// [`ParseOptionErrorType::RequiredField`]
+    RequiredField = 108,
+    /// [`ParseOptionErrorType::UnknownField`]
+    UnknownField = 109,
+    /// [`ParseOptionErrorType::UnknownSubcommand`]
+    UnknownSubcommand = 110,
+}
+
+impl ParseErrorKind {
+    /// Get the stable numeric code of the kind.
+    pub const fn code(self) -> u16 {
+        self as u16
+    }
+}
+
+impl From<&ParseOptionErrorType> for ParseErrorKind {
+    fn from(kind: &ParseOptionErrorType) -> Self {
+        match kind {
+            ParseOptionErrorType::InvalidType(_) => ParseErrorKind::InvalidType,
+            ParseOptionErrorType::InvalidChoice(_) => ParseErrorKind::InvalidChoice,
+            ParseOptionErrorType::OutOfRange { .. } => ParseErrorKind::OutOfRange,
+            ParseOptionErrorType::StringLengthOutOfRange(_) => {
+                ParseErrorKind::StringLengthOutOfRange
+            }
+            ParseOptionErrorType::InvalidChannelType(_) => ParseErrorKind::InvalidChannelType,
+            ParseOptionErrorType::InvalidValue(_) => ParseErrorKind::InvalidValue,
+            ParseOptionErrorType::ValidationFailed(_) => ParseErrorKind::ValidationFailed,
+            ParseOptionErrorType::LookupFailed(_) => ParseErrorKind::LookupFailed,
+            ParseOptionErrorType::RequiredField => ParseErrorKind::RequiredField,
+            ParseOptionErrorType::UnknownField => ParseErrorKind::UnknownField,
+            ParseOptionErrorType::UnknownSubcommand => ParseErrorKind::UnknownSubcommand,
+        }
+    }
+}
+
+/// Error when dispatching an interaction.
+///
+/// This error type is returned by the [`CommandRegistry::handle`] and
+/// [`InteractionKindRouter::handle`] methods.
+///
+/// [`CommandRegistry::handle`]: crate::registry::CommandRegistry::handle
+/// [`InteractionKindRouter::handle`]: crate::registry::InteractionKindRouter::handle
+#[derive(Debug, Clone, PartialEq)]
+pub enum DispatchError {
+    /// Received an interaction whose kind is not supported.
+    UnsupportedInteraction(InteractionType),
+    /// Received an interaction without data.
+    MissingData,
+    /// Received a command that is not registered.
+    UnknownCommand(String),
+    /// Error when parsing the command data.
+    Parse(ParseError),
+    /// The command handler panicked.
+    ///
+    /// This error is only returned if panic isolation is enabled.
+    Panicked(HandlerPanic),
+    /// The command handler exceeded its timeout.
+    ///
+    /// This error is only returned if the `tokio` feature is enabled.
+    TimedOut(HandlerTimeout),
+    /// The command is on cooldown.
+    ///
+    /// This error is only returned if a [`CooldownTracker`] is set.
+    ///
+    /// [`CooldownTracker`]: crate::cooldown::CooldownTracker
+    OnCooldown(CommandOnCooldown),
+    /// The command cannot be used in the context of the interaction.
+    AccessDenied(AccessDenied),
+}
+
+impl Error for DispatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DispatchError::Parse(error) => Some(error),
+            DispatchError::Panicked(panic) => Some(panic),
+            DispatchError::TimedOut(timeout) => Some(timeout),
+            DispatchError::OnCooldown(cooldown) => Some(cooldown),
+            DispatchError::AccessDenied(denied) => Some(denied),
+            DispatchError::UnsupportedInteraction(_)
+            | DispatchError::MissingData
+            | DispatchError::UnknownCommand(_) => None,
+        }
+    }
+}
+
+impl Display for DispatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate() {
+            return write_verbose(f, self);
+        }
+
+        match self {
+            DispatchError::UnsupportedInteraction(kind) => {
+                write!(f, "unsupported interaction type `{}`", kind.kind())
+            }
+            DispatchError::MissingData => write!(f, "received an interaction without data"),
+            DispatchError::UnknownCommand(name) => write!(f, "unknown command `{name}`"),
+            DispatchError::Parse(error) => error.fmt(f),
+            DispatchError::Panicked(panic) => panic.fmt(f),
+            DispatchError::TimedOut(timeout) => timeout.fmt(f),
+            DispatchError::OnCooldown(cooldown) => cooldown.fmt(f),
+            DispatchError::AccessDenied(denied) => denied.fmt(f),
+        }
+    }
+}
+
+impl From<ParseError> for DispatchError {
+    fn from(error: ParseError) -> Self {
+        DispatchError::Parse(error)
+    }
+}
+
+/// Details about a panic that occurred in a command handler.
+///
+/// This type is used by [`DispatchError`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandlerPanic {
+    /// Name of the command whose handler panicked.
+    pub command: String,
+    /// ID of the interaction that was being handled.
+    pub interaction_id: Id<InteractionMarker>,
+    /// Message of the panic.
+    pub message: String,
+}
+
+impl HandlerPanic {
+    /// Create an ephemeral error response for the interaction.
+    ///
+    /// The response should be sent to Discord to avoid leaving the
+    /// interaction without a response.
+    pub fn response(&self) -> InteractionResponse {
+        let data = InteractionResponseData {
+            content: Some(String::from(
+                "An unexpected error occurred while running this command.",
+            )),
+            flags: Some(MessageFlags::EPHEMERAL),
+            ..Default::default()
+        };
+
+        InteractionResponse {
+            kind: InteractionResponseType::ChannelMessageWithSource,
+            data: Some(data),
+        }
+    }
+}
+
+impl Error for HandlerPanic {}
+
+impl Display for HandlerPanic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "handler of command `{}` panicked: {}",
+            self.command, self.message
+        )
+    }
+}
+
+/// Error when parsing the values selected in a select menu.
+///
+/// This error is returned by [`SelectOption::from_values`] when a value does
+/// not correspond to any option.
+///
+/// [`SelectOption::from_values`]: crate::component::SelectOption::from_values
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseSelectError {
+    /// The unknown value.
+    pub value: String,
+}
+
+impl Error for ParseSelectError {}
+
+impl Display for ParseSelectError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "unknown select menu value `{}`", self.value)
+    }
+}
+
+/// Error when parsing a select menu interaction into a [`SelectMenuModel`].
+///
+/// [`SelectMenuModel`]: crate::component::SelectMenuModel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseSelectMenuError {
+    /// A selected value is invalid.
+    InvalidValue(ParseSelectError),
+    /// No value has been selected but the model requires one.
+    MissingValue,
+    /// Multiple values have been selected but the model accepts at most one.
+    TooManyValues {
+        /// Number of selected values.
+        found: usize,
+    },
+}
+
+impl Error for ParseSelectMenuError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidValue(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ParseSelectMenuError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::InvalidValue(error) => Display::fmt(error, f),
+            Self::MissingValue => f.write_str("missing selected value"),
+            Self::TooManyValues { found } => {
+                write!(f, "expected a single selected value, found {found}")
+            }
+        }
+    }
+}
+
+impl From<ParseSelectError> for ParseSelectMenuError {
+    fn from(error: ParseSelectError) -> Self {
+        Self::InvalidValue(error)
+    }
+}
+
+/// Error when encoding or parsing a custom id with the [`CustomId`] trait.
+///
+/// [`CustomId`]: crate::component::CustomId
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CustomIdError {
+    /// The encoded custom id exceeds the maximum length.
+    TooLong {
+        /// Length of the custom id, in characters.
+        length: usize,
+    },
+    /// The custom id does not start with the prefix of the type.
+    PrefixMismatch,
+    /// The custom id does not have the expected number of fields.
+    FieldCount {
+        /// Number of fields of the type.
+        expected: usize,
+        /// Number of fields of the custom id.
+        found: usize,
+    },
+    /// The custom id is not correctly encoded with the compact encoding.
+    InvalidEncoding,
+    /// A field of the custom id could not be parsed.
+    InvalidField {
+        /// Position of the field, starting at zero.
+        index: usize,
+        /// Value of the field.
+        value: String,
+    },
+}
+
+impl Error for CustomIdError {}
+
+impl Display for CustomIdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::TooLong { length } => write!(
+                f,
+                "custom id is {length} characters long (maximum is {})",
+                crate::component::CUSTOM_ID_LENGTH
+            ),
+            Self::PrefixMismatch => f.write_str("custom id does not match the expected prefix"),
+            Self::FieldCount { expected, found } => {
+                write!(f, "custom id has {found} fields, expected {expected}")
+            }
+            Self::InvalidEncoding => f.write_str("custom id is not correctly encoded"),
+            Self::InvalidField { index, value } => {
+                write!(f, "invalid custom id field {index}: `{value}`")
+            }
+        }
+    }
+}
+
+/// Details about a command handler that exceeded its timeout.
+///
+/// This type is used by [`DispatchError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandlerTimeout {
+    /// Name of the command whose handler timed out.
+    pub command: String,
+    /// ID of the interaction that was being handled.
+    pub interaction_id: Id<InteractionMarker>,
+    /// Timeout of the command.
+    pub timeout: Duration,
+    /// Whether the interaction was deferred before timing out.
+    pub deferred: bool,
+}
+
+impl HandlerTimeout {
+    /// Message sent to the user when a command times out.
+    pub const MESSAGE: &'static str = "This command took too long to respond.";
+
+    /// Create an ephemeral error response for the interaction.
+    ///
+    /// If the interaction has been [`deferred`], this response cannot be sent
+    /// anymore. The original response should be updated with [`MESSAGE`]
+    /// instead.
+    ///
+    /// [`deferred`]: Self::deferred
+    /// [`MESSAGE`]: Self::MESSAGE
+    pub fn response(&self) -> InteractionResponse {
+        let data = InteractionResponseData {
+            content: Some(String::from(Self::MESSAGE)),
+            flags: Some(MessageFlags::EPHEMERAL),
+            ..Default::default()
+        };
+
+        InteractionResponse {
+            kind: InteractionResponseType::ChannelMessageWithSource,
+            data: Some(data),
+        }
+    }
+}
+
+impl Error for HandlerTimeout {}
+
+impl Display for HandlerTimeout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "handler of command `{}` timed out after {:?}",
+            self.command, self.timeout
+        )
+    }
+}
+
+/// Details about a command used while on cooldown.
+///
+/// This type is returned by [`CooldownTracker::check`] and used by
+/// [`DispatchError`].
+///
+/// [`CooldownTracker::check`]: crate::cooldown::CooldownTracker::check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandOnCooldown {
+    /// Name of the command.
+    pub command: String,
+    /// Scope of the cooldown.
+    pub scope: CooldownScope,
+    /// Remaining time before the command can be used again.
+    pub remaining: Duration,
+}
+
+impl CommandOnCooldown {
+    /// Create an ephemeral error response for the interaction.
+    ///
+    /// The response tells the user how many seconds remain before the command
+    /// can be used again.
+    pub fn response(&self) -> InteractionResponse {
+        let seconds = self.remaining.as_secs() + u64::from(self.remaining.subsec_nanos() > 0);
+        let data = InteractionResponseData {
+            content: Some(format!(
+                "This command is on cooldown, try again in {seconds} seconds."
+            )),
+            flags: Some(MessageFlags::EPHEMERAL),
+            ..Default::default()
+        };
+
+        InteractionResponse {
+            kind: InteractionResponseType::ChannelMessageWithSource,
+            data: Some(data),
+        }
+    }
+}
+
+impl Error for CommandOnCooldown {}
+
+impl Display for CommandOnCooldown {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "command `{}` is on cooldown for {:?}",
+            self.command, self.remaining
+        )
+    }
+}
+
+/// Details about a command used without meeting its access restrictions.
+///
+/// This type is returned by [`ApplicationCommandData::check_access`] and used
+/// by [`DispatchError`].
+///
+/// [`ApplicationCommandData::check_access`]: crate::command::ApplicationCommandData::check_access
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessDenied {
+    /// Name of the command.
+    pub command: String,
+    /// Restriction of the command that was not met.
+    pub restriction: AccessRestriction,
+}
+
+impl AccessDenied {
+    /// Create an ephemeral error response for the interaction.
+    pub fn response(&self) -> InteractionResponse {
+        let data = InteractionResponseData {
+            content: Some(String::from(self.restriction.message())),
+            flags: Some(MessageFlags::EPHEMERAL),
+            ..Default::default()
+        };
+
+        InteractionResponse {
+            kind: InteractionResponseType::ChannelMessageWithSource,
+            data: Some(data),
+        }
+    }
+}
+
+impl Error for AccessDenied {}
+
+impl Display for AccessDenied {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.restriction {
+            AccessRestriction::GuildOnly => {
+                write!(f, "command `{}` used outside of a guild", self.command)
+            }
+            AccessRestriction::OwnerOnly => {
+                write!(
+                    f,
+                    "command `{}` used by a user that is not an owner",
+                    self.command
+                )
+            }
+        }
+    }
+}
+
+/// Access restriction of a command, used by [`AccessDenied`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccessRestriction {
+    /// The command can only be used in guilds.
+    GuildOnly,
+    /// The command can only be used by the owners of the bot.
+    OwnerOnly,
+}
+
+impl AccessRestriction {
+    /// Message sent to the user when the restriction is not met.
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::GuildOnly => "This command can only be used in a server.",
+            Self::OwnerOnly => "This command can only be used by the owners of the bot.",
+        }
+    }
+}
+
+/// Error when converting a [`Command`] into an [`ApplicationCommandData`].
+///
+/// Only chat input commands can be represented by [`ApplicationCommandData`].
+///
+/// [`Command`]: twilight_model::application::command::Command
+/// [`ApplicationCommandData`]: crate::command::ApplicationCommandData
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandConversionError {
+    /// Name of the command.
+    pub name: String,
+    /// Type of the command.
+    pub kind: CommandType,
+}
+
+impl Error for CommandConversionError {}
+
+impl Display for CommandConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "command `{}` of type {:?} is not a chat input command",
+            self.name, self.kind
+        )
+    }
+}
+
+/// Error returned by [`FakeDiscord`].
+///
+/// [`FakeDiscord`]: crate::testing::FakeDiscord
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FakeDiscordError {
+    /// A command does not respect Discord limits.
+    InvalidCommand {
+        /// Name of the command.
+        name: String,
+        /// List of limits that are not respected.
+        violations: Vec<String>,
+    },
+    /// Several commands with the same name and type were provided.
+    DuplicateCommand(String),
+    /// No command with the provided name is registered.
+    UnknownCommand(String),
+    /// The option is not part of the registered command.
+    UnknownOption(String),
+    /// The value of an option does not match its registered type.
+    InvalidOptionType {
+        /// Path of the option.
+        option: String,
+        /// Registered type of the option.
+        expected: CommandOptionType,
+        /// Type of the provided value.
+        found: CommandOptionType,
+    },
+    /// A required option is missing.
+    MissingOption(String),
+}
+
+#[cfg(feature = "testing")]
+impl Error for FakeDiscordError {}
+
+#[cfg(feature = "testing")]
+impl Display for FakeDiscordError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            FakeDiscordError::InvalidCommand { name, violations } => write!(
+                f,
+                "command `{name}` does not respect Discord limits: {}",
+                violations.join(", ")
+            ),
+            FakeDiscordError::DuplicateCommand(name) => write!(f, "duplicate command `{name}`"),
+            FakeDiscordError::UnknownCommand(name) => write!(f, "unknown command `{name}`"),
+            FakeDiscordError::UnknownOption(path) => write!(f, "unknown option `{path}`"),
+            FakeDiscordError::InvalidOptionType {
+                option,
+                expected,
+                found,
+            } => write!(
+                f,
+                "invalid type for option `{option}`, expected {} but found {}",
+                expected.kind(),
+                found.kind()
+            ),
+            FakeDiscordError::MissingOption(path) => write!(f, "missing required option `{path}`"),
+        }
+    }
+}
+
+/// Error when performing requests with the [`http`](crate::http) helpers.
+#[cfg(feature = "http")]
+#[cfg_attr(docsrs, doc(cfg(feature = "http")))]
+#[derive(Debug)]
+pub enum HttpError {
+    /// The HTTP request failed.
+    Request(twilight_http::Error),
+    /// Failed to deserialize the response body.
+    Deserialize(twilight_http::response::DeserializeBodyError),
+    /// No command with the provided name is registered on Discord.
+    UnknownCommand(String),
+}
+
+#[cfg(feature = "http")]
+impl Error for HttpError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            HttpError::Request(error) => Some(error),
+            HttpError::Deserialize(error) => Some(error),
+            HttpError::UnknownCommand(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+impl Display for HttpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate() {
+            return write_verbose(f, self);
+        }
+
+        match self {
+            HttpError::Request(_) => write!(f, "http request failed"),
+            HttpError::Deserialize(_) => write!(f, "failed to deserialize response body"),
+            HttpError::UnknownCommand(name) => write!(f, "unknown command `{name}`"),
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+impl From<twilight_http::Error> for HttpError {
+    fn from(error: twilight_http::Error) -> Self {
+        HttpError::Request(error)
+    }
+}
+
+#[cfg(feature = "http")]
+impl From<twilight_http::response::DeserializeBodyError> for HttpError {
+    fn from(error: twilight_http::response::DeserializeBodyError) -> Self {
+        HttpError::Deserialize(error)
+    }
+}
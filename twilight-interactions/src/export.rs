@@ -0,0 +1,305 @@
+//! Export of commands as a manifest for documentation.
+//!
+//! A [`Manifest`] describes a set of commands with all their options,
+//! choices and localizations, along with their category and [`metadata`],
+//! such as a help text. It is intended to be written as JSON and used to
+//! generate the documentation of a bot, for example in a build script or a
+//! test.
+//!
+//! Localizations and metadata are sorted by key, so that exporting the same
+//! commands always produces the same output.
+//!
+//! ## Example
+//! ```
+//! use twilight_interactions::{command::CreateCommand, export::Manifest};
+//!
+//! #[derive(CreateCommand)]
+//! #[command(name = "hello", desc = "Say hello")]
+//! struct HelloCommand {
+//!     /// The message to send
+//!     message: String,
+//! }
+//!
+//! let manifest = Manifest::new([&HelloCommand::create_command()]);
+//! assert_eq!(manifest.commands[0].options[0].kind, "string");
+//!
+//! let json = manifest.to_json();
+//! assert!(json.contains(r#""name": "hello""#));
+//! ```
+//!
+//! [`metadata`]: ApplicationCommandData::metadata
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+use twilight_model::{
+    application::{
+        command::{
+            CommandOption, CommandOptionChoice, CommandOptionChoiceValue, CommandOptionValue,
+        },
+        interaction::InteractionContextType,
+    },
+    oauth::ApplicationIntegrationType,
+};
+
+use crate::command::{tree::option_type, ApplicationCommandData};
+
+/// Manifest of a set of commands.
+///
+/// See the [module documentation](self) for more information.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Manifest {
+    /// Exported commands, in the order they were provided.
+    pub commands: Vec<CommandManifest>,
+}
+
+impl Manifest {
+    /// Create a manifest of the provided commands.
+    ///
+    /// Commands registered in a [`CommandRegistry`] are returned by
+    /// [`CommandRegistry::command_data`].
+    ///
+    /// [`CommandRegistry`]: crate::registry::CommandRegistry
+    /// [`CommandRegistry::command_data`]: crate::registry::CommandRegistry::command_data
+    pub fn new<'a>(commands: impl IntoIterator<Item = &'a ApplicationCommandData>) -> Self {
+        Self {
+            commands: commands.into_iter().map(CommandManifest::new).collect(),
+        }
+    }
+
+    /// Serialize the manifest as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("manifest serialization cannot fail")
+    }
+}
+
+/// Exported command, part of a [`Manifest`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CommandManifest {
+    /// Name of the command.
+    pub name: String,
+    /// Description of the command.
+    pub description: String,
+    /// Localized names and descriptions, by locale.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub localizations: BTreeMap<String, Localization>,
+    /// Whether the command is age-restricted.
+    pub nsfw: bool,
+    /// Names of the permissions required by default to use the command.
+    ///
+    /// This is `None` if the command is available to everyone, and empty if
+    /// it is only available to administrators.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_member_permissions: Option<Vec<String>>,
+    /// Interaction contexts where the command can be used, in `snake_case`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub contexts: Vec<&'static str>,
+    /// Installation contexts where the command is available, in
+    /// `snake_case`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub integration_types: Vec<&'static str>,
+    /// Category of the command.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Metadata of the command.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, String>,
+    /// Options or subcommands of the command.
+    pub options: Vec<OptionManifest>,
+}
+
+impl CommandManifest {
+    /// Create the manifest of a command.
+    pub fn new(command: &ApplicationCommandData) -> Self {
+        let default_member_permissions = command.default_member_permissions.map(|permissions| {
+            permissions
+                .iter_names()
+                .map(|(name, _)| name.to_owned())
+                .collect()
+        });
+
+        Self {
+            name: command.name.clone(),
+            description: command.description.clone(),
+            localizations: localizations(
+                command.name_localizations.as_ref(),
+                command.description_localizations.as_ref(),
+            ),
+            nsfw: command.nsfw.unwrap_or_default(),
+            default_member_permissions,
+            contexts: command
+                .contexts
+                .iter()
+                .flatten()
+                .map(context_name)
+                .collect(),
+            integration_types: command
+                .integration_types
+                .iter()
+                .flatten()
+                .map(integration_type_name)
+                .collect(),
+            category: command.category.clone(),
+            metadata: command
+                .metadata
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+            options: command.options.iter().map(OptionManifest::new).collect(),
+        }
+    }
+}
+
+/// Exported option or subcommand, part of a [`CommandManifest`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OptionManifest {
+    /// Name of the option.
+    pub name: String,
+    /// Description of the option.
+    pub description: String,
+    /// Type of the option, such as `string` or `subcommand`.
+    pub kind: &'static str,
+    /// Localized names and descriptions, by locale.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub localizations: BTreeMap<String, Localization>,
+    /// Whether the option is required.
+    pub required: bool,
+    /// Whether the option is autocompleted.
+    pub autocomplete: bool,
+    /// Minimum value of `INTEGER` and `NUMBER` options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_value: Option<CommandOptionValue>,
+    /// Maximum value of `INTEGER` and `NUMBER` options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<CommandOptionValue>,
+    /// Minimum length of `STRING` options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u16>,
+    /// Maximum length of `STRING` options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u16>,
+    /// Channel types allowed for `CHANNEL` options.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub channel_types: Vec<&'static str>,
+    /// Choices of the option.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub choices: Vec<ChoiceManifest>,
+    /// Options of a subcommand, or subcommands of a subcommand group.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<OptionManifest>,
+}
+
+impl OptionManifest {
+    /// Create the manifest of an option.
+    pub fn new(option: &CommandOption) -> Self {
+        Self {
+            name: option.name.clone(),
+            description: option.description.clone(),
+            kind: option_type(option.kind),
+            localizations: localizations(
+                option.name_localizations.as_ref(),
+                option.description_localizations.as_ref(),
+            ),
+            required: option.required.unwrap_or_default(),
+            autocomplete: option.autocomplete.unwrap_or_default(),
+            min_value: option.min_value,
+            max_value: option.max_value,
+            min_length: option.min_length,
+            max_length: option.max_length,
+            channel_types: option
+                .channel_types
+                .iter()
+                .flatten()
+                .map(|kind| kind.name())
+                .collect(),
+            choices: option
+                .choices
+                .iter()
+                .flatten()
+                .map(ChoiceManifest::new)
+                .collect(),
+            options: option
+                .options
+                .iter()
+                .flatten()
+                .map(OptionManifest::new)
+                .collect(),
+        }
+    }
+}
+
+/// Exported option choice, part of an [`OptionManifest`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChoiceManifest {
+    /// Name of the choice.
+    pub name: String,
+    /// Value of the choice.
+    pub value: CommandOptionChoiceValue,
+    /// Localized names, by locale.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub localizations: BTreeMap<String, String>,
+}
+
+impl ChoiceManifest {
+    /// Create the manifest of a choice.
+    pub fn new(choice: &CommandOptionChoice) -> Self {
+        Self {
+            name: choice.name.clone(),
+            value: choice.value.clone(),
+            localizations: choice
+                .name_localizations
+                .iter()
+                .flatten()
+                .map(|(locale, name)| (locale.clone(), name.clone()))
+                .collect(),
+        }
+    }
+}
+
+/// Localized name and description of a command or an option.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Localization {
+    /// Localized name, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Localized description, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Merge name and description localizations by locale.
+fn localizations(
+    names: Option<&HashMap<String, String>>,
+    descriptions: Option<&HashMap<String, String>>,
+) -> BTreeMap<String, Localization> {
+    let mut localizations: BTreeMap<String, Localization> = BTreeMap::new();
+
+    for (locale, name) in names.into_iter().flatten() {
+        localizations.entry(locale.clone()).or_default().name = Some(name.clone());
+    }
+
+    for (locale, description) in descriptions.into_iter().flatten() {
+        localizations.entry(locale.clone()).or_default().description = Some(description.clone());
+    }
+
+    localizations
+}
+
+/// Name of an interaction context type.
+fn context_name(context: &InteractionContextType) -> &'static str {
+    match context {
+        InteractionContextType::Guild => "guild",
+        InteractionContextType::BotDm => "bot_dm",
+        InteractionContextType::PrivateChannel => "private_channel",
+        _ => "unknown",
+    }
+}
+
+/// Name of an application integration type.
+fn integration_type_name(kind: &ApplicationIntegrationType) -> &'static str {
+    match kind {
+        ApplicationIntegrationType::GuildInstall => "guild_install",
+        ApplicationIntegrationType::UserInstall => "user_install",
+        _ => "unknown",
+    }
+}
@@ -0,0 +1,53 @@
+//! Helpers built on top of twilight-http.
+//!
+//! This module requires the `http` feature and provides helpers to perform
+//! common requests related to commands with a twilight-http
+//! [`InteractionClient`].
+//!
+//! ## Command registration
+//! The [`register_global`] and [`register_guild`] functions convert commands
+//! and replace the global or guild commands of the application with them.
+//!
+//! ```no_run
+//! use twilight_http::Client;
+//! use twilight_interactions::{command::CreateCommand, http::register_global};
+//! use twilight_model::id::Id;
+//!
+//! #[derive(CreateCommand)]
+//! #[command(name = "hello", desc = "Say hello")]
+//! struct HelloCommand;
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new("token".to_owned());
+//! let interaction = client.interaction(Id::new(1));
+//!
+//! register_global(&interaction, &[HelloCommand::create_command()]).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Command permissions
+//! The [`fetch_permissions`] and [`sync_permissions`] functions manage the
+//! permission overrides of commands in a guild. Commands are identified by
+//! their name, and overrides are built with the [`PermissionOverrideSet`]
+//! type.
+//!
+//! ## Interaction responses
+//! When the `tokio` feature is enabled, the [`defer_response`] and
+//! [`respond_timeout`] functions send the responses used to defer slow
+//! handlers and report timeouts. They are used by
+//! [`CommandRegistry::respond_with`].
+//!
+//! [`InteractionClient`]: twilight_http::client::InteractionClient
+//! [`CommandRegistry::respond_with`]: crate::registry::CommandRegistry::respond_with
+
+mod permissions;
+mod register;
+#[cfg(feature = "tokio")]
+mod respond;
+
+pub use permissions::{fetch_permissions, sync_permissions, PermissionOverrideSet};
+pub use register::{register_global, register_guild};
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub use respond::{defer_response, respond_timeout};
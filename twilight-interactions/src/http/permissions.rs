@@ -0,0 +1,222 @@
+//! Guild command permissions helpers.
+
+use std::collections::HashMap;
+
+use twilight_http::client::InteractionClient;
+use twilight_model::{
+    application::command::{
+        permissions::{CommandPermission, CommandPermissionType},
+        Command, CommandType,
+    },
+    id::{
+        marker::{ChannelMarker, CommandMarker, GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+use crate::error::HttpError;
+
+/// Set of permission overrides of a command in a guild.
+///
+/// Each override allows or denies the usage of the command for a role, a user
+/// or a channel. Setting an override for a target that already has one
+/// replaces it.
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::http::PermissionOverrideSet;
+/// use twilight_model::id::Id;
+///
+/// let guild_id = Id::new(1);
+///
+/// let overrides = PermissionOverrideSet::new()
+///     .everyone(guild_id, false)
+///     .role(Id::new(2), true)
+///     .user(Id::new(3), true);
+///
+/// assert_eq!(overrides.permissions().len(), 3);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PermissionOverrideSet {
+    permissions: Vec<CommandPermission>,
+}
+
+impl PermissionOverrideSet {
+    /// Create a new empty [`PermissionOverrideSet`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow or deny the command for members with a role.
+    pub fn role(self, role_id: Id<RoleMarker>, allowed: bool) -> Self {
+        self.set(CommandPermissionType::Role(role_id), allowed)
+    }
+
+    /// Allow or deny the command for a user.
+    pub fn user(self, user_id: Id<UserMarker>, allowed: bool) -> Self {
+        self.set(CommandPermissionType::User(user_id), allowed)
+    }
+
+    /// Allow or deny the command in a channel.
+    pub fn channel(self, channel_id: Id<ChannelMarker>, allowed: bool) -> Self {
+        self.set(CommandPermissionType::Channel(channel_id), allowed)
+    }
+
+    /// Allow or deny the command for all members of the guild.
+    ///
+    /// This sets an override for the `@everyone` role, whose ID is the same as
+    /// the guild ID.
+    pub fn everyone(self, guild_id: Id<GuildMarker>, allowed: bool) -> Self {
+        self.role(guild_id.cast(), allowed)
+    }
+
+    /// Allow or deny the command in all channels of the guild.
+    ///
+    /// This sets an override for the constant `guild_id - 1` used by Discord
+    /// to represent all channels.
+    pub fn all_channels(self, guild_id: Id<GuildMarker>, allowed: bool) -> Self {
+        match Id::new_checked(guild_id.get() - 1) {
+            Some(channel_id) => self.channel(channel_id, allowed),
+            None => self,
+        }
+    }
+
+    /// Get the list of permission overrides.
+    pub fn permissions(&self) -> &[CommandPermission] {
+        &self.permissions
+    }
+
+    /// Whether the set contains the same overrides as the provided list,
+    /// regardless of their order.
+    fn matches(&self, permissions: &[CommandPermission]) -> bool {
+        self.permissions.len() == permissions.len()
+            && self
+                .permissions
+                .iter()
+                .all(|permission| permissions.contains(permission))
+    }
+
+    fn set(mut self, id: CommandPermissionType, permission: bool) -> Self {
+        let override_ = CommandPermission { id, permission };
+
+        match self.permissions.iter_mut().find(|other| other.id == id) {
+            Some(other) => *other = override_,
+            None => self.permissions.push(override_),
+        }
+
+        self
+    }
+}
+
+impl From<Vec<CommandPermission>> for PermissionOverrideSet {
+    fn from(permissions: Vec<CommandPermission>) -> Self {
+        permissions
+            .into_iter()
+            .fold(Self::new(), |set, permission| {
+                set.set(permission.id, permission.permission)
+            })
+    }
+}
+
+impl From<PermissionOverrideSet> for Vec<CommandPermission> {
+    fn from(set: PermissionOverrideSet) -> Self {
+        set.permissions
+    }
+}
+
+/// Fetch the permission overrides of commands in a guild.
+///
+/// The returned map is keyed by command name and only contains chat input
+/// commands with at least one override. Overrides applying to all commands of
+/// the application are not included.
+pub async fn fetch_permissions(
+    client: &InteractionClient<'_>,
+    guild_id: Id<GuildMarker>,
+) -> Result<HashMap<String, PermissionOverrideSet>, HttpError> {
+    let commands = command_ids(client, guild_id).await?;
+    let permissions = client
+        .guild_command_permissions(guild_id)
+        .await?
+        .models()
+        .await?;
+
+    let overrides = permissions
+        .into_iter()
+        .filter_map(|permissions| {
+            let (name, _) = commands.iter().find(|(_, id)| *id == permissions.id)?;
+
+            Some((name.clone(), permissions.permissions.into()))
+        })
+        .collect();
+
+    Ok(overrides)
+}
+
+/// Synchronize the permission overrides of commands in a guild.
+///
+/// Commands are identified by their name, and can be either global or guild
+/// commands. The overrides of a command are only updated if they differ from
+/// the current ones. The names of the updated commands are returned.
+///
+/// Updating command permissions requires the client to be configured with an
+/// OAuth2 Bearer token that has the `applications.commands.permissions.update`
+/// scope.
+pub async fn sync_permissions(
+    client: &InteractionClient<'_>,
+    guild_id: Id<GuildMarker>,
+    overrides: &[(&str, PermissionOverrideSet)],
+) -> Result<Vec<String>, HttpError> {
+    let commands = command_ids(client, guild_id).await?;
+    let current = client
+        .guild_command_permissions(guild_id)
+        .await?
+        .models()
+        .await?;
+
+    let mut updated = Vec::new();
+
+    for (name, set) in overrides {
+        let Some((_, command_id)) = commands.iter().find(|(other, _)| other == name) else {
+            return Err(HttpError::UnknownCommand((*name).to_owned()));
+        };
+
+        let current = current
+            .iter()
+            .find(|permissions| permissions.id == *command_id)
+            .map(|permissions| &*permissions.permissions)
+            .unwrap_or_default();
+
+        if set.matches(current) {
+            continue;
+        }
+
+        client
+            .update_command_permissions(guild_id, *command_id, set.permissions())
+            .await?;
+
+        updated.push((*name).to_owned());
+    }
+
+    Ok(updated)
+}
+
+/// Fetch the name and ID of global and guild chat input commands.
+///
+/// Guild commands are listed first, so they take precedence over global
+/// commands with the same name.
+async fn command_ids(
+    client: &InteractionClient<'_>,
+    guild_id: Id<GuildMarker>,
+) -> Result<Vec<(String, Id<CommandMarker>)>, HttpError> {
+    let guild = client.guild_commands(guild_id).await?.models().await?;
+    let global = client.global_commands().await?.models().await?;
+
+    let ids = guild
+        .into_iter()
+        .chain(global)
+        .filter(|command| command.kind == CommandType::ChatInput)
+        .filter_map(|command: Command| Some((command.name, command.id?)))
+        .collect();
+
+    Ok(ids)
+}
@@ -0,0 +1,53 @@
+//! Command registration helpers.
+
+use twilight_http::client::InteractionClient;
+use twilight_model::{
+    application::command::Command,
+    id::{marker::GuildMarker, Id},
+};
+
+use crate::{command::ApplicationCommandData, error::HttpError};
+
+/// Register the global commands of the application.
+///
+/// Commands are converted into [`Command`]s and replace all existing global
+/// commands. Commands that are not part of the list are deleted. The
+/// registered commands are returned, including their IDs.
+pub async fn register_global(
+    client: &InteractionClient<'_>,
+    commands: &[ApplicationCommandData],
+) -> Result<Vec<Command>, HttpError> {
+    let commands = into_commands(commands);
+    let registered = client
+        .set_global_commands(&commands)
+        .await?
+        .models()
+        .await?;
+
+    Ok(registered)
+}
+
+/// Register the commands of the application in a guild.
+///
+/// This works like [`register_global`], but replaces the commands of a
+/// single guild. Guild commands are updated instantly, which makes them
+/// useful when testing commands.
+pub async fn register_guild(
+    client: &InteractionClient<'_>,
+    guild_id: Id<GuildMarker>,
+    commands: &[ApplicationCommandData],
+) -> Result<Vec<Command>, HttpError> {
+    let commands = into_commands(commands);
+    let registered = client
+        .set_guild_commands(guild_id, &commands)
+        .await?
+        .models()
+        .await?;
+
+    Ok(registered)
+}
+
+/// Convert commands into the format sent to Discord.
+fn into_commands(commands: &[ApplicationCommandData]) -> Vec<Command> {
+    commands.iter().cloned().map(Command::from).collect()
+}
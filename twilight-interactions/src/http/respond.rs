@@ -0,0 +1,55 @@
+//! Interaction responses sent by the command registry.
+
+use twilight_http::client::InteractionClient;
+use twilight_model::http::interaction::{InteractionResponse, InteractionResponseType};
+
+use crate::{
+    error::{HandlerTimeout, HttpError},
+    registry::InteractionToken,
+};
+
+/// Defer the response to an interaction.
+///
+/// A [`DeferredChannelMessageWithSource`] response is sent, which shows a
+/// loading state to the user until the original response is updated.
+///
+/// [`DeferredChannelMessageWithSource`]: InteractionResponseType::DeferredChannelMessageWithSource
+pub async fn defer_response(
+    client: &InteractionClient<'_>,
+    token: &InteractionToken,
+) -> Result<(), HttpError> {
+    let response = InteractionResponse {
+        kind: InteractionResponseType::DeferredChannelMessageWithSource,
+        data: None,
+    };
+
+    client
+        .create_response(token.interaction_id, &token.token, &response)
+        .await?;
+
+    Ok(())
+}
+
+/// Tell the user that the handler of an interaction timed out.
+///
+/// If the interaction has been deferred, the original response is updated
+/// with [`HandlerTimeout::MESSAGE`]. Otherwise, the
+/// [`HandlerTimeout::response`] is sent.
+pub async fn respond_timeout(
+    client: &InteractionClient<'_>,
+    token: &InteractionToken,
+    timeout: &HandlerTimeout,
+) -> Result<(), HttpError> {
+    if timeout.deferred {
+        client
+            .update_response(&token.token)
+            .content(Some(HandlerTimeout::MESSAGE))
+            .await?;
+    } else {
+        client
+            .create_response(token.interaction_id, &token.token, &timeout.response())
+            .await?;
+    }
+
+    Ok(())
+}
@@ -13,10 +13,114 @@
 //!
 //! See the [`command`] module for more information.
 //!
+//! ### Autocomplete
+//! The [`autocomplete`] module provides helpers to respond to autocomplete
+//! interactions, such as filtering choices matching the input of the user.
+//!
+//! ### Message components
+//! The [`component`] module provides the [`SelectOption`] trait to create the
+//! options of select menus from enums, and the [`SelectMenuModel`] trait to
+//! parse select menu interactions into typed structs. The [`CustomId`] trait
+//! stores the state of components in their custom id.
+//!
+//! [`SelectOption`]: component::SelectOption
+//! [`SelectMenuModel`]: component::SelectMenuModel
+//! [`CustomId`]: component::CustomId
+//!
+//! ### Command registry
+//! The [`CommandRegistry`] type can be used to register commands along with
+//! their handler, and dispatch received interactions to the right handler.
+//!
+//! See the [`registry`] module for more information.
+//!
+//! [`CommandRegistry`]: registry::CommandRegistry
+//!
+//! ### Input sanitization
+//! The [`sanitize`] module escapes markdown and mentions in option values
+//! before they are included in responses, and truncates them to the length
+//! limits of Discord.
+//!
+//! ### Usage statistics
+//! The [`stats`] module aggregates events emitted by the registry into
+//! per-command usage statistics, such as error rates and latencies.
+//!
+//! ### Command synchronization
+//! The [`sync`] module compares local commands with commands registered on
+//! Discord, and computes the operations required to update them.
+//!
+//! ### Migration
+//! The [`migrate`] module helps moving commands created with the twilight
+//! command builders to the derive macros of this crate.
+//!
+//! ### Command audit
+//! The [`audit`] module checks commands against user experience guidelines,
+//! such as consistent punctuation in descriptions.
+//!
+//! ### HTTP helpers
+//! When the `http` feature is enabled, the [`http`] module provides helpers
+//! built on top of twilight-http, such as the management of guild command
+//! permissions.
+//!
+//! ### Testing
+//! When the `testing` feature is enabled, the [`testing`] module provides
+//! assertions to check that commands are valid. These tests can be generated
+//! with the `generate_tests` attribute of the derive macros.
+//!
+//! ### Serialization
+//! When the `serde` feature is enabled, [`ApplicationCommandData`] can be
+//! serialized, for example to save command definitions as JSON snapshots.
+//!
+//! [`ApplicationCommandData`]: command::ApplicationCommandData
+//!
+//! ### Documentation export
+//! When the `export` feature is enabled, the [`export`] module creates a
+//! JSON manifest of commands with their options, choices, localizations and
+//! metadata, which can be used to generate the documentation of a
+//! bot.
+//!
+//! ### Tracing
+//! When the `tracing` feature is enabled, types deriving `CommandModel` emit
+//! a `WARN` event with the [tracing](https://docs.rs/tracing) crate when an
+//! interaction fails to parse. Events include the name of the command, the
+//! path of the invalid option and the kind of error.
+//!
+//! ### Prelude
+//! The [`prelude`] module re-exports the traits and derive macros of the
+//! crate along with commonly used twilight-model types, which avoids long
+//! import lists in modules declaring commands.
+//!
+//! ## Re-exporting the crate
+//! Code generated by the derive macros refers to this crate as
+//! `::twilight_interactions`, which does not resolve if the crate is renamed
+//! or re-exported by another crate. The path can be overridden with the
+//! `crate` argument of the type attribute of all derive macros, such as
+//! `#[command(crate = "my_framework::interactions")]` or
+//! `#[select(crate = "...")]`. The `twilight-model` crate must still be a
+//! dependency of the crate using the macros.
+//!
 //! ## Versioning
 //! To facilitate dependencies management, this crate will always use the same
 //! major version as the official `twilight` crates.
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+pub mod audit;
+pub mod autocomplete;
 pub mod command;
+pub mod component;
+pub mod cooldown;
 pub mod error;
+#[cfg(feature = "export")]
+#[cfg_attr(docsrs, doc(cfg(feature = "export")))]
+pub mod export;
+#[cfg(feature = "http")]
+#[cfg_attr(docsrs, doc(cfg(feature = "http")))]
+pub mod http;
+pub mod migrate;
+pub mod prelude;
+pub mod registry;
+pub mod sanitize;
+pub mod stats;
+pub mod sync;
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
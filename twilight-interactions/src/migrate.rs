@@ -0,0 +1,495 @@
+//! Migration of existing commands to the derive macros.
+//!
+//! Commands created manually with the twilight [`Command`] type can be
+//! converted to [`ApplicationCommandData`] with
+//! [`ApplicationCommandData::from_twilight_builder_output`], which allows
+//! using them alongside commands created with the [`CreateCommand`] trait.
+//!
+//! To move these commands to the derive macros, the [`suggest_derive`]
+//! function generates the source code of equivalent command models. The
+//! generated code is a starting point and should be reviewed, for instance to
+//! give more meaningful names to the generated types.
+//!
+//! ## Example
+//! ```
+//! use twilight_interactions::{command::ApplicationCommandData, migrate::suggest_derive};
+//! use twilight_model::application::command::Command;
+//! # use twilight_interactions::command::CreateCommand;
+//! #
+//! # #[derive(CreateCommand)]
+//! # #[command(name = "hello", desc = "Say hello")]
+//! # struct HelloCommand {
+//! #     /// The message to send
+//! #     message: String,
+//! # }
+//!
+//! // Command created with the twilight command builders
+//! let command: Command = legacy_command();
+//!
+//! let data = ApplicationCommandData::from_twilight_builder_output(command.clone())?;
+//! assert_eq!(data.name, "hello");
+//!
+//! println!("{}", suggest_derive(&command));
+//! # fn legacy_command() -> Command {
+//! #     HelloCommand::create_command().into()
+//! # }
+//! # Ok::<(), twilight_interactions::error::CommandConversionError>(())
+//! ```
+//!
+//! [`ApplicationCommandData`]: crate::command::ApplicationCommandData
+//! [`ApplicationCommandData::from_twilight_builder_output`]:
+//!     crate::command::ApplicationCommandData::from_twilight_builder_output
+//! [`CreateCommand`]: crate::command::CreateCommand
+
+use std::{collections::HashMap, fmt::Write};
+
+use twilight_model::{
+    application::command::{
+        Command, CommandOption, CommandOptionChoice, CommandOptionChoiceValue, CommandOptionType,
+        CommandOptionValue,
+    },
+    application::interaction::InteractionContextType,
+    channel::ChannelType,
+    guild::Permissions,
+    oauth::ApplicationIntegrationType,
+};
+
+/// Generate the source code of command models equivalent to a [`Command`].
+///
+/// The generated code contains a type deriving [`CommandModel`] and
+/// [`CreateCommand`] for the command and each of its subcommands, a type
+/// deriving [`CommandOption`] and [`CreateOption`] for each option with
+/// choices, and the functions used by the `name_localizations`,
+/// `desc_localizations` and `default_permissions` attributes.
+///
+/// [`CommandModel`]: crate::command::CommandModel
+/// [`CreateCommand`]: crate::command::CreateCommand
+/// [`CommandOption`]: crate::command::CommandOption
+/// [`CreateOption`]: crate::command::CreateOption
+pub fn suggest_derive(command: &Command) -> String {
+    let mut generator = Generator::default();
+
+    let mut attributes = generator.localizations(
+        &[&command.name],
+        &command.name_localizations,
+        &command.description,
+        &command.description_localizations,
+    );
+
+    #[allow(deprecated)]
+    if let Some(dm_permission) = command.dm_permission {
+        attributes.push(format!("dm_permission = {dm_permission}"));
+    }
+
+    if let Some(permissions) = command.default_member_permissions {
+        attributes.push(generator.permissions(&command.name, permissions));
+    }
+
+    if let Some(nsfw) = command.nsfw {
+        attributes.push(format!("nsfw = {nsfw}"));
+    }
+
+    if let Some(contexts) = &command.contexts {
+        let contexts = contexts.iter().filter_map(context_name);
+        attributes.push(format!("contexts = {:?}", join(contexts)));
+    }
+
+    if let Some(integration_types) = &command.integration_types {
+        let integration_types = integration_types.iter().filter_map(integration_type_name);
+        attributes.push(format!("integration_types = {:?}", join(integration_types)));
+    }
+
+    generator.command(&[&command.name], attributes, &command.options);
+
+    let output = generator.output + &generator.choices + &generator.functions;
+    output.trim_end().to_owned()
+}
+
+/// Generator of the source code returned by [`suggest_derive`].
+#[derive(Default)]
+struct Generator {
+    /// Generated command types.
+    output: String,
+    /// Generated choice types, appended after the command types.
+    choices: String,
+    /// Generated functions, appended after the choice types.
+    functions: String,
+}
+
+impl Generator {
+    /// Generate the type of a command or subcommand.
+    fn command(&mut self, path: &[&str], attributes: Vec<String>, options: &[CommandOption]) {
+        let ident = format!("{}Command", pascal_case(path));
+        let subcommands = options.iter().any(|option| {
+            matches!(
+                option.kind,
+                CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup
+            )
+        });
+
+        self.output
+            .push_str("#[derive(CommandModel, CreateCommand)]\n");
+        let _ = writeln!(self.output, "#[command({})]", attributes.join(", "));
+
+        let mut children = Vec::new();
+
+        if subcommands {
+            let _ = writeln!(self.output, "enum {ident} {{");
+
+            for option in options {
+                let mut path = path.to_vec();
+                path.push(&option.name);
+
+                let _ = writeln!(self.output, "    #[command(name = {:?})]", option.name);
+                let _ = writeln!(
+                    self.output,
+                    "    {}({}Command),",
+                    pascal_case(&[&option.name]),
+                    pascal_case(&path)
+                );
+
+                children.push((path, option));
+            }
+        } else {
+            let _ = writeln!(self.output, "struct {ident} {{");
+
+            for option in options {
+                self.field(path, option);
+            }
+        }
+
+        self.output.push_str("}\n\n");
+
+        for (path, option) in children {
+            let attributes = self.localizations(
+                &path,
+                &option.name_localizations,
+                &option.description,
+                &option.description_localizations,
+            );
+            let options = option.options.as_deref().unwrap_or_default();
+
+            self.command(&path, attributes, options);
+        }
+    }
+
+    /// Generate a field of a command struct.
+    fn field(&mut self, path: &[&str], option: &CommandOption) {
+        let mut path = path.to_vec();
+        path.push(&option.name);
+
+        let mut attributes = Vec::new();
+        let ident = field_ident(&option.name);
+
+        if ident != option.name {
+            attributes.push(format!("rename = {:?}", option.name));
+        }
+
+        if let Some(localizations) = &option.name_localizations {
+            attributes.push(self.name_localizations(&path, localizations));
+        }
+
+        if let Some(localizations) = &option.description_localizations {
+            attributes.push(self.desc_localizations(&path, &option.description, localizations));
+        }
+
+        if let Some(channel_types) = &option.channel_types {
+            let channel_types = channel_types.iter().filter_map(channel_type_name);
+            attributes.push(format!("channel_types = {:?}", join(channel_types)));
+        }
+
+        for (name, value) in [
+            ("min_value", option.min_value),
+            ("max_value", option.max_value),
+        ] {
+            match value {
+                Some(CommandOptionValue::Integer(value)) => {
+                    attributes.push(format!("{name} = {value}"))
+                }
+                Some(CommandOptionValue::Number(value)) => {
+                    attributes.push(format!("{name} = {value:?}"))
+                }
+                None => (),
+            }
+        }
+
+        for (name, value) in [
+            ("min_length", option.min_length),
+            ("max_length", option.max_length),
+        ] {
+            if let Some(value) = value {
+                attributes.push(format!("{name} = {value}"));
+            }
+        }
+
+        if option.autocomplete == Some(true) {
+            attributes.push("autocomplete = true".to_owned());
+        }
+
+        let mut kind = match option.choices.as_deref() {
+            Some(choices) if !choices.is_empty() => self.choices(&path, choices),
+            _ => option_type(option.kind).to_owned(),
+        };
+
+        if option.required != Some(true) {
+            kind = format!("Option<{kind}>");
+        }
+
+        if option.description_localizations.is_none() {
+            let _ = writeln!(self.output, "    /// {}", option.description);
+        }
+
+        if !attributes.is_empty() {
+            let _ = writeln!(self.output, "    #[command({})]", attributes.join(", "));
+        }
+
+        let _ = writeln!(self.output, "    {ident}: {kind},");
+    }
+
+    /// Generate the enum of an option with choices and return its name.
+    fn choices(&mut self, path: &[&str], choices: &[CommandOptionChoice]) -> String {
+        let ident = pascal_case(path);
+        self.choices
+            .push_str("#[derive(CommandOption, CreateOption)]\n");
+        let _ = writeln!(self.choices, "enum {ident} {{");
+
+        for choice in choices {
+            let mut path = path.to_vec();
+            path.push(&choice.name);
+
+            let value = match &choice.value {
+                CommandOptionChoiceValue::String(value) => format!("{value:?}"),
+                CommandOptionChoiceValue::Integer(value) => value.to_string(),
+                CommandOptionChoiceValue::Number(value) => format!("{value:?}"),
+            };
+            let mut attributes = vec![format!("name = {:?}", choice.name)];
+
+            if let Some(localizations) = &choice.name_localizations {
+                let function = self.function(
+                    &path,
+                    "name",
+                    "ChoiceLocalizations",
+                    format!("ChoiceLocalizations::new({})", locales(localizations)),
+                );
+                attributes.push(format!("name_localizations = {function:?}"));
+            }
+
+            attributes.push(format!("value = {value}"));
+
+            let _ = writeln!(self.choices, "    #[option({})]", attributes.join(", "));
+            let _ = writeln!(self.choices, "    {},", variant_ident(&choice.name));
+        }
+
+        self.choices.push_str("}\n\n");
+
+        ident
+    }
+
+    /// Generate the `name`, `name_localizations`, `desc` and
+    /// `desc_localizations` attributes of a command.
+    fn localizations(
+        &mut self,
+        path: &[&str],
+        name_localizations: &Option<HashMap<String, String>>,
+        description: &str,
+        description_localizations: &Option<HashMap<String, String>>,
+    ) -> Vec<String> {
+        let mut attributes = vec![format!("name = {:?}", path[path.len() - 1])];
+
+        if let Some(localizations) = name_localizations {
+            attributes.push(self.name_localizations(path, localizations));
+        }
+
+        match description_localizations {
+            Some(localizations) => {
+                attributes.push(self.desc_localizations(path, description, localizations))
+            }
+            None => attributes.push(format!("desc = {description:?}")),
+        }
+
+        attributes
+    }
+
+    /// Generate a `name_localizations` attribute and its function.
+    fn name_localizations(
+        &mut self,
+        path: &[&str],
+        localizations: &HashMap<String, String>,
+    ) -> String {
+        let function = self.function(
+            path,
+            "name",
+            "NameLocalizations",
+            format!("NameLocalizations::new({})", locales(localizations)),
+        );
+
+        format!("name_localizations = {function:?}")
+    }
+
+    /// Generate a `desc_localizations` attribute and its function.
+    fn desc_localizations(
+        &mut self,
+        path: &[&str],
+        description: &str,
+        localizations: &HashMap<String, String>,
+    ) -> String {
+        let function = self.function(
+            path,
+            "desc",
+            "DescLocalizations",
+            format!(
+                "DescLocalizations::new({description:?}, {})",
+                locales(localizations)
+            ),
+        );
+
+        format!("desc_localizations = {function:?}")
+    }
+
+    /// Generate a `default_permissions` attribute and its function.
+    fn permissions(&mut self, name: &str, permissions: Permissions) -> String {
+        let function = self.function(
+            &[name],
+            "permissions",
+            "Permissions",
+            format!("Permissions::from_bits_truncate({})", permissions.bits()),
+        );
+
+        format!("default_permissions = {function:?}")
+    }
+
+    /// Generate a function returning an expression and return its name.
+    fn function(&mut self, path: &[&str], suffix: &str, kind: &str, body: String) -> String {
+        let name = format!("{}_{suffix}", snake_case(path));
+        let _ = writeln!(self.functions, "fn {name}() -> {kind} {{\n    {body}\n}}\n");
+
+        name
+    }
+}
+
+/// Type used for an option without choices.
+fn option_type(kind: CommandOptionType) -> &'static str {
+    match kind {
+        CommandOptionType::String => "String",
+        CommandOptionType::Integer => "i64",
+        CommandOptionType::Number => "f64",
+        CommandOptionType::Boolean => "bool",
+        CommandOptionType::User => "ResolvedUser",
+        CommandOptionType::Channel => "InteractionChannel",
+        CommandOptionType::Role => "Role",
+        CommandOptionType::Mentionable => "ResolvedMentionable",
+        CommandOptionType::Attachment => "Attachment",
+        _ => "()",
+    }
+}
+
+/// Name of a channel type in the `channel_types` attribute.
+fn channel_type_name(kind: &ChannelType) -> Option<&'static str> {
+    let name = match kind {
+        ChannelType::GuildText => "guild_text",
+        ChannelType::Private => "private",
+        ChannelType::GuildVoice => "guild_voice",
+        ChannelType::Group => "group",
+        ChannelType::GuildCategory => "guild_category",
+        ChannelType::GuildAnnouncement => "guild_announcement",
+        ChannelType::AnnouncementThread => "announcement_thread",
+        ChannelType::PublicThread => "public_thread",
+        ChannelType::PrivateThread => "private_thread",
+        ChannelType::GuildStageVoice => "guild_stage_voice",
+        ChannelType::GuildDirectory => "guild_directory",
+        ChannelType::GuildForum => "guild_forum",
+        ChannelType::GuildMedia => "guild_media",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Name of a context type in the `contexts` attribute.
+fn context_name(kind: &InteractionContextType) -> Option<&'static str> {
+    match kind {
+        InteractionContextType::Guild => Some("guild"),
+        InteractionContextType::BotDm => Some("bot_dm"),
+        InteractionContextType::PrivateChannel => Some("private_channel"),
+        _ => None,
+    }
+}
+
+/// Name of an integration type in the `integration_types` attribute.
+fn integration_type_name(kind: &ApplicationIntegrationType) -> Option<&'static str> {
+    match kind {
+        ApplicationIntegrationType::GuildInstall => Some("guild_install"),
+        ApplicationIntegrationType::UserInstall => Some("user_install"),
+        _ => None,
+    }
+}
+
+/// Format localizations as an array of tuples, sorted by locale.
+fn locales(localizations: &HashMap<String, String>) -> String {
+    let mut localizations = localizations.iter().collect::<Vec<_>>();
+    localizations.sort();
+
+    let localizations = localizations
+        .into_iter()
+        .map(|(locale, value)| format!("({locale:?}, {value:?})"))
+        .collect::<Vec<_>>();
+
+    format!("[{}]", localizations.join(", "))
+}
+
+fn join<'a>(values: impl Iterator<Item = &'a str>) -> String {
+    values.collect::<Vec<_>>().join(" ")
+}
+
+/// Split names into words on any non-alphanumeric character.
+fn words<'a>(path: &'a [&'a str]) -> impl Iterator<Item = &'a str> + 'a {
+    path.iter()
+        .flat_map(|name| name.split(|c: char| !c.is_alphanumeric()))
+        .filter(|word| !word.is_empty())
+}
+
+fn pascal_case(path: &[&str]) -> String {
+    words(path)
+        .map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().map(|c| c.to_uppercase().to_string());
+
+            first.unwrap_or_default() + chars.as_str()
+        })
+        .collect()
+}
+
+fn snake_case(path: &[&str]) -> String {
+    words(path)
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Identifier of a struct field, avoiding Rust keywords.
+fn field_ident(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+        "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+        "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true",
+        "type", "unsafe", "use", "where", "while",
+    ];
+
+    let ident = snake_case(&[name]);
+
+    if KEYWORDS.contains(&ident.as_str()) {
+        format!("{ident}_")
+    } else {
+        ident
+    }
+}
+
+/// Identifier of a choice enum variant.
+fn variant_ident(name: &str) -> String {
+    let ident = pascal_case(&[name]);
+
+    match ident.chars().next() {
+        Some(c) if c.is_alphabetic() => ident,
+        _ => format!("Choice{ident}"),
+    }
+}
@@ -0,0 +1,47 @@
+//! Commonly used traits and types.
+//!
+//! This module re-exports the traits and derive macros of the crate, along
+//! with the twilight-model types frequently used in command models. It is
+//! intended to be glob-imported in modules declaring commands.
+//!
+//! ```
+//! use twilight_interactions::prelude::*;
+//!
+//! #[derive(CommandModel, CreateCommand)]
+//! #[command(name = "greet", desc = "Greet a user", default_permissions = "permissions")]
+//! struct GreetCommand {
+//!     /// User to greet.
+//!     user: ResolvedUser,
+//!     /// Channel to send the message in.
+//!     #[command(channel_types = "guild_text")]
+//!     channel: Option<Id<ChannelMarker>>,
+//! }
+//!
+//! fn permissions() -> Permissions {
+//!     Permissions::SEND_MESSAGES
+//! }
+//! ```
+
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use crate::command::SlashCommand;
+pub use crate::{
+    command::{
+        ApplicationCommandData, AutocompleteValue, ChoiceLocalizations, CommandInputData,
+        CommandModel, CommandOption, CommandRouter, CreateAutocomplete, CreateCommand,
+        CreateOption, DescLocalizations, FocusedOption, NameLocalizations, ResolvedChannel,
+        ResolvedMember, ResolvedMentionable, ResolvedUser,
+    },
+    component::{CustomId, SelectMenuModel, SelectOption},
+};
+pub use twilight_model::{
+    channel::{Attachment, ChannelType},
+    guild::{Permissions, Role},
+    id::{
+        marker::{
+            AttachmentMarker, ChannelMarker, GenericMarker, GuildMarker, RoleMarker, UserMarker,
+        },
+        Id,
+    },
+    user::User,
+};
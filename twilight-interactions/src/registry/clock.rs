@@ -0,0 +1,65 @@
+//! Time source used by the registry.
+
+use std::{
+    future::{poll_fn, Future},
+    pin::Pin,
+    task::Poll,
+    time::{Duration, Instant},
+};
+
+/// Delay within which Discord requires an initial response to an interaction.
+pub const RESPONSE_DEADLINE: Duration = Duration::from_secs(3);
+
+/// Delay during which an interaction token can be used to send follow-up
+/// messages or edit the original response.
+pub const TOKEN_LIFETIME: Duration = Duration::from_secs(15 * 60);
+
+/// Boxed future returned by [`Clock::sleep_until`].
+pub type Sleep = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Source of time used to defer handlers and enforce timeouts.
+///
+/// The registry uses [`TokioClock`] by default. Another clock can be set with
+/// [`CommandRegistry::clock`], for example the `MockClock` of the `testing`
+/// module to simulate the response windows of Discord in tests.
+///
+/// [`CommandRegistry::clock`]: super::CommandRegistry::clock
+pub trait Clock: Send + Sync {
+    /// Get the current time.
+    fn now(&self) -> Instant;
+
+    /// Wait until a deadline is reached.
+    fn sleep_until(&self, deadline: Instant) -> Sleep;
+}
+
+/// Clock using the Tokio time driver.
+///
+/// Time can be paused and advanced with Tokio's `test-util` feature.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioClock;
+
+impl Clock for TokioClock {
+    fn now(&self) -> Instant {
+        tokio::time::Instant::now().into_std()
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Sleep {
+        Box::pin(tokio::time::sleep_until(deadline.into()))
+    }
+}
+
+/// Poll a future until it completes or a sleep ends, returning `None` if the
+/// sleep ended first.
+pub(super) async fn race<F: Future>(
+    mut future: Pin<&mut F>,
+    mut sleep: Sleep,
+) -> Option<F::Output> {
+    poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            return Poll::Ready(Some(output));
+        }
+
+        sleep.as_mut().poll(cx).map(|()| None)
+    })
+    .await
+}
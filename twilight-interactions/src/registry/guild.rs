@@ -0,0 +1,111 @@
+//! Commands restricted to some guilds.
+
+use std::collections::HashSet;
+
+use twilight_model::{
+    application::command::Command,
+    id::{marker::GuildMarker, Id},
+};
+
+use super::CommandRegistry;
+use crate::{
+    command::{ApplicationCommandData, CreateCommand},
+    sync::{diff, SyncPlan},
+};
+
+/// Guilds where a command is registered.
+pub(super) enum GuildScope {
+    /// Fixed set of guilds.
+    Guilds(HashSet<Id<GuildMarker>>),
+    /// Guilds matching a predicate.
+    Predicate(Box<dyn Fn(Id<GuildMarker>) -> bool + Send + Sync>),
+}
+
+impl GuildScope {
+    /// Whether the command is registered in the guild.
+    fn contains(&self, guild_id: Id<GuildMarker>) -> bool {
+        match self {
+            Self::Guilds(guilds) => guilds.contains(&guild_id),
+            Self::Predicate(predicate) => predicate(guild_id),
+        }
+    }
+}
+
+impl<R> CommandRegistry<R> {
+    /// Restrict a command to a set of guilds.
+    ///
+    /// The command is no longer returned by [`commands`], and must be
+    /// registered in each guild with the commands returned by
+    /// [`guild_commands`]. The command can be registered before or after
+    /// calling this method. Calling it again for the same command replaces
+    /// the previous set of guilds.
+    ///
+    /// [`commands`]: Self::commands
+    /// [`guild_commands`]: Self::guild_commands
+    pub fn guilds<T>(mut self, guilds: impl IntoIterator<Item = Id<GuildMarker>>) -> Self
+    where
+        T: CreateCommand,
+    {
+        let scope = GuildScope::Guilds(guilds.into_iter().collect());
+        self.guild_scopes.insert(T::NAME.to_owned(), scope);
+
+        self
+    }
+
+    /// Restrict a command to the guilds matching a predicate.
+    ///
+    /// This works like [`guilds`], but the predicate is called each time
+    /// the commands of a guild are computed. It can depend on state shared
+    /// with the rest of the bot, such as features enabled in each guild.
+    ///
+    /// [`guilds`]: Self::guilds
+    pub fn guilds_where<T, F>(mut self, predicate: F) -> Self
+    where
+        T: CreateCommand,
+        F: Fn(Id<GuildMarker>) -> bool + Send + Sync + 'static,
+    {
+        let scope = GuildScope::Predicate(Box::new(predicate));
+        self.guild_scopes.insert(T::NAME.to_owned(), scope);
+
+        self
+    }
+
+    /// Get the commands to register in a guild.
+    ///
+    /// Only commands restricted to guilds including this one are returned.
+    /// The returned commands can be registered to Discord, for example using
+    /// twilight-http's `set_guild_commands` method.
+    pub fn guild_commands(&self, guild_id: Id<GuildMarker>) -> Vec<Command> {
+        self.guild_command_data(guild_id)
+            .into_iter()
+            .map(Command::from)
+            .collect()
+    }
+
+    /// Compare the commands to register in a guild against the commands
+    /// currently registered in this guild.
+    ///
+    /// The returned [`SyncPlan`] contains the operations needed to update the
+    /// commands of the guild, such as after the features enabled in the guild
+    /// changed. See the [`sync`](crate::sync) module for more information.
+    pub fn diff_guild(&self, guild_id: Id<GuildMarker>, remote: &[Command]) -> SyncPlan {
+        diff(&self.guild_command_data(guild_id), remote)
+    }
+
+    /// Whether a command is restricted to some guilds.
+    pub(super) fn is_guild_command(&self, data: &ApplicationCommandData) -> bool {
+        self.guild_scopes.contains_key(&data.name)
+    }
+
+    /// Get the data of the commands to register in a guild.
+    fn guild_command_data(&self, guild_id: Id<GuildMarker>) -> Vec<ApplicationCommandData> {
+        self.command_data()
+            .filter(|data| {
+                self.guild_scopes
+                    .get(&data.name)
+                    .is_some_and(|scope| scope.contains(guild_id))
+            })
+            .cloned()
+            .collect()
+    }
+}
@@ -0,0 +1,57 @@
+use std::future::Future;
+
+use twilight_model::application::interaction::Interaction;
+
+use crate::command::{CommandModel, CreateCommand};
+
+/// Command model that runs itself.
+///
+/// This trait allows implementing the handler of a command on its model
+/// instead of registering a closure. Types implementing this trait are
+/// registered with [`CommandRegistry::handler`], which parses the received
+/// command and calls [`run`].
+///
+/// The [`Output`] of the handler is returned by [`CommandRegistry::handle`],
+/// and can be a [`Result`] to report errors.
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::{
+///     command::{CommandModel, CreateCommand},
+///     registry::{CommandHandler, CommandRegistry},
+/// };
+/// use twilight_model::application::interaction::Interaction;
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "hello", desc = "Say hello")]
+/// struct HelloCommand {
+///     /// The message to send.
+///     message: String,
+/// }
+///
+/// impl CommandHandler for HelloCommand {
+///     type Output = Result<String, String>;
+///
+///     async fn run(self, _interaction: Interaction) -> Self::Output {
+///         Ok(format!("Hello {}!", self.message))
+///     }
+/// }
+///
+/// let registry = CommandRegistry::new().handler::<HelloCommand>();
+/// assert_eq!(registry.commands()[0].name, "hello");
+/// ```
+///
+/// [`CommandRegistry::handler`]: super::CommandRegistry::handler
+/// [`CommandRegistry::handle`]: super::CommandRegistry::handle
+/// [`run`]: Self::run
+/// [`Output`]: Self::Output
+pub trait CommandHandler: CommandModel + CreateCommand + Send + 'static {
+    /// Output of the handler.
+    type Output;
+
+    /// Run the command.
+    ///
+    /// The handler receives the [`Interaction`] the command was received
+    /// with, which can be used to respond to it.
+    fn run(self, interaction: Interaction) -> impl Future<Output = Self::Output> + Send;
+}
@@ -0,0 +1,771 @@
+//! Command registration and dispatch.
+//!
+//! The [`CommandRegistry`] type holds a list of commands along with their
+//! handler. It can be used both to obtain the list of commands to register to
+//! Discord and to dispatch received interactions to the right handler.
+//!
+//! Handlers are closures that receive the parsed command model and the
+//! [`Interaction`] the command was received with, and return a [`Future`]. The
+//! registry does not depend on a specific async runtime and does not send
+//! responses by itself: the output of the handler is returned by
+//! [`CommandRegistry::handle`].
+//!
+//! ## Example
+//! ```
+//! use twilight_interactions::{
+//!     command::{CommandModel, CreateCommand},
+//!     registry::CommandRegistry,
+//! };
+//! # use twilight_model::application::interaction::Interaction;
+//!
+//! #[derive(CommandModel, CreateCommand)]
+//! #[command(name = "hello", desc = "Say hello")]
+//! struct HelloCommand {
+//!     /// The message to send.
+//!     message: String,
+//! }
+//!
+//! let registry = CommandRegistry::new().register(|command: HelloCommand, _interaction| {
+//!     async move { format!("Hello {}!", command.message) }
+//! });
+//!
+//! // Commands to register to Discord
+//! let commands = registry.commands();
+//! assert_eq!(commands[0].name, "hello");
+//!
+//! # async fn handle(registry: CommandRegistry<String>, interaction: Interaction) {
+//! // Dispatch a received interaction
+//! let response = registry.handle(interaction).await;
+//! # }
+//! ```
+//!
+//! Handlers can also be implemented on the command model with the
+//! [`CommandHandler`] trait, and registered with [`CommandRegistry::handler`].
+//!
+//! ## Function-style commands
+//! Small commands can be declared as an async function with the
+//! [`command_handler`] attribute macro, instead of a struct and a closure. The
+//! first argument of the function receives the [`Interaction`], and other
+//! arguments are command options configured with the same `#[command]`
+//! attribute as fields of the [`CommandModel`] derive macro. Arguments of the
+//! macro are the type attributes of the [`CreateCommand`] derive macro.
+//!
+//! A `<Name>Command` model is generated from the function name, with a
+//! `register` function adding the command and its handler to a registry.
+//!
+//! ```
+//! use twilight_interactions::{
+//!     command::{command_handler, ResolvedUser},
+//!     registry::CommandRegistry,
+//! };
+//! use twilight_model::application::interaction::Interaction;
+//!
+//! /// Warn a user
+//! #[command_handler(name = "warn")]
+//! async fn warn(
+//!     _interaction: Interaction,
+//!     #[command(desc = "User to warn")] user: ResolvedUser,
+//!     #[command(desc = "Reason of the warning")] reason: Option<String>,
+//! ) -> String {
+//!     format!("Warned {}: {reason:?}", user.resolved.name)
+//! }
+//!
+//! let registry = WarnCommand::register(CommandRegistry::new());
+//! assert_eq!(registry.commands()[0].name, "warn");
+//! ```
+//!
+//! ## Other interaction kinds
+//! The registry only handles application command interactions. Use the
+//! [`InteractionKindRouter`] type to also handle autocomplete, component and
+//! modal interactions from a single entry point.
+//!
+//! ## Panic isolation
+//! By default, a panic in a handler unwinds through [`CommandRegistry::handle`].
+//! Panic isolation can be enabled with [`CommandRegistry::catch_panics`], in
+//! which case panics are converted into a [`DispatchError::Panicked`] error
+//! that can be used to respond to the interaction with an ephemeral error
+//! message.
+//!
+//! ## Timeouts
+//! When the `tokio` feature is enabled, the registry enforces the timeout set
+//! with the `#[command(timeout = "...")]` attribute. A handler exceeding its
+//! timeout is cancelled and a [`DispatchError::TimedOut`] error is returned.
+//!
+//! Since Discord requires a response within 3 seconds, a hook can be
+//! registered with [`CommandRegistry::on_defer`] to defer the interaction when
+//! a handler is still running after 2.5 seconds, and with
+//! [`CommandRegistry::on_timeout`] to tell the user that the command timed
+//! out. When the `http` feature is enabled, [`CommandRegistry::respond_with`]
+//! sets both hooks to send these responses with a twilight-http client.
+//! Timeouts require a Tokio runtime with the time driver enabled, unless
+//! another [`Clock`] is set with [`CommandRegistry::clock`].
+//!
+//! ## Guild commands
+//! Commands can be restricted to some guilds with [`CommandRegistry::guilds`],
+//! or to guilds matching a predicate with [`CommandRegistry::guilds_where`],
+//! for example to only register commands of the features enabled in each
+//! guild. The commands of a guild are returned by
+//! [`CommandRegistry::guild_commands`], and [`CommandRegistry::diff_guild`]
+//! computes the operations needed to update them when these features change.
+//!
+//! ## Cooldowns
+//! Cooldowns set with the `#[command(cooldown = "...")]` attribute are
+//! enforced when a [`CooldownTracker`] is set with
+//! [`CommandRegistry::cooldowns`]. See the [`cooldown`](crate::cooldown)
+//! module for more information.
+//!
+//! ## Access restrictions
+//! Commands with the `#[command(guild_only)]` attribute are rejected outside
+//! of guilds, and commands with the `#[command(owner_only)]` attribute are
+//! rejected unless used by one of the owners set with
+//! [`CommandRegistry::owners`]. A [`DispatchError::AccessDenied`] error is
+//! returned in both cases.
+//!
+//! ## Command policies
+//! Settings shared by all commands, such as the contexts where they can be
+//! used, can be set with [`CommandRegistry::policy`] instead of attributes on
+//! each command. See the [`CommandPolicy`] trait for more information.
+//!
+//! ## Dispatch events
+//! A hook can be registered with [`CommandRegistry::on_dispatch`] to be
+//! notified of each dispatched command, with the time spent parsing and
+//! handling it. The [`UsageStats`] type can be used with this hook to collect
+//! usage statistics.
+//!
+//! ## Unknown options
+//! Received options that are not part of the registered command make parsing
+//! fail by default. This can happen when the command registered on Discord is
+//! more recent than the running bot. Use [`CommandRegistry::unknown_options`]
+//! to ignore such options instead, and [`CommandRegistry::on_unknown_option`]
+//! to be notified when one is received.
+//!
+//! [`Future`]: std::future::Future
+//! [`command_handler`]: crate::command::command_handler
+//! [`CommandModel`]: crate::command::CommandModel
+//! [`CreateCommand`]: crate::command::CreateCommand
+//! [`UsageStats`]: crate::stats::UsageStats
+
+#[cfg(feature = "tokio")]
+mod clock;
+mod guild;
+mod handler;
+mod panic;
+mod router;
+#[cfg(feature = "tokio")]
+mod timeout;
+mod unknown;
+
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use twilight_model::{
+    application::{
+        command::Command,
+        interaction::{
+            application_command::{CommandData, CommandOptionValue},
+            Interaction, InteractionData, InteractionType,
+        },
+    },
+    id::{
+        marker::{InteractionMarker, UserMarker},
+        Id,
+    },
+};
+
+#[cfg(feature = "tokio")]
+use crate::error::HandlerTimeout;
+use crate::{
+    command::{ApplicationCommandData, CommandModel, CommandPolicy, CreateCommand},
+    cooldown::{CooldownReservation, CooldownTracker},
+    error::{DispatchError, HandlerPanic, ParseError},
+};
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub use clock::{Clock, Sleep, TokioClock, RESPONSE_DEADLINE, TOKEN_LIFETIME};
+pub use handler::CommandHandler;
+pub use router::InteractionKindRouter;
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub use timeout::{InteractionToken, DEFER_AFTER};
+pub use unknown::{UnknownOption, UnknownOptionPolicy};
+
+/// Boxed future returned by command handlers.
+type HandlerFuture<R> = Pin<Box<dyn Future<Output = R> + Send>>;
+
+/// Type-erased command handler, parsing the command data before calling the
+/// user-provided closure.
+type BoxedHandler<R> =
+    Box<dyn Fn(CommandData, Interaction) -> Result<HandlerFuture<R>, ParseError> + Send + Sync>;
+
+/// Hook called when a handler panics.
+type PanicHook = Box<dyn Fn(&HandlerPanic) + Send + Sync>;
+
+/// Hook called after a command is dispatched.
+type DispatchHook = Box<dyn Fn(&DispatchEvent<'_>) + Send + Sync>;
+
+/// Information about a dispatched command.
+///
+/// This type is passed to the hook registered with
+/// [`CommandRegistry::on_dispatch`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DispatchEvent<'a> {
+    /// Name of the command, followed by the name of the subcommand group and
+    /// subcommand if any (e.g. `config set`).
+    pub command: &'a str,
+    /// Time spent parsing the command.
+    pub parse_duration: Duration,
+    /// Time spent running the handler.
+    pub handle_duration: Duration,
+    /// Error returned while dispatching the command, if any.
+    pub error: Option<&'a DispatchError>,
+}
+
+/// Registry of commands and their handlers.
+///
+/// Commands are registered with the [`register`] method, which takes any type
+/// implementing both [`CommandModel`] and [`CreateCommand`] along with a
+/// handler closure. See the [module documentation](crate::registry) for an
+/// example.
+///
+/// [`register`]: Self::register
+pub struct CommandRegistry<R> {
+    commands: Vec<RegisteredCommand<R>>,
+    guild_scopes: HashMap<String, guild::GuildScope>,
+    policies: Vec<Box<dyn CommandPolicy>>,
+    panic_hook: Option<PanicHook>,
+    dispatch_hook: Option<DispatchHook>,
+    unknown_options: UnknownOptionPolicy,
+    unknown_option_hook: Option<unknown::UnknownOptionHook>,
+    cooldowns: Option<Arc<CooldownTracker>>,
+    owners: Vec<Id<UserMarker>>,
+    #[cfg(feature = "tokio")]
+    defer_hook: Option<timeout::DeferHook>,
+    #[cfg(feature = "tokio")]
+    timeout_hook: Option<timeout::TimeoutHook>,
+    #[cfg(feature = "tokio")]
+    clock: Arc<dyn Clock>,
+}
+
+/// A command stored in a [`CommandRegistry`].
+struct RegisteredCommand<R> {
+    data: ApplicationCommandData,
+    handler: BoxedHandler<R>,
+}
+
+impl<R> CommandRegistry<R> {
+    /// Create a new empty [`CommandRegistry`].
+    pub fn new() -> Self {
+        Self {
+            commands: Vec::new(),
+            guild_scopes: HashMap::new(),
+            policies: Vec::new(),
+            panic_hook: None,
+            dispatch_hook: None,
+            unknown_options: UnknownOptionPolicy::Error,
+            unknown_option_hook: None,
+            cooldowns: None,
+            owners: Vec::new(),
+            #[cfg(feature = "tokio")]
+            defer_hook: None,
+            #[cfg(feature = "tokio")]
+            timeout_hook: None,
+            #[cfg(feature = "tokio")]
+            clock: Arc::new(TokioClock),
+        }
+    }
+
+    /// Register a command with its handler.
+    ///
+    /// The command name is obtained from [`CreateCommand::create_command`].
+    /// Registering a command with the same name as an already registered one
+    /// replaces it.
+    pub fn register<T, F, Fut>(mut self, handler: F) -> Self
+    where
+        T: CommandModel + CreateCommand + 'static,
+        F: Fn(T, Interaction) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = R> + Send + 'static,
+    {
+        let handler: BoxedHandler<R> = Box::new(move |data, interaction| {
+            let model = T::from_interaction_context(data.into(), Some(&interaction))?;
+
+            Ok(Box::pin(handler(model, interaction)))
+        });
+
+        let mut data = T::create_command();
+        for policy in &self.policies {
+            policy.apply(&mut data);
+        }
+
+        let command = RegisteredCommand { data, handler };
+
+        match self
+            .commands
+            .iter_mut()
+            .find(|registered| registered.data.name == command.data.name)
+        {
+            Some(registered) => *registered = command,
+            None => self.commands.push(command),
+        }
+
+        self
+    }
+
+    /// Register a command implementing [`CommandHandler`].
+    ///
+    /// This is equivalent to [`register`] with a closure calling
+    /// [`CommandHandler::run`].
+    ///
+    /// [`register`]: Self::register
+    pub fn handler<T>(self) -> Self
+    where
+        T: CommandHandler<Output = R>,
+    {
+        self.register(|command: T, interaction| command.run(interaction))
+    }
+
+    /// Apply a [`CommandPolicy`] to all commands.
+    ///
+    /// The policy is applied to already registered commands and to commands
+    /// registered later, in the order policies are added. The settings of the
+    /// commands returned by [`commands`] and [`command_data`] reflect applied
+    /// policies.
+    ///
+    /// [`commands`]: Self::commands
+    /// [`command_data`]: Self::command_data
+    pub fn policy(mut self, policy: impl CommandPolicy + 'static) -> Self {
+        for command in &mut self.commands {
+            policy.apply(&mut command.data);
+        }
+
+        self.policies.push(Box::new(policy));
+
+        self
+    }
+
+    /// Enable panic isolation for handlers.
+    ///
+    /// When enabled, a panic occurring while parsing the command or running
+    /// its handler is caught and returned as a [`DispatchError::Panicked`]
+    /// error instead of unwinding through [`handle`]. The provided hook is
+    /// called with details about the panic, and can be used to log the event.
+    /// Autocomplete handlers of an [`InteractionKindRouter`] wrapping the
+    /// registry are isolated the same way.
+    ///
+    /// Use [`HandlerPanic::response`] to respond to the interaction with an
+    /// ephemeral error message.
+    ///
+    /// [`handle`]: Self::handle
+    pub fn catch_panics<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&HandlerPanic) + Send + Sync + 'static,
+    {
+        self.panic_hook = Some(Box::new(hook));
+
+        self
+    }
+
+    /// Register a hook called after each dispatched command.
+    ///
+    /// The hook is called with a [`DispatchEvent`] once the handler has
+    /// completed, or once an error occurred. It is not called for interactions
+    /// that do not match a registered command.
+    pub fn on_dispatch<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&DispatchEvent<'_>) + Send + Sync + 'static,
+    {
+        self.dispatch_hook = Some(Box::new(hook));
+
+        self
+    }
+
+    /// Set how received options that are not part of the registered command
+    /// are handled.
+    ///
+    /// By default, such options are kept and parsing the command fails. See
+    /// [`UnknownOptionPolicy`] for the available policies.
+    pub fn unknown_options(mut self, policy: UnknownOptionPolicy) -> Self {
+        self.unknown_options = policy;
+
+        self
+    }
+
+    /// Register a hook called when an unknown option is received.
+    ///
+    /// The hook is called with an [`UnknownOption`] for each received option
+    /// that is not part of the registered command, before the command is
+    /// parsed. It is not called if the [`UnknownOptionPolicy::Ignore`] policy
+    /// is set.
+    pub fn on_unknown_option<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&UnknownOption<'_>) + Send + Sync + 'static,
+    {
+        self.unknown_option_hook = Some(Box::new(hook));
+
+        self
+    }
+
+    /// Set the owners of the bot, allowed to use `owner_only` commands.
+    ///
+    /// Commands with the `owner_only` or `guild_only` attribute are checked
+    /// with [`ApplicationCommandData::check_access`] before being parsed, and
+    /// a [`DispatchError::AccessDenied`] error is returned if they cannot be
+    /// used. Without owners, `owner_only` commands cannot be used by anyone.
+    pub fn owners(mut self, owners: impl IntoIterator<Item = Id<UserMarker>>) -> Self {
+        self.owners = owners.into_iter().collect();
+
+        self
+    }
+
+    /// Enforce the cooldowns of commands with a [`CooldownTracker`].
+    ///
+    /// Commands with a cooldown are checked before being parsed, and a
+    /// [`DispatchError::OnCooldown`] error is returned if they are still on
+    /// cooldown. The cooldown only starts once the command has been parsed,
+    /// so interactions that fail to parse are not counted. The tracker can be
+    /// shared with other code, for example to reset the cooldown of a
+    /// command.
+    pub fn cooldowns(mut self, tracker: Arc<CooldownTracker>) -> Self {
+        self.cooldowns = Some(tracker);
+
+        self
+    }
+
+    /// Defer handlers that take too long to respond.
+    ///
+    /// The provided hook is called with the [`InteractionToken`] of the
+    /// interaction when a handler is still running after [`DEFER_AFTER`]. It
+    /// should send a deferred response to Discord, for example using
+    /// twilight-http's `create_response` method with a
+    /// [`DeferredChannelMessageWithSource`] response. The handler is then
+    /// expected to update the original response.
+    ///
+    /// [`DeferredChannelMessageWithSource`]: twilight_model::http::interaction::InteractionResponseType::DeferredChannelMessageWithSource
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub fn on_defer<F, Fut>(mut self, hook: F) -> Self
+    where
+        F: Fn(InteractionToken) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.defer_hook = Some(Box::new(move |token| Box::pin(hook(token))));
+
+        self
+    }
+
+    /// Respond to interactions whose handler timed out.
+    ///
+    /// The provided hook is called with the [`InteractionToken`] of the
+    /// interaction and the [`HandlerTimeout`] error when a handler exceeds its
+    /// timeout, before the error is returned by [`handle`]. It should send a
+    /// message to the user, for example the [`HandlerTimeout::response`] if
+    /// the interaction has not been deferred, or [`HandlerTimeout::MESSAGE`]
+    /// as the updated original response otherwise.
+    ///
+    /// [`handle`]: Self::handle
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub fn on_timeout<F, Fut>(mut self, hook: F) -> Self
+    where
+        F: Fn(InteractionToken, HandlerTimeout) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.timeout_hook = Some(Box::new(move |token, timeout| {
+            Box::pin(hook(token, timeout))
+        }));
+
+        self
+    }
+
+    /// Defer slow handlers and respond to timed out interactions with a
+    /// twilight-http [`Client`].
+    ///
+    /// This sets the [`on_defer`] and [`on_timeout`] hooks to send the
+    /// responses of [`http::defer_response`] and [`http::respond_timeout`].
+    /// Errors returned by Discord are ignored.
+    ///
+    /// [`Client`]: twilight_http::Client
+    /// [`on_defer`]: Self::on_defer
+    /// [`on_timeout`]: Self::on_timeout
+    /// [`http::defer_response`]: crate::http::defer_response
+    /// [`http::respond_timeout`]: crate::http::respond_timeout
+    #[cfg(all(feature = "tokio", feature = "http"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "tokio", feature = "http"))))]
+    pub fn respond_with(self, client: Arc<twilight_http::Client>) -> Self {
+        let defer_client = client.clone();
+
+        self.on_defer(move |token| {
+            let client = defer_client.clone();
+            async move {
+                let client = client.interaction(token.application_id);
+                let _ = crate::http::defer_response(&client, &token).await;
+            }
+        })
+        .on_timeout(move |token, timeout| {
+            let client = client.clone();
+            async move {
+                let client = client.interaction(token.application_id);
+                let _ = crate::http::respond_timeout(&client, &token, &timeout).await;
+            }
+        })
+    }
+
+    /// Set the [`Clock`] used to defer handlers and enforce timeouts.
+    ///
+    /// The registry uses [`TokioClock`] by default. This is mostly useful to
+    /// simulate time in tests.
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+
+        self
+    }
+
+    /// Get the list of registered global commands.
+    ///
+    /// The returned commands can be registered to Discord, for example using
+    /// twilight-http's `set_global_commands` method. Commands restricted to
+    /// some guilds with [`guilds`] are returned by [`guild_commands`] instead.
+    ///
+    /// [`guilds`]: Self::guilds
+    /// [`guild_commands`]: Self::guild_commands
+    pub fn commands(&self) -> Vec<Command> {
+        self.commands
+            .iter()
+            .filter(|command| !self.is_guild_command(&command.data))
+            .map(|command| command.data.clone().into())
+            .collect()
+    }
+
+    /// Get the [`ApplicationCommandData`] of the registered commands,
+    /// including commands restricted to some guilds.
+    pub fn command_data(&self) -> impl Iterator<Item = &ApplicationCommandData> {
+        self.commands.iter().map(|command| &command.data)
+    }
+
+    /// Parse and dispatch an interaction to the corresponding handler.
+    ///
+    /// The command is matched using the name received in the interaction
+    /// data, and parsed with its [`CommandModel`] implementation before
+    /// calling the handler. The output of the handler is returned.
+    ///
+    /// Only [`ApplicationCommand`] interactions are supported, an error is
+    /// returned for other interaction types.
+    ///
+    /// [`ApplicationCommand`]: InteractionType::ApplicationCommand
+    pub async fn handle(&self, mut interaction: Interaction) -> Result<R, DispatchError> {
+        if interaction.kind != InteractionType::ApplicationCommand {
+            return Err(DispatchError::UnsupportedInteraction(interaction.kind));
+        }
+
+        let mut data = match interaction.data.take() {
+            Some(InteractionData::ApplicationCommand(data)) => *data,
+            _ => return Err(DispatchError::MissingData),
+        };
+
+        let Some(command) = self
+            .commands
+            .iter()
+            .find(|command| command.data.name == data.name)
+        else {
+            return Err(DispatchError::UnknownCommand(data.name));
+        };
+
+        command
+            .data
+            .check_access(&interaction, &self.owners)
+            .map_err(DispatchError::AccessDenied)?;
+
+        let reservation = match &self.cooldowns {
+            Some(tracker) => {
+                #[cfg(feature = "tokio")]
+                let now = self.clock.now();
+                #[cfg(not(feature = "tokio"))]
+                let now = Instant::now();
+
+                let reservation = tracker
+                    .reserve_at(&command.data, &interaction, now)
+                    .map_err(DispatchError::OnCooldown)?;
+
+                Some(reservation)
+            }
+            None => None,
+        };
+
+        if self.unknown_options != UnknownOptionPolicy::Error || self.unknown_option_hook.is_some()
+        {
+            unknown::check_options(
+                &data.name,
+                &command.data.options,
+                &mut data.options,
+                self.unknown_options,
+                self.unknown_option_hook.as_ref(),
+            );
+        }
+
+        let path = self.dispatch_hook.as_ref().map(|_| command_path(&data));
+        let start = Instant::now();
+        let mut parse_duration = Duration::ZERO;
+
+        #[cfg(feature = "tokio")]
+        let output = {
+            let token = timeout::InteractionToken::new(&interaction, self.clock.now());
+            let future = self.execute(command, data, interaction, reservation, &mut parse_duration);
+
+            timeout::run(
+                future,
+                &command.data.name,
+                command.data.timeout,
+                token,
+                self.defer_hook.as_ref(),
+                self.timeout_hook.as_ref(),
+                &*self.clock,
+            )
+            .await
+        };
+
+        #[cfg(not(feature = "tokio"))]
+        let output = self
+            .execute(command, data, interaction, reservation, &mut parse_duration)
+            .await;
+
+        if let (Some(hook), Some(path)) = (&self.dispatch_hook, path) {
+            hook(&DispatchEvent {
+                command: &path,
+                parse_duration,
+                handle_duration: start.elapsed().saturating_sub(parse_duration),
+                error: output.as_ref().err(),
+            });
+        }
+
+        output
+    }
+
+    /// Run the handler of a command, catching panics if enabled.
+    ///
+    /// The cooldown reservation is committed once the command is parsed, and
+    /// the time spent parsing the command is written to `parse_duration`.
+    async fn execute(
+        &self,
+        command: &RegisteredCommand<R>,
+        data: CommandData,
+        interaction: Interaction,
+        reservation: Option<CooldownReservation<'_>>,
+        parse_duration: &mut Duration,
+    ) -> Result<R, DispatchError> {
+        let start = Instant::now();
+        let interaction_id = interaction.id;
+
+        self.run_handler(
+            &command.data.name,
+            interaction_id,
+            || (command.handler)(data, interaction),
+            |parsed| {
+                *parse_duration = start.elapsed();
+
+                if let (true, Some(reservation)) = (parsed, reservation) {
+                    reservation.commit();
+                }
+            },
+        )
+        .await
+    }
+
+    /// Call a handler and await its output, catching panics if enabled with
+    /// [`catch_panics`](Self::catch_panics).
+    ///
+    /// `parsed` is called once the handler returns, with whether the
+    /// interaction data was successfully parsed.
+    pub(super) async fn run_handler(
+        &self,
+        command: &str,
+        interaction_id: Id<InteractionMarker>,
+        call: impl FnOnce() -> Result<HandlerFuture<R>, ParseError>,
+        parsed: impl FnOnce(bool),
+    ) -> Result<R, DispatchError> {
+        let Some(hook) = &self.panic_hook else {
+            let future = call();
+            parsed(future.is_ok());
+
+            return Ok(future?.await);
+        };
+
+        let future = panic::catch(call);
+        parsed(matches!(future, Ok(Ok(_))));
+
+        let output = match future {
+            Ok(future) => panic::catch_future(future?).await,
+            Err(message) => Err(message),
+        };
+
+        output.map_err(|message| {
+            let panic = HandlerPanic {
+                command: command.to_owned(),
+                interaction_id,
+                message,
+            };
+            hook(&panic);
+
+            DispatchError::Panicked(panic)
+        })
+    }
+}
+
+impl<R> Default for CommandRegistry<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R> Debug for CommandRegistry<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("CommandRegistry")
+            .field(
+                "commands",
+                &self
+                    .commands
+                    .iter()
+                    .map(|command| &command.data.name)
+                    .collect::<Vec<_>>(),
+            )
+            .field(
+                "guild_commands",
+                &self.guild_scopes.keys().collect::<Vec<_>>(),
+            )
+            .field("policies", &self.policies.len())
+            .field("catch_panics", &self.panic_hook.is_some())
+            .field("on_dispatch", &self.dispatch_hook.is_some())
+            .field("unknown_options", &self.unknown_options)
+            .field("on_unknown_option", &self.unknown_option_hook.is_some())
+            .field("cooldowns", &self.cooldowns.is_some())
+            .field("owners", &self.owners)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Get the name of a command followed by its subcommand group and subcommand.
+fn command_path(data: &CommandData) -> String {
+    let mut path = data.name.clone();
+    let mut options = &data.options;
+
+    while let Some(option) = options.first() {
+        match &option.value {
+            CommandOptionValue::SubCommand(nested)
+            | CommandOptionValue::SubCommandGroup(nested) => {
+                path.push(' ');
+                path.push_str(&option.name);
+                options = nested;
+            }
+            _ => break,
+        }
+    }
+
+    path
+}
@@ -0,0 +1,38 @@
+//! Panic isolation for command handlers.
+
+use std::{
+    any::Any,
+    future::{poll_fn, Future},
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+};
+
+/// Call a function, catching any panic that occurs.
+pub(super) fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| panic_message(&*payload))
+}
+
+/// Poll a future to completion, catching any panic that occurs while polling.
+pub(super) async fn catch_future<F>(mut future: Pin<Box<F>>) -> Result<F::Output, String>
+where
+    F: Future + ?Sized,
+{
+    poll_fn(|cx| match catch(|| future.as_mut().poll(cx)) {
+        Ok(poll) => poll.map(Ok),
+        Err(message) => Err(message).into(),
+    })
+    .await
+}
+
+/// Extract the message from a panic payload.
+///
+/// Panic payloads are usually either a `&'static str` or a `String`.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("Box<dyn Any>")
+    }
+}
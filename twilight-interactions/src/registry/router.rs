@@ -0,0 +1,251 @@
+//! Routing of interactions based on their kind.
+
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::Future,
+};
+
+use twilight_model::application::interaction::{
+    application_command::CommandData, message_component::MessageComponentInteractionData,
+    modal::ModalInteractionData, Interaction, InteractionData, InteractionType,
+};
+
+use super::{BoxedHandler, CommandRegistry, HandlerFuture};
+use crate::{command::CommandModel, error::DispatchError};
+
+/// Type-erased handler of component or modal interactions.
+type BoxedDataHandler<D, R> = Box<dyn Fn(D, Interaction) -> HandlerFuture<R> + Send + Sync>;
+
+/// Router dispatching interactions according to their kind.
+///
+/// This type wraps a [`CommandRegistry`] and allows registering handlers for
+/// the other kinds of interactions, so that all received interactions can be
+/// handled with a single call to [`handle`]:
+///
+/// - [`ApplicationCommand`] interactions are dispatched to the registry.
+/// - [`ApplicationCommandAutocomplete`] interactions are parsed with the
+///   [`CommandModel`] registered for the command with [`autocomplete`].
+/// - [`MessageComponent`] and [`ModalSubmit`] interactions are passed to the
+///   handlers registered with [`component`] and [`modal`].
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::{
+///     command::{AutocompleteValue, CommandModel, CreateCommand},
+///     registry::{CommandRegistry, InteractionKindRouter},
+/// };
+/// # use twilight_model::application::interaction::Interaction;
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "color", desc = "Choose a color")]
+/// struct ColorCommand {
+///     /// Name of the color.
+///     #[command(autocomplete = true)]
+///     name: String,
+/// }
+///
+/// #[derive(CommandModel)]
+/// #[command(autocomplete = true)]
+/// struct ColorAutocomplete {
+///     name: AutocompleteValue<String>,
+/// }
+///
+/// let commands = CommandRegistry::new().register(|command: ColorCommand, _interaction| {
+///     async move { format!("You chose {}", command.name) }
+/// });
+///
+/// let router = InteractionKindRouter::new(commands)
+///     .autocomplete("color", |_: ColorAutocomplete, _interaction| async move {
+///         String::from("suggestions")
+///     })
+///     .component(|data, _interaction| async move { format!("Clicked {}", data.custom_id) });
+///
+/// # async fn handle(router: InteractionKindRouter<String>, interaction: Interaction) {
+/// let response = router.handle(interaction).await;
+/// # }
+/// ```
+///
+/// [`handle`]: Self::handle
+/// [`autocomplete`]: Self::autocomplete
+/// [`component`]: Self::component
+/// [`modal`]: Self::modal
+/// [`ApplicationCommand`]: InteractionType::ApplicationCommand
+/// [`ApplicationCommandAutocomplete`]: InteractionType::ApplicationCommandAutocomplete
+/// [`MessageComponent`]: InteractionType::MessageComponent
+/// [`ModalSubmit`]: InteractionType::ModalSubmit
+pub struct InteractionKindRouter<R> {
+    commands: CommandRegistry<R>,
+    autocomplete: Vec<(String, BoxedHandler<R>)>,
+    component: Option<BoxedDataHandler<MessageComponentInteractionData, R>>,
+    modal: Option<BoxedDataHandler<ModalInteractionData, R>>,
+}
+
+impl<R> InteractionKindRouter<R> {
+    /// Create a new [`InteractionKindRouter`] dispatching commands to the
+    /// provided registry.
+    pub fn new(commands: CommandRegistry<R>) -> Self {
+        Self {
+            commands,
+            autocomplete: Vec::new(),
+            component: None,
+            modal: None,
+        }
+    }
+
+    /// Register an autocomplete handler for a command.
+    ///
+    /// The received data is parsed with the [`CommandModel`] implementation
+    /// of `T`, which should be an autocomplete model. Registering a handler
+    /// for a command that already has one replaces it.
+    ///
+    /// Panics are caught if enabled with [`CommandRegistry::catch_panics`].
+    /// Timeouts of commands are not enforced, since autocomplete interactions
+    /// cannot be deferred.
+    pub fn autocomplete<T, F, Fut>(mut self, command: impl Into<String>, handler: F) -> Self
+    where
+        T: CommandModel + 'static,
+        F: Fn(T, Interaction) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = R> + Send + 'static,
+    {
+        let command = command.into();
+        let handler: BoxedHandler<R> = Box::new(move |data: CommandData, interaction| {
+            let model = T::from_interaction_context(data.into(), Some(&interaction))?;
+
+            Ok(Box::pin(handler(model, interaction)))
+        });
+
+        match self
+            .autocomplete
+            .iter_mut()
+            .find(|(name, _)| *name == command)
+        {
+            Some((_, registered)) => *registered = handler,
+            None => self.autocomplete.push((command, handler)),
+        }
+
+        self
+    }
+
+    /// Register the handler of message component interactions.
+    ///
+    /// Unlike command and autocomplete handlers, panics of this handler are
+    /// not caught.
+    pub fn component<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(MessageComponentInteractionData, Interaction) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = R> + Send + 'static,
+    {
+        self.component = Some(Box::new(move |data, interaction| {
+            Box::pin(handler(data, interaction))
+        }));
+
+        self
+    }
+
+    /// Register the handler of modal submit interactions.
+    ///
+    /// Unlike command and autocomplete handlers, panics of this handler are
+    /// not caught.
+    pub fn modal<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(ModalInteractionData, Interaction) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = R> + Send + 'static,
+    {
+        self.modal = Some(Box::new(move |data, interaction| {
+            Box::pin(handler(data, interaction))
+        }));
+
+        self
+    }
+
+    /// Get the wrapped [`CommandRegistry`].
+    pub fn commands(&self) -> &CommandRegistry<R> {
+        &self.commands
+    }
+
+    /// Update the wrapped [`CommandRegistry`] with the provided function.
+    #[cfg(feature = "testing")]
+    pub(crate) fn map_commands(
+        mut self,
+        f: impl FnOnce(CommandRegistry<R>) -> CommandRegistry<R>,
+    ) -> Self {
+        self.commands = f(std::mem::take(&mut self.commands));
+
+        self
+    }
+
+    /// Dispatch an interaction to the handler corresponding to its kind.
+    ///
+    /// A [`DispatchError::UnsupportedInteraction`] error is returned if no
+    /// handler is registered for the kind of the interaction.
+    pub async fn handle(&self, mut interaction: Interaction) -> Result<R, DispatchError> {
+        match interaction.kind {
+            InteractionType::ApplicationCommand => self.commands.handle(interaction).await,
+            InteractionType::ApplicationCommandAutocomplete => {
+                let data = match interaction.data.take() {
+                    Some(InteractionData::ApplicationCommand(data)) => *data,
+                    _ => return Err(DispatchError::MissingData),
+                };
+
+                let Some((command, handler)) = self
+                    .autocomplete
+                    .iter()
+                    .find(|(name, _)| *name == data.name)
+                else {
+                    return Err(DispatchError::UnknownCommand(data.name));
+                };
+
+                let interaction_id = interaction.id;
+                self.commands
+                    .run_handler(
+                        command,
+                        interaction_id,
+                        || handler(data, interaction),
+                        |_| (),
+                    )
+                    .await
+            }
+            InteractionType::MessageComponent => {
+                let Some(handler) = &self.component else {
+                    return Err(DispatchError::UnsupportedInteraction(interaction.kind));
+                };
+                let data = match interaction.data.take() {
+                    Some(InteractionData::MessageComponent(data)) => *data,
+                    _ => return Err(DispatchError::MissingData),
+                };
+
+                Ok(handler(data, interaction).await)
+            }
+            InteractionType::ModalSubmit => {
+                let Some(handler) = &self.modal else {
+                    return Err(DispatchError::UnsupportedInteraction(interaction.kind));
+                };
+                let data = match interaction.data.take() {
+                    Some(InteractionData::ModalSubmit(data)) => data,
+                    _ => return Err(DispatchError::MissingData),
+                };
+
+                Ok(handler(data, interaction).await)
+            }
+            kind => Err(DispatchError::UnsupportedInteraction(kind)),
+        }
+    }
+}
+
+impl<R> Debug for InteractionKindRouter<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("InteractionKindRouter")
+            .field("commands", &self.commands)
+            .field(
+                "autocomplete",
+                &self
+                    .autocomplete
+                    .iter()
+                    .map(|(name, _)| name)
+                    .collect::<Vec<_>>(),
+            )
+            .field("component", &self.component.is_some())
+            .field("modal", &self.modal.is_some())
+            .finish()
+    }
+}
@@ -0,0 +1,138 @@
+//! Timeout enforcement for command handlers.
+
+use std::{
+    future::Future,
+    pin::{pin, Pin},
+    time::{Duration, Instant},
+};
+
+use twilight_model::{
+    application::interaction::Interaction,
+    id::{
+        marker::{ApplicationMarker, InteractionMarker},
+        Id,
+    },
+};
+
+use super::clock::{self, Clock, TOKEN_LIFETIME};
+use crate::error::{DispatchError, HandlerTimeout};
+
+/// Delay after which a running handler is deferred.
+///
+/// Discord requires an initial response within [`RESPONSE_DEADLINE`], this
+/// leaves some margin to send the deferred response.
+///
+/// [`RESPONSE_DEADLINE`]: super::RESPONSE_DEADLINE
+pub const DEFER_AFTER: Duration = Duration::from_millis(2500);
+
+/// Boxed future returned by the defer and timeout hooks.
+type HookFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Hook called when a handler is deferred.
+pub(super) type DeferHook = Box<dyn Fn(InteractionToken) -> HookFuture + Send + Sync>;
+
+/// Hook called when a handler times out.
+pub(super) type TimeoutHook =
+    Box<dyn Fn(InteractionToken, HandlerTimeout) -> HookFuture + Send + Sync>;
+
+/// Data required to respond to an interaction.
+///
+/// This type is passed to the hooks registered with
+/// [`CommandRegistry::on_defer`] and [`CommandRegistry::on_timeout`].
+///
+/// [`CommandRegistry::on_defer`]: super::CommandRegistry::on_defer
+/// [`CommandRegistry::on_timeout`]: super::CommandRegistry::on_timeout
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InteractionToken {
+    /// ID of the application the interaction was sent to.
+    pub application_id: Id<ApplicationMarker>,
+    /// ID of the interaction.
+    pub interaction_id: Id<InteractionMarker>,
+    /// Token used to respond to the interaction.
+    pub token: String,
+    /// Time at which the interaction was received, according to the
+    /// [`Clock`] of the registry.
+    pub received_at: Instant,
+}
+
+impl InteractionToken {
+    /// Create a new [`InteractionToken`] for an interaction received at the
+    /// given time.
+    pub fn new(interaction: &Interaction, received_at: Instant) -> Self {
+        Self {
+            application_id: interaction.application_id,
+            interaction_id: interaction.id,
+            token: interaction.token.clone(),
+            received_at,
+        }
+    }
+
+    /// Get the time after which the token can no longer be used.
+    pub fn expires_at(&self) -> Instant {
+        self.received_at + TOKEN_LIFETIME
+    }
+
+    /// Returns `true` if the token can no longer be used, according to the
+    /// given [`Clock`].
+    pub fn is_expired(&self, clock: &dyn Clock) -> bool {
+        clock.now() >= self.expires_at()
+    }
+}
+
+/// Run a handler, deferring it and enforcing its timeout.
+pub(super) async fn run<R>(
+    future: impl Future<Output = Result<R, DispatchError>>,
+    command: &str,
+    timeout: Option<Duration>,
+    token: InteractionToken,
+    defer: Option<&DeferHook>,
+    on_timeout: Option<&TimeoutHook>,
+    clock: &dyn Clock,
+) -> Result<R, DispatchError> {
+    let start = token.received_at;
+    let mut future = pin!(future);
+    let mut deferred = false;
+
+    let timed_out = |deferred| {
+        let token = &token;
+
+        async move {
+            let error = HandlerTimeout {
+                command: command.to_owned(),
+                interaction_id: token.interaction_id,
+                timeout: timeout.unwrap_or_default(),
+                deferred,
+            };
+
+            if let Some(hook) = on_timeout {
+                hook(token.clone(), error.clone()).await;
+            }
+
+            Err(DispatchError::TimedOut(error))
+        }
+    };
+
+    if let Some(defer) = defer {
+        let delay = timeout.map_or(DEFER_AFTER, |timeout| timeout.min(DEFER_AFTER));
+
+        match clock::race(future.as_mut(), clock.sleep_until(start + delay)).await {
+            Some(output) => return output,
+            None if timeout.is_some_and(|timeout| timeout <= DEFER_AFTER) => {
+                return timed_out(false).await
+            }
+            None => {
+                defer(token.clone()).await;
+                deferred = true;
+            }
+        }
+    }
+
+    let Some(timeout) = timeout else {
+        return future.await;
+    };
+
+    match clock::race(future, clock.sleep_until(start + timeout)).await {
+        Some(output) => output,
+        None => timed_out(deferred).await,
+    }
+}
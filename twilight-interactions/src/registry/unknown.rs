@@ -0,0 +1,88 @@
+use twilight_model::application::{
+    command::{CommandOption, CommandOptionType},
+    interaction::application_command::{CommandDataOption, CommandOptionValue},
+};
+
+/// Hook called when an unknown option is received.
+pub(super) type UnknownOptionHook = Box<dyn Fn(&UnknownOption<'_>) + Send + Sync>;
+
+/// Handling of received options that are not part of the registered command.
+///
+/// Such options are usually received when the command registered on Discord
+/// is more recent than the running bot, or when Discord sends a payload shape
+/// that is not supported by this crate. This policy is set with
+/// [`CommandRegistry::unknown_options`].
+///
+/// [`CommandRegistry::unknown_options`]: super::CommandRegistry::unknown_options
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnknownOptionPolicy {
+    /// Keep the option, which makes parsing the command fail with an
+    /// [`UnknownField`] error.
+    ///
+    /// [`UnknownField`]: crate::error::ParseOptionErrorType::UnknownField
+    #[default]
+    Error,
+    /// Call the hook registered with [`CommandRegistry::on_unknown_option`]
+    /// and remove the option before parsing the command.
+    ///
+    /// [`CommandRegistry::on_unknown_option`]: super::CommandRegistry::on_unknown_option
+    Warn,
+    /// Silently remove the option before parsing the command.
+    Ignore,
+}
+
+/// Information about a received option that is not part of the registered
+/// command.
+///
+/// This type is passed to the hook registered with
+/// [`CommandRegistry::on_unknown_option`].
+///
+/// [`CommandRegistry::on_unknown_option`]: super::CommandRegistry::on_unknown_option
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownOption<'a> {
+    /// Name of the command, followed by the name of the subcommand group and
+    /// subcommand if any (e.g. `config set`).
+    pub command: &'a str,
+    /// Name of the unknown option.
+    pub option: &'a str,
+    /// Type of the unknown option.
+    pub kind: CommandOptionType,
+}
+
+/// Check received options against the options of the registered command.
+///
+/// The hook is called for each unknown option unless the policy is
+/// [`UnknownOptionPolicy::Ignore`], and unknown options are removed unless the
+/// policy is [`UnknownOptionPolicy::Error`].
+pub(super) fn check_options(
+    path: &str,
+    registered: &[CommandOption],
+    received: &mut Vec<CommandDataOption>,
+    policy: UnknownOptionPolicy,
+    hook: Option<&UnknownOptionHook>,
+) {
+    received.retain_mut(|option| {
+        let Some(expected) = registered.iter().find(|other| other.name == option.name) else {
+            if let (Some(hook), false) = (hook, policy == UnknownOptionPolicy::Ignore) {
+                hook(&UnknownOption {
+                    command: path,
+                    option: &option.name,
+                    kind: option.value.kind(),
+                });
+            }
+
+            return policy == UnknownOptionPolicy::Error;
+        };
+
+        if let CommandOptionValue::SubCommand(options)
+        | CommandOptionValue::SubCommandGroup(options) = &mut option.value
+        {
+            let path = format!("{path} {}", option.name);
+            let registered = expected.options.as_deref().unwrap_or_default();
+
+            check_options(&path, registered, options, policy, hook);
+        }
+
+        true
+    });
+}
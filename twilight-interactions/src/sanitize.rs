@@ -0,0 +1,151 @@
+//! Sanitization of user input echoed in responses.
+//!
+//! Option values are typed by users, and can contain Discord markdown or
+//! mentions. Including them as-is in a response can break its formatting,
+//! or ping `@everyone` and roles when the response is sent by the bot. This
+//! module provides helpers to escape these values and truncate them to the
+//! length limits of Discord.
+//!
+//! Mentions are escaped by inserting a zero-width space after `@`, which
+//! prevents the Discord client from parsing them. Setting the allowed mentions
+//! of the response is still recommended, since it also covers mentions added
+//! by the bot itself.
+//!
+//! ## Example
+//! ```
+//! use twilight_interactions::sanitize;
+//!
+//! let input = "**@everyone** look at this";
+//! let content = format!("You said: {}", sanitize::sanitize(input, 24));
+//!
+//! assert_eq!(content, "You said: \\*\\*@\u{200B}everyone\\*\\* look…");
+//! ```
+
+use std::borrow::Cow;
+
+/// Maximum length of the content of a message, in characters.
+pub const MESSAGE_CONTENT_LENGTH: usize = 2000;
+
+/// Maximum length of the title of an embed, in characters.
+pub const EMBED_TITLE_LENGTH: usize = 256;
+
+/// Maximum length of the description of an embed, in characters.
+pub const EMBED_DESCRIPTION_LENGTH: usize = 4096;
+
+/// Maximum length of the value of an embed field, in characters.
+pub const EMBED_FIELD_VALUE_LENGTH: usize = 1024;
+
+/// Character appended to truncated values.
+pub const ELLIPSIS: char = '…';
+
+/// Characters escaped anywhere in a value.
+const INLINE_MARKDOWN: &[char] = &['\\', '*', '_', '~', '`', '|', '[', ']'];
+
+/// Characters escaped at the start of a line (headers, lists and quotes).
+const LINE_MARKDOWN: &[char] = &['#', '-', '>'];
+
+/// Zero-width space inserted after `@` to prevent mentions.
+const ZERO_WIDTH_SPACE: char = '\u{200B}';
+
+/// Escape markdown and mentions, and truncate the result to a maximum
+/// number of characters.
+///
+/// Unlike escaping then calling [`truncate`], escape sequences are never
+/// split by the truncation. The result is at most `max` characters long,
+/// including the [`ELLIPSIS`] appended if the value was truncated.
+pub fn sanitize(value: &str, max: usize) -> String {
+    let escaped = escape(value);
+    if escaped.chars().count() <= max {
+        return escaped;
+    } else if max == 0 {
+        return String::new();
+    }
+
+    let mut output = String::with_capacity(max);
+    let mut length = 0;
+    let mut piece = String::new();
+    let mut line_start = true;
+
+    for c in value.chars() {
+        piece.clear();
+        push_escaped(&mut piece, c, line_start, true, true);
+        line_start = c == '\n';
+
+        let piece_length = piece.chars().count();
+        if length + piece_length >= max {
+            break;
+        }
+
+        output.push_str(&piece);
+        length += piece_length;
+    }
+
+    output.push(ELLIPSIS);
+    output
+}
+
+/// Escape Discord markdown and mentions.
+pub fn escape(value: &str) -> String {
+    escape_with(value, true, true)
+}
+
+/// Escape Discord markdown, such as bold text, code blocks or masked links.
+pub fn escape_markdown(value: &str) -> String {
+    escape_with(value, true, false)
+}
+
+/// Escape mentions, including `@everyone`, `@here`, and user and role
+/// mentions.
+pub fn escape_mentions(value: &str) -> String {
+    escape_with(value, false, true)
+}
+
+/// Truncate a value to a maximum number of characters.
+///
+/// The [`ELLIPSIS`] character is appended if the value is truncated, and is
+/// included in the maximum length. The value is borrowed if it is not
+/// truncated.
+pub fn truncate(value: &str, max: usize) -> Cow<'_, str> {
+    match value.char_indices().nth(max) {
+        None => Cow::Borrowed(value),
+        Some(_) if max == 0 => Cow::Borrowed(""),
+        Some(_) => {
+            let end = value
+                .char_indices()
+                .nth(max - 1)
+                .map_or(value.len(), |(index, _)| index);
+
+            let mut truncated = String::with_capacity(end + ELLIPSIS.len_utf8());
+            truncated.push_str(&value[..end]);
+            truncated.push(ELLIPSIS);
+
+            Cow::Owned(truncated)
+        }
+    }
+}
+
+/// Escape a value with the selected escaping rules.
+fn escape_with(value: &str, markdown: bool, mentions: bool) -> String {
+    let mut output = String::with_capacity(value.len());
+    let mut line_start = true;
+
+    for c in value.chars() {
+        push_escaped(&mut output, c, line_start, markdown, mentions);
+        line_start = c == '\n';
+    }
+
+    output
+}
+
+/// Push a character to a string, escaped with the selected escaping rules.
+fn push_escaped(output: &mut String, c: char, line_start: bool, markdown: bool, mentions: bool) {
+    if markdown && (INLINE_MARKDOWN.contains(&c) || (line_start && LINE_MARKDOWN.contains(&c))) {
+        output.push('\\');
+    }
+
+    output.push(c);
+
+    if mentions && c == '@' {
+        output.push(ZERO_WIDTH_SPACE);
+    }
+}
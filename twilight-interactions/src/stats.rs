@@ -0,0 +1,186 @@
+//! Command usage statistics.
+//!
+//! The [`UsageStats`] type aggregates the events emitted by
+//! [`CommandRegistry::on_dispatch`] into per-command counters, error rates and
+//! latency percentiles. It provides basic usage insight for bots that do not
+//! have a metrics stack.
+//!
+//! ## Example
+//! ```
+//! use std::sync::Arc;
+//!
+//! use twilight_interactions::{
+//!     command::{CommandModel, CreateCommand},
+//!     registry::CommandRegistry,
+//!     stats::UsageStats,
+//! };
+//!
+//! #[derive(CommandModel, CreateCommand)]
+//! #[command(name = "hello", desc = "Say hello")]
+//! struct HelloCommand;
+//!
+//! let stats = Arc::new(UsageStats::new());
+//!
+//! let registry = CommandRegistry::new()
+//!     .register(|_: HelloCommand, _interaction| async move { "Hello!" })
+//!     .on_dispatch({
+//!         let stats = Arc::clone(&stats);
+//!         move |event| stats.record(event)
+//!     });
+//!
+//! // Later, for example in an admin command
+//! for usage in stats.export() {
+//!     println!("{}: {} uses", usage.command, usage.count);
+//! }
+//! ```
+//!
+//! [`CommandRegistry::on_dispatch`]: crate::registry::CommandRegistry::on_dispatch
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+    time::Duration,
+};
+
+use crate::registry::DispatchEvent;
+
+/// Maximum number of latency samples kept for each command.
+///
+/// Percentiles are computed from the most recent samples.
+pub const MAX_SAMPLES: usize = 1024;
+
+/// Accumulator of command usage statistics.
+///
+/// Statistics are recorded with [`record`] for each command path, which
+/// is the name of the command followed by the name of its subcommand group and
+/// subcommand (e.g. `config set`). See the [module documentation](self) for an
+/// example.
+///
+/// [`record`]: Self::record
+#[derive(Debug, Default)]
+pub struct UsageStats {
+    commands: Mutex<HashMap<String, Counter>>,
+}
+
+/// Statistics of a single command path.
+#[derive(Debug, Default)]
+struct Counter {
+    count: u64,
+    errors: u64,
+    parse: VecDeque<Duration>,
+    handle: VecDeque<Duration>,
+}
+
+impl UsageStats {
+    /// Create a new empty [`UsageStats`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a dispatched command.
+    pub fn record(&self, event: &DispatchEvent<'_>) {
+        let mut commands = self.commands.lock().unwrap();
+        let counter = commands.entry(event.command.to_owned()).or_default();
+
+        counter.count += 1;
+        if event.error.is_some() {
+            counter.errors += 1;
+        }
+
+        push_sample(&mut counter.parse, event.parse_duration);
+        push_sample(&mut counter.handle, event.handle_duration);
+    }
+
+    /// Export the recorded statistics, sorted by command path.
+    pub fn export(&self) -> Vec<CommandUsage> {
+        let commands = self.commands.lock().unwrap();
+
+        let mut usage: Vec<_> = commands
+            .iter()
+            .map(|(command, counter)| CommandUsage {
+                command: command.clone(),
+                count: counter.count,
+                errors: counter.errors,
+                parse_latency: Latency::from_samples(&counter.parse),
+                handle_latency: Latency::from_samples(&counter.handle),
+            })
+            .collect();
+
+        usage.sort_by(|a, b| a.command.cmp(&b.command));
+
+        usage
+    }
+
+    /// Clear the recorded statistics.
+    pub fn reset(&self) {
+        self.commands.lock().unwrap().clear();
+    }
+}
+
+/// Usage statistics of a command path, returned by [`UsageStats::export`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandUsage {
+    /// Name of the command, followed by the name of the subcommand group and
+    /// subcommand if any.
+    pub command: String,
+    /// Number of times the command was dispatched.
+    pub count: u64,
+    /// Number of times dispatching the command returned an error.
+    pub errors: u64,
+    /// Time spent parsing the command.
+    pub parse_latency: Latency,
+    /// Time spent running the handler.
+    pub handle_latency: Latency,
+}
+
+impl CommandUsage {
+    /// Ratio of dispatches that returned an error, between 0 and 1.
+    pub fn error_rate(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+
+        self.errors as f64 / self.count as f64
+    }
+}
+
+/// Latency percentiles computed from the recent samples of a command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Latency {
+    /// Median latency.
+    pub p50: Duration,
+    /// 99th percentile latency.
+    pub p99: Duration,
+}
+
+impl Latency {
+    fn from_samples(samples: &VecDeque<Duration>) -> Self {
+        let mut sorted: Vec<_> = samples.iter().copied().collect();
+        sorted.sort_unstable();
+
+        Self {
+            p50: percentile(&sorted, 50),
+            p99: percentile(&sorted, 99),
+        }
+    }
+}
+
+/// Add a sample, removing the oldest one if [`MAX_SAMPLES`] is reached.
+fn push_sample(samples: &mut VecDeque<Duration>, sample: Duration) {
+    if samples.len() == MAX_SAMPLES {
+        samples.pop_front();
+    }
+
+    samples.push_back(sample);
+}
+
+/// Compute a percentile of sorted samples using the nearest-rank method.
+fn percentile(sorted: &[Duration], percent: usize) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+
+    let rank = (percent * sorted.len()).div_ceil(100);
+
+    sorted[rank.saturating_sub(1)]
+}
@@ -0,0 +1,930 @@
+//! Synchronization of commands with Discord.
+//!
+//! This module compares commands generated locally with the [`CreateCommand`]
+//! trait against the commands currently registered on Discord, and computes
+//! the minimal set of operations needed to bring Discord up to date.
+//!
+//! The [`diff_command`] function lists detailed changes of a single command,
+//! which can be shown to users before overwriting remote commands.
+//!
+//! Fields populated by Discord (such as the command ID or version) are
+//! ignored when comparing commands. Fields that are not set locally are
+//! compared against the default value used by Discord when one exists
+//! (e.g. `nsfw` defaults to `false`), and ignored otherwise.
+//!
+//! Only chat input commands are compared. Remote user and message commands
+//! are ignored since they cannot be created with this crate.
+//!
+//! The [`hash`] function computes a hash of local commands, which can be
+//! stored after registering commands to skip the registration on the next
+//! startup if commands did not change, without fetching remote commands.
+//!
+//! ## Example
+//! ```
+//! use twilight_interactions::{
+//!     command::CreateCommand,
+//!     sync::{diff, SyncOperation},
+//! };
+//! # use twilight_model::application::command::Command;
+//!
+//! #[derive(CreateCommand)]
+//! #[command(name = "hello", desc = "Say hello")]
+//! struct HelloCommand;
+//!
+//! // Commands fetched from Discord, e.g. with twilight-http
+//! let remote: Vec<Command> = Vec::new();
+//!
+//! let plan = diff(&[HelloCommand::create_command()], &remote);
+//! let operations = plan.operations().collect::<Vec<_>>();
+//!
+//! assert!(matches!(operations[0], SyncOperation::Create(command) if command.name == "hello"));
+//! ```
+//!
+//! [`CreateCommand`]: crate::command::CreateCommand
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug, Display, Formatter},
+};
+
+use twilight_model::{
+    application::command::{
+        Command, CommandOption, CommandOptionChoice, CommandOptionChoiceValue, CommandOptionValue,
+        CommandType,
+    },
+    id::{marker::CommandMarker, Id},
+};
+
+use crate::command::{ApplicationCommandData, CommandPolicy};
+
+/// Compare local commands against commands registered on Discord.
+///
+/// Commands are matched by name. See the [module documentation](crate::sync)
+/// for more information about how commands are compared.
+pub fn diff(local: &[ApplicationCommandData], remote: &[Command]) -> SyncPlan {
+    diff_commands(local.iter().cloned(), remote)
+}
+
+/// Compare local commands against commands registered on Discord, after
+/// applying a [`CommandPolicy`] to local commands.
+///
+/// This is equivalent to [`diff`] with the policy applied to each local
+/// command beforehand, and should be used with the same policy as the one set
+/// on the [`CommandRegistry`] if any.
+///
+/// [`CommandRegistry`]: crate::registry::CommandRegistry
+pub fn diff_with(
+    local: &[ApplicationCommandData],
+    remote: &[Command],
+    policy: &dyn CommandPolicy,
+) -> SyncPlan {
+    let local = local.iter().map(|data| {
+        let mut data = data.clone();
+        policy.apply(&mut data);
+
+        data
+    });
+
+    diff_commands(local, remote)
+}
+
+/// Compare local commands against commands registered on Discord.
+fn diff_commands(
+    local: impl ExactSizeIterator<Item = ApplicationCommandData>,
+    remote: &[Command],
+) -> SyncPlan {
+    let mut remote: Vec<&Command> = remote
+        .iter()
+        .filter(|command| command.kind == CommandType::ChatInput)
+        .collect();
+    let mut changes = Vec::with_capacity(local.len() + remote.len());
+
+    for data in local {
+        let command = Command::from(data);
+        let position = remote.iter().position(|other| other.name == command.name);
+
+        let change = match position.map(|index| remote.swap_remove(index)) {
+            None => CommandChange::Created(command),
+            Some(other) => {
+                let mut changes = Vec::new();
+                command_changes(&command, other, &mut changes);
+
+                if changes.is_empty() {
+                    CommandChange::Unchanged {
+                        id: other.id,
+                        name: command.name,
+                    }
+                } else {
+                    CommandChange::Updated {
+                        id: other.id,
+                        command,
+                        fields: changed_fields(&changes),
+                        changes,
+                    }
+                }
+            }
+        };
+
+        changes.push(change);
+    }
+
+    changes.extend(remote.into_iter().map(|command| CommandChange::Deleted {
+        id: command.id,
+        name: command.name.clone(),
+    }));
+
+    SyncPlan { changes }
+}
+
+/// Result of the comparison between local and remote commands.
+///
+/// This type is returned by the [`diff`] and [`diff_with`] functions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncPlan {
+    /// Changes for each local and remote command.
+    ///
+    /// Local commands are listed first, in the same order as provided,
+    /// followed by remote commands that do not exist locally.
+    pub changes: Vec<CommandChange>,
+}
+
+impl SyncPlan {
+    /// Whether the remote commands are up to date.
+    pub fn is_up_to_date(&self) -> bool {
+        self.changes
+            .iter()
+            .all(|change| matches!(change, CommandChange::Unchanged { .. }))
+    }
+
+    /// Get the operations required to synchronize remote commands.
+    pub fn operations(&self) -> impl Iterator<Item = SyncOperation<'_>> {
+        self.changes.iter().filter_map(|change| match change {
+            CommandChange::Created(command) => Some(SyncOperation::Create(command)),
+            CommandChange::Updated { id, command, .. } => {
+                Some(SyncOperation::Update { id: *id, command })
+            }
+            CommandChange::Deleted { id, name } => Some(SyncOperation::Delete { id: *id, name }),
+            CommandChange::Unchanged { .. } => None,
+        })
+    }
+}
+
+/// Change of a single command.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommandChange {
+    /// The command only exists locally.
+    Created(Command),
+    /// The command exists on both sides but differs.
+    Updated {
+        /// ID of the remote command.
+        id: Option<Id<CommandMarker>>,
+        /// Local version of the command.
+        command: Command,
+        /// Fields that differ between the local and remote command.
+        fields: Vec<CommandField>,
+        /// Detailed changes between the local and remote command.
+        changes: Vec<FieldChange>,
+    },
+    /// The command only exists remotely.
+    Deleted {
+        /// ID of the remote command.
+        id: Option<Id<CommandMarker>>,
+        /// Name of the remote command.
+        name: String,
+    },
+    /// The command is identical on both sides.
+    Unchanged {
+        /// ID of the remote command.
+        id: Option<Id<CommandMarker>>,
+        /// Name of the command.
+        name: String,
+    },
+}
+
+/// Field of a command compared by [`diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CommandField {
+    /// Localization dictionary for the command name.
+    NameLocalizations,
+    /// Description of the command.
+    Description,
+    /// Localization dictionary for the command description.
+    DescriptionLocalizations,
+    /// List of command options.
+    Options,
+    /// Default permissions required for a member to run the command.
+    DefaultMemberPermissions,
+    /// Whether the command is available in DMs.
+    DmPermission,
+    /// Whether the command is nsfw.
+    Nsfw,
+    /// Interaction context(s) where the command can be used.
+    Contexts,
+    /// Installation contexts where the command is available.
+    IntegrationTypes,
+}
+
+/// Operation to perform on Discord to synchronize commands.
+///
+/// This type is returned by [`SyncPlan::operations`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SyncOperation<'a> {
+    /// Create a new command.
+    Create(&'a Command),
+    /// Update an existing command.
+    Update {
+        /// ID of the command to update.
+        id: Option<Id<CommandMarker>>,
+        /// New version of the command.
+        command: &'a Command,
+    },
+    /// Delete an existing command.
+    Delete {
+        /// ID of the command to delete.
+        id: Option<Id<CommandMarker>>,
+        /// Name of the command to delete.
+        name: &'a str,
+    },
+}
+
+/// Compare a local command against a command registered on Discord.
+///
+/// This returns the detailed list of changes between both commands, such as
+/// added options or changed localizations. Commands are compared the same way
+/// as with [`diff`], including their names. An empty list is returned if the
+/// commands are identical.
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::{command::CreateCommand, sync::diff_command};
+/// use twilight_model::application::command::Command;
+///
+/// #[derive(CreateCommand)]
+/// #[command(name = "hello", desc = "Say hello")]
+/// struct HelloCommand;
+///
+/// let mut remote = Command::from(HelloCommand::create_command());
+/// remote.description = "Say hi".into();
+///
+/// let changes = diff_command(&HelloCommand::create_command(), &remote);
+/// assert_eq!(
+///     changes[0].to_string(),
+///     r#"`description` changed from "Say hi" to "Say hello""#
+/// );
+/// ```
+pub fn diff_command(local: &ApplicationCommandData, remote: &Command) -> Vec<FieldChange> {
+    let local = Command::from(local.clone());
+    let mut changes = Vec::new();
+
+    if local.name != remote.name {
+        changes.push(FieldChange::value(&[], "name", &local.name, &remote.name));
+    }
+
+    command_changes(&local, remote, &mut changes);
+
+    changes
+}
+
+/// Compute a stable hash of a list of commands.
+///
+/// The hash only depends on the content of the commands sent to Discord, and
+/// not on their order. It is the same across runs and platforms, so it can be
+/// stored to detect whether commands changed since they were last registered.
+/// Fields that are not sent to Discord (such as [`metadata`]) are ignored.
+///
+/// The hash may change in a new version of this crate, which only causes
+/// commands to be registered once more.
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::{command::CreateCommand, sync::hash};
+///
+/// #[derive(CreateCommand)]
+/// #[command(name = "hello", desc = "Say hello")]
+/// struct HelloCommand;
+///
+/// #[derive(CreateCommand)]
+/// #[command(name = "ping", desc = "Ping the bot")]
+/// struct PingCommand;
+///
+/// let commands = [HelloCommand::create_command(), PingCommand::create_command()];
+/// let stored = hash(&commands);
+///
+/// // Compare against the hash stored after the last registration
+/// assert_eq!(hash(&commands), stored);
+/// assert_ne!(hash(&commands[..1]), stored);
+/// ```
+///
+/// [`metadata`]: ApplicationCommandData::metadata
+pub fn hash(commands: &[ApplicationCommandData]) -> u64 {
+    let mut hashes: Vec<u64> = commands
+        .iter()
+        .map(|data| {
+            let mut hasher = StableHasher::new();
+            hasher.command(&Command::from(data.clone()));
+
+            hasher.finish()
+        })
+        .collect();
+    hashes.sort_unstable();
+
+    let mut hasher = StableHasher::new();
+    hasher.len(hashes.len());
+    for hash in hashes {
+        hasher.u64(hash);
+    }
+
+    hasher.finish()
+}
+
+/// Detailed change between a local and a remote command.
+///
+/// Changes of options contain the path to the option, made of the names of
+/// the subcommands and options leading to it. The path is empty for changes
+/// of the command itself.
+///
+/// The [`Display`] implementation describes the change in a
+/// single line, which can be shown to users before updating commands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldChange {
+    /// An option only exists locally.
+    OptionAdded {
+        /// Path to the added option, including its name.
+        path: Vec<String>,
+    },
+    /// An option only exists remotely.
+    OptionRemoved {
+        /// Path to the removed option, including its name.
+        path: Vec<String>,
+    },
+    /// Options exist on both sides but in a different order.
+    OptionsReordered {
+        /// Path to the parent of the options.
+        path: Vec<String>,
+    },
+    /// A localization differs.
+    Localization {
+        /// Path to the option, empty for the command itself.
+        path: Vec<String>,
+        /// Localized field.
+        field: LocalizedField,
+        /// Locale of the localization.
+        locale: String,
+        /// Local localization, if any.
+        local: Option<String>,
+        /// Remote localization, if any.
+        remote: Option<String>,
+    },
+    /// The value of a field differs.
+    ///
+    /// Values are formatted with their [`Debug`] implementation.
+    Value {
+        /// Path to the option, empty for the command itself.
+        path: Vec<String>,
+        /// Name of the field, such as `description` or `required`.
+        field: &'static str,
+        /// Local value.
+        local: String,
+        /// Remote value.
+        remote: String,
+    },
+}
+
+impl FieldChange {
+    /// Path to the changed option, or to the parent of reordered options.
+    pub fn path(&self) -> &[String] {
+        match self {
+            Self::OptionAdded { path }
+            | Self::OptionRemoved { path }
+            | Self::OptionsReordered { path }
+            | Self::Localization { path, .. }
+            | Self::Value { path, .. } => path,
+        }
+    }
+
+    /// Field of the command affected by the change.
+    ///
+    /// Returns `None` for changes of the command name, which is not compared
+    /// by [`diff`] since commands are matched by name.
+    pub fn command_field(&self) -> Option<CommandField> {
+        let field = match self {
+            Self::OptionAdded { .. }
+            | Self::OptionRemoved { .. }
+            | Self::OptionsReordered { .. } => CommandField::Options,
+            _ if !self.path().is_empty() => CommandField::Options,
+            Self::Localization { field, .. } => match field {
+                LocalizedField::Name => CommandField::NameLocalizations,
+                LocalizedField::Description => CommandField::DescriptionLocalizations,
+            },
+            Self::Value { field, .. } => match *field {
+                "description" => CommandField::Description,
+                "default_member_permissions" => CommandField::DefaultMemberPermissions,
+                "dm_permission" => CommandField::DmPermission,
+                "nsfw" => CommandField::Nsfw,
+                "contexts" => CommandField::Contexts,
+                "integration_types" => CommandField::IntegrationTypes,
+                _ => return None,
+            },
+        };
+
+        Some(field)
+    }
+
+    fn value(path: &[String], field: &'static str, local: impl Debug, remote: impl Debug) -> Self {
+        Self::Value {
+            path: path.to_vec(),
+            field,
+            local: format!("{local:?}"),
+            remote: format!("{remote:?}"),
+        }
+    }
+}
+
+impl Display for FieldChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let [_, ..] = self.path() {
+            if !matches!(self, Self::OptionAdded { .. } | Self::OptionRemoved { .. }) {
+                write!(f, "option `{}`: ", self.path().join(" "))?;
+            }
+        }
+
+        match self {
+            Self::OptionAdded { path } => write!(f, "added option `{}`", path.join(" ")),
+            Self::OptionRemoved { path } => write!(f, "removed option `{}`", path.join(" ")),
+            Self::OptionsReordered { .. } => f.write_str("options reordered"),
+            Self::Localization {
+                field,
+                locale,
+                local,
+                remote,
+                ..
+            } => match (local, remote) {
+                (Some(local), None) => write!(f, "added `{locale}` {field} localization {local:?}"),
+                (None, Some(_)) => write!(f, "removed `{locale}` {field} localization"),
+                (local, remote) => write!(
+                    f,
+                    "`{locale}` {field} localization changed from {remote:?} to {local:?}"
+                ),
+            },
+            Self::Value {
+                field,
+                local,
+                remote,
+                ..
+            } => write!(f, "`{field}` changed from {remote} to {local}"),
+        }
+    }
+}
+
+/// Field that can be localized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocalizedField {
+    /// Name of a command or option.
+    Name,
+    /// Description of a command or option.
+    Description,
+}
+
+impl Display for LocalizedField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Name => f.write_str("name"),
+            Self::Description => f.write_str("description"),
+        }
+    }
+}
+
+/// List the fields that differ between a local and a remote command.
+fn changed_fields(changes: &[FieldChange]) -> Vec<CommandField> {
+    let mut fields: Vec<_> = changes
+        .iter()
+        .filter_map(FieldChange::command_field)
+        .collect();
+    fields.sort_unstable();
+    fields.dedup();
+
+    fields
+}
+
+/// List the changes between a local and a remote command, except its name.
+fn command_changes(local: &Command, remote: &Command, changes: &mut Vec<FieldChange>) {
+    localization_changes(
+        &[],
+        LocalizedField::Name,
+        &local.name_localizations,
+        &remote.name_localizations,
+        changes,
+    );
+
+    if local.description != remote.description {
+        changes.push(FieldChange::value(
+            &[],
+            "description",
+            &local.description,
+            &remote.description,
+        ));
+    }
+
+    localization_changes(
+        &[],
+        LocalizedField::Description,
+        &local.description_localizations,
+        &remote.description_localizations,
+        changes,
+    );
+
+    options_changes(&[], &local.options, &remote.options, changes);
+
+    if local.default_member_permissions != remote.default_member_permissions {
+        changes.push(FieldChange::value(
+            &[],
+            "default_member_permissions",
+            local.default_member_permissions,
+            remote.default_member_permissions,
+        ));
+    }
+
+    #[allow(deprecated)]
+    let (local_dm, remote_dm) = (
+        local.dm_permission.unwrap_or(true),
+        remote.dm_permission.unwrap_or(true),
+    );
+    if local_dm != remote_dm {
+        changes.push(FieldChange::value(
+            &[],
+            "dm_permission",
+            local_dm,
+            remote_dm,
+        ));
+    }
+
+    let (local_nsfw, remote_nsfw) = (local.nsfw.unwrap_or(false), remote.nsfw.unwrap_or(false));
+    if local_nsfw != remote_nsfw {
+        changes.push(FieldChange::value(&[], "nsfw", local_nsfw, remote_nsfw));
+    }
+
+    if local.contexts.is_some() && local.contexts != remote.contexts {
+        changes.push(FieldChange::value(
+            &[],
+            "contexts",
+            &local.contexts,
+            &remote.contexts,
+        ));
+    }
+
+    if local.integration_types.is_some() && local.integration_types != remote.integration_types {
+        changes.push(FieldChange::value(
+            &[],
+            "integration_types",
+            &local.integration_types,
+            &remote.integration_types,
+        ));
+    }
+}
+
+/// List the changes between two lists of options, matched by name.
+fn options_changes(
+    path: &[String],
+    local: &[CommandOption],
+    remote: &[CommandOption],
+    changes: &mut Vec<FieldChange>,
+) {
+    let option_path = |name: &str| {
+        let mut path = path.to_vec();
+        path.push(name.to_owned());
+        path
+    };
+
+    for option in local {
+        match remote.iter().find(|other| other.name == option.name) {
+            Some(other) => option_changes(&option_path(&option.name), option, other, changes),
+            None => changes.push(FieldChange::OptionAdded {
+                path: option_path(&option.name),
+            }),
+        }
+    }
+
+    for option in remote {
+        if !local.iter().any(|other| other.name == option.name) {
+            changes.push(FieldChange::OptionRemoved {
+                path: option_path(&option.name),
+            });
+        }
+    }
+
+    // Options existing on both sides must be in the same order
+    if common_names(local, remote) != common_names(remote, local) {
+        changes.push(FieldChange::OptionsReordered {
+            path: path.to_vec(),
+        });
+    }
+}
+
+/// Format two values if they differ.
+fn value_change<T: PartialEq + Debug>(local: T, remote: T) -> Option<(String, String)> {
+    (local != remote).then(|| (format!("{local:?}"), format!("{remote:?}")))
+}
+
+/// Names of the options that also exist in another list, in order.
+fn common_names<'a>(options: &'a [CommandOption], others: &[CommandOption]) -> Vec<&'a str> {
+    options
+        .iter()
+        .filter(|option| others.iter().any(|other| other.name == option.name))
+        .map(|option| option.name.as_str())
+        .collect()
+}
+
+/// List the changes between two options with the same name.
+fn option_changes(
+    path: &[String],
+    local: &CommandOption,
+    remote: &CommandOption,
+    changes: &mut Vec<FieldChange>,
+) {
+    if local.kind != remote.kind {
+        changes.push(FieldChange::value(path, "type", local.kind, remote.kind));
+    }
+
+    localization_changes(
+        path,
+        LocalizedField::Name,
+        &local.name_localizations,
+        &remote.name_localizations,
+        changes,
+    );
+
+    if local.description != remote.description {
+        changes.push(FieldChange::value(
+            path,
+            "description",
+            &local.description,
+            &remote.description,
+        ));
+    }
+
+    localization_changes(
+        path,
+        LocalizedField::Description,
+        &local.description_localizations,
+        &remote.description_localizations,
+        changes,
+    );
+
+    let values = [
+        (
+            "required",
+            value_change(
+                local.required.unwrap_or(false),
+                remote.required.unwrap_or(false),
+            ),
+        ),
+        (
+            "autocomplete",
+            value_change(
+                local.autocomplete.unwrap_or(false),
+                remote.autocomplete.unwrap_or(false),
+            ),
+        ),
+        (
+            "choices",
+            value_change(
+                local.choices.as_deref().unwrap_or_default(),
+                remote.choices.as_deref().unwrap_or_default(),
+            ),
+        ),
+        (
+            "channel_types",
+            value_change(
+                local.channel_types.as_deref().unwrap_or_default(),
+                remote.channel_types.as_deref().unwrap_or_default(),
+            ),
+        ),
+        ("min_value", value_change(local.min_value, remote.min_value)),
+        ("max_value", value_change(local.max_value, remote.max_value)),
+        (
+            "min_length",
+            value_change(local.min_length, remote.min_length),
+        ),
+        (
+            "max_length",
+            value_change(local.max_length, remote.max_length),
+        ),
+    ];
+
+    for (field, change) in values {
+        if let Some((local, remote)) = change {
+            changes.push(FieldChange::Value {
+                path: path.to_vec(),
+                field,
+                local,
+                remote,
+            });
+        }
+    }
+
+    options_changes(
+        path,
+        local.options.as_deref().unwrap_or_default(),
+        remote.options.as_deref().unwrap_or_default(),
+        changes,
+    );
+}
+
+/// List the changes between two localization maps, considering unset maps
+/// as empty.
+fn localization_changes(
+    path: &[String],
+    field: LocalizedField,
+    local: &Option<HashMap<String, String>>,
+    remote: &Option<HashMap<String, String>>,
+    changes: &mut Vec<FieldChange>,
+) {
+    let empty = HashMap::new();
+    let (local, remote) = (
+        local.as_ref().unwrap_or(&empty),
+        remote.as_ref().unwrap_or(&empty),
+    );
+
+    let mut locales: Vec<_> = local.keys().chain(remote.keys()).collect();
+    locales.sort_unstable();
+    locales.dedup();
+
+    for locale in locales {
+        let (local, remote) = (local.get(locale), remote.get(locale));
+
+        if local != remote {
+            changes.push(FieldChange::Localization {
+                path: path.to_vec(),
+                field,
+                locale: locale.clone(),
+                local: local.cloned(),
+                remote: remote.cloned(),
+            });
+        }
+    }
+}
+
+/// Hasher of commands producing the same output across runs and platforms.
+///
+/// This uses the 64-bit FNV-1a algorithm. Variable-length values are prefixed
+/// with their length and maps are hashed in the order of their keys, so
+/// different commands do not produce the same sequence of bytes.
+struct StableHasher(u64);
+
+impl StableHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn u8(&mut self, value: u8) {
+        self.bytes(&[value]);
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn len(&mut self, len: usize) {
+        self.u64(len as u64);
+    }
+
+    fn str(&mut self, value: &str) {
+        self.len(value.len());
+        self.bytes(value.as_bytes());
+    }
+
+    fn option<T>(&mut self, value: Option<T>, hash: impl FnOnce(&mut Self, T)) {
+        match value {
+            None => self.u8(0),
+            Some(value) => {
+                self.u8(1);
+                hash(self, value);
+            }
+        }
+    }
+
+    fn bool(&mut self, value: Option<bool>) {
+        self.option(value, |hasher, value| hasher.u8(value.into()));
+    }
+
+    fn localizations(&mut self, localizations: Option<&HashMap<String, String>>) {
+        self.option(localizations, |hasher, localizations| {
+            let mut entries: Vec<_> = localizations.iter().collect();
+            entries.sort_unstable();
+
+            hasher.len(entries.len());
+            for (locale, value) in entries {
+                hasher.str(locale);
+                hasher.str(value);
+            }
+        });
+    }
+
+    fn number(&mut self, value: Option<CommandOptionValue>) {
+        self.option(value, |hasher, value| match value {
+            CommandOptionValue::Integer(value) => {
+                hasher.u8(0);
+                hasher.u64(value as u64);
+            }
+            CommandOptionValue::Number(value) => {
+                hasher.u8(1);
+                hasher.u64(value.to_bits());
+            }
+        });
+    }
+
+    #[allow(deprecated)]
+    fn command(&mut self, command: &Command) {
+        self.u8(command.kind.into());
+        self.str(&command.name);
+        self.localizations(command.name_localizations.as_ref());
+        self.str(&command.description);
+        self.localizations(command.description_localizations.as_ref());
+        self.option(command.default_member_permissions, |hasher, permissions| {
+            hasher.u64(permissions.bits());
+        });
+        self.bool(command.dm_permission);
+        self.bool(command.nsfw);
+        self.option(command.contexts.as_ref(), |hasher, contexts| {
+            hasher.len(contexts.len());
+            for context in contexts {
+                hasher.u8((*context).into());
+            }
+        });
+        self.option(command.integration_types.as_ref(), |hasher, types| {
+            hasher.len(types.len());
+            for kind in types {
+                hasher.u8((*kind).into());
+            }
+        });
+        self.options(&command.options);
+    }
+
+    fn options(&mut self, options: &[CommandOption]) {
+        self.len(options.len());
+        for option in options {
+            self.option_data(option);
+        }
+    }
+
+    fn option_data(&mut self, option: &CommandOption) {
+        self.u8(option.kind as u8);
+        self.str(&option.name);
+        self.localizations(option.name_localizations.as_ref());
+        self.str(&option.description);
+        self.localizations(option.description_localizations.as_ref());
+        self.bool(option.required);
+        self.bool(option.autocomplete);
+        self.number(option.min_value);
+        self.number(option.max_value);
+        self.option(option.min_length, |hasher, len| hasher.u64(len.into()));
+        self.option(option.max_length, |hasher, len| hasher.u64(len.into()));
+        self.option(option.channel_types.as_ref(), |hasher, types| {
+            hasher.len(types.len());
+            for kind in types {
+                hasher.u8((*kind).into());
+            }
+        });
+        self.option(option.choices.as_ref(), |hasher, choices| {
+            hasher.len(choices.len());
+            for choice in choices {
+                hasher.choice(choice);
+            }
+        });
+        self.option(option.options.as_ref(), |hasher, options| {
+            hasher.options(options);
+        });
+    }
+
+    fn choice(&mut self, choice: &CommandOptionChoice) {
+        self.str(&choice.name);
+        self.localizations(choice.name_localizations.as_ref());
+        match &choice.value {
+            CommandOptionChoiceValue::String(value) => {
+                self.u8(0);
+                self.str(value);
+            }
+            CommandOptionChoiceValue::Integer(value) => {
+                self.u8(1);
+                self.u64(*value as u64);
+            }
+            CommandOptionChoiceValue::Number(value) => {
+                self.u8(2);
+                self.u64(value.to_bits());
+            }
+        }
+    }
+}
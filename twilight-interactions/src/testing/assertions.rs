@@ -0,0 +1,615 @@
+use std::{
+    borrow::Cow,
+    collections::{BTreeSet, HashMap},
+};
+
+use twilight_model::{
+    application::{
+        command::{
+            CommandOption, CommandOptionChoice, CommandOptionChoiceValue, CommandOptionType,
+            CommandOptionValue as NumberCommandOptionValue,
+        },
+        interaction::{
+            application_command::{CommandDataOption, CommandOptionValue},
+            InteractionChannel, InteractionDataResolved,
+        },
+    },
+    channel::{Attachment, ChannelType},
+    guild::{Permissions, Role, RoleFlags},
+    id::Id,
+    user::User,
+};
+
+use crate::{
+    command::{CommandInputData, CommandModel, CreateCommand},
+    error::ParseError,
+};
+
+/// Maximum length of command, option and choice names.
+const NAME_MAX_LENGTH: usize = 32;
+/// Maximum length of command and option descriptions.
+const DESCRIPTION_MAX_LENGTH: usize = 100;
+/// Maximum number of options or choices.
+const OPTIONS_MAX_COUNT: usize = 25;
+/// Maximum length of choice names and string values.
+const CHOICE_MAX_LENGTH: usize = 100;
+/// Maximum length of string options.
+const STRING_MAX_LENGTH: u16 = 6000;
+/// Maximum combined length of names, descriptions and choices of a command.
+const COMMAND_MAX_LENGTH: usize = 4000;
+
+/// Assert that input matching a command can be parsed.
+///
+/// A sample input is generated for the command and each of its subcommands,
+/// with a value for each required option that respects the option
+/// constraints. The input is then parsed with [`CommandModel`], which must
+/// succeed. Only [`ParseError::MissingContext`] errors are ignored, since
+/// sample inputs are not attached to an interaction.
+///
+/// Sample values are simple placeholders, such as `"a"` for strings. Required
+/// options parsed from a specific format (like [`Parsed`] or [`Duration`]) or
+/// checked by validation functions must use choices so that the sample
+/// matches their format, otherwise this assertion fails.
+///
+/// [`Parsed`]: crate::command::Parsed
+/// [`Duration`]: std::time::Duration
+///
+/// # Panics
+/// Panics if a sample input cannot be parsed.
+#[track_caller]
+pub fn assert_create_parse_symmetry<T: CommandModel + CreateCommand>() {
+    let command = T::create_command();
+    let mut samples = Vec::new();
+    sample_inputs(&command.name, &command.options, &mut samples);
+
+    for (path, options) in samples {
+        let data = CommandInputData {
+            options,
+            resolved: Some(Cow::Owned(sample_resolved())),
+        };
+
+        match T::from_interaction(data) {
+            Ok(_) | Err(ParseError::MissingContext(_)) => (),
+            Err(error) => panic!("failed to parse sample input for `{path}`: {error}"),
+        }
+    }
+}
+
+/// Assert that a command respects the limits enforced by Discord.
+///
+/// The following limits are checked:
+/// - Names are between 1 and 32 characters, lowercase and without spaces.
+/// - Descriptions are between 1 and 100 characters.
+/// - There is at most 25 options and choices, with required options first.
+/// - Choice names and string values are between 1 and 100 characters.
+/// - Minimum values and lengths are not greater than maximum ones, and string
+///   lengths are not greater than 6000.
+/// - The combined length of names, descriptions and choices is not greater
+///   than 4000 characters.
+///
+/// # Panics
+/// Panics with the list of violations if any limit is not respected.
+#[track_caller]
+pub fn assert_limits<T: CreateCommand>() {
+    let command = T::create_command();
+    let violations = limit_violations(
+        &command.name,
+        &command.name_localizations,
+        Some((&command.description, &command.description_localizations)),
+        &command.options,
+    );
+
+    if !violations.is_empty() {
+        panic!(
+            "command `{}` does not respect Discord limits:\n- {}",
+            command.name,
+            violations.join("\n- ")
+        );
+    }
+}
+
+/// Collect violations of the limits checked by [`assert_limits`].
+///
+/// The description is only checked for chat input commands, which is
+/// indicated by passing `Some`.
+pub(super) fn limit_violations(
+    name: &str,
+    name_localizations: &Option<HashMap<String, String>>,
+    description: Option<(&str, &Option<HashMap<String, String>>)>,
+    options: &[CommandOption],
+) -> Vec<String> {
+    let mut checker = LimitChecker::default();
+
+    checker.name(name, name, name_localizations);
+    if let Some((description, localizations)) = description {
+        checker.description(name, description, localizations);
+    }
+    checker.options(name, options);
+
+    if checker.length > COMMAND_MAX_LENGTH {
+        checker.violations.push(format!(
+            "`{name}`: combined length of {} characters (more than {COMMAND_MAX_LENGTH})",
+            checker.length
+        ));
+    }
+
+    checker.violations
+}
+
+/// Assert that every name and description of a command is localized in the
+/// same locales.
+///
+/// The expected locales are all locales used in any localization of the
+/// command, its options or their choices. Commands without localizations
+/// always pass this check.
+///
+/// # Panics
+/// Panics with the list of missing localizations if any.
+#[track_caller]
+pub fn assert_localizations<T: CreateCommand>() {
+    let command = T::create_command();
+    let mut localizations = Vec::new();
+
+    localizations.push((
+        format!("{} (name)", command.name),
+        command.name_localizations.as_ref(),
+    ));
+    localizations.push((
+        format!("{} (description)", command.name),
+        command.description_localizations.as_ref(),
+    ));
+    option_localizations(&command.name, &command.options, &mut localizations);
+
+    let locales = localizations
+        .iter()
+        .flat_map(|(_, localizations)| localizations.iter().flat_map(|map| map.keys()))
+        .collect::<BTreeSet<_>>();
+
+    let missing = localizations
+        .iter()
+        .filter_map(|(path, localizations)| {
+            let missing = locales
+                .iter()
+                .filter(|locale| !localizations.is_some_and(|map| map.contains_key(**locale)))
+                .map(|locale| locale.as_str())
+                .collect::<Vec<_>>();
+
+            (!missing.is_empty()).then(|| format!("`{path}`: {}", missing.join(", ")))
+        })
+        .collect::<Vec<_>>();
+
+    if !missing.is_empty() {
+        panic!(
+            "command `{}` has missing localizations:\n- {}",
+            command.name,
+            missing.join("\n- ")
+        );
+    }
+}
+
+/// Generate sample input for a command and each of its subcommands.
+pub(super) fn sample_inputs(
+    path: &str,
+    options: &[CommandOption],
+    samples: &mut Vec<(String, Vec<CommandDataOption>)>,
+) {
+    let subcommands = options.iter().filter(|option| {
+        matches!(
+            option.kind,
+            CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup
+        )
+    });
+
+    let mut has_subcommands = false;
+
+    for subcommand in subcommands {
+        has_subcommands = true;
+
+        let mut nested = Vec::new();
+        let sub_path = format!("{path} {}", subcommand.name);
+        sample_inputs(
+            &sub_path,
+            subcommand.options.as_deref().unwrap_or_default(),
+            &mut nested,
+        );
+
+        samples.extend(nested.into_iter().map(|(path, options)| {
+            let value = match subcommand.kind {
+                CommandOptionType::SubCommandGroup => CommandOptionValue::SubCommandGroup(options),
+                _ => CommandOptionValue::SubCommand(options),
+            };
+
+            let option = CommandDataOption {
+                name: subcommand.name.clone(),
+                value,
+            };
+
+            (path, vec![option])
+        }));
+    }
+
+    if !has_subcommands {
+        let options = options
+            .iter()
+            .filter(|option| option.required == Some(true))
+            .map(|option| CommandDataOption {
+                name: option.name.clone(),
+                value: sample_value(option),
+            })
+            .collect();
+
+        samples.push((path.to_owned(), options));
+    }
+}
+
+/// Generate a sample value respecting the constraints of an option.
+fn sample_value(option: &CommandOption) -> CommandOptionValue {
+    if let Some(choice) = option.choices.as_deref().and_then(<[_]>::first) {
+        return match &choice.value {
+            CommandOptionChoiceValue::String(value) => CommandOptionValue::String(value.clone()),
+            CommandOptionChoiceValue::Integer(value) => CommandOptionValue::Integer(*value),
+            CommandOptionChoiceValue::Number(value) => CommandOptionValue::Number(*value),
+        };
+    }
+
+    match option.kind {
+        CommandOptionType::String => {
+            let length = option.min_length.unwrap_or(1).max(1);
+            CommandOptionValue::String("a".repeat(length.into()))
+        }
+        CommandOptionType::Integer => {
+            let mut value = 1;
+            if let Some(NumberCommandOptionValue::Integer(max)) = option.max_value {
+                value = value.min(max);
+            }
+            if let Some(NumberCommandOptionValue::Integer(min)) = option.min_value {
+                value = value.max(min);
+            }
+            CommandOptionValue::Integer(value)
+        }
+        CommandOptionType::Number => {
+            let mut value = 1.0;
+            if let Some(NumberCommandOptionValue::Number(max)) = option.max_value {
+                value = f64::min(value, max);
+            }
+            if let Some(NumberCommandOptionValue::Number(min)) = option.min_value {
+                value = f64::max(value, min);
+            }
+            CommandOptionValue::Number(value)
+        }
+        CommandOptionType::Boolean => CommandOptionValue::Boolean(true),
+        CommandOptionType::User => CommandOptionValue::User(Id::new(1)),
+        CommandOptionType::Channel => {
+            let kind = option
+                .channel_types
+                .as_deref()
+                .and_then(<[_]>::first)
+                .copied()
+                .unwrap_or(ChannelType::GuildText);
+
+            CommandOptionValue::Channel(Id::new(sample_channel_id(kind)))
+        }
+        CommandOptionType::Role => CommandOptionValue::Role(Id::new(1)),
+        CommandOptionType::Mentionable => CommandOptionValue::Mentionable(Id::new(1)),
+        CommandOptionType::Attachment => CommandOptionValue::Attachment(Id::new(1)),
+        kind => panic!(
+            "cannot generate a sample value for option type {}",
+            kind.kind()
+        ),
+    }
+}
+
+/// Channel types for which a sample channel is resolved.
+const SAMPLE_CHANNEL_TYPES: [ChannelType; 13] = [
+    ChannelType::GuildText,
+    ChannelType::Private,
+    ChannelType::GuildVoice,
+    ChannelType::Group,
+    ChannelType::GuildCategory,
+    ChannelType::GuildAnnouncement,
+    ChannelType::AnnouncementThread,
+    ChannelType::PublicThread,
+    ChannelType::PrivateThread,
+    ChannelType::GuildStageVoice,
+    ChannelType::GuildDirectory,
+    ChannelType::GuildForum,
+    ChannelType::GuildMedia,
+];
+
+/// ID of the sample channel with the given type.
+fn sample_channel_id(kind: ChannelType) -> u64 {
+    let position = SAMPLE_CHANNEL_TYPES
+        .iter()
+        .position(|other| *other == kind)
+        .unwrap_or_default();
+
+    position as u64 + 1
+}
+
+/// Resolved data referenced by sample values.
+pub(super) fn sample_resolved() -> InteractionDataResolved {
+    let user = User {
+        accent_color: None,
+        avatar: None,
+        avatar_decoration: None,
+        avatar_decoration_data: None,
+        banner: None,
+        bot: false,
+        discriminator: 0,
+        email: None,
+        flags: None,
+        global_name: None,
+        id: Id::new(1),
+        locale: None,
+        mfa_enabled: None,
+        name: "user".into(),
+        premium_type: None,
+        public_flags: None,
+        system: None,
+        verified: None,
+    };
+
+    let role = Role {
+        color: 0,
+        hoist: false,
+        icon: None,
+        id: Id::new(1),
+        managed: false,
+        mentionable: true,
+        name: "role".into(),
+        permissions: Permissions::empty(),
+        position: 0,
+        flags: RoleFlags::empty(),
+        tags: None,
+        unicode_emoji: None,
+    };
+
+    let channels = SAMPLE_CHANNEL_TYPES.into_iter().map(|kind| {
+        let id = Id::new(sample_channel_id(kind));
+        let channel = InteractionChannel {
+            id,
+            kind,
+            name: "channel".into(),
+            parent_id: None,
+            permissions: Permissions::empty(),
+            thread_metadata: None,
+        };
+
+        (id, channel)
+    });
+
+    let attachment = Attachment {
+        content_type: None,
+        ephemeral: false,
+        duration_secs: None,
+        filename: "file.txt".into(),
+        flags: None,
+        description: None,
+        height: None,
+        id: Id::new(1),
+        proxy_url: "https://media.discordapp.net/file.txt".into(),
+        size: 0,
+        title: None,
+        url: "https://cdn.discordapp.com/file.txt".into(),
+        waveform: None,
+        width: None,
+    };
+
+    InteractionDataResolved {
+        attachments: HashMap::from([(attachment.id, attachment)]),
+        channels: channels.collect(),
+        members: HashMap::new(),
+        messages: HashMap::new(),
+        roles: HashMap::from([(role.id, role)]),
+        users: HashMap::from([(user.id, user)]),
+    }
+}
+
+/// Collect the localizations of options and their choices.
+fn option_localizations<'a>(
+    path: &str,
+    options: &'a [CommandOption],
+    localizations: &mut Vec<(String, Option<&'a HashMap<String, String>>)>,
+) {
+    for option in options {
+        let path = format!("{path} {}", option.name);
+
+        localizations.push((format!("{path} (name)"), option.name_localizations.as_ref()));
+        localizations.push((
+            format!("{path} (description)"),
+            option.description_localizations.as_ref(),
+        ));
+
+        for choice in option.choices.as_deref().unwrap_or_default() {
+            localizations.push((
+                format!("{path} (choice `{}`)", choice.name),
+                choice.name_localizations.as_ref(),
+            ));
+        }
+
+        option_localizations(
+            &path,
+            option.options.as_deref().unwrap_or_default(),
+            localizations,
+        );
+    }
+}
+
+/// Collect violations of Discord limits.
+#[derive(Default)]
+struct LimitChecker {
+    /// Combined length of names, descriptions and choices.
+    length: usize,
+    violations: Vec<String>,
+}
+
+impl LimitChecker {
+    fn name(&mut self, path: &str, name: &str, localizations: &Option<HashMap<String, String>>) {
+        self.length += name.chars().count();
+
+        let localized = localizations.iter().flat_map(|map| map.values());
+
+        for name in std::iter::once(name).chain(localized.map(String::as_str)) {
+            let length = name.chars().count();
+
+            if length == 0 || length > NAME_MAX_LENGTH {
+                self.violations.push(format!(
+                    "`{path}`: name `{name}` must be between 1 and {NAME_MAX_LENGTH} characters"
+                ));
+            }
+
+            if name.chars().any(|c| !valid_name_char(c)) {
+                self.violations.push(format!(
+                    "`{path}`: name `{name}` must be lowercase and only contain letters, numbers, \
+                     `-` and `_`"
+                ));
+            }
+        }
+    }
+
+    fn description(
+        &mut self,
+        path: &str,
+        description: &str,
+        localizations: &Option<HashMap<String, String>>,
+    ) {
+        self.length += description.chars().count();
+
+        let localized = localizations.iter().flat_map(|map| map.values());
+
+        for description in std::iter::once(description).chain(localized.map(String::as_str)) {
+            let length = description.chars().count();
+
+            if length == 0 || length > DESCRIPTION_MAX_LENGTH {
+                self.violations.push(format!(
+                    "`{path}`: description `{description}` must be between 1 and \
+                     {DESCRIPTION_MAX_LENGTH} characters"
+                ));
+            }
+        }
+    }
+
+    fn options(&mut self, path: &str, options: &[CommandOption]) {
+        if options.len() > OPTIONS_MAX_COUNT {
+            self.violations.push(format!(
+                "`{path}`: {} options (more than {OPTIONS_MAX_COUNT})",
+                options.len()
+            ));
+        }
+
+        let first_optional = options
+            .iter()
+            .position(|option| option.required != Some(true));
+
+        if let Some(first_optional) = first_optional {
+            let required_after = options[first_optional..]
+                .iter()
+                .any(|option| option.required == Some(true));
+
+            if required_after {
+                self.violations.push(format!(
+                    "`{path}`: required options must be placed before optional options"
+                ));
+            }
+        }
+
+        for option in options {
+            let path = format!("{path} {}", option.name);
+
+            self.name(&path, &option.name, &option.name_localizations);
+            self.description(
+                &path,
+                &option.description,
+                &option.description_localizations,
+            );
+            self.constraints(&path, option);
+            self.choices(&path, option.choices.as_deref().unwrap_or_default());
+            self.options(&path, option.options.as_deref().unwrap_or_default());
+        }
+    }
+
+    fn constraints(&mut self, path: &str, option: &CommandOption) {
+        let values = match (option.min_value, option.max_value) {
+            (
+                Some(NumberCommandOptionValue::Integer(min)),
+                Some(NumberCommandOptionValue::Integer(max)),
+            ) => Some((min as f64, max as f64)),
+            (
+                Some(NumberCommandOptionValue::Number(min)),
+                Some(NumberCommandOptionValue::Number(max)),
+            ) => Some((min, max)),
+            _ => None,
+        };
+
+        if values.is_some_and(|(min, max)| min > max) {
+            self.violations.push(format!(
+                "`{path}`: minimum value is greater than maximum value"
+            ));
+        }
+
+        if let (Some(min), Some(max)) = (option.min_length, option.max_length) {
+            if min > max {
+                self.violations.push(format!(
+                    "`{path}`: minimum length is greater than maximum length"
+                ));
+            }
+        }
+
+        for length in [option.min_length, option.max_length].into_iter().flatten() {
+            if length > STRING_MAX_LENGTH {
+                self.violations.push(format!(
+                    "`{path}`: length of {length} (more than {STRING_MAX_LENGTH})"
+                ));
+            }
+        }
+    }
+
+    fn choices(&mut self, path: &str, choices: &[CommandOptionChoice]) {
+        if choices.len() > OPTIONS_MAX_COUNT {
+            self.violations.push(format!(
+                "`{path}`: {} choices (more than {OPTIONS_MAX_COUNT})",
+                choices.len()
+            ));
+        }
+
+        for choice in choices {
+            let localized = choice
+                .name_localizations
+                .iter()
+                .flat_map(|map| map.values());
+
+            for name in std::iter::once(&choice.name).chain(localized) {
+                let length = name.chars().count();
+
+                if length == 0 || length > CHOICE_MAX_LENGTH {
+                    self.violations.push(format!(
+                        "`{path}`: choice name `{name}` must be between 1 and \
+                         {CHOICE_MAX_LENGTH} characters"
+                    ));
+                }
+            }
+
+            self.length += choice.name.chars().count();
+
+            if let CommandOptionChoiceValue::String(value) = &choice.value {
+                let length = value.chars().count();
+                self.length += length;
+
+                if length == 0 || length > CHOICE_MAX_LENGTH {
+                    self.violations.push(format!(
+                        "`{path}`: choice value `{value}` must be between 1 and \
+                         {CHOICE_MAX_LENGTH} characters"
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Whether a character is allowed in names.
+fn valid_name_char(c: char) -> bool {
+    let allowed = c.is_alphanumeric() || c == '-' || c == '_';
+    let lowercase = c.to_lowercase().eq(std::iter::once(c));
+
+    allowed && lowercase
+}
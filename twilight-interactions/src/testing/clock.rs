@@ -0,0 +1,118 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+use crate::registry::{Clock, Sleep};
+
+/// Clock whose time only changes when advanced manually.
+///
+/// This type implements [`Clock`] and can be set on a [`CommandRegistry`] to
+/// simulate the response windows of Discord deterministically. Clones of a
+/// [`MockClock`] share the same time, so a clone can be kept to advance the
+/// clock used by the registry.
+///
+/// ## Example
+/// ```
+/// use std::time::Duration;
+///
+/// use twilight_interactions::{
+///     registry::{Clock, CommandRegistry, RESPONSE_DEADLINE},
+///     testing::MockClock,
+/// };
+///
+/// let clock = MockClock::new();
+/// let registry = CommandRegistry::<()>::new().clock(clock.clone());
+///
+/// let start = clock.now();
+/// clock.advance(RESPONSE_DEADLINE);
+/// assert_eq!(clock.now() - start, Duration::from_secs(3));
+/// ```
+///
+/// [`CommandRegistry`]: crate::registry::CommandRegistry
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    state: Arc<Mutex<MockState>>,
+}
+
+/// Shared state of a [`MockClock`].
+#[derive(Debug)]
+struct MockState {
+    now: Instant,
+    sleepers: Vec<(Instant, Waker)>,
+}
+
+impl MockClock {
+    /// Create a new [`MockClock`] starting at the current time.
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(MockState {
+                now: Instant::now(),
+                sleepers: Vec::new(),
+            })),
+        }
+    }
+
+    /// Advance the time of the clock, waking sleeps whose deadline is
+    /// reached.
+    pub fn advance(&self, duration: Duration) {
+        let mut state = self.state.lock().unwrap();
+        state.now += duration;
+
+        let now = state.now;
+        let (ready, pending) = state
+            .sleepers
+            .drain(..)
+            .partition(|(deadline, _)| *deadline <= now);
+        state.sleepers = pending;
+        drop(state);
+
+        for (_, waker) in ready {
+            waker.wake();
+        }
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.state.lock().unwrap().now
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Sleep {
+        Box::pin(MockSleep {
+            state: Arc::clone(&self.state),
+            deadline,
+        })
+    }
+}
+
+/// Future returned by [`MockClock::sleep_until`].
+struct MockSleep {
+    state: Arc<Mutex<MockState>>,
+    deadline: Instant,
+}
+
+impl Future for MockSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+
+        if state.now >= self.deadline {
+            return Poll::Ready(());
+        }
+
+        state.sleepers.push((self.deadline, cx.waker().clone()));
+
+        Poll::Pending
+    }
+}
@@ -0,0 +1,301 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use twilight_model::{
+    application::{
+        command::{Command, CommandOption, CommandOptionType, CommandType},
+        interaction::{
+            application_command::{CommandData, CommandDataOption, CommandOptionValue},
+            Interaction, InteractionData, InteractionType,
+        },
+    },
+    id::{marker::ApplicationMarker, Id},
+    oauth::ApplicationIntegrationMap,
+};
+
+use super::assertions::{limit_violations, sample_inputs, sample_resolved};
+use crate::error::FakeDiscordError;
+
+/// Simulated Discord server for local development.
+///
+/// This type stores registered commands like Discord would, and synthesizes
+/// interactions against them. It allows iterating on commands without
+/// registering them in a test guild:
+///
+/// - [`set_commands`] validates the commands with the same rules as
+///   [`assert_limits`] and stores them, replacing previously registered
+///   commands.
+/// - [`interaction`] synthesizes a command interaction, checking that the
+///   provided options match the registered command.
+/// - [`sample_interactions`] synthesizes an interaction for the command and
+///   each of its subcommands, with a value for every required option.
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::{
+///     command::{CommandModel, CreateCommand},
+///     registry::CommandRegistry,
+///     testing::FakeDiscord,
+/// };
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "hello", desc = "Say hello")]
+/// struct HelloCommand {
+///     /// The message to send.
+///     message: String,
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let registry = CommandRegistry::new().register(|command: HelloCommand, _interaction| {
+///     async move { format!("Hello {}!", command.message) }
+/// });
+///
+/// let mut discord = FakeDiscord::new();
+/// discord.set_commands(&registry.commands()).unwrap();
+///
+/// for interaction in discord.sample_interactions("hello").unwrap() {
+///     let response = registry.handle(interaction).await.unwrap();
+///     assert_eq!(response, "Hello a!");
+/// }
+/// # }
+/// ```
+///
+/// [`set_commands`]: Self::set_commands
+/// [`interaction`]: Self::interaction
+/// [`sample_interactions`]: Self::sample_interactions
+/// [`assert_limits`]: super::assert_limits
+#[derive(Debug)]
+pub struct FakeDiscord {
+    application_id: Id<ApplicationMarker>,
+    commands: Vec<Command>,
+    /// Last ID given to a command or interaction.
+    last_id: AtomicU64,
+}
+
+impl FakeDiscord {
+    /// Create a new [`FakeDiscord`] without registered commands.
+    pub fn new() -> Self {
+        Self {
+            application_id: Id::new(1),
+            commands: Vec::new(),
+            last_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Register commands, replacing previously registered ones.
+    ///
+    /// Commands are validated before being stored, and no command is
+    /// registered if any of them is invalid. Like Discord, commands keep
+    /// their ID if a command with the same name and type was already
+    /// registered.
+    ///
+    /// The registered commands are returned.
+    pub fn set_commands(&mut self, commands: &[Command]) -> Result<&[Command], FakeDiscordError> {
+        for (index, command) in commands.iter().enumerate() {
+            let description = match command.kind {
+                CommandType::ChatInput => {
+                    Some((&*command.description, &command.description_localizations))
+                }
+                _ => None,
+            };
+
+            let violations = limit_violations(
+                &command.name,
+                &command.name_localizations,
+                description,
+                &command.options,
+            );
+
+            if !violations.is_empty() {
+                return Err(FakeDiscordError::InvalidCommand {
+                    name: command.name.clone(),
+                    violations,
+                });
+            }
+
+            let duplicate = commands[..index]
+                .iter()
+                .any(|other| other.name == command.name && other.kind == command.kind);
+
+            if duplicate {
+                return Err(FakeDiscordError::DuplicateCommand(command.name.clone()));
+            }
+        }
+
+        let commands = commands
+            .iter()
+            .map(|command| {
+                let id = self
+                    .commands
+                    .iter()
+                    .find(|other| other.name == command.name && other.kind == command.kind)
+                    .and_then(|other| other.id)
+                    .unwrap_or_else(|| Id::new(self.next_id()));
+
+                Command {
+                    application_id: Some(self.application_id),
+                    id: Some(id),
+                    version: Id::new(self.next_id()),
+                    ..command.clone()
+                }
+            })
+            .collect();
+
+        self.commands = commands;
+
+        Ok(&self.commands)
+    }
+
+    /// Get the registered commands.
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    /// Synthesize a chat input command interaction.
+    ///
+    /// The provided options are checked against the registered command: each
+    /// option must exist and have a value of the right type, and required
+    /// options must be present. Resolved data is provided for users, roles,
+    /// channels and attachments with any ID.
+    pub fn interaction(
+        &self,
+        name: &str,
+        options: Vec<CommandDataOption>,
+    ) -> Result<Interaction, FakeDiscordError> {
+        let command = self.command(name)?;
+        check_options(name, &command.options, &options)?;
+
+        Ok(self.build_interaction(command, options))
+    }
+
+    /// Synthesize sample interactions for a chat input command.
+    ///
+    /// An interaction is returned for the command, or for each of its
+    /// subcommands, with a value for every required option that respects the
+    /// option constraints. These are the same values as the ones used by
+    /// [`assert_create_parse_symmetry`].
+    ///
+    /// [`assert_create_parse_symmetry`]: super::assert_create_parse_symmetry
+    pub fn sample_interactions(&self, name: &str) -> Result<Vec<Interaction>, FakeDiscordError> {
+        let command = self.command(name)?;
+
+        let mut samples = Vec::new();
+        sample_inputs(name, &command.options, &mut samples);
+
+        Ok(samples
+            .into_iter()
+            .map(|(_, options)| self.build_interaction(command, options))
+            .collect())
+    }
+
+    /// Find a registered chat input command.
+    fn command(&self, name: &str) -> Result<&Command, FakeDiscordError> {
+        self.commands
+            .iter()
+            .find(|command| command.kind == CommandType::ChatInput && command.name == name)
+            .ok_or_else(|| FakeDiscordError::UnknownCommand(name.to_owned()))
+    }
+
+    fn next_id(&self) -> u64 {
+        self.last_id.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    fn build_interaction(&self, command: &Command, options: Vec<CommandDataOption>) -> Interaction {
+        let resolved = sample_resolved();
+        let user = resolved.users.values().next().cloned();
+
+        let data = CommandData {
+            guild_id: None,
+            id: command.id.unwrap_or_else(|| Id::new(1)).cast(),
+            name: command.name.clone(),
+            kind: command.kind,
+            options,
+            resolved: Some(resolved),
+            target_id: None,
+        };
+
+        #[allow(deprecated)]
+        Interaction {
+            app_permissions: None,
+            application_id: self.application_id,
+            authorizing_integration_owners: ApplicationIntegrationMap {
+                guild: None,
+                user: None,
+            },
+            channel: None,
+            channel_id: None,
+            context: None,
+            data: Some(InteractionData::ApplicationCommand(Box::new(data))),
+            entitlements: Vec::new(),
+            guild: None,
+            guild_id: None,
+            guild_locale: None,
+            id: Id::new(self.next_id()),
+            kind: InteractionType::ApplicationCommand,
+            locale: Some("en-US".into()),
+            member: None,
+            message: None,
+            token: "token".into(),
+            user,
+        }
+    }
+}
+
+impl Default for FakeDiscord {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Check received options against the options of a registered command.
+fn check_options(
+    path: &str,
+    registered: &[CommandOption],
+    received: &[CommandDataOption],
+) -> Result<(), FakeDiscordError> {
+    for option in received {
+        let option_path = format!("{path} {}", option.name);
+
+        let Some(expected) = registered.iter().find(|other| other.name == option.name) else {
+            return Err(FakeDiscordError::UnknownOption(option_path));
+        };
+
+        let found = option.value.kind();
+        if found != expected.kind {
+            return Err(FakeDiscordError::InvalidOptionType {
+                option: option_path,
+                expected: expected.kind,
+                found,
+            });
+        }
+
+        if let CommandOptionValue::SubCommand(options)
+        | CommandOptionValue::SubCommandGroup(options) = &option.value
+        {
+            let registered = expected.options.as_deref().unwrap_or_default();
+            check_options(&option_path, registered, options)?;
+        }
+    }
+
+    let subcommands = received.iter().any(|option| {
+        matches!(
+            option.value.kind(),
+            CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup
+        )
+    });
+
+    if !subcommands {
+        let missing = registered.iter().find(|option| {
+            option.required == Some(true) && !received.iter().any(|other| other.name == option.name)
+        });
+
+        if let Some(missing) = missing {
+            return Err(FakeDiscordError::MissingOption(format!(
+                "{path} {}",
+                missing.name
+            )));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+//! Utilities to test commands.
+//!
+//! This module provides assertion functions checking that commands created
+//! with the [`CreateCommand`] trait are valid and consistent:
+//!
+//! - [`assert_create_parse_symmetry`] checks that input matching the created
+//!   command can be parsed by the [`CommandModel`] implementation.
+//! - [`assert_limits`] checks that the command respects the limits enforced
+//!   by Discord (name and description lengths, number of options, ...).
+//! - [`assert_localizations`] checks that every name and description is
+//!   localized in the same locales.
+//!
+//! These tests can be generated automatically for a command with the
+//! `generate_tests` attribute of the derive macros. The `testing` feature must
+//! be enabled, for example in the `dev-dependencies` section. Since tests are
+//! generated in a child module, this attribute cannot be used on types
+//! declared inside a function.
+//!
+//! ```
+//! use twilight_interactions::command::{CommandModel, CreateCommand};
+//!
+//! #[derive(CommandModel, CreateCommand)]
+//! #[command(name = "hello", desc = "Say hello", generate_tests)]
+//! struct HelloCommand {
+//!     /// The message to send
+//!     message: String,
+//! }
+//! ```
+//!
+//! Multi-step interaction flows can be tested with the [`Recorder`] type,
+//! which dispatches interactions to handlers and records them. The
+//! [`FakeDiscord`] type simulates Discord to register commands and synthesize
+//! interactions against them.
+//!
+//! When the `tokio` feature is enabled, the [`MockClock`] type can be set on a
+//! registry to simulate the response windows of Discord deterministically.
+//!
+//! [`CreateCommand`]: crate::command::CreateCommand
+//! [`CommandModel`]: crate::command::CommandModel
+
+mod assertions;
+#[cfg(feature = "tokio")]
+mod clock;
+mod fake_discord;
+mod recorder;
+
+pub use assertions::{assert_create_parse_symmetry, assert_limits, assert_localizations};
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub use clock::MockClock;
+pub use fake_discord::FakeDiscord;
+pub use recorder::{RecordedStep, Recorder};
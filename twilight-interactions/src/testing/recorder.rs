@@ -0,0 +1,245 @@
+use std::{fmt::Debug, future::Future, sync::Mutex};
+
+use twilight_model::application::interaction::{
+    message_component::MessageComponentInteractionData, modal::ModalInteractionData, Interaction,
+    InteractionData, InteractionType,
+};
+
+use crate::{
+    command::{CommandModel, CreateCommand},
+    error::DispatchError,
+    registry::{CommandRegistry, InteractionKindRouter},
+};
+
+/// Output of the wrapped handlers, along with the debug representation of the
+/// parsed model.
+type Tagged<R> = (Option<String>, R);
+
+/// Function returning the kind of a handler output.
+type ResponseKind<R> = Box<dyn Fn(&R) -> String + Send + Sync>;
+
+/// Dispatcher recording handled interactions.
+///
+/// This type works like an [`InteractionKindRouter`] and records each handled
+/// interaction as a [`RecordedStep`], so that multi-step flows (e.g. a command
+/// responding with a modal, followed by a component interaction) can be tested
+/// end-to-end without sending requests to Discord.
+///
+/// Handlers are registered on the recorder directly, since command models must
+/// implement [`Debug`] to be recorded. The kind of the handler output is
+/// obtained with the function provided to [`new`].
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::{
+///     command::{CommandModel, CreateCommand},
+///     testing::{RecordedStep, Recorder},
+/// };
+/// use twilight_model::application::interaction::InteractionType;
+/// # use twilight_model::application::interaction::Interaction;
+///
+/// #[derive(CommandModel, CreateCommand, Debug)]
+/// #[command(name = "hello", desc = "Say hello")]
+/// struct HelloCommand {
+///     /// The message to send.
+///     message: String,
+/// }
+///
+/// let recorder = Recorder::new(|response: &String| response.clone())
+///     .command(|command: HelloCommand, _interaction| async move {
+///         String::from("message")
+///     })
+///     .component(|_data, _interaction| async move { String::from("update") });
+///
+/// # async fn run(recorder: Recorder<String>, command: Interaction, component: Interaction) {
+/// recorder.handle(command).await.unwrap();
+/// recorder.handle(component).await.unwrap();
+///
+/// recorder.assert_script(&[
+///     RecordedStep {
+///         kind: InteractionType::ApplicationCommand,
+///         name: "hello".into(),
+///         model: Some(r#"HelloCommand { message: "hi" }"#.into()),
+///         response: Ok("message".into()),
+///     },
+///     RecordedStep {
+///         kind: InteractionType::MessageComponent,
+///         name: "confirm".into(),
+///         model: None,
+///         response: Ok("update".into()),
+///     },
+/// ]);
+/// # }
+/// ```
+///
+/// [`new`]: Self::new
+pub struct Recorder<R> {
+    router: InteractionKindRouter<Tagged<R>>,
+    response_kind: ResponseKind<R>,
+    steps: Mutex<Vec<RecordedStep>>,
+}
+
+/// Interaction recorded by a [`Recorder`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedStep {
+    /// Kind of the interaction.
+    pub kind: InteractionType,
+    /// Name of the command, or custom id of the component or modal.
+    pub name: String,
+    /// Debug representation of the parsed command model.
+    ///
+    /// This is `None` for component and modal interactions.
+    pub model: Option<String>,
+    /// Kind of the handler output, or the error returned while dispatching.
+    pub response: Result<String, DispatchError>,
+}
+
+impl<R: 'static> Recorder<R> {
+    /// Create a new [`Recorder`] without handlers.
+    ///
+    /// The provided function is used to get the kind of the output of
+    /// handlers, for example the [`InteractionResponseType`] of an
+    /// [`InteractionResponse`].
+    ///
+    /// [`InteractionResponseType`]: twilight_model::http::interaction::InteractionResponseType
+    /// [`InteractionResponse`]: twilight_model::http::interaction::InteractionResponse
+    pub fn new<F>(response_kind: F) -> Self
+    where
+        F: Fn(&R) -> String + Send + Sync + 'static,
+    {
+        Self {
+            router: InteractionKindRouter::new(CommandRegistry::new()),
+            response_kind: Box::new(response_kind),
+            steps: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Register a command with its handler.
+    ///
+    /// See [`CommandRegistry::register`].
+    pub fn command<T, F, Fut>(mut self, handler: F) -> Self
+    where
+        T: CommandModel + CreateCommand + Debug + 'static,
+        F: Fn(T, Interaction) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = R> + Send + 'static,
+    {
+        self.router = self.router.map_commands(|commands| {
+            commands.register(move |model: T, interaction| {
+                let debug = format!("{model:?}");
+                let future = handler(model, interaction);
+
+                async move { (Some(debug), future.await) }
+            })
+        });
+
+        self
+    }
+
+    /// Register an autocomplete handler for a command.
+    ///
+    /// See [`InteractionKindRouter::autocomplete`].
+    pub fn autocomplete<T, F, Fut>(mut self, command: impl Into<String>, handler: F) -> Self
+    where
+        T: CommandModel + Debug + 'static,
+        F: Fn(T, Interaction) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = R> + Send + 'static,
+    {
+        self.router = self
+            .router
+            .autocomplete(command, move |model: T, interaction| {
+                let debug = format!("{model:?}");
+                let future = handler(model, interaction);
+
+                async move { (Some(debug), future.await) }
+            });
+
+        self
+    }
+
+    /// Register the handler of message component interactions.
+    pub fn component<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(MessageComponentInteractionData, Interaction) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = R> + Send + 'static,
+    {
+        self.router = self.router.component(move |data, interaction| {
+            let future = handler(data, interaction);
+
+            async move { (None, future.await) }
+        });
+
+        self
+    }
+
+    /// Register the handler of modal submit interactions.
+    pub fn modal<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(ModalInteractionData, Interaction) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = R> + Send + 'static,
+    {
+        self.router = self.router.modal(move |data, interaction| {
+            let future = handler(data, interaction);
+
+            async move { (None, future.await) }
+        });
+
+        self
+    }
+
+    /// Dispatch an interaction and record it.
+    ///
+    /// See [`InteractionKindRouter::handle`].
+    pub async fn handle(&self, interaction: Interaction) -> Result<R, DispatchError> {
+        let kind = interaction.kind;
+        let name = match &interaction.data {
+            Some(InteractionData::ApplicationCommand(data)) => data.name.clone(),
+            Some(InteractionData::MessageComponent(data)) => data.custom_id.clone(),
+            Some(InteractionData::ModalSubmit(data)) => data.custom_id.clone(),
+            _ => String::new(),
+        };
+
+        let (model, output) = match self.router.handle(interaction).await {
+            Ok((model, output)) => (model, Ok(output)),
+            Err(error) => (None, Err(error)),
+        };
+
+        let step = RecordedStep {
+            kind,
+            name,
+            model,
+            response: match &output {
+                Ok(output) => Ok((self.response_kind)(output)),
+                Err(error) => Err(error.clone()),
+            },
+        };
+        self.steps.lock().unwrap().push(step);
+
+        output
+    }
+
+    /// Get the recorded steps.
+    pub fn steps(&self) -> Vec<RecordedStep> {
+        self.steps.lock().unwrap().clone()
+    }
+
+    /// Assert that the recorded steps match the expected script.
+    ///
+    /// # Panics
+    /// Panics if the recorded steps are different from the provided ones.
+    #[track_caller]
+    pub fn assert_script(&self, script: &[RecordedStep]) {
+        let steps = self.steps.lock().unwrap();
+
+        for (index, (step, expected)) in steps.iter().zip(script).enumerate() {
+            assert_eq!(step, expected, "step {index} does not match the script");
+        }
+
+        assert_eq!(
+            steps.len(),
+            script.len(),
+            "recorded {} steps, expected {}",
+            steps.len(),
+            script.len()
+        );
+    }
+}
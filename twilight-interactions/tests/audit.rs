@@ -0,0 +1,171 @@
+use twilight_interactions::{
+    audit::{lint, LintKind, LintWarning, RESERVED_NAMES},
+    command::{CommandModel, CommandOption, CreateCommand, CreateOption},
+};
+
+#[derive(CreateCommand, Debug, PartialEq)]
+#[command(name = "hello", desc = "Say hello.")]
+struct HelloCommand {
+    /// The message to send.
+    message: String,
+    /// The user to mention
+    user: Option<i64>,
+}
+
+#[derive(CommandOption, CreateOption, Debug, PartialEq)]
+enum Color {
+    #[option(name = "Red", value = "red")]
+    Red,
+}
+
+#[derive(CreateCommand, Debug, PartialEq)]
+#[command(name = "paint", desc = "Paint something")]
+struct PaintCommand {
+    /// The color to use
+    color: Color,
+    /// Whether to paint quickly
+    #[command(autocomplete = true)]
+    fast: Option<bool>,
+}
+
+#[derive(CreateCommand, Debug, PartialEq)]
+#[command(name = "many", desc = "Many options")]
+struct ManyCommand {
+    /// Option
+    a: i64,
+    /// Option
+    b: i64,
+    /// Option
+    c: i64,
+    /// Option
+    d: i64,
+    /// Option
+    e: i64,
+    /// Option
+    f: i64,
+    /// Option
+    g: i64,
+    /// Option
+    h: i64,
+    /// Option
+    i: i64,
+    /// Option
+    j: i64,
+    /// Option
+    k: i64,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "config", desc = "Configure the bot")]
+enum ConfigCommand {
+    #[command(name = "show")]
+    Show(ConfigShowCommand),
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "show", desc = "Show the configuration")]
+struct ConfigShowCommand {
+    /// Key to show.
+    key: String,
+}
+
+#[test]
+fn test_lint_punctuation() {
+    let warnings = lint(&HelloCommand::create_command());
+
+    assert_eq!(
+        warnings,
+        vec![LintWarning {
+            path: "hello user".into(),
+            kind: LintKind::InconsistentPunctuation {
+                expected_period: true
+            },
+        }]
+    );
+    assert_eq!(
+        warnings[0].to_string(),
+        "`hello user`: description should end with a period"
+    );
+
+    let warnings = lint(&ConfigCommand::create_command());
+
+    assert_eq!(
+        warnings,
+        vec![LintWarning {
+            path: "config show key".into(),
+            kind: LintKind::InconsistentPunctuation {
+                expected_period: false
+            },
+        }]
+    );
+}
+
+#[test]
+fn test_lint_options() {
+    let warnings = lint(&PaintCommand::create_command());
+
+    assert_eq!(
+        warnings,
+        vec![
+            LintWarning {
+                path: "paint color".into(),
+                kind: LintKind::SingleChoice,
+            },
+            LintWarning {
+                path: "paint fast".into(),
+                kind: LintKind::UnusedAutocomplete,
+            },
+        ]
+    );
+
+    let warnings = lint(&ManyCommand::create_command());
+
+    assert_eq!(
+        warnings,
+        vec![LintWarning {
+            path: "many".into(),
+            kind: LintKind::TooManyRequiredOptions { count: 11 },
+        }]
+    );
+}
+
+#[derive(CreateCommand, Debug, PartialEq)]
+#[command(name = "shrug", desc = "Shrug")]
+struct ShrugCommand;
+
+#[derive(CreateCommand, Debug, PartialEq)]
+#[command(name = "shout", desc = "Shout", deny_reserved_name)]
+struct ShoutCommand;
+
+#[test]
+fn test_lint_reserved_name() {
+    let warnings = lint(&ShrugCommand::create_command());
+
+    assert_eq!(
+        warnings,
+        vec![LintWarning {
+            path: "shrug".into(),
+            kind: LintKind::ReservedName,
+        }]
+    );
+    assert_eq!(lint(&ShoutCommand::create_command()), Vec::new());
+}
+
+#[test]
+fn test_reserved_names_match_derive() {
+    // The derive macros keep their own copy of the list, since they cannot
+    // depend on this crate
+    let source = include_str!("../../twilight-interactions-derive/src/parse/parsers.rs");
+    let (_, list) = source
+        .split_once("const RESERVED_NAMES: &'static [&'static str] = &[")
+        .expect("reserved names of the derive macros");
+    let (list, _) = list.split_once("];").unwrap();
+
+    let names: Vec<&str> = list
+        .split(',')
+        .map(|name| name.trim().trim_matches('"'))
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    assert_eq!(names, RESERVED_NAMES);
+}
@@ -1,8 +1,13 @@
-use twilight_interactions::command::{
-    AutocompleteValue, CommandInputData, CommandModel, ResolvedUser,
+use twilight_interactions::{
+    autocomplete,
+    command::{
+        AutocompleteValue, CommandInputData, CommandModel, CreateAutocomplete, CreateCommand,
+        FocusedOption, ResolvedUser,
+    },
+    error::{ParseError, ParseOptionError, ParseOptionErrorType},
 };
 use twilight_model::application::{
-    command::CommandOptionType,
+    command::{CommandOptionChoice, CommandOptionChoiceValue, CommandOptionType},
     interaction::application_command::{CommandDataOption, CommandOptionValue},
 };
 
@@ -42,3 +47,255 @@ fn test_autocomplete_model() {
         result
     )
 }
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+#[command(autocomplete = true, deny_unknown_fields)]
+struct StrictDemoCommand {
+    user: Option<ResolvedUser>,
+    string: AutocompleteValue<String>,
+}
+
+#[test]
+fn test_autocomplete_deny_unknown_fields() {
+    let mut options = vec![CommandDataOption {
+        name: "string".to_string(),
+        value: CommandOptionValue::Focused("test".to_string(), CommandOptionType::String),
+    }];
+
+    let data = CommandInputData {
+        options: options.clone(),
+        resolved: None,
+    };
+    assert_eq!(
+        StrictDemoCommand::from_interaction(data),
+        Ok(StrictDemoCommand {
+            user: None,
+            string: AutocompleteValue::Focused("test".to_string())
+        })
+    );
+
+    options.push(CommandDataOption {
+        name: "number".to_string(),
+        value: CommandOptionValue::Integer(42),
+    });
+    let data = CommandInputData {
+        options,
+        resolved: None,
+    };
+    assert_eq!(
+        StrictDemoCommand::from_interaction(data),
+        Err(ParseError::Option(ParseOptionError {
+            field: "number".to_string(),
+            kind: ParseOptionErrorType::UnknownField,
+            path: Vec::new(),
+        }))
+    );
+}
+
+#[test]
+fn test_focused_option() {
+    let subcommand_options = vec![
+        CommandDataOption {
+            name: "from".to_string(),
+            value: CommandOptionValue::String("Paris".to_string()),
+        },
+        CommandDataOption {
+            name: "to".to_string(),
+            value: CommandOptionValue::Focused("Ly".to_string(), CommandOptionType::String),
+        },
+    ];
+
+    let data = CommandInputData {
+        options: vec![CommandDataOption {
+            name: "train".to_string(),
+            value: CommandOptionValue::SubCommand(subcommand_options),
+        }],
+        resolved: None,
+    };
+
+    let expected = FocusedOption {
+        name: "to".to_string(),
+        value: "Ly".to_string(),
+        kind: CommandOptionType::String,
+    };
+    assert_eq!(data.focused(), None);
+    assert_eq!(data.focused_option().as_ref(), Some(&expected));
+
+    let result = FocusedOption::from_interaction(data);
+    assert_eq!(result, Ok(expected));
+
+    let data = CommandInputData {
+        options: Vec::new(),
+        resolved: None,
+    };
+
+    let result = FocusedOption::from_interaction(data);
+    assert_eq!(result, Err(ParseError::MissingFocused));
+}
+
+#[test]
+fn test_autocomplete_filter() {
+    let countries = [
+        ("France", "fr"),
+        ("Germany", "de"),
+        ("Great Britain", "gb"),
+        ("United Kingdom", "uk"),
+        ("Bulgaria", "bg"),
+    ];
+
+    let names = |input| {
+        autocomplete::filter(countries, input)
+            .into_iter()
+            .map(|choice| choice.name)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(names("GR"), ["Great Britain", "Germany", "Bulgaria"]);
+    assert_eq!(names("ge"), ["Germany", "Great Britain"]);
+    assert_eq!(names("king"), ["United Kingdom"]);
+    assert_eq!(names("ri"), ["Great Britain", "Bulgaria"]);
+    assert_eq!(names("gbr"), ["Great Britain"]);
+    assert_eq!(names("").len(), 5);
+    assert!(names("xyz").is_empty());
+
+    let choices = autocomplete::filter([("Germany", "de")], "germ");
+    assert_eq!(
+        choices[0].value,
+        CommandOptionChoiceValue::String("de".into())
+    );
+}
+
+#[test]
+fn test_autocomplete_filter_limits() {
+    let choices = autocomplete::filter((0..50).map(|i| (format!("item {i}"), i)), "item");
+    assert_eq!(choices.len(), autocomplete::MAX_CHOICES);
+    assert_eq!(choices[0].value, CommandOptionChoiceValue::Integer(0));
+
+    let choices = autocomplete::filter([("a".repeat(150), 1)], "a");
+    assert_eq!(
+        choices[0].name.chars().count(),
+        autocomplete::MAX_NAME_LENGTH
+    );
+}
+
+fn suggest_country(value: &str) -> Vec<CommandOptionChoice> {
+    autocomplete::filter(
+        [("France", "fr"), ("Germany", "de"), ("Italy", "it")],
+        value,
+    )
+}
+
+fn suggest_days(value: &str) -> Vec<CommandOptionChoice> {
+    match value.parse::<i64>() {
+        Ok(days) => autocomplete::filter([(format!("{days} days"), days)], ""),
+        Err(_) => Vec::new(),
+    }
+}
+
+#[derive(CreateCommand, CreateAutocomplete, Debug, PartialEq)]
+#[command(name = "travel", desc = "Plan a trip")]
+struct TravelCommand {
+    /// Destination country.
+    #[command(autocomplete_with = "suggest_country")]
+    country: String,
+    /// Number of days.
+    #[command(rename = "days", autocomplete_with = "suggest_days")]
+    duration: i64,
+    /// Whether to book a hotel.
+    hotel: Option<bool>,
+}
+
+#[test]
+fn test_create_autocomplete() {
+    let choices = TravelCommand::autocomplete("country", "ita");
+    assert_eq!(choices.len(), 1);
+    assert_eq!(choices[0].name, "Italy");
+    assert_eq!(
+        choices[0].value,
+        CommandOptionChoiceValue::String("it".into())
+    );
+
+    let choices = TravelCommand::autocomplete("days", "3");
+    assert_eq!(choices[0].name, "3 days");
+    assert_eq!(choices[0].value, CommandOptionChoiceValue::Integer(3));
+
+    assert!(TravelCommand::autocomplete("hotel", "").is_empty());
+    assert!(TravelCommand::autocomplete("unknown", "").is_empty());
+
+    let focused = FocusedOption {
+        name: "country".into(),
+        value: String::new(),
+        kind: CommandOptionType::String,
+    };
+    assert_eq!(TravelCommand::suggest(&focused).len(), 3);
+
+    let command = TravelCommand::create_command();
+    let autocomplete = command
+        .options
+        .iter()
+        .map(|option| (option.name.as_str(), option.autocomplete))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        autocomplete,
+        [
+            ("country", Some(true)),
+            ("days", Some(true)),
+            ("hotel", Some(false))
+        ]
+    );
+}
+
+#[derive(CommandModel, CreateAutocomplete, Debug, PartialEq, Eq)]
+#[command(autocomplete = true)]
+struct TravelAutocomplete {
+    #[command(autocomplete = "suggest_country")]
+    country: AutocompleteValue<String>,
+    days: Option<i64>,
+}
+
+#[test]
+fn test_resolve_autocomplete() {
+    let data = CommandInputData {
+        options: vec![
+            CommandDataOption {
+                name: "days".into(),
+                value: CommandOptionValue::Integer(3),
+            },
+            CommandDataOption {
+                name: "country".into(),
+                value: CommandOptionValue::Focused("fr".into(), CommandOptionType::String),
+            },
+        ],
+        resolved: None,
+    };
+
+    let choices = TravelAutocomplete::resolve_autocomplete(&data);
+    assert_eq!(choices.len(), 1);
+    assert_eq!(choices[0].name, "France");
+
+    let model = TravelAutocomplete::from_interaction(data).unwrap();
+    assert_eq!(model.country, AutocompleteValue::Focused("fr".into()));
+    assert_eq!(model.days, Some(3));
+
+    let data = CommandInputData {
+        options: Vec::new(),
+        resolved: None,
+    };
+    assert!(TravelAutocomplete::resolve_autocomplete(&data).is_empty());
+
+    // Options of subcommands are searched
+    let data = CommandInputData {
+        options: vec![CommandDataOption {
+            name: "plan".into(),
+            value: CommandOptionValue::SubCommand(vec![CommandDataOption {
+                name: "country".into(),
+                value: CommandOptionValue::Focused("ger".into(), CommandOptionType::String),
+            }]),
+        }],
+        resolved: None,
+    };
+
+    let choices = TravelAutocomplete::resolve_autocomplete(&data);
+    assert_eq!(choices.len(), 1);
+    assert_eq!(choices[0].name, "Germany");
+}
@@ -1,8 +1,11 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
-use twilight_interactions::command::{
-    internal::{CommandOptionData, CreateOptionData},
-    CommandOption, CreateOption, NameLocalizations,
+use twilight_interactions::{
+    command::{
+        internal::{CommandOptionData, CreateOptionData},
+        ChoiceLocalizations, CommandOption, CreateOption, NameLocalizations,
+    },
+    error::ParseOptionErrorType,
 };
 use twilight_model::application::{
     command::{
@@ -16,7 +19,7 @@ use twilight_model::application::{
 enum ChoiceString {
     #[option(name = "Dog", name_localizations = "name_dog", value = "dog")]
     Dog,
-    #[option(name = "Cat", value = "cat")]
+    #[option(name = "Cat", name_localizations = "name_cat", value = "cat")]
     Cat,
     #[option(name = "Crab", value = "crab")]
     Crab,
@@ -42,10 +45,94 @@ enum ChoiceNumber {
     Quarter,
 }
 
+#[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+#[option(rename_all = "kebab-case")]
+enum ChoiceRenamed {
+    LightBlue,
+    #[option(name = "Dark blue")]
+    DarkBlue,
+    #[option(value = "red")]
+    PureRed,
+}
+
+#[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+#[option(rename_all = "camelCase")]
+enum ChoiceAccentedCamel {
+    Étape,
+    GrandeÉtape,
+}
+
+#[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+#[option(rename_all = "snake_case")]
+enum ChoiceAccentedSnake {
+    ÉtapeFinale,
+    GrandeÉtape,
+}
+
+#[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+#[option(rename_all = "lowercase")]
+enum ChoiceDiscriminant {
+    Minute = 60,
+    #[option(name = "1 hour")]
+    Hour = 3600,
+    #[option(value = 86400)]
+    Day = 1,
+    Past = -1,
+}
+
+#[derive(CommandOption, CreateOption, Debug, Clone, PartialEq, Eq)]
+enum ChoiceOther {
+    #[option(name = "Rust", value = "rust")]
+    Rust,
+    #[option(name = "Python", value = "python")]
+    Python,
+    #[option(other)]
+    Other(String),
+}
+
+#[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+enum ChoiceOtherInt {
+    #[option(name = "One", value = 1)]
+    One,
+    #[option(other)]
+    Other(i64),
+}
+
+#[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+#[option(rename_all = "snake_case", case_insensitive)]
+enum ChoiceCaseInsensitive {
+    LightBlue,
+    #[option(value = "Dark_Blue")]
+    DarkBlue,
+}
+
+#[derive(CommandOption, CreateOption, Debug, Clone, Copy, PartialEq, Eq)]
+#[option(display)]
+enum ChoiceDisplay {
+    Small,
+    Large,
+}
+
+impl fmt::Display for ChoiceDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Small => f.write_str("small size"),
+            Self::Large => f.write_str("large size"),
+        }
+    }
+}
+
 pub fn name_dog() -> NameLocalizations {
     NameLocalizations::new([("en", "Dog")])
 }
 
+pub fn name_cat() -> ChoiceLocalizations {
+    ChoiceLocalizations::builder()
+        .localization("fr", "Chat")
+        .localization("de", "Katze")
+        .build()
+}
+
 #[test]
 fn test_command_option_string() {
     let parsed = ChoiceString::from_option(
@@ -64,6 +151,7 @@ fn test_command_option_string() {
         min_value: None,
         max_length: None,
         min_length: None,
+        format: None,
     };
     let create_data = CreateOptionData {
         name: "name".to_string(),
@@ -86,7 +174,10 @@ fn test_command_option_string() {
             },
             CommandOptionChoice {
                 name: "Cat".to_string(),
-                name_localizations: None,
+                name_localizations: Some(HashMap::from([
+                    ("fr".to_string(), "Chat".to_string()),
+                    ("de".to_string(), "Katze".to_string()),
+                ])),
                 value: CommandOptionChoiceValue::String("cat".to_string()),
             },
             CommandOptionChoice {
@@ -122,6 +213,11 @@ fn test_command_option_integer() {
     assert_eq!(ChoiceInt::One.value(), 1);
     assert_eq!(ChoiceInt::Two.value(), 2);
     assert_eq!(ChoiceInt::Three.value(), 3);
+    assert_eq!(ChoiceInt::Three.name(), "Three");
+    assert_eq!(
+        ChoiceInt::VARIANTS,
+        &[ChoiceInt::One, ChoiceInt::Two, ChoiceInt::Three]
+    );
 
     let data = CommandOptionData {
         channel_types: None,
@@ -129,6 +225,7 @@ fn test_command_option_integer() {
         min_value: None,
         min_length: None,
         max_length: None,
+        format: None,
     };
     let create_data = CreateOptionData {
         name: "name".to_string(),
@@ -194,6 +291,7 @@ fn test_command_option_number() {
         min_value: None,
         max_length: None,
         min_length: None,
+        format: None,
     };
     let create_data = CreateOptionData {
         name: "name".to_string(),
@@ -240,3 +338,267 @@ fn test_command_option_number() {
 
     assert_eq!(command_option, ChoiceNumber::create_option(create_data));
 }
+
+#[test]
+fn test_command_option_rename_all() {
+    let parsed = ChoiceRenamed::from_option(
+        CommandOptionValue::String("light-blue".to_string()),
+        CommandOptionData::default(),
+        None,
+    );
+    assert_eq!(parsed, Ok(ChoiceRenamed::LightBlue));
+    assert_eq!(ChoiceRenamed::DarkBlue.name(), "Dark blue");
+    assert_eq!(ChoiceRenamed::DarkBlue.value(), "dark-blue");
+    assert_eq!(ChoiceRenamed::PureRed.name(), "pure-red");
+    assert_eq!(ChoiceRenamed::PureRed.value(), "red");
+
+    // Non-ASCII identifiers
+    assert_eq!(ChoiceAccentedCamel::Étape.value(), "étape");
+    assert_eq!(ChoiceAccentedCamel::GrandeÉtape.value(), "grandeÉtape");
+    assert_eq!(ChoiceAccentedSnake::ÉtapeFinale.value(), "étape_finale");
+    assert_eq!(ChoiceAccentedSnake::GrandeÉtape.value(), "grande_étape");
+
+    let create_data = CreateOptionData {
+        name: "name".to_string(),
+        name_localizations: None,
+        description: "description".to_string(),
+        description_localizations: None,
+        required: Some(true),
+        autocomplete: false,
+        data: CommandOptionData::default(),
+    };
+    let choices = ChoiceRenamed::create_option(create_data).choices.unwrap();
+
+    assert_eq!(
+        choices,
+        vec![
+            CommandOptionChoice {
+                name: "light-blue".to_string(),
+                name_localizations: None,
+                value: CommandOptionChoiceValue::String("light-blue".to_string()),
+            },
+            CommandOptionChoice {
+                name: "Dark blue".to_string(),
+                name_localizations: None,
+                value: CommandOptionChoiceValue::String("dark-blue".to_string()),
+            },
+            CommandOptionChoice {
+                name: "pure-red".to_string(),
+                name_localizations: None,
+                value: CommandOptionChoiceValue::String("red".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_command_option_discriminant() {
+    let parsed = ChoiceDiscriminant::from_option(
+        CommandOptionValue::Integer(3600),
+        CommandOptionData::default(),
+        None,
+    );
+    assert_eq!(parsed, Ok(ChoiceDiscriminant::Hour));
+    assert_eq!(ChoiceDiscriminant::Minute.value(), 60);
+    assert_eq!(ChoiceDiscriminant::Day.value(), 86400);
+    assert_eq!(ChoiceDiscriminant::Past.value(), -1);
+
+    let create_data = CreateOptionData {
+        name: "name".to_string(),
+        name_localizations: None,
+        description: "description".to_string(),
+        description_localizations: None,
+        required: Some(true),
+        autocomplete: false,
+        data: CommandOptionData::default(),
+    };
+    let option = ChoiceDiscriminant::create_option(create_data);
+
+    assert_eq!(option.kind, CommandOptionType::Integer);
+    assert_eq!(
+        option.choices.unwrap(),
+        vec![
+            CommandOptionChoice {
+                name: "minute".to_string(),
+                name_localizations: None,
+                value: CommandOptionChoiceValue::Integer(60),
+            },
+            CommandOptionChoice {
+                name: "1 hour".to_string(),
+                name_localizations: None,
+                value: CommandOptionChoiceValue::Integer(3600),
+            },
+            CommandOptionChoice {
+                name: "day".to_string(),
+                name_localizations: None,
+                value: CommandOptionChoiceValue::Integer(86400),
+            },
+            CommandOptionChoice {
+                name: "past".to_string(),
+                name_localizations: None,
+                value: CommandOptionChoiceValue::Integer(-1),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_command_option_other() {
+    let parse = |value: &str| {
+        ChoiceOther::from_option(
+            CommandOptionValue::String(value.to_string()),
+            CommandOptionData::default(),
+            None,
+        )
+    };
+    assert_eq!(parse("rust"), Ok(ChoiceOther::Rust));
+    assert_eq!(parse("go"), Ok(ChoiceOther::Other("go".to_string())));
+
+    assert_eq!(ChoiceOther::Python.name(), Some("Python"));
+    assert_eq!(ChoiceOther::Python.value(), "python");
+    assert_eq!(ChoiceOther::Other("go".to_string()).name(), None);
+    assert_eq!(ChoiceOther::Other("go".to_string()).value(), "go");
+    assert_eq!(
+        ChoiceOther::VARIANTS,
+        [ChoiceOther::Rust, ChoiceOther::Python]
+    );
+
+    let parsed = ChoiceOtherInt::from_option(
+        CommandOptionValue::Integer(42),
+        CommandOptionData::default(),
+        None,
+    );
+    assert_eq!(parsed, Ok(ChoiceOtherInt::Other(42)));
+    assert_eq!(ChoiceOtherInt::Other(42).value(), 42);
+    assert_eq!(ChoiceOtherInt::One.value(), 1);
+
+    let create_data = CreateOptionData {
+        name: "name".to_string(),
+        name_localizations: None,
+        description: "description".to_string(),
+        description_localizations: None,
+        required: Some(true),
+        autocomplete: false,
+        data: CommandOptionData::default(),
+    };
+    let option = ChoiceOther::create_option(create_data);
+
+    assert_eq!(option.kind, CommandOptionType::String);
+    assert_eq!(option.choices.unwrap().len(), 2);
+}
+
+#[test]
+fn test_command_option_case_insensitive() {
+    let parse = |value: &str| {
+        ChoiceCaseInsensitive::from_option(
+            CommandOptionValue::String(value.to_string()),
+            CommandOptionData::default(),
+            None,
+        )
+    };
+    assert_eq!(parse("light_blue"), Ok(ChoiceCaseInsensitive::LightBlue));
+    assert_eq!(parse("LIGHT_Blue"), Ok(ChoiceCaseInsensitive::LightBlue));
+    assert_eq!(parse("dark_blue"), Ok(ChoiceCaseInsensitive::DarkBlue));
+    assert_eq!(
+        parse("Red"),
+        Err(ParseOptionErrorType::InvalidChoice("Red".to_string()))
+    );
+    assert_eq!(ChoiceCaseInsensitive::DarkBlue.value(), "Dark_Blue");
+}
+
+#[test]
+fn test_command_option_display() {
+    let parse = |value: &str| {
+        ChoiceDisplay::from_option(
+            CommandOptionValue::String(value.to_string()),
+            CommandOptionData::default(),
+            None,
+        )
+    };
+    assert_eq!(parse("large size"), Ok(ChoiceDisplay::Large));
+    assert_eq!(
+        parse("Large"),
+        Err(ParseOptionErrorType::InvalidChoice("Large".to_string()))
+    );
+    assert_eq!(
+        ChoiceDisplay::VARIANTS,
+        [ChoiceDisplay::Small, ChoiceDisplay::Large]
+    );
+
+    let create_data = CreateOptionData {
+        name: "name".to_string(),
+        name_localizations: None,
+        description: "description".to_string(),
+        description_localizations: None,
+        required: Some(true),
+        autocomplete: false,
+        data: CommandOptionData::default(),
+    };
+    let option = ChoiceDisplay::create_option(create_data);
+
+    assert_eq!(option.kind, CommandOptionType::String);
+    assert_eq!(
+        option.choices.unwrap(),
+        vec![
+            CommandOptionChoice {
+                name: "small size".to_string(),
+                name_localizations: None,
+                value: CommandOptionChoiceValue::String("small size".to_string()),
+            },
+            CommandOptionChoice {
+                name: "large size".to_string(),
+                name_localizations: None,
+                value: CommandOptionChoiceValue::String("large size".to_string()),
+            },
+        ]
+    );
+}
+
+#[derive(CommandOption, CreateOption, Debug, PartialEq, Eq)]
+#[option(transparent)]
+struct Tag(String);
+
+#[derive(CommandOption, CreateOption, Debug, PartialEq, Eq)]
+#[option(transparent)]
+struct Wrapper<T> {
+    inner: T,
+}
+
+#[test]
+fn test_transparent_option() {
+    let tag = Tag::from_option(
+        CommandOptionValue::String("rust".to_string()),
+        CommandOptionData::default(),
+        None,
+    );
+    assert_eq!(tag, Ok(Tag("rust".to_string())));
+
+    let choice = Wrapper::<ChoiceString>::from_option(
+        CommandOptionValue::String("cat".to_string()),
+        CommandOptionData::default(),
+        None,
+    );
+    assert_eq!(
+        choice,
+        Ok(Wrapper {
+            inner: ChoiceString::Cat
+        })
+    );
+
+    let create_data = || CreateOptionData {
+        name: "name".to_string(),
+        name_localizations: None,
+        description: "description".to_string(),
+        description_localizations: None,
+        required: Some(true),
+        autocomplete: false,
+        data: CommandOptionData::default(),
+    };
+
+    let option = Tag::create_option(create_data());
+    assert_eq!(option.kind, CommandOptionType::String);
+    assert_eq!(option.choices, None);
+
+    let option = Wrapper::<ChoiceString>::create_option(create_data());
+    assert_eq!(option.choices.unwrap().len(), 3);
+}
@@ -1,15 +1,40 @@
-use std::{borrow::Cow, collections::HashMap};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    error::Error,
+    net::{IpAddr, Ipv4Addr},
+    num::{NonZeroI64, NonZeroU64},
+    rc::Rc,
+    sync::Arc,
+};
 
-use twilight_interactions::command::{
-    CommandInputData, CommandModel, CommandOption, ResolvedMentionable, ResolvedUser,
+use twilight_interactions::{
+    command::{
+        CommandInputData, CommandModel, CommandModelRef, CommandOption, Interned, Parsed,
+        ResolvedChannel, ResolvedMember, ResolvedMentionable, ResolvedThread, ResolvedUser,
+        SymbolTable,
+    },
+    error::{ParseError, ParseErrorKind, ParseOptionError, ParseOptionErrorType, ValueError},
 };
 use twilight_model::{
-    application::interaction::{
-        application_command::{CommandDataOption, CommandOptionValue},
-        InteractionDataResolved, InteractionMember,
+    application::{
+        command::{CommandOptionType, CommandOptionValue as NumberCommandOptionValue, CommandType},
+        interaction::{
+            application_command::{CommandData, CommandDataOption, CommandOptionValue},
+            Interaction, InteractionChannel, InteractionData, InteractionDataResolved,
+            InteractionMember, InteractionType,
+        },
+    },
+    channel::{
+        thread::{AutoArchiveDuration, ThreadMetadata},
+        ChannelType,
     },
     guild::{MemberFlags, Permissions},
-    id::Id,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+    oauth::ApplicationIntegrationMap,
     user::User,
     util::Timestamp,
 };
@@ -31,6 +56,18 @@ where
 #[derive(CommandModel, Debug, PartialEq, Eq)]
 struct UnitCommand;
 
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+struct ParsedCommand {
+    #[command(max_length = 15)]
+    address: Parsed<IpAddr>,
+}
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+struct NonZeroCommand {
+    signed: NonZeroI64,
+    unsigned: NonZeroU64,
+}
+
 #[test]
 fn test_command_model() {
     let user_id = Id::new(123);
@@ -139,3 +176,1167 @@ fn test_unit_command_model() {
 
     assert_eq!(UnitCommand, result);
 }
+
+#[test]
+fn test_non_zero_command_model() {
+    let data = |signed, unsigned| CommandInputData {
+        options: vec![
+            CommandDataOption {
+                name: "signed".into(),
+                value: CommandOptionValue::Integer(signed),
+            },
+            CommandDataOption {
+                name: "unsigned".into(),
+                value: CommandOptionValue::Integer(unsigned),
+            },
+        ],
+        resolved: None,
+    };
+
+    let result = NonZeroCommand::from_interaction(data(-5, 5));
+    assert_eq!(
+        result,
+        Ok(NonZeroCommand {
+            signed: NonZeroI64::new(-5).unwrap(),
+            unsigned: NonZeroU64::new(5).unwrap(),
+        })
+    );
+
+    let result = NonZeroCommand::from_interaction(data(0, 5));
+    assert_eq!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            field: "signed".into(),
+            kind: ParseOptionErrorType::OutOfRange {
+                value: NumberCommandOptionValue::Integer(0),
+                min: None,
+                max: None,
+            },
+            path: Vec::new(),
+        }))
+    );
+
+    let result = NonZeroCommand::from_interaction(data(1, -1));
+    assert_eq!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            field: "unsigned".into(),
+            kind: ParseOptionErrorType::OutOfRange {
+                value: NumberCommandOptionValue::Integer(-1),
+                min: Some(NumberCommandOptionValue::Integer(1)),
+                max: None,
+            },
+            path: Vec::new(),
+        }))
+    );
+}
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+struct SmallIntegerCommand {
+    #[command(max_value = 100)]
+    percent: u8,
+    offset: i16,
+}
+
+#[test]
+fn test_small_integer_command_model() {
+    let data = |percent, offset| CommandInputData {
+        options: vec![
+            CommandDataOption {
+                name: "percent".into(),
+                value: CommandOptionValue::Integer(percent),
+            },
+            CommandDataOption {
+                name: "offset".into(),
+                value: CommandOptionValue::Integer(offset),
+            },
+        ],
+        resolved: None,
+    };
+
+    let result = SmallIntegerCommand::from_interaction(data(50, -200));
+    assert_eq!(
+        result,
+        Ok(SmallIntegerCommand {
+            percent: 50,
+            offset: -200,
+        })
+    );
+
+    let result = SmallIntegerCommand::from_interaction(data(300, 0));
+    assert_eq!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            field: "percent".into(),
+            kind: ParseOptionErrorType::OutOfRange {
+                value: NumberCommandOptionValue::Integer(300),
+                min: None,
+                max: Some(NumberCommandOptionValue::Integer(100)),
+            },
+            path: Vec::new(),
+        }))
+    );
+
+    let result = SmallIntegerCommand::from_interaction(data(1, 40_000));
+    assert_eq!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            field: "offset".into(),
+            kind: ParseOptionErrorType::OutOfRange {
+                value: NumberCommandOptionValue::Integer(40_000),
+                min: Some(NumberCommandOptionValue::Integer(i16::MIN.into())),
+                max: Some(NumberCommandOptionValue::Integer(i16::MAX.into())),
+            },
+            path: Vec::new(),
+        }))
+    );
+}
+
+#[test]
+fn test_parsed_command_model() {
+    let data = |address: &str| CommandInputData {
+        options: vec![CommandDataOption {
+            name: "address".into(),
+            value: CommandOptionValue::String(address.into()),
+        }],
+        resolved: None,
+    };
+
+    let result = ParsedCommand::from_interaction(data("127.0.0.1"));
+    assert_eq!(
+        result,
+        Ok(ParsedCommand {
+            address: Parsed(IpAddr::V4(Ipv4Addr::LOCALHOST)),
+        })
+    );
+
+    let result = ParsedCommand::from_interaction(data("localhost"));
+    assert_eq!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            field: "address".into(),
+            kind: ParseOptionErrorType::InvalidValue("invalid IP address syntax".into()),
+            path: Vec::new(),
+        }))
+    );
+
+    let result = ParsedCommand::from_interaction(data("2001:db8:0:0:0:0:0:1"));
+    assert_eq!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            field: "address".into(),
+            kind: ParseOptionErrorType::StringLengthOutOfRange("2001:db8:0:0:0:0:0:1".into()),
+            path: Vec::new(),
+        }))
+    );
+}
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+struct ChannelCommand {
+    #[command(channel_types = "guild_text public_thread")]
+    channel: ResolvedChannel,
+}
+
+#[test]
+fn test_resolved_channel_command_model() {
+    let metadata = ThreadMetadata {
+        archived: false,
+        auto_archive_duration: AutoArchiveDuration::Day,
+        archive_timestamp: Timestamp::from_secs(1_700_000_000).unwrap(),
+        create_timestamp: None,
+        invitable: None,
+        locked: false,
+    };
+    let thread = InteractionChannel {
+        id: Id::new(2),
+        kind: ChannelType::PublicThread,
+        name: "thread".into(),
+        parent_id: Some(Id::new(1)),
+        permissions: Permissions::SEND_MESSAGES_IN_THREADS,
+        thread_metadata: Some(metadata.clone()),
+    };
+    let voice = InteractionChannel {
+        id: Id::new(3),
+        kind: ChannelType::GuildVoice,
+        name: "voice".into(),
+        parent_id: None,
+        permissions: Permissions::CONNECT,
+        thread_metadata: None,
+    };
+    let text = InteractionChannel {
+        id: Id::new(4),
+        kind: ChannelType::GuildText,
+        name: "text".into(),
+        parent_id: Some(Id::new(5)),
+        permissions: Permissions::SEND_MESSAGES,
+        thread_metadata: None,
+    };
+
+    let data = |id| CommandInputData {
+        options: vec![CommandDataOption {
+            name: "channel".into(),
+            value: CommandOptionValue::Channel(Id::new(id)),
+        }],
+        resolved: Some(Cow::Owned(InteractionDataResolved {
+            attachments: HashMap::new(),
+            channels: HashMap::from([
+                (thread.id, thread.clone()),
+                (voice.id, voice.clone()),
+                (text.id, text.clone()),
+            ]),
+            members: HashMap::new(),
+            messages: HashMap::new(),
+            roles: HashMap::new(),
+            users: HashMap::new(),
+        })),
+    };
+
+    let result = ChannelCommand::from_interaction(data(2)).unwrap();
+    assert!(result.channel.is_thread());
+    assert_eq!(
+        result.channel,
+        ResolvedChannel {
+            id: Id::new(2),
+            kind: ChannelType::PublicThread,
+            name: "thread".into(),
+            parent_id: Some(Id::new(1)),
+            permissions: Permissions::SEND_MESSAGES_IN_THREADS,
+            thread: Some(ResolvedThread {
+                parent_id: Id::new(1),
+                metadata,
+            }),
+        }
+    );
+
+    // The parent of a channel in a category is kept
+    let result = ChannelCommand::from_interaction(data(4)).unwrap();
+    assert!(!result.channel.is_thread());
+    assert_eq!(result.channel.parent_id, Some(Id::new(5)));
+
+    let result = ChannelCommand::from_interaction(data(3));
+    assert_eq!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            field: "channel".into(),
+            kind: ParseOptionErrorType::InvalidChannelType(ChannelType::GuildVoice),
+            path: Vec::new(),
+        }))
+    );
+}
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+struct MemberCommand {
+    member: ResolvedMember,
+}
+
+#[test]
+fn test_resolved_member_command_model() {
+    let member = InteractionMember {
+        joined_at: Some(Timestamp::from_secs(1609455600).unwrap()),
+        nick: Some("nick".into()),
+        premium_since: None,
+        roles: vec![],
+        avatar: None,
+        communication_disabled_until: None,
+        pending: false,
+        permissions: Permissions::empty(),
+        flags: MemberFlags::empty(),
+    };
+
+    let user = |id| User {
+        avatar: None,
+        bot: false,
+        discriminator: 1,
+        email: None,
+        flags: None,
+        id: Id::new(id),
+        locale: None,
+        mfa_enabled: None,
+        name: "someone".into(),
+        premium_type: None,
+        public_flags: None,
+        system: None,
+        verified: None,
+        accent_color: None,
+        banner: None,
+        avatar_decoration: None,
+        global_name: None,
+        avatar_decoration_data: None,
+    };
+
+    let data = |id| CommandInputData {
+        options: vec![CommandDataOption {
+            name: "member".into(),
+            value: CommandOptionValue::User(Id::new(id)),
+        }],
+        resolved: Some(Cow::Owned(InteractionDataResolved {
+            attachments: HashMap::new(),
+            channels: HashMap::new(),
+            members: HashMap::from([(Id::new(1), member.clone())]),
+            messages: HashMap::new(),
+            roles: HashMap::new(),
+            users: HashMap::from([(Id::new(1), user(1)), (Id::new(2), user(2))]),
+        })),
+    };
+
+    let result = MemberCommand::from_interaction(data(1));
+    assert_eq!(
+        result,
+        Ok(MemberCommand {
+            member: ResolvedMember {
+                resolved: user(1),
+                member: member.clone(),
+            }
+        })
+    );
+
+    let resolved_user = ResolvedUser::from(result.unwrap().member);
+    assert_eq!(resolved_user.member, Some(member.clone()));
+
+    // The user is not a member of the guild
+    let result = MemberCommand::from_interaction(data(2));
+    assert_eq!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            field: "member".into(),
+            kind: ParseOptionErrorType::LookupFailed(2),
+            path: Vec::new(),
+        }))
+    );
+}
+
+#[derive(CommandOption, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+enum Visibility {
+    #[option(name = "Public", value = "public")]
+    Public,
+    #[option(name = "Private", value = "private")]
+    Private,
+}
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+struct NonExhaustiveCommand {
+    visibility: Visibility,
+    repeat: Option<i64>,
+}
+
+#[test]
+fn test_non_exhaustive_command_model() {
+    let data = |visibility: &str| CommandInputData {
+        options: vec![CommandDataOption {
+            name: "visibility".into(),
+            value: CommandOptionValue::String(visibility.into()),
+        }],
+        resolved: None,
+    };
+
+    for (value, visibility) in [
+        ("public", Visibility::Public),
+        ("private", Visibility::Private),
+    ] {
+        let result = NonExhaustiveCommand::from_interaction(data(value));
+        assert_eq!(
+            result,
+            Ok(NonExhaustiveCommand {
+                visibility,
+                repeat: None,
+            })
+        );
+    }
+}
+
+#[cfg(feature = "humantime")]
+#[test]
+fn test_duration_command_model() {
+    use std::time::Duration;
+
+    #[derive(CommandModel, Debug, PartialEq, Eq)]
+    struct MuteCommand {
+        #[command(max_length = 10)]
+        duration: Duration,
+    }
+
+    let data = |duration: &str| CommandInputData {
+        options: vec![CommandDataOption {
+            name: "duration".into(),
+            value: CommandOptionValue::String(duration.into()),
+        }],
+        resolved: None,
+    };
+
+    let result = MuteCommand::from_interaction(data("2h30m"));
+    assert_eq!(
+        result,
+        Ok(MuteCommand {
+            duration: Duration::from_secs(9000),
+        })
+    );
+
+    let result = MuteCommand::from_interaction(data("soon"));
+    assert!(matches!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            kind: ParseOptionErrorType::InvalidValue(_),
+            ..
+        }))
+    ));
+
+    let result = MuteCommand::from_interaction(data("1h 2m 3s 4ms"));
+    assert!(matches!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            kind: ParseOptionErrorType::StringLengthOutOfRange(_),
+            ..
+        }))
+    ));
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn test_url_command_model() {
+    use url::Url;
+
+    #[derive(CommandModel, Debug, PartialEq, Eq)]
+    struct LinkCommand {
+        #[command(min_length = 10)]
+        url: Url,
+    }
+
+    let data = |url: &str| CommandInputData {
+        options: vec![CommandDataOption {
+            name: "url".into(),
+            value: CommandOptionValue::String(url.into()),
+        }],
+        resolved: None,
+    };
+
+    let result = LinkCommand::from_interaction(data("https://twilight.rs/"));
+    assert_eq!(
+        result,
+        Ok(LinkCommand {
+            url: Url::parse("https://twilight.rs/").unwrap(),
+        })
+    );
+
+    let result = LinkCommand::from_interaction(data("twilight.rs"));
+    assert_eq!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            field: "url".into(),
+            kind: ParseOptionErrorType::InvalidValue(
+                "invalid URL: relative URL without a base".into()
+            ),
+            path: Vec::new(),
+        }))
+    );
+
+    let result = LinkCommand::from_interaction(data("http://a"));
+    assert_eq!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            field: "url".into(),
+            kind: ParseOptionErrorType::StringLengthOutOfRange("http://a".into()),
+            path: Vec::new(),
+        }))
+    );
+}
+
+fn parse_hex(
+    value: CommandOptionValue,
+    _resolved: Option<&InteractionDataResolved>,
+) -> Result<u32, String> {
+    match value {
+        CommandOptionValue::String(value) => {
+            u32::from_str_radix(value.trim_start_matches('#'), 16).map_err(|err| err.to_string())
+        }
+        other => Err(format!("unexpected {:?} option", other.kind())),
+    }
+}
+
+#[test]
+fn test_with_command_model() {
+    #[derive(CommandModel, Debug, PartialEq, Eq)]
+    struct ColorCommand {
+        #[command(with = "parse_hex")]
+        color: u32,
+        #[command(with = "parse_hex")]
+        background: Option<u32>,
+    }
+
+    let data = |color: &str| CommandInputData {
+        options: vec![CommandDataOption {
+            name: "color".into(),
+            value: CommandOptionValue::String(color.into()),
+        }],
+        resolved: None,
+    };
+
+    let result = ColorCommand::from_interaction(data("#ff8000"));
+    assert_eq!(
+        result,
+        Ok(ColorCommand {
+            color: 0xff8000,
+            background: None,
+        })
+    );
+
+    let result = ColorCommand::from_interaction(data("orange"));
+    assert_eq!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            field: "color".into(),
+            kind: ParseOptionErrorType::InvalidValue("invalid digit found in string".into()),
+            path: Vec::new(),
+        }))
+    );
+}
+
+fn parse_port(
+    value: CommandOptionValue,
+    _resolved: Option<&InteractionDataResolved>,
+) -> Result<u16, ValueError> {
+    match value {
+        CommandOptionValue::String(value) => value
+            .parse()
+            .map_err(|error| ValueError::with_source("invalid port", error)),
+        other => Err(format!("unexpected {:?} option", other.kind()).into()),
+    }
+}
+
+#[test]
+fn test_error_source_chain() {
+    #[derive(CommandModel, Debug, PartialEq, Eq)]
+    struct ConnectCommand {
+        #[command(with = "parse_port")]
+        port: u16,
+    }
+
+    let data = CommandInputData {
+        options: vec![CommandDataOption {
+            name: "port".into(),
+            value: CommandOptionValue::String("http".into()),
+        }],
+        resolved: None,
+    };
+
+    let error = ConnectCommand::from_interaction(data).unwrap_err();
+    let ParseError::Option(option_error) = &error else {
+        panic!("expected option error, got {error:?}");
+    };
+    assert_eq!(
+        option_error.kind,
+        ParseOptionErrorType::InvalidValue("invalid port".into())
+    );
+
+    let mut chain = Vec::new();
+    let mut source: Option<&dyn Error> = Some(&error);
+    while let Some(error) = source {
+        chain.push(error.to_string());
+        source = error.source();
+    }
+    assert_eq!(
+        chain.last().map(String::as_str),
+        Some("invalid digit found in string")
+    );
+
+    assert_eq!(
+        error.to_string(),
+        "failed to parse option `port`: invalid value: invalid port"
+    );
+    assert_eq!(
+        format!("{error:#}"),
+        "failed to parse option `port`: invalid value: invalid port: invalid digit found in string"
+    );
+}
+
+fn validate_username(value: &str) -> Result<(), String> {
+    if value.chars().all(|c| c.is_ascii_alphanumeric()) {
+        Ok(())
+    } else {
+        Err("username must be alphanumeric".into())
+    }
+}
+
+#[test]
+fn test_validate_command_model() {
+    #[derive(CommandModel, Debug, PartialEq, Eq)]
+    struct RegisterCommand {
+        #[command(validate = "validate_username")]
+        username: String,
+    }
+
+    let data = |username: &str| CommandInputData {
+        options: vec![CommandDataOption {
+            name: "username".into(),
+            value: CommandOptionValue::String(username.into()),
+        }],
+        resolved: None,
+    };
+
+    let result = RegisterCommand::from_interaction(data("twilight"));
+    assert_eq!(
+        result,
+        Ok(RegisterCommand {
+            username: "twilight".into()
+        })
+    );
+
+    let result = RegisterCommand::from_interaction(data("twi light"));
+    assert_eq!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            field: "username".into(),
+            kind: ParseOptionErrorType::ValidationFailed("username must be alphanumeric".into()),
+            path: Vec::new(),
+        }))
+    );
+}
+
+#[test]
+fn test_generic_command_model() {
+    #[derive(CommandModel, Debug, PartialEq, Eq)]
+    struct SetCommand<T> {
+        value: T,
+    }
+
+    let data = CommandInputData {
+        options: vec![CommandDataOption {
+            name: "value".into(),
+            value: CommandOptionValue::Integer(42),
+        }],
+        resolved: None,
+    };
+
+    let result = SetCommand::<i64>::from_interaction(data.clone());
+    assert_eq!(result, Ok(SetCommand { value: 42 }));
+
+    let result = SetCommand::<String>::from_interaction(data);
+    assert_eq!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            field: "value".into(),
+            kind: ParseOptionErrorType::InvalidType(CommandOptionType::Integer),
+            path: Vec::new(),
+        }))
+    );
+}
+
+#[test]
+fn test_command_model_ref() {
+    #[derive(CommandModelRef, Debug, PartialEq)]
+    struct NoteCommand<'a> {
+        #[command(rename = "text", max_length = 10)]
+        content: &'a str,
+        title: Option<Cow<'a, str>>,
+        author: Option<&'a User>,
+        priority: Option<i64>,
+    }
+
+    let user_id = Id::new(123);
+    let user = User {
+        accent_color: None,
+        avatar: None,
+        avatar_decoration: None,
+        avatar_decoration_data: None,
+        banner: None,
+        bot: false,
+        discriminator: 1,
+        global_name: Some("Twilight".into()),
+        id: user_id,
+        locale: None,
+        mfa_enabled: None,
+        name: "twilight".into(),
+        premium_type: None,
+        public_flags: None,
+        system: None,
+        verified: None,
+        email: None,
+        flags: None,
+    };
+
+    let resolved = InteractionDataResolved {
+        channels: HashMap::new(),
+        members: HashMap::new(),
+        roles: HashMap::new(),
+        users: HashMap::from([(user_id, user.clone())]),
+        messages: HashMap::new(),
+        attachments: HashMap::new(),
+    };
+
+    let options = vec![
+        CommandDataOption {
+            name: "text".into(),
+            value: CommandOptionValue::String("hello".into()),
+        },
+        CommandDataOption {
+            name: "title".into(),
+            value: CommandOptionValue::String("greeting".into()),
+        },
+        CommandDataOption {
+            name: "author".into(),
+            value: CommandOptionValue::User(user_id),
+        },
+    ];
+
+    let result = NoteCommand::from_interaction_ref(&options, Some(&resolved)).unwrap();
+    assert_eq!(
+        result,
+        NoteCommand {
+            content: "hello",
+            title: Some(Cow::Borrowed("greeting")),
+            author: Some(&user),
+            priority: None,
+        }
+    );
+    assert!(matches!(result.title, Some(Cow::Borrowed(_))));
+
+    let options = vec![CommandDataOption {
+        name: "text".into(),
+        value: CommandOptionValue::String("hello world!".into()),
+    }];
+
+    let result = NoteCommand::from_interaction_ref(&options, None);
+    assert_eq!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            field: "text".into(),
+            kind: ParseOptionErrorType::StringLengthOutOfRange("hello world!".into()),
+            path: Vec::new(),
+        }))
+    );
+
+    let result = NoteCommand::from_interaction_ref(&[], None);
+    assert_eq!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            field: "content".into(),
+            kind: ParseOptionErrorType::RequiredField,
+            path: Vec::new(),
+        }))
+    );
+}
+
+#[test]
+fn test_string_length() {
+    #[derive(CommandModel, Debug, PartialEq, Eq)]
+    struct OwnedCommand {
+        #[command(min_length = 3, max_length = 5)]
+        text: String,
+    }
+
+    #[derive(CommandModelRef, Debug, PartialEq, Eq)]
+    struct BorrowedCommand<'a> {
+        #[command(min_length = 3, max_length = 5)]
+        text: &'a str,
+    }
+
+    #[derive(CommandModel, Debug, PartialEq, Eq)]
+    struct CowCommand<'a> {
+        #[command(min_length = 3, max_length = 5)]
+        text: Cow<'a, str>,
+    }
+
+    for text in ["abc", "abcde", "ééé", "ééééé"] {
+        let options = vec![CommandDataOption {
+            name: "text".into(),
+            value: CommandOptionValue::String(text.into()),
+        }];
+
+        assert_eq!(
+            BorrowedCommand::from_interaction_ref(&options, None),
+            Ok(BorrowedCommand { text })
+        );
+        let data = CommandInputData {
+            options,
+            resolved: None,
+        };
+        assert_eq!(
+            CowCommand::from_interaction(data.clone()),
+            Ok(CowCommand { text: text.into() })
+        );
+        assert_eq!(
+            OwnedCommand::from_interaction(data),
+            Ok(OwnedCommand { text: text.into() })
+        );
+    }
+
+    for text in ["ab", "abcdef", "éé", "éééééé"] {
+        let options = vec![CommandDataOption {
+            name: "text".into(),
+            value: CommandOptionValue::String(text.into()),
+        }];
+        let error = ParseError::Option(ParseOptionError {
+            field: "text".into(),
+            kind: ParseOptionErrorType::StringLengthOutOfRange(text.into()),
+            path: Vec::new(),
+        });
+
+        assert_eq!(
+            BorrowedCommand::from_interaction_ref(&options, None),
+            Err(error.clone())
+        );
+        let data = CommandInputData {
+            options,
+            resolved: None,
+        };
+        assert_eq!(
+            CowCommand::from_interaction(data.clone()),
+            Err(error.clone())
+        );
+        assert_eq!(OwnedCommand::from_interaction(data), Err(error));
+    }
+}
+
+#[test]
+fn test_parse_error_kind() {
+    let error = ParseError::Option(ParseOptionError {
+        field: "delay".into(),
+        kind: ParseOptionErrorType::OutOfRange {
+            value: NumberCommandOptionValue::Integer(-1),
+            min: Some(NumberCommandOptionValue::Integer(0)),
+            max: None,
+        },
+        path: Vec::new(),
+    });
+
+    assert_eq!(error.kind(), ParseErrorKind::OutOfRange);
+    assert_eq!(error.kind().code(), 102);
+    assert_eq!(ParseError::EmptyOptions.kind().code(), 1);
+
+    let source = std::error::Error::source(&error).unwrap();
+    assert_eq!(
+        source.to_string(),
+        "failed to parse option `delay`: out of range value, received `-1` (expected at least 0)"
+    );
+
+    let source = source.source().unwrap();
+    assert_eq!(
+        source.to_string(),
+        "out of range value, received `-1` (expected at least 0)"
+    );
+    assert!(source.source().is_none());
+}
+
+#[test]
+fn test_interned_command_model() {
+    #[derive(CommandModel, Debug, PartialEq, Eq)]
+    struct SubscribeCommand {
+        feed: Interned,
+    }
+
+    let data = CommandInputData {
+        options: vec![CommandDataOption {
+            name: "feed".into(),
+            value: CommandOptionValue::String("releases".into()),
+        }],
+        resolved: None,
+    };
+
+    let first = SubscribeCommand::from_interaction(data.clone()).unwrap();
+    let second = SubscribeCommand::from_interaction(data).unwrap();
+
+    assert_eq!(&*first.feed, "releases");
+    assert!(Arc::ptr_eq(&first.feed.0, &second.feed.0));
+}
+
+#[test]
+fn test_shared_str_command_model() {
+    #[derive(CommandModel, Debug, PartialEq, Eq)]
+    struct NoteCommand {
+        #[command(max_length = 10)]
+        title: Box<str>,
+        #[command(min_length = 5)]
+        body: Rc<str>,
+        #[command(min_length = 3, max_length = 10)]
+        tag: Option<Arc<str>>,
+    }
+
+    let data = CommandInputData {
+        options: vec![
+            CommandDataOption {
+                name: "title".into(),
+                value: CommandOptionValue::String("Groceries".into()),
+            },
+            CommandDataOption {
+                name: "body".into(),
+                value: CommandOptionValue::String("Milk and eggs".into()),
+            },
+            CommandDataOption {
+                name: "tag".into(),
+                value: CommandOptionValue::String("shopping".into()),
+            },
+        ],
+        resolved: None,
+    };
+
+    let result = NoteCommand::from_interaction(data).unwrap();
+
+    assert_eq!(
+        NoteCommand {
+            title: "Groceries".into(),
+            body: "Milk and eggs".into(),
+            tag: Some("shopping".into()),
+        },
+        result
+    );
+
+    let data = |field: &str, value: &str| {
+        let mut options = vec![
+            CommandDataOption {
+                name: "title".into(),
+                value: CommandOptionValue::String("Groceries".into()),
+            },
+            CommandDataOption {
+                name: "body".into(),
+                value: CommandOptionValue::String("Milk and eggs".into()),
+            },
+        ];
+        options.retain(|option| option.name != field);
+        options.push(CommandDataOption {
+            name: field.into(),
+            value: CommandOptionValue::String(value.into()),
+        });
+
+        CommandInputData {
+            options,
+            resolved: None,
+        }
+    };
+
+    for (field, value) in [
+        ("title", "Shopping list"),
+        ("body", "Milk"),
+        ("tag", "me"),
+        ("tag", "groceries!!"),
+    ] {
+        assert_eq!(
+            NoteCommand::from_interaction(data(field, value)),
+            Err(ParseError::Option(ParseOptionError {
+                field: field.into(),
+                kind: ParseOptionErrorType::StringLengthOutOfRange(value.into()),
+                path: Vec::new(),
+            }))
+        );
+    }
+}
+
+#[test]
+fn test_symbol_table_limit() {
+    let table = SymbolTable::new(1);
+
+    let first = table.intern("a");
+    assert!(Arc::ptr_eq(&first, &table.intern("a")));
+
+    let second = table.intern("b");
+    assert!(!Arc::ptr_eq(&second, &table.intern("b")));
+    assert_eq!(table.len(), 1);
+
+    table.clear();
+    assert!(table.is_empty());
+}
+
+fn interaction(kind: InteractionType, data: Option<InteractionData>) -> Interaction {
+    #[allow(deprecated)]
+    Interaction {
+        app_permissions: None,
+        application_id: Id::new(1),
+        authorizing_integration_owners: ApplicationIntegrationMap {
+            guild: None,
+            user: None,
+        },
+        channel: None,
+        channel_id: None,
+        context: None,
+        data,
+        entitlements: Vec::new(),
+        guild: None,
+        guild_id: None,
+        guild_locale: None,
+        id: Id::new(1),
+        kind,
+        locale: None,
+        member: None,
+        message: None,
+        token: "token".into(),
+        user: None,
+    }
+}
+
+#[test]
+fn test_from_interaction_full() {
+    let data = CommandData {
+        guild_id: None,
+        id: Id::new(1),
+        name: "small".into(),
+        kind: CommandType::ChatInput,
+        options: vec![
+            CommandDataOption {
+                name: "percent".into(),
+                value: CommandOptionValue::Integer(50),
+            },
+            CommandDataOption {
+                name: "offset".into(),
+                value: CommandOptionValue::Integer(-3),
+            },
+        ],
+        resolved: None,
+        target_id: None,
+    };
+    let command = interaction(
+        InteractionType::ApplicationCommand,
+        Some(InteractionData::ApplicationCommand(Box::new(data))),
+    );
+
+    assert_eq!(
+        SmallIntegerCommand::from_interaction_full(&command),
+        Ok(SmallIntegerCommand {
+            percent: 50,
+            offset: -3
+        })
+    );
+
+    let ping = interaction(InteractionType::Ping, None);
+    let error = SmallIntegerCommand::from_interaction_full(&ping).unwrap_err();
+
+    assert_eq!(error, ParseError::InvalidInteraction(InteractionType::Ping));
+    assert_eq!(error.kind(), ParseErrorKind::InvalidInteraction);
+    assert_eq!(
+        error.to_string(),
+        "received Ping interaction without command data"
+    );
+}
+
+#[derive(CommandModel, Debug, PartialEq)]
+struct WarnCommand {
+    reason: String,
+    #[command(ctx)]
+    guild_id: Option<Id<GuildMarker>>,
+    #[command(ctx)]
+    user_id: Id<UserMarker>,
+}
+
+#[test]
+fn test_context_fields() {
+    let data = CommandData {
+        guild_id: None,
+        id: Id::new(1),
+        name: "warn".into(),
+        kind: CommandType::ChatInput,
+        options: vec![CommandDataOption {
+            name: "reason".into(),
+            value: CommandOptionValue::String("spam".into()),
+        }],
+        resolved: None,
+        target_id: None,
+    };
+    let mut command = interaction(
+        InteractionType::ApplicationCommand,
+        Some(InteractionData::ApplicationCommand(Box::new(data.clone()))),
+    );
+
+    let error = WarnCommand::from_interaction_full(&command).unwrap_err();
+    assert_eq!(error, ParseError::MissingContext("user_id".into()));
+    assert_eq!(error.kind(), ParseErrorKind::MissingContext);
+
+    command.user = Some(User {
+        accent_color: None,
+        avatar: None,
+        avatar_decoration: None,
+        avatar_decoration_data: None,
+        banner: None,
+        bot: false,
+        discriminator: 1,
+        email: None,
+        flags: None,
+        global_name: None,
+        id: Id::new(42),
+        locale: None,
+        mfa_enabled: None,
+        name: "user".into(),
+        premium_type: None,
+        public_flags: None,
+        system: None,
+        verified: None,
+    });
+
+    assert_eq!(
+        WarnCommand::from_interaction_full(&command),
+        Ok(WarnCommand {
+            reason: "spam".into(),
+            guild_id: None,
+            user_id: Id::new(42),
+        })
+    );
+
+    command.guild_id = Some(Id::new(7));
+    assert_eq!(
+        WarnCommand::from_interaction_full(&command).map(|command| command.guild_id),
+        Ok(Some(Id::new(7)))
+    );
+
+    // Context fields are not available without the interaction
+    assert_eq!(
+        WarnCommand::from_interaction(data.into()),
+        Err(ParseError::MissingContext("user_id".into()))
+    );
+}
+
+#[derive(CommandModel, Debug, PartialEq)]
+struct RestCommand {
+    message: String,
+    #[command(rest)]
+    rest: HashMap<String, CommandOptionValue>,
+}
+
+#[derive(CommandModel, Debug, PartialEq)]
+struct RestListCommand {
+    message: Option<String>,
+    #[command(rest)]
+    rest: Vec<CommandDataOption>,
+}
+
+#[test]
+fn test_rest_field() {
+    let options = vec![
+        CommandDataOption {
+            name: "message".into(),
+            value: CommandOptionValue::String("hello".into()),
+        },
+        CommandDataOption {
+            name: "silent".into(),
+            value: CommandOptionValue::Boolean(true),
+        },
+    ];
+    let data = CommandInputData {
+        options: options.clone(),
+        resolved: None,
+    };
+
+    assert_eq!(
+        RestCommand::from_interaction(data.clone()),
+        Ok(RestCommand {
+            message: "hello".into(),
+            rest: HashMap::from([("silent".into(), CommandOptionValue::Boolean(true))]),
+        })
+    );
+    assert_eq!(
+        RestListCommand::from_interaction(data),
+        Ok(RestListCommand {
+            message: Some("hello".into()),
+            rest: vec![options[1].clone()],
+        })
+    );
+
+    let data = CommandInputData {
+        options: vec![options[0].clone()],
+        resolved: None,
+    };
+    assert_eq!(
+        RestCommand::from_interaction(data),
+        Ok(RestCommand {
+            message: "hello".into(),
+            rest: HashMap::new(),
+        })
+    );
+}
@@ -0,0 +1,281 @@
+use twilight_interactions::{
+    component::{CustomId, SelectMenuModel, SelectOption},
+    error::{CustomIdError, ParseSelectError, ParseSelectMenuError},
+};
+use twilight_model::{
+    application::interaction::message_component::MessageComponentInteractionData,
+    channel::message::{
+        component::{ComponentType, SelectMenuOption},
+        EmojiReactionType,
+    },
+    id::{
+        marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+#[derive(SelectOption, SelectMenuModel, Debug, PartialEq, Eq)]
+enum Language {
+    #[select(label = "Rust", value = "rust", emoji = "🦀", default)]
+    Rust,
+    #[select(
+        label = "Python",
+        value = "python",
+        description = "Batteries included",
+        emoji = "<a:snake:123>"
+    )]
+    Python,
+}
+
+#[derive(SelectOption, SelectMenuModel, Debug, PartialEq, Eq)]
+#[select(rename_all = "snake_case")]
+enum Theme {
+    #[select(label = "Light theme")]
+    Light,
+    DarkBlue,
+}
+
+#[test]
+fn test_select_options() {
+    assert_eq!(
+        Language::select_options(),
+        [
+            SelectMenuOption {
+                default: true,
+                description: None,
+                emoji: Some(EmojiReactionType::Unicode {
+                    name: "🦀".into()
+                }),
+                label: "Rust".into(),
+                value: "rust".into(),
+            },
+            SelectMenuOption {
+                default: false,
+                description: Some("Batteries included".into()),
+                emoji: Some(EmojiReactionType::Custom {
+                    animated: true,
+                    id: Id::new(123),
+                    name: Some("snake".into()),
+                }),
+                label: "Python".into(),
+                value: "python".into(),
+            },
+        ]
+    );
+
+    let options = Theme::select_options();
+    assert_eq!(options[0].label, "Light theme");
+    assert_eq!(options[0].value, "light");
+    assert_eq!(options[1].label, "dark_blue");
+    assert_eq!(options[1].value, "dark_blue");
+}
+
+#[test]
+fn test_select_values() {
+    assert_eq!(Language::from_value("rust"), Some(Language::Rust));
+    assert_eq!(Language::from_value("go"), None);
+    assert_eq!(Language::Python.select_value(), "python");
+    assert_eq!(Theme::DarkBlue.select_value(), "dark_blue");
+
+    let data = MessageComponentInteractionData {
+        custom_id: "theme".into(),
+        component_type: ComponentType::TextSelectMenu,
+        resolved: None,
+        values: vec!["dark_blue".into(), "light".into()],
+    };
+    assert_eq!(
+        Theme::from_component(&data),
+        Ok(vec![Theme::DarkBlue, Theme::Light])
+    );
+
+    assert_eq!(
+        Language::from_values(["rust", "go"]),
+        Err(ParseSelectError { value: "go".into() })
+    );
+}
+
+#[derive(SelectMenuModel, Debug, PartialEq, Eq)]
+struct LanguagesSelect {
+    languages: Vec<Language>,
+}
+
+#[derive(SelectMenuModel, Debug, PartialEq, Eq)]
+struct RoleSelect(Option<Id<RoleMarker>>);
+
+#[test]
+fn test_select_menu_model() {
+    let mut data = MessageComponentInteractionData {
+        custom_id: "select".into(),
+        component_type: ComponentType::TextSelectMenu,
+        resolved: None,
+        values: vec!["python".into(), "rust".into()],
+    };
+
+    assert_eq!(
+        LanguagesSelect::from_interaction(&data),
+        Ok(LanguagesSelect {
+            languages: vec![Language::Python, Language::Rust]
+        })
+    );
+    assert_eq!(
+        Language::from_interaction(&data),
+        Err(ParseSelectMenuError::TooManyValues { found: 2 })
+    );
+
+    data.values = vec!["123".into()];
+    assert_eq!(
+        RoleSelect::from_interaction(&data),
+        Ok(RoleSelect(Some(Id::new(123))))
+    );
+    assert_eq!(
+        LanguagesSelect::from_interaction(&data),
+        Err(ParseSelectMenuError::InvalidValue(ParseSelectError {
+            value: "123".into()
+        }))
+    );
+
+    data.values = vec![];
+    assert_eq!(RoleSelect::from_interaction(&data), Ok(RoleSelect(None)));
+    assert_eq!(
+        Theme::from_interaction(&data),
+        Err(ParseSelectMenuError::MissingValue)
+    );
+}
+
+#[derive(CustomId, Debug, PartialEq, Eq)]
+#[custom_id(prefix = "poll")]
+struct PollButton {
+    question: String,
+    language: Option<Language>,
+    closed: bool,
+}
+
+#[derive(CustomId, Debug, PartialEq, Eq)]
+#[custom_id(prefix = "page")]
+struct PageButton(u16, i64);
+
+#[derive(CustomId, Debug, PartialEq, Eq)]
+#[custom_id(prefix = "refresh")]
+struct RefreshButton;
+
+#[test]
+fn test_custom_id() {
+    let button = PollButton {
+        question: r"12:30 or 13:00 \o/".into(),
+        language: Some(Language::Rust),
+        closed: false,
+    };
+    let custom_id = button.to_custom_id().unwrap();
+
+    assert_eq!(custom_id, r"poll:12\:30 or 13\:00 \\o/:rust:0");
+    assert_eq!(PollButton::from_custom_id(&custom_id), Ok(button));
+
+    assert_eq!(PageButton(2, -5).to_custom_id().unwrap(), "page:2:-5");
+    assert_eq!(
+        PageButton::from_custom_id("page:2:-5"),
+        Ok(PageButton(2, -5))
+    );
+    assert_eq!(RefreshButton.to_custom_id().unwrap(), "refresh");
+    assert_eq!(RefreshButton::from_custom_id("refresh"), Ok(RefreshButton));
+
+    assert!(PageButton::matches("page:1:2"));
+    assert!(!PageButton::matches("pages:1:2"));
+    assert!(!PageButton::matches("poll:1:2"));
+}
+
+#[test]
+fn test_custom_id_errors() {
+    assert_eq!(
+        PageButton::from_custom_id("poll:1:2"),
+        Err(CustomIdError::PrefixMismatch)
+    );
+    assert_eq!(
+        PageButton::from_custom_id("page:1"),
+        Err(CustomIdError::FieldCount {
+            expected: 2,
+            found: 1
+        })
+    );
+    assert_eq!(
+        PageButton::from_custom_id("page:1:two"),
+        Err(CustomIdError::InvalidField {
+            index: 1,
+            value: "two".into()
+        })
+    );
+    assert_eq!(
+        PollButton::from_custom_id("poll:hi:go:0"),
+        Err(CustomIdError::InvalidField {
+            index: 1,
+            value: "go".into()
+        })
+    );
+
+    let button = PollButton {
+        question: "?".repeat(100),
+        language: None,
+        closed: true,
+    };
+    assert_eq!(
+        button.to_custom_id(),
+        Err(CustomIdError::TooLong { length: 108 })
+    );
+}
+
+#[derive(CustomId, Debug, PartialEq, Eq)]
+#[custom_id(prefix = "trade", encoding = "compact")]
+struct TradeButton {
+    sender: Id<UserMarker>,
+    receiver: Id<UserMarker>,
+    guild: Id<GuildMarker>,
+    channel: Id<ChannelMarker>,
+    amount: i64,
+    note: Option<String>,
+    language: Language,
+}
+
+#[test]
+fn test_compact_custom_id() {
+    let button = TradeButton {
+        sender: Id::new(1_150_000_000_000_000_001),
+        receiver: Id::new(1_150_000_000_000_000_002),
+        guild: Id::new(1_150_000_000_000_000_003),
+        channel: Id::new(1_150_000_000_000_000_004),
+        amount: -250,
+        note: Some("gg".into()),
+        language: Language::Python,
+    };
+    let custom_id = button.to_custom_id().unwrap();
+
+    assert!(custom_id.starts_with("trade:"));
+    assert!(custom_id.len() <= 100);
+    assert!(TradeButton::matches(&custom_id));
+    assert_eq!(TradeButton::from_custom_id(&custom_id), Ok(button));
+
+    assert_eq!(
+        TradeButton::from_custom_id("trade:not*base64"),
+        Err(CustomIdError::InvalidEncoding)
+    );
+    assert_eq!(
+        TradeButton::from_custom_id(&custom_id[..custom_id.len() - 4]),
+        Err(CustomIdError::InvalidEncoding)
+    );
+    assert_eq!(
+        TradeButton::from_custom_id("poll:AAAA"),
+        Err(CustomIdError::PrefixMismatch)
+    );
+
+    let button = TradeButton {
+        sender: Id::new(1),
+        receiver: Id::new(2),
+        guild: Id::new(3),
+        channel: Id::new(4),
+        amount: 0,
+        note: Some("a".repeat(80)),
+        language: Language::Rust,
+    };
+    assert!(matches!(
+        button.to_custom_id(),
+        Err(CustomIdError::TooLong { .. })
+    ));
+}
@@ -0,0 +1,99 @@
+//! Derive macros used through a re-export of the crate, as done by frameworks
+//! built on top of `twilight-interactions`.
+
+mod framework {
+    pub use twilight_interactions as interactions;
+}
+
+use framework::interactions::{
+    command::{CommandInputData, CommandModel, CommandOption, CreateCommand, CreateOption},
+    component::{CustomId, SelectMenuModel, SelectOption},
+};
+use twilight_model::{
+    application::{
+        command::CommandOptionType,
+        interaction::{
+            application_command::{CommandDataOption, CommandOptionValue},
+            message_component::MessageComponentInteractionData,
+        },
+    },
+    channel::message::component::ComponentType,
+};
+
+#[derive(CommandOption, CreateOption, Debug, PartialEq, Eq)]
+#[option(crate = "framework::interactions")]
+enum Speed {
+    #[option(name = "Slow", value = "slow")]
+    Slow,
+    #[option(name = "Fast", value = "fast")]
+    Fast,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(
+    name = "race",
+    desc = "Start a race",
+    crate = "framework::interactions"
+)]
+struct RaceCommand {
+    /// Speed of the race
+    speed: Speed,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "game", desc = "Play a game", crate = "framework::interactions")]
+enum GameCommand {
+    #[command(name = "race")]
+    Race(RaceCommand),
+}
+
+#[derive(SelectOption, SelectMenuModel, Debug, PartialEq, Eq)]
+#[select(crate = "framework::interactions")]
+enum Track {
+    #[select(label = "Desert", value = "desert")]
+    Desert,
+}
+
+#[derive(CustomId, Debug, PartialEq, Eq)]
+#[custom_id(prefix = "race", crate = "framework::interactions")]
+struct RaceButton(u16, Track);
+
+#[test]
+fn test_crate_path_command() {
+    let data = CommandInputData {
+        options: vec![CommandDataOption {
+            name: "speed".into(),
+            value: CommandOptionValue::String("fast".into()),
+        }],
+        resolved: None,
+    };
+
+    assert_eq!(
+        RaceCommand::from_interaction(data),
+        Ok(RaceCommand { speed: Speed::Fast })
+    );
+
+    let command = GameCommand::create_command();
+    assert_eq!(command.name, "game");
+    assert_eq!(command.options[0].kind, CommandOptionType::SubCommand);
+    assert_eq!(
+        command.options[0].options.as_ref().unwrap()[0].kind,
+        CommandOptionType::String
+    );
+}
+
+#[test]
+fn test_crate_path_component() {
+    let data = MessageComponentInteractionData {
+        custom_id: "track".into(),
+        component_type: ComponentType::TextSelectMenu,
+        resolved: None,
+        values: vec!["desert".into()],
+    };
+    assert_eq!(Track::from_interaction(&data), Ok(Track::Desert));
+
+    let button = RaceButton(3, Track::Desert);
+    let custom_id = button.to_custom_id().unwrap();
+    assert_eq!(custom_id, "race:3:desert");
+    assert_eq!(RaceButton::from_custom_id(&custom_id), Ok(button));
+}
@@ -1,13 +1,22 @@
-use std::{borrow::Cow, collections::HashMap};
+use std::{borrow::Cow, collections::HashMap, num::NonZeroU64, rc::Rc, sync::Arc, time::Duration};
 
 use twilight_interactions::command::{
-    ApplicationCommandData, CreateCommand, CreateOption, DescLocalizations, NameLocalizations,
-    ResolvedUser,
+    set_localization_provider, ApplicationCommandData, CommandInputData, CommandModel,
+    CreateCommand, CreateOption, DescLocalizations, LocalizationKey, LocalizationProvider,
+    NameLocalizations, ResolvedUser, SlashCommand,
 };
 use twilight_model::{
     application::{
-        command::{CommandOption, CommandOptionType, CommandOptionValue},
-        interaction::{InteractionChannel, InteractionContextType},
+        command::{
+            Command, CommandOption, CommandOptionChoice, CommandOptionChoiceValue,
+            CommandOptionType, CommandOptionValue,
+        },
+        interaction::{
+            application_command::{
+                CommandDataOption, CommandOptionValue as InteractionOptionValue,
+            },
+            InteractionChannel, InteractionContextType, InteractionDataResolved,
+        },
     },
     channel::ChannelType,
     guild::Permissions,
@@ -23,7 +32,9 @@ use twilight_model::{
     dm_permission = false,
     contexts = "guild private_channel",
     integration_types = "guild_install",
-    nsfw = true
+    nsfw = true,
+    timeout = "1m 30s",
+    names_module
 )]
 struct DemoCommand<'a, T>
 where
@@ -65,6 +76,16 @@ fn demo_desc() -> DescLocalizations {
 #[command(name = "unit", desc = "Unit command for testing purposes")]
 struct UnitCommand;
 
+#[derive(CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "non-zero", desc = "Non-zero command")]
+struct NonZeroCommand {
+    /// Default minimum value
+    count: NonZeroU64,
+    /// Custom minimum value
+    #[command(min_value = 10, max_value = 20)]
+    bounded: NonZeroU64,
+}
+
 #[test]
 fn test_create_command() {
     let options = vec![
@@ -184,10 +205,22 @@ fn test_create_command() {
             InteractionContextType::PrivateChannel,
         ]),
         integration_types: Some(vec![ApplicationIntegrationType::GuildInstall]),
+        timeout: Some(Duration::from_secs(90)),
+        cooldown: None,
+        guild_only: false,
+        owner_only: false,
+        category: None,
+        metadata: HashMap::new(),
     };
 
     assert_eq!(DemoCommand::<i64>::create_command(), expected);
     assert_eq!(DemoCommand::<i64>::NAME, "demo");
+    assert_eq!(
+        DemoCommand::<i64>::DESCRIPTION,
+        "Demo command for testing purposes"
+    );
+    const { assert!(DemoCommand::<i64>::NSFW) };
+    const { assert!(!DemoCommand::<i64>::GROUP) };
 }
 
 #[test]
@@ -205,8 +238,639 @@ fn test_unit_create_command() {
         nsfw: None,
         contexts: None,
         integration_types: None,
+        timeout: None,
+        cooldown: None,
+        guild_only: false,
+        owner_only: false,
+        category: None,
+        metadata: HashMap::new(),
     };
 
     assert_eq!(UnitCommand::create_command(), expected);
     assert_eq!(UnitCommand::NAME, "unit");
+    assert_eq!(
+        UnitCommand::DESCRIPTION,
+        "Unit command for testing purposes"
+    );
+    const { assert!(!UnitCommand::NSFW) };
+}
+
+#[derive(CreateCommand)]
+#[command(
+    name = "flagged",
+    desc = "Flagged command",
+    meta(tier = 3, level = -1, r#type = "beta", "feature-flag" = true)
+)]
+struct FlaggedCommand;
+
+#[test]
+fn test_meta_attribute() {
+    let metadata = HashMap::from([
+        ("tier".to_owned(), "3".to_owned()),
+        ("level".to_owned(), "-1".to_owned()),
+        ("type".to_owned(), "beta".to_owned()),
+        ("feature-flag".to_owned(), "true".to_owned()),
+    ]);
+
+    assert_eq!(FlaggedCommand::create_command().metadata, metadata);
+    assert!(UnitCommand::create_command().metadata.is_empty());
+}
+
+#[derive(CreateCommand)]
+#[command(name = "warn", desc = "Warn a member", category = "moderation")]
+struct WarnCommand;
+
+#[test]
+fn test_category() {
+    let command = WarnCommand::create_command();
+
+    assert_eq!(command.category.as_deref(), Some("moderation"));
+    assert!(command.metadata.is_empty());
+    assert_eq!(WarnCommand::CATEGORY, Some("moderation"));
+    assert_eq!(UnitCommand::create_command().category, None);
+    assert_eq!(UnitCommand::CATEGORY, None);
+}
+
+#[test]
+fn test_non_zero_create_command() {
+    let options = NonZeroCommand::create_command().options;
+
+    assert_eq!(options[0].kind, CommandOptionType::Integer);
+    assert_eq!(options[0].min_value, Some(CommandOptionValue::Integer(1)));
+    assert_eq!(options[0].max_value, None);
+    assert_eq!(options[1].min_value, Some(CommandOptionValue::Integer(10)));
+    assert_eq!(options[1].max_value, Some(CommandOptionValue::Integer(20)));
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "color", desc = "Set a color")]
+struct ColorCommand {
+    /// Color in hexadecimal notation
+    #[command(with = "parse_hex", option_type = "string", max_length = 7)]
+    color: u32,
+}
+
+fn parse_hex(
+    value: InteractionOptionValue,
+    _resolved: Option<&InteractionDataResolved>,
+) -> Result<u32, String> {
+    match value {
+        InteractionOptionValue::String(value) => {
+            u32::from_str_radix(value.trim_start_matches('#'), 16).map_err(|err| err.to_string())
+        }
+        other => Err(format!("unexpected {:?} option", other.kind())),
+    }
+}
+
+#[test]
+fn test_with_create_command() {
+    let options = ColorCommand::create_command().options;
+
+    assert_eq!(options[0].kind, CommandOptionType::String);
+    assert_eq!(options[0].name, "color");
+    assert_eq!(options[0].required, Some(true));
+    assert_eq!(options[0].max_length, Some(7));
+}
+
+#[derive(CreateOption, Debug, PartialEq)]
+#[allow(dead_code)]
+enum Unit {
+    #[option(name = "Metric", value = "metric")]
+    Metric,
+    #[option(name = "Imperial", value = "imperial")]
+    Imperial,
+}
+
+// No names module is generated without the `names_module` attribute, so a
+// module with the same name can be declared
+mod travel_command {}
+
+#[derive(CreateCommand, Debug, PartialEq)]
+#[command(name = "travel", desc = "Plan a trip", autocomplete_all)]
+struct TravelCommand {
+    /// Departure city
+    from: String,
+    /// Number of travelers
+    travelers: i64,
+    /// Unit of distances
+    unit: Unit,
+    /// Whether to avoid tolls
+    avoid_tolls: Option<bool>,
+}
+
+#[test]
+fn test_autocomplete_all_create_command() {
+    let options = TravelCommand::create_command().options;
+
+    assert_eq!(options[0].autocomplete, Some(true));
+    assert_eq!(options[1].autocomplete, Some(true));
+    assert_eq!(options[2].autocomplete, Some(false));
+    assert_eq!(options[3].autocomplete, Some(false));
+}
+
+#[derive(CreateCommand, Debug, PartialEq)]
+#[command(name = "region", desc = "Select a region", autocomplete_all)]
+struct RegionCommand {
+    /// Region of the server
+    #[command(choices = "region_choices")]
+    region: String,
+}
+
+fn region_choices() -> Vec<CommandOptionChoice> {
+    ["eu", "us"]
+        .into_iter()
+        .map(|region| CommandOptionChoice {
+            name: region.to_uppercase(),
+            name_localizations: None,
+            value: CommandOptionChoiceValue::String(region.into()),
+        })
+        .collect()
+}
+
+#[test]
+fn test_dynamic_choices_create_command() {
+    let options = RegionCommand::create_command().options;
+
+    assert_eq!(options[0].choices, Some(region_choices()));
+    assert_eq!(options[0].autocomplete, Some(false));
+}
+
+#[derive(CreateCommand, Debug, PartialEq)]
+#[command(name = "post", desc = "Post a message")]
+struct PostCommand {
+    /// Channel to post in
+    #[command(channel_types = "guild_forum guild_media guild_news announcement_thread")]
+    channel: InteractionChannel,
+}
+
+#[test]
+fn test_channel_types_create_command() {
+    let options = PostCommand::create_command().options;
+
+    assert_eq!(
+        options[0].channel_types,
+        Some(vec![
+            ChannelType::GuildForum,
+            ChannelType::GuildMedia,
+            ChannelType::GuildAnnouncement,
+            ChannelType::AnnouncementThread,
+        ])
+    );
+}
+
+const THREAD_TYPES: &[ChannelType] = &[ChannelType::PublicThread, ChannelType::PrivateThread];
+
+#[derive(CreateCommand, Debug, PartialEq)]
+#[command(name = "archive", desc = "Archive a channel")]
+struct ArchiveCommand {
+    /// Channel to archive
+    #[command(channel_types(GuildText, GuildForum))]
+    channel: InteractionChannel,
+    /// Thread to archive
+    #[command(channel_types = THREAD_TYPES)]
+    thread: Option<InteractionChannel>,
+}
+
+#[test]
+fn test_channel_types_paths_create_command() {
+    let options = ArchiveCommand::create_command().options;
+
+    assert_eq!(
+        options[0].channel_types,
+        Some(vec![ChannelType::GuildText, ChannelType::GuildForum])
+    );
+    assert_eq!(options[1].channel_types, Some(THREAD_TYPES.to_vec()));
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "reminder", desc = "Set a reminder")]
+struct ReminderCommand {
+    /// Whether to send the reminder in DMs
+    private: Option<bool>,
+    /// Message of the reminder
+    #[command(order = -1)]
+    message: String,
+    /// Delay in minutes
+    #[command(order = -1)]
+    delay: i64,
+    /// Channel of the reminder
+    #[command(order = 1)]
+    channel: Option<InteractionChannel>,
+}
+
+#[test]
+fn test_order_create_command() {
+    let names: Vec<_> = ReminderCommand::create_command()
+        .options
+        .into_iter()
+        .map(|option| option.name)
+        .collect();
+
+    assert_eq!(names, ["message", "delay", "private", "channel"]);
+}
+
+#[derive(CreateCommand, Debug, PartialEq)]
+#[command(name = "thermostat", desc = "Set the temperature")]
+struct ThermostatCommand {
+    /// Temperature in degrees
+    #[command(min_value = -40, max_value = 40)]
+    temperature: i64,
+    /// Offset applied to the sensor
+    #[command(min_value = -2.5, max_value = -0.5)]
+    offset: Option<f64>,
+}
+
+#[test]
+fn test_negative_bounds_create_command() {
+    let options = ThermostatCommand::create_command().options;
+
+    assert_eq!(options[0].min_value, Some(CommandOptionValue::Integer(-40)));
+    assert_eq!(options[0].max_value, Some(CommandOptionValue::Integer(40)));
+    assert_eq!(options[1].min_value, Some(CommandOptionValue::Number(-2.5)));
+    assert_eq!(options[1].max_value, Some(CommandOptionValue::Number(-0.5)));
+}
+
+mod limits {
+    pub const NAME: &str = "donate";
+    pub const DESC: &str = "Donate coins to another user";
+    pub const MAX_AMOUNT: i32 = 10_000;
+    pub const MAX_FEE: f64 = 0.5;
+    pub const NOTE: &str = "message";
+    pub const MIN_NOTE: u16 = 10;
+    pub const MAX_NOTE: u16 = 200;
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = limits::NAME, desc = limits::DESC, deny_reserved_name)]
+struct DonateCommand {
+    /// Amount of coins
+    #[command(min_value = 1, max_value = limits::MAX_AMOUNT)]
+    amount: i64,
+    /// Fee ratio
+    #[command(max_value = limits::MAX_FEE)]
+    fee: Option<f64>,
+    #[command(
+        rename = limits::NOTE,
+        desc = limits::DESC,
+        min_length = limits::MIN_NOTE,
+        max_length = limits::MAX_NOTE
+    )]
+    note: Option<String>,
+}
+
+#[test]
+fn test_const_attributes_create_command() {
+    assert_eq!(DonateCommand::NAME, "donate");
+
+    let command = DonateCommand::create_command();
+    assert_eq!(command.name, "donate");
+    assert_eq!(command.description, "Donate coins to another user");
+
+    let options = command.options;
+    assert_eq!(options[0].min_value, Some(CommandOptionValue::Integer(1)));
+    assert_eq!(
+        options[0].max_value,
+        Some(CommandOptionValue::Integer(10_000))
+    );
+    assert_eq!(options[1].max_value, Some(CommandOptionValue::Number(0.5)));
+    assert_eq!(options[2].name, "message");
+    assert_eq!(options[2].description, "Donate coins to another user");
+    assert_eq!(options[2].min_length, Some(10));
+    assert_eq!(options[2].max_length, Some(200));
+
+    let data = CommandInputData {
+        options: vec![
+            CommandDataOption {
+                name: "amount".into(),
+                value: InteractionOptionValue::Integer(100),
+            },
+            CommandDataOption {
+                name: "message".into(),
+                value: InteractionOptionValue::String("Thanks for the help".into()),
+            },
+        ],
+        resolved: None,
+    };
+    assert_eq!(
+        DonateCommand::from_interaction(data),
+        Ok(DonateCommand {
+            amount: 100,
+            fee: None,
+            note: Some("Thanks for the help".into()),
+        })
+    );
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "set", desc = "Set a value", names_module)]
+struct SetCommand<T> {
+    /// Value to set
+    value: T,
+    /// Previous value
+    previous: Option<T>,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "settings", desc = "Manage settings", names_module)]
+enum SettingsCommand<T> {
+    #[command(name = "set")]
+    Set(SetCommand<T>),
+}
+
+#[test]
+fn test_generic_create_command() {
+    let options = SetCommand::<i64>::create_command().options;
+    assert_eq!(options[0].kind, CommandOptionType::Integer);
+    assert_eq!(options[1].kind, CommandOptionType::Integer);
+    assert_eq!(options[1].required, Some(false));
+
+    let options = SettingsCommand::<String>::create_command().options;
+    assert_eq!(options[0].kind, CommandOptionType::SubCommand);
+    assert_eq!(
+        options[0].options.as_ref().unwrap()[0].kind,
+        CommandOptionType::String
+    );
+}
+
+#[test]
+fn test_option_names() {
+    assert_eq!(demo_command::options::MEMBER, "member");
+    assert_eq!(demo_command::options::TEXT, "text");
+    assert_eq!(demo_command::options::GENERIC, "generic");
+    assert_eq!(set_command::options::VALUE, "value");
+    assert_eq!(settings_command::subcommands::SET, "set");
+}
+
+#[test]
+fn test_cached_command() {
+    let command = UnitCommand::command();
+    assert_eq!(*command, UnitCommand::create_command());
+    assert!(std::ptr::eq(command, UnitCommand::command()));
+    assert!(std::ptr::eq(command, Box::<UnitCommand>::command()));
+
+    // Each instantiation of a generic type has its own command
+    let integer = SetCommand::<i64>::command();
+    let string = SetCommand::<String>::command();
+    assert_eq!(integer.options[0].kind, CommandOptionType::Integer);
+    assert_eq!(string.options[0].kind, CommandOptionType::String);
+}
+
+#[test]
+fn test_localizations_merge() {
+    let base = NameLocalizations::new([("fr", "bonjour"), ("de", "hallo")]);
+    let overrides: NameLocalizations = [("fr", "salut")].into_iter().collect();
+
+    let mut merged = base.merge(overrides);
+    merged.extend([("es", "hola")]);
+
+    assert_eq!(
+        merged,
+        NameLocalizations::new([("fr", "salut"), ("de", "hallo"), ("es", "hola")])
+    );
+
+    let base = DescLocalizations::new("Say hello", [("fr", "Dis bonjour")]);
+    let overrides = DescLocalizations::new("Ignored", [("fr", "Dis salut")]);
+
+    let merged = base.merge(overrides).with_fallback("Greet someone");
+
+    assert_eq!(
+        merged,
+        DescLocalizations::new("Greet someone", [("fr", "Dis salut")])
+    );
+}
+
+struct Catalog;
+
+impl LocalizationProvider for Catalog {
+    fn name_localizations(&self, key: LocalizationKey<'_>) -> Option<HashMap<String, String>> {
+        let name = match (key.path, key.choice) {
+            ("travel", None) => "voyage",
+            ("travel unit", None) => "unite",
+            ("travel unit", Some("Metric")) => "Metrique",
+            _ => return None,
+        };
+
+        Some(HashMap::from([("fr".into(), name.into())]))
+    }
+
+    fn desc_localizations(&self, key: LocalizationKey<'_>) -> Option<HashMap<String, String>> {
+        match key.path {
+            "travel from" => Some(HashMap::from([("fr".into(), "Ville de depart".into())])),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn test_localization_provider() {
+    let fr = |value: &str| Some(HashMap::from([("fr".into(), value.into())]));
+
+    let command = TravelCommand::create_command_with(&Catalog);
+    assert_eq!(command.name_localizations, fr("voyage"));
+    assert_eq!(command.description_localizations, None);
+    assert_eq!(
+        command.options[0].description_localizations,
+        fr("Ville de depart")
+    );
+    assert_eq!(command.options[2].name_localizations, fr("unite"));
+
+    let choices = command.options[2].choices.as_ref().unwrap();
+    assert_eq!(choices[0].name_localizations, fr("Metrique"));
+    assert_eq!(choices[1].name_localizations, None);
+
+    // The global provider is applied by `create_command`
+    assert!(TravelCommand::create_command().name_localizations.is_none());
+    assert!(set_localization_provider(Catalog).is_ok());
+    assert!(set_localization_provider(Catalog).is_err());
+
+    let mut command = TravelCommand::create_command();
+    assert_eq!(command.name_localizations, fr("voyage"));
+    assert_eq!(
+        command.options[0].description_localizations,
+        fr("Ville de depart")
+    );
+
+    // Converting into a `Command` keeps the localizations unchanged
+    command.name_localizations = None;
+    assert_eq!(Command::from(command).name_localizations, None);
+
+    // Subcommands are localized with the path of their parent command
+    let command = PlanCommand::create_command();
+    assert_eq!(command.options[0].name_localizations, None);
+
+    // An explicit provider takes precedence over the global one
+    let command = TravelCommand::create_command_with(&Rename);
+    assert_eq!(command.name_localizations, fr("trajet"));
+    assert_eq!(
+        command.options[0].description_localizations,
+        fr("Ville de depart")
+    );
+}
+
+#[allow(dead_code)]
+#[derive(CreateCommand)]
+#[command(name = "plan", desc = "Plan something")]
+enum PlanCommand {
+    #[command(name = "travel")]
+    Travel(TravelCommand),
+}
+
+struct Rename;
+
+impl LocalizationProvider for Rename {
+    fn name_localizations(&self, key: LocalizationKey<'_>) -> Option<HashMap<String, String>> {
+        match key.path {
+            "travel" => Some(HashMap::from([("fr".into(), "trajet".into())])),
+            _ => None,
+        }
+    }
+
+    fn desc_localizations(&self, _key: LocalizationKey<'_>) -> Option<HashMap<String, String>> {
+        None
+    }
+}
+
+#[test]
+fn test_localizations_resolve() {
+    let desc = DescLocalizations::new(
+        "Say hello",
+        [
+            ("en-US", "Say hello!"),
+            ("fr", "Dis bonjour"),
+            ("es-419", "Di hola"),
+        ],
+    );
+
+    assert_eq!(desc.resolve("fr"), "Dis bonjour");
+    assert_eq!(desc.resolve("en-GB"), "Say hello!");
+    assert_eq!(desc.resolve("es-ES"), "Di hola");
+    assert_eq!(desc.resolve("de"), "Say hello");
+
+    let name = NameLocalizations::new([("en-GB", "colour"), ("en-US", "color")]);
+
+    assert_eq!(name.resolve("en-GB"), Some("colour"));
+    assert_eq!(name.resolve("en"), Some("color"));
+    assert_eq!(name.resolve("fr"), None);
+}
+
+#[test]
+fn test_create_option_data_constructors() {
+    use twilight_interactions::command::ext::{CommandOptionData, CreateOptionData};
+    use twilight_model::application::command::CommandOptionValue;
+
+    let data = CreateOptionData::new("count", "Number of items")
+        .name_localizations(NameLocalizations::new([("fr", "nombre")]))
+        .required(true)
+        .autocomplete(true)
+        .data(
+            CommandOptionData::new()
+                .min_value(CommandOptionValue::Integer(1))
+                .max_value(CommandOptionValue::Integer(10)),
+        );
+
+    assert_eq!(
+        data,
+        CreateOptionData {
+            name: "count".into(),
+            name_localizations: Some(HashMap::from([("fr".into(), "nombre".into())])),
+            description: "Number of items".into(),
+            description_localizations: None,
+            required: Some(true),
+            autocomplete: true,
+            data: CommandOptionData {
+                channel_types: None,
+                max_value: Some(CommandOptionValue::Integer(10)),
+                min_value: Some(CommandOptionValue::Integer(1)),
+                max_length: None,
+                min_length: None,
+                format: None,
+            },
+        }
+    );
+
+    let data = data.description_localizations(DescLocalizations::new(
+        "Item count",
+        [("fr", "Nombre d'éléments")],
+    ));
+    assert_eq!(data.description, "Item count");
+}
+
+#[derive(SlashCommand, Debug, PartialEq, Eq)]
+#[command(name = "remind", desc = "Set a reminder", rename_all = "kebab-case")]
+struct RemindCommand {
+    /// Reminder text
+    text: String,
+    /// Delay in minutes
+    #[command(min_value = 1)]
+    delay_minutes: Option<i64>,
+}
+
+#[test]
+fn test_slash_command_derive() {
+    let command = RemindCommand::create_command();
+    assert_eq!(RemindCommand::NAME, "remind");
+    assert_eq!(command.options[0].name, "text");
+    assert_eq!(command.options[0].required, Some(true));
+    assert_eq!(command.options[1].name, "delay-minutes");
+    assert_eq!(command.options[1].required, Some(false));
+
+    let data = CommandInputData {
+        options: vec![
+            CommandDataOption {
+                name: "text".into(),
+                value: InteractionOptionValue::String("tea".into()),
+            },
+            CommandDataOption {
+                name: "delay-minutes".into(),
+                value: InteractionOptionValue::Integer(5),
+            },
+        ],
+        resolved: None,
+    };
+    assert_eq!(
+        RemindCommand::from_interaction(data),
+        Ok(RemindCommand {
+            text: "tea".into(),
+            delay_minutes: Some(5),
+        })
+    );
+}
+
+#[test]
+fn test_shared_str_create_option() {
+    #[derive(CreateCommand)]
+    #[allow(dead_code)]
+    #[command(name = "note", desc = "Write a note")]
+    struct NoteCommand {
+        /// Title of the note
+        #[command(max_length = 50)]
+        title: Box<str>,
+        /// Content of the note
+        body: Rc<str>,
+        /// Tag of the note
+        tag: Option<Arc<str>>,
+    }
+
+    let command = NoteCommand::create_command();
+
+    assert!(command
+        .options
+        .iter()
+        .all(|option| option.kind == CommandOptionType::String));
+    assert_eq!(command.options[0].max_length, Some(50));
+    assert_eq!(command.options[2].required, Some(false));
+}
+
+#[test]
+fn test_create_command_tree() {
+    assert_eq!(
+        DemoCommand::<Unit>::create_command().to_string(),
+        r#"/demo (nsfw, permissions: SEND_MESSAGES): Demo command for testing purposes
+├── member (user, required): A member
+├── text (string, required, length >= 5): Some text
+├── number (number, required, autocomplete, <= 50): A number
+├── channel (channel, channels: GuildText | Private): A text channel
+├── generic (string, choices: Metric = "metric" | Imperial = "imperial"): Generic field
+└── cow (string): More text"#
+    );
 }
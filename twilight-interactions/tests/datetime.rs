@@ -0,0 +1,101 @@
+#![cfg(any(feature = "chrono", feature = "time"))]
+
+use twilight_interactions::{
+    command::{CommandInputData, CommandModel, CreateCommand},
+    error::{ParseError, ParseOptionError, ParseOptionErrorType},
+};
+use twilight_model::application::interaction::application_command::{
+    CommandDataOption, CommandOptionValue,
+};
+
+fn data(start: &str, end: &str) -> CommandInputData<'static> {
+    CommandInputData {
+        options: vec![
+            CommandDataOption {
+                name: "start".into(),
+                value: CommandOptionValue::String(start.into()),
+            },
+            CommandDataOption {
+                name: "end".into(),
+                value: CommandOptionValue::String(end.into()),
+            },
+        ],
+        resolved: None,
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_datetime() {
+    use chrono::{DateTime, TimeZone, Utc};
+
+    #[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+    #[command(name = "event", desc = "Create an event")]
+    struct EventCommand {
+        /// Start of the event
+        start: DateTime<Utc>,
+        /// End of the event
+        #[command(format = "%Y-%m-%d %H:%M")]
+        end: DateTime<Utc>,
+    }
+
+    let result =
+        EventCommand::from_interaction(data("2024-05-01T10:00:00+02:00", "2024-05-01 12:30"));
+    assert_eq!(
+        result,
+        Ok(EventCommand {
+            start: Utc.with_ymd_and_hms(2024, 5, 1, 8, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2024, 5, 1, 12, 30, 0).unwrap(),
+        })
+    );
+
+    let result = EventCommand::from_interaction(data("tomorrow", "2024-05-01 12:30"));
+    assert!(matches!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            kind: ParseOptionErrorType::InvalidValue(_),
+            ..
+        }))
+    ));
+
+    let options = EventCommand::create_command().options;
+    assert_eq!(options[0].min_length, Some(20));
+    assert_eq!(options[0].max_length, Some(35));
+    assert_eq!(options[1].min_length, None);
+    assert_eq!(options[1].max_length, None);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time_datetime() {
+    use time::{macros::datetime, OffsetDateTime};
+
+    #[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+    #[command(name = "event", desc = "Create an event")]
+    struct EventCommand {
+        /// Start of the event
+        start: OffsetDateTime,
+        /// End of the event
+        #[command(format = "[year]-[month]-[day] [hour]:[minute]")]
+        end: OffsetDateTime,
+    }
+
+    let result =
+        EventCommand::from_interaction(data("2024-05-01T10:00:00+02:00", "2024-05-01 12:30"));
+    assert_eq!(
+        result,
+        Ok(EventCommand {
+            start: datetime!(2024-05-01 10:00:00 +02:00),
+            end: datetime!(2024-05-01 12:30:00 UTC),
+        })
+    );
+
+    let result = EventCommand::from_interaction(data("2024-05-01T10:00:00+02:00", "tomorrow"));
+    assert!(matches!(
+        result,
+        Err(ParseError::Option(ParseOptionError {
+            kind: ParseOptionErrorType::InvalidValue(_),
+            ..
+        }))
+    ));
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "export")]
+
+use twilight_interactions::{
+    command::{CommandOption, CreateCommand, CreateOption, DescLocalizations},
+    export::{Localization, Manifest},
+};
+use twilight_model::application::command::CommandOptionChoiceValue;
+
+#[derive(CommandOption, CreateOption)]
+enum Unit {
+    #[option(name = "Seconds", value = "s")]
+    Seconds,
+    #[option(name = "Minutes", value = "m")]
+    Minutes,
+}
+
+#[derive(CreateCommand)]
+#[allow(dead_code)]
+#[command(name = "start", desc = "Start a timer")]
+struct StartCommand {
+    /// Duration of the timer
+    #[command(min_value = 1, max_value = 60)]
+    duration: i64,
+    /// Unit of the duration
+    unit: Option<Unit>,
+}
+
+#[derive(CreateCommand)]
+#[allow(dead_code)]
+#[command(
+    name = "timer",
+    desc_localizations = "timer_desc",
+    category = "utility",
+    meta(tier = 1)
+)]
+enum TimerCommand {
+    #[command(name = "start")]
+    Start(StartCommand),
+}
+
+fn timer_desc() -> DescLocalizations {
+    DescLocalizations::new("Manage timers", [("fr", "Gérer les minuteurs")])
+}
+
+#[test]
+fn test_manifest() {
+    let manifest = Manifest::new([&TimerCommand::create_command()]);
+    let command = &manifest.commands[0];
+
+    assert_eq!(command.name, "timer");
+    assert_eq!(command.description, "Manage timers");
+    assert_eq!(
+        command.localizations["fr"],
+        Localization {
+            name: None,
+            description: Some("Gérer les minuteurs".into()),
+        }
+    );
+    assert_eq!(command.category.as_deref(), Some("utility"));
+    assert_eq!(command.metadata["tier"], "1");
+
+    let subcommand = &command.options[0];
+    assert_eq!(subcommand.kind, "subcommand");
+    assert_eq!(subcommand.options[0].name, "duration");
+    assert_eq!(subcommand.options[0].kind, "integer");
+    assert!(subcommand.options[0].required);
+
+    let unit = &subcommand.options[1];
+    assert!(!unit.required);
+    assert_eq!(unit.choices.len(), 2);
+    assert_eq!(unit.choices[1].name, "Minutes");
+    assert_eq!(
+        unit.choices[1].value,
+        CommandOptionChoiceValue::String("m".into())
+    );
+}
+
+#[test]
+fn test_manifest_json() {
+    let json = Manifest::new([&StartCommand::create_command()]).to_json();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    let duration = &value["commands"][0]["options"][0];
+    assert_eq!(duration["name"], "duration");
+    assert_eq!(duration["min_value"], 1);
+    assert_eq!(duration["max_value"], 60);
+    // Empty fields are omitted
+    assert!(duration.get("choices").is_none());
+
+    let unit = &value["commands"][0]["options"][1];
+    assert_eq!(unit["choices"][0]["value"], "s");
+}
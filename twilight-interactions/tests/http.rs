@@ -0,0 +1,42 @@
+#![cfg(feature = "http")]
+
+use twilight_interactions::http::PermissionOverrideSet;
+use twilight_model::{
+    application::command::permissions::{CommandPermission, CommandPermissionType},
+    id::Id,
+};
+
+#[test]
+fn test_permission_override_set() {
+    let guild_id = Id::new(10);
+
+    let set = PermissionOverrideSet::new()
+        .everyone(guild_id, false)
+        .all_channels(guild_id, false)
+        .role(Id::new(2), true)
+        .user(Id::new(3), true)
+        .role(Id::new(2), false);
+
+    let expected = vec![
+        CommandPermission {
+            id: CommandPermissionType::Role(Id::new(10)),
+            permission: false,
+        },
+        CommandPermission {
+            id: CommandPermissionType::Channel(Id::new(9)),
+            permission: false,
+        },
+        CommandPermission {
+            id: CommandPermissionType::Role(Id::new(2)),
+            permission: false,
+        },
+        CommandPermission {
+            id: CommandPermissionType::User(Id::new(3)),
+            permission: true,
+        },
+    ];
+
+    assert_eq!(set.permissions(), expected);
+    assert_eq!(PermissionOverrideSet::from(expected.clone()), set);
+    assert_eq!(Vec::from(set), expected);
+}
@@ -0,0 +1,152 @@
+use twilight_interactions::{
+    command::{
+        ApplicationCommandData, CommandModel, CommandOption, CreateCommand, CreateOption,
+        NameLocalizations,
+    },
+    error::CommandConversionError,
+    migrate::suggest_derive,
+};
+use twilight_model::{
+    application::command::{Command, CommandOptionType, CommandType},
+    id::Id,
+};
+
+#[derive(CommandOption, CreateOption, Debug, PartialEq)]
+enum Color {
+    #[option(name = "Red", value = "red")]
+    Red,
+    #[option(name = "Light blue", value = "light-blue")]
+    LightBlue,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(
+    name = "paint",
+    name_localizations = "paint_name",
+    desc = "Paint something",
+    nsfw = false
+)]
+struct PaintCommand {
+    /// The color to use
+    color: Color,
+    /// Size of the brush
+    #[command(rename = "brush-size", min_value = 1, max_value = 10)]
+    brush_size: Option<i64>,
+}
+
+fn paint_name() -> NameLocalizations {
+    NameLocalizations::new([("fr", "peindre")])
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "config", desc = "Configure the bot")]
+enum ConfigCommand {
+    #[command(name = "show")]
+    Show(ConfigShowCommand),
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "show", desc = "Show the configuration")]
+struct ConfigShowCommand {
+    /// Key to show
+    #[command(autocomplete = true)]
+    key: String,
+}
+
+#[test]
+fn test_from_twilight_builder_output() {
+    let data = PaintCommand::create_command();
+    let mut command = Command::from(data.clone());
+
+    assert_eq!(
+        ApplicationCommandData::from_twilight_builder_output(command.clone()),
+        Ok(data)
+    );
+
+    let data = ConfigCommand::create_command();
+    assert!(data.group);
+    let converted =
+        ApplicationCommandData::from_twilight_builder_output(Command::from(data.clone()));
+    assert_eq!(converted, Ok(data));
+
+    let option = twilight_model::application::command::CommandOption::from(converted.unwrap());
+    assert_eq!(option.kind, CommandOptionType::SubCommandGroup);
+
+    command.kind = CommandType::User;
+    assert_eq!(
+        ApplicationCommandData::from_twilight_builder_output(command),
+        Err(CommandConversionError {
+            name: "paint".into(),
+            kind: CommandType::User,
+        })
+    );
+}
+
+#[test]
+fn test_try_from_fetched_command() {
+    let data = PaintCommand::create_command();
+    let mut command = Command::from(data.clone());
+    command.id = Some(Id::new(1));
+    command.application_id = Some(Id::new(2));
+    command.version = Id::new(3);
+
+    assert_eq!(ApplicationCommandData::try_from(command.clone()), Ok(data));
+
+    command.kind = CommandType::Message;
+    assert_eq!(
+        ApplicationCommandData::try_from(command),
+        Err(CommandConversionError {
+            name: "paint".into(),
+            kind: CommandType::Message,
+        })
+    );
+}
+
+#[test]
+fn test_suggest_derive() {
+    let command = Command::from(PaintCommand::create_command());
+    let expected = r#"#[derive(CommandModel, CreateCommand)]
+#[command(name = "paint", name_localizations = "paint_name", desc = "Paint something", nsfw = false)]
+struct PaintCommand {
+    /// The color to use
+    color: PaintColor,
+    /// Size of the brush
+    #[command(rename = "brush-size", min_value = 1, max_value = 10)]
+    brush_size: Option<i64>,
+}
+
+#[derive(CommandOption, CreateOption)]
+enum PaintColor {
+    #[option(name = "Red", value = "red")]
+    Red,
+    #[option(name = "Light blue", value = "light-blue")]
+    LightBlue,
+}
+
+fn paint_name() -> NameLocalizations {
+    NameLocalizations::new([("fr", "peindre")])
+}"#;
+
+    assert_eq!(suggest_derive(&command), expected);
+}
+
+#[test]
+fn test_suggest_derive_subcommands() {
+    let command = Command::from(ConfigCommand::create_command());
+    let expected = r#"#[derive(CommandModel, CreateCommand)]
+#[command(name = "config", desc = "Configure the bot")]
+enum ConfigCommand {
+    #[command(name = "show")]
+    Show(ConfigShowCommand),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "show", desc = "Show the configuration")]
+struct ConfigShowCommand {
+    /// Key to show
+    #[command(autocomplete = true)]
+    key: String,
+}"#;
+
+    assert_eq!(suggest_derive(&command), expected);
+}
@@ -0,0 +1,875 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+
+use twilight_interactions::{
+    command::{
+        command_handler, ApplicationCommandData, AutocompleteValue, CommandInputData, CommandModel,
+        CreateCommand,
+    },
+    cooldown::{Cooldown, CooldownScope, CooldownTracker},
+    error::{
+        AccessDenied, AccessRestriction, CommandOnCooldown, DispatchError, HandlerPanic,
+        ParseError, ParseOptionError, ParseOptionErrorType,
+    },
+    registry::{CommandHandler, CommandRegistry, InteractionKindRouter, UnknownOptionPolicy},
+    sync::SyncOperation,
+};
+use twilight_model::{
+    application::{
+        command::{CommandOptionType, CommandOptionValue as NumberCommandOptionValue, CommandType},
+        interaction::{
+            application_command::{CommandData, CommandDataOption, CommandOptionValue},
+            message_component::MessageComponentInteractionData,
+            Interaction, InteractionContextType, InteractionData, InteractionType,
+        },
+    },
+    channel::message::{component::ComponentType, MessageFlags},
+    guild::Permissions,
+    http::interaction::InteractionResponseType,
+    id::Id,
+    oauth::ApplicationIntegrationMap,
+    user::User,
+};
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "hello", desc = "Say hello")]
+struct HelloCommand {
+    /// Message to send
+    message: String,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "ping", desc = "Ping the bot")]
+struct PingCommand;
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+#[command(autocomplete = true)]
+struct HelloAutocomplete {
+    message: AutocompleteValue<String>,
+}
+
+fn interaction(kind: InteractionType, name: &str, options: Vec<CommandDataOption>) -> Interaction {
+    let data = CommandData {
+        guild_id: None,
+        id: Id::new(1),
+        name: name.into(),
+        kind: CommandType::ChatInput,
+        options,
+        resolved: None,
+        target_id: None,
+    };
+
+    #[allow(deprecated)]
+    Interaction {
+        app_permissions: None,
+        application_id: Id::new(1),
+        authorizing_integration_owners: ApplicationIntegrationMap {
+            guild: None,
+            user: None,
+        },
+        channel: None,
+        channel_id: None,
+        context: None,
+        data: Some(InteractionData::ApplicationCommand(Box::new(data))),
+        entitlements: Vec::new(),
+        guild: None,
+        guild_id: None,
+        guild_locale: None,
+        id: Id::new(1),
+        kind,
+        locale: None,
+        member: None,
+        message: None,
+        token: "token".into(),
+        user: None,
+    }
+}
+
+fn registry() -> CommandRegistry<String> {
+    CommandRegistry::new()
+        .register(|command: HelloCommand, _| async move { format!("hello {}", command.message) })
+        .register(|_: PingCommand, interaction| async move { format!("pong {}", interaction.id) })
+}
+
+#[test]
+fn test_registry_commands() {
+    let commands = registry().commands();
+
+    assert_eq!(commands.len(), 2);
+    assert_eq!(commands[0].name, "hello");
+    assert_eq!(commands[1].name, "ping");
+}
+
+#[test]
+fn test_registry_guild_commands() {
+    let enabled = Arc::new(AtomicBool::new(true));
+    let feature = enabled.clone();
+
+    // Scopes apply to commands registered before and after them
+    let registry = CommandRegistry::<()>::new()
+        .guilds::<HelloCommand>([Id::new(1), Id::new(2)])
+        .register(|_: HelloCommand, _| async {})
+        .register(|_: PingCommand, _| async {})
+        .guilds_where::<PingCommand, _>(move |guild_id| {
+            guild_id == Id::new(1) && feature.load(Ordering::Relaxed)
+        });
+
+    assert!(registry.commands().is_empty());
+    assert_eq!(registry.command_data().count(), 2);
+
+    let commands = registry.guild_commands(Id::new(1));
+    assert_eq!(commands.len(), 2);
+    assert_eq!(commands[0].name, "hello");
+    assert_eq!(commands[1].name, "ping");
+
+    let commands = registry.guild_commands(Id::new(2));
+    assert_eq!(commands.len(), 1);
+    assert_eq!(commands[0].name, "hello");
+    assert!(registry.guild_commands(Id::new(3)).is_empty());
+
+    // Disabling the feature deletes the command from the guild
+    let mut remote = registry.guild_commands(Id::new(1));
+    remote[0].id = Some(Id::new(10));
+    remote[1].id = Some(Id::new(11));
+    assert!(registry.diff_guild(Id::new(1), &remote).is_up_to_date());
+
+    enabled.store(false, Ordering::Relaxed);
+    let plan = registry.diff_guild(Id::new(1), &remote);
+    let operations = plan.operations().collect::<Vec<_>>();
+    assert_eq!(operations.len(), 1);
+    assert!(matches!(
+        operations[0],
+        SyncOperation::Delete { id, name } if id == Some(Id::new(11)) && name == "ping"
+    ));
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "daily",
+    desc = "Claim a daily reward",
+    cooldown = "1h per guild"
+)]
+struct DailyCommand;
+
+#[tokio::test]
+async fn test_registry_cooldowns() {
+    use std::time::{Duration, Instant};
+
+    let cooldown = Cooldown::new(Duration::from_secs(3600), CooldownScope::Guild);
+    assert_eq!(DailyCommand::create_command().cooldown, Some(cooldown));
+
+    let tracker = Arc::new(CooldownTracker::new());
+    let registry = CommandRegistry::new()
+        .register(|_: DailyCommand, _| async {})
+        .register(|_: PingCommand, _| async {})
+        .cooldowns(tracker.clone());
+
+    let daily = |guild_id| {
+        let mut interaction = interaction(InteractionType::ApplicationCommand, "daily", vec![]);
+        interaction.guild_id = Some(Id::new(guild_id));
+        interaction
+    };
+
+    assert_eq!(registry.handle(daily(1)).await, Ok(()));
+    assert_eq!(registry.handle(daily(2)).await, Ok(()));
+    let Err(DispatchError::OnCooldown(error)) = registry.handle(daily(1)).await else {
+        panic!("expected cooldown error");
+    };
+    assert_eq!(error.command, "daily");
+    assert_eq!(error.scope, CooldownScope::Guild);
+    assert!(error.remaining <= Duration::from_secs(3600));
+
+    // Commands without cooldown are not tracked
+    let ping = interaction(InteractionType::ApplicationCommand, "ping", vec![]);
+    assert_eq!(registry.handle(ping.clone()).await, Ok(()));
+    assert_eq!(registry.handle(ping).await, Ok(()));
+
+    tracker.reset("daily");
+    assert_eq!(registry.handle(daily(1)).await, Ok(()));
+
+    // Cooldowns expire after their duration
+    let data = DailyCommand::create_command();
+    let tracker = CooldownTracker::new();
+    let now = Instant::now();
+    assert_eq!(tracker.check_at(&data, &daily(1), now), Ok(()));
+
+    let later = now + Duration::from_secs(600);
+    let expected = CommandOnCooldown {
+        command: "daily".into(),
+        scope: CooldownScope::Guild,
+        remaining: Duration::from_secs(3000),
+    };
+    assert_eq!(tracker.check_at(&data, &daily(1), later), Err(expected));
+
+    let later = now + Duration::from_secs(3600);
+    tracker.remove_expired(later);
+    assert_eq!(tracker.check_at(&data, &daily(1), later), Ok(()));
+
+    // Interactions without the id of the scope are not tracked
+    let anywhere = interaction(InteractionType::ApplicationCommand, "daily", vec![]);
+    assert_eq!(tracker.check_at(&data, &anywhere, later), Ok(()));
+    assert_eq!(tracker.check_at(&data, &anywhere, later), Ok(()));
+
+    // Cooldowns only start when reservations are committed
+    let tracker = CooldownTracker::new();
+    drop(tracker.reserve_at(&data, &daily(1), now).unwrap());
+    tracker.reserve_at(&data, &daily(1), now).unwrap().commit();
+    assert!(tracker.reserve_at(&data, &daily(1), now).is_err());
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "gift", desc = "Send a gift", cooldown = "1h per guild")]
+struct GiftCommand {
+    /// Number of coins to send
+    #[allow(dead_code)]
+    coins: i64,
+}
+
+#[tokio::test]
+async fn test_registry_cooldown_parse_error() {
+    let registry = CommandRegistry::new()
+        .register(|_: GiftCommand, _| async {})
+        .cooldowns(Arc::new(CooldownTracker::new()));
+
+    let gift = |options| {
+        let mut interaction = interaction(InteractionType::ApplicationCommand, "gift", options);
+        interaction.guild_id = Some(Id::new(1));
+        interaction
+    };
+    let coins = vec![CommandDataOption {
+        name: "coins".into(),
+        value: CommandOptionValue::Integer(10),
+    }];
+
+    // A command that fails to parse does not start the cooldown
+    assert!(matches!(
+        registry.handle(gift(vec![])).await,
+        Err(DispatchError::Parse(_))
+    ));
+    assert_eq!(registry.handle(gift(coins.clone())).await, Ok(()));
+    assert!(matches!(
+        registry.handle(gift(coins)).await,
+        Err(DispatchError::OnCooldown(_))
+    ));
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "shutdown", desc = "Stop the bot", owner_only)]
+struct ShutdownCommand;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "prune", desc = "Prune inactive members", guild_only)]
+struct PruneCommand;
+
+#[tokio::test]
+async fn test_registry_access() {
+    let registry = CommandRegistry::new()
+        .register(|_: ShutdownCommand, _| async {})
+        .register(|_: PruneCommand, _| async {})
+        .owners([Id::new(1)]);
+
+    let used_by = |name, user_id| {
+        let mut interaction = interaction(InteractionType::ApplicationCommand, name, vec![]);
+        interaction.user = Some(User {
+            avatar: None,
+            bot: false,
+            discriminator: 1,
+            email: None,
+            flags: None,
+            id: Id::new(user_id),
+            locale: None,
+            mfa_enabled: None,
+            name: "someone".into(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+            accent_color: None,
+            banner: None,
+            avatar_decoration: None,
+            global_name: None,
+            avatar_decoration_data: None,
+        });
+        interaction
+    };
+
+    assert_eq!(registry.handle(used_by("shutdown", 1)).await, Ok(()));
+    assert_eq!(
+        registry.handle(used_by("shutdown", 2)).await,
+        Err(DispatchError::AccessDenied(AccessDenied {
+            command: "shutdown".into(),
+            restriction: AccessRestriction::OwnerOnly,
+        }))
+    );
+
+    assert_eq!(
+        registry.handle(used_by("prune", 1)).await,
+        Err(DispatchError::AccessDenied(AccessDenied {
+            command: "prune".into(),
+            restriction: AccessRestriction::GuildOnly,
+        }))
+    );
+
+    let mut prune = used_by("prune", 2);
+    prune.guild_id = Some(Id::new(1));
+    assert_eq!(registry.handle(prune).await, Ok(()));
+}
+
+#[test]
+fn test_registry_policy() {
+    fn guild_only(command: &mut ApplicationCommandData) {
+        command.contexts = Some(vec![InteractionContextType::Guild]);
+    }
+
+    fn admin_commands(command: &mut ApplicationCommandData) {
+        if command.name == "ping" {
+            command
+                .default_member_permissions
+                .get_or_insert(Permissions::ADMINISTRATOR);
+        }
+    }
+
+    // Policies apply to commands registered before and after them
+    let registry = CommandRegistry::<()>::new()
+        .register(|_: HelloCommand, _| async {})
+        .policy(guild_only)
+        .policy(admin_commands)
+        .register(|_: PingCommand, _| async {});
+
+    let commands = registry.commands();
+    assert_eq!(
+        commands[0].contexts,
+        Some(vec![InteractionContextType::Guild])
+    );
+    assert_eq!(commands[0].default_member_permissions, None);
+    assert_eq!(
+        commands[1].contexts,
+        Some(vec![InteractionContextType::Guild])
+    );
+    assert_eq!(
+        commands[1].default_member_permissions,
+        Some(Permissions::ADMINISTRATOR)
+    );
+}
+
+#[tokio::test]
+async fn test_registry_handle() {
+    let registry = registry();
+
+    let options = vec![CommandDataOption {
+        name: "message".into(),
+        value: CommandOptionValue::String("world".into()),
+    }];
+    let result = registry
+        .handle(interaction(
+            InteractionType::ApplicationCommand,
+            "hello",
+            options,
+        ))
+        .await;
+    assert_eq!(result, Ok("hello world".to_string()));
+
+    let result = registry
+        .handle(interaction(
+            InteractionType::ApplicationCommand,
+            "ping",
+            vec![],
+        ))
+        .await;
+    assert_eq!(result, Ok("pong 1".to_string()));
+}
+
+/// Repeat a message
+#[command_handler(name = "repeat")]
+async fn repeat(
+    interaction: Interaction,
+    #[command(desc = "Message to repeat")] message: String,
+    #[command(desc = "Number of repetitions", min_value = 1)] count: Option<i64>,
+) -> String {
+    let count = count.unwrap_or(2) as usize;
+    format!("{} {}", interaction.id, message.repeat(count))
+}
+
+#[tokio::test]
+async fn test_command_handler() {
+    let command = RepeatCommand::create_command();
+    assert_eq!(command.name, "repeat");
+    assert_eq!(command.description, "Repeat a message");
+    assert_eq!(command.options.len(), 2);
+    assert_eq!(
+        command.options[1].min_value,
+        Some(NumberCommandOptionValue::Integer(1))
+    );
+
+    let registry = RepeatCommand::register(CommandRegistry::new());
+    let options = vec![
+        CommandDataOption {
+            name: "message".into(),
+            value: CommandOptionValue::String("ab".into()),
+        },
+        CommandDataOption {
+            name: "count".into(),
+            value: CommandOptionValue::Integer(3),
+        },
+    ];
+    let result = registry
+        .handle(interaction(
+            InteractionType::ApplicationCommand,
+            "repeat",
+            options,
+        ))
+        .await;
+    assert_eq!(result, Ok("1 ababab".to_string()));
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "divide", desc = "Divide two numbers")]
+struct DivideCommand {
+    /// Dividend
+    dividend: i64,
+    /// Divisor
+    divisor: i64,
+}
+
+impl CommandHandler for DivideCommand {
+    type Output = Result<i64, String>;
+
+    async fn run(self, _interaction: Interaction) -> Self::Output {
+        self.dividend
+            .checked_div(self.divisor)
+            .ok_or_else(|| "division by zero".to_string())
+    }
+}
+
+#[tokio::test]
+async fn test_command_handler_trait() {
+    let registry = CommandRegistry::new().handler::<DivideCommand>();
+    assert_eq!(registry.commands()[0].name, "divide");
+
+    let options = |divisor| {
+        vec![
+            CommandDataOption {
+                name: "dividend".into(),
+                value: CommandOptionValue::Integer(12),
+            },
+            CommandDataOption {
+                name: "divisor".into(),
+                value: CommandOptionValue::Integer(divisor),
+            },
+        ]
+    };
+
+    let result = registry
+        .handle(interaction(
+            InteractionType::ApplicationCommand,
+            "divide",
+            options(4),
+        ))
+        .await;
+    assert_eq!(result, Ok(Ok(3)));
+
+    let result = registry
+        .handle(interaction(
+            InteractionType::ApplicationCommand,
+            "divide",
+            options(0),
+        ))
+        .await;
+    assert_eq!(result, Ok(Err("division by zero".to_string())));
+}
+
+#[tokio::test]
+async fn test_registry_errors() {
+    let registry = registry();
+
+    let result = registry
+        .handle(interaction(
+            InteractionType::ApplicationCommand,
+            "unknown",
+            vec![],
+        ))
+        .await;
+    assert_eq!(
+        result,
+        Err(DispatchError::UnknownCommand("unknown".to_string()))
+    );
+
+    let result = registry
+        .handle(interaction(
+            InteractionType::ApplicationCommand,
+            "hello",
+            vec![],
+        ))
+        .await;
+    assert_eq!(
+        result,
+        Err(DispatchError::Parse(ParseError::Option(ParseOptionError {
+            field: "message".into(),
+            kind: ParseOptionErrorType::RequiredField,
+            path: Vec::new(),
+        })))
+    );
+
+    let result = registry
+        .handle(interaction(
+            InteractionType::ApplicationCommandAutocomplete,
+            "hello",
+            vec![],
+        ))
+        .await;
+    assert_eq!(
+        result,
+        Err(DispatchError::UnsupportedInteraction(
+            InteractionType::ApplicationCommandAutocomplete
+        ))
+    );
+}
+
+#[tokio::test]
+async fn test_registry_catch_panics() {
+    let panics = Arc::new(Mutex::new(Vec::new()));
+    let hook_panics = panics.clone();
+
+    let registry = CommandRegistry::new()
+        .register(|_: PingCommand, _| async move { panic!("ping failed") })
+        .register(|_: HelloCommand, _| -> std::future::Ready<()> { panic!("hello failed") })
+        .catch_panics(move |panic: &HandlerPanic| hook_panics.lock().unwrap().push(panic.clone()));
+
+    let result = registry
+        .handle(interaction(
+            InteractionType::ApplicationCommand,
+            "ping",
+            vec![],
+        ))
+        .await;
+    let expected = HandlerPanic {
+        command: "ping".into(),
+        interaction_id: Id::new(1),
+        message: "ping failed".into(),
+    };
+    assert_eq!(result, Err(DispatchError::Panicked(expected.clone())));
+
+    let options = vec![CommandDataOption {
+        name: "message".into(),
+        value: CommandOptionValue::String("world".into()),
+    }];
+    let result = registry
+        .handle(interaction(
+            InteractionType::ApplicationCommand,
+            "hello",
+            options,
+        ))
+        .await;
+    assert!(
+        matches!(result, Err(DispatchError::Panicked(panic)) if panic.message == "hello failed")
+    );
+
+    let panics = panics.lock().unwrap();
+    assert_eq!(panics.len(), 2);
+    assert_eq!(panics[0], expected);
+
+    let response = expected.response();
+    assert_eq!(
+        response.kind,
+        InteractionResponseType::ChannelMessageWithSource
+    );
+    assert_eq!(response.data.unwrap().flags, Some(MessageFlags::EPHEMERAL));
+}
+
+#[tokio::test]
+async fn test_registry_unknown_options() {
+    let unknown = Arc::new(Mutex::new(Vec::new()));
+    let options = || {
+        vec![
+            CommandDataOption {
+                name: "message".into(),
+                value: CommandOptionValue::String("world".into()),
+            },
+            CommandDataOption {
+                name: "language".into(),
+                value: CommandOptionValue::String("en".into()),
+            },
+        ]
+    };
+
+    assert_eq!(CommandInputData::api_version(), 10);
+
+    let registry = registry().on_unknown_option({
+        let unknown = Arc::clone(&unknown);
+        move |option| {
+            let event = (
+                option.command.to_owned(),
+                option.option.to_owned(),
+                option.kind,
+            );
+            unknown.lock().unwrap().push(event);
+        }
+    });
+
+    let result = registry
+        .handle(interaction(
+            InteractionType::ApplicationCommand,
+            "hello",
+            options(),
+        ))
+        .await;
+    assert_eq!(
+        result,
+        Err(DispatchError::Parse(ParseError::Option(ParseOptionError {
+            field: "language".into(),
+            kind: ParseOptionErrorType::UnknownField,
+            path: Vec::new(),
+        })))
+    );
+
+    let registry = registry.unknown_options(UnknownOptionPolicy::Warn);
+    let result = registry
+        .handle(interaction(
+            InteractionType::ApplicationCommand,
+            "hello",
+            options(),
+        ))
+        .await;
+    assert_eq!(result, Ok("hello world".to_string()));
+
+    let registry = registry.unknown_options(UnknownOptionPolicy::Ignore);
+    let result = registry
+        .handle(interaction(
+            InteractionType::ApplicationCommand,
+            "hello",
+            options(),
+        ))
+        .await;
+    assert_eq!(result, Ok("hello world".to_string()));
+
+    let event = (
+        "hello".to_owned(),
+        "language".to_owned(),
+        CommandOptionType::String,
+    );
+    assert_eq!(*unknown.lock().unwrap(), vec![event.clone(), event]);
+}
+
+#[tokio::test]
+async fn test_interaction_kind_router() {
+    let router = InteractionKindRouter::new(registry())
+        .autocomplete("hello", |command: HelloAutocomplete, _| async move {
+            format!("autocomplete {:?}", command.message)
+        })
+        .component(|data, _| async move { format!("component {}", data.custom_id) });
+
+    let result = router
+        .handle(interaction(
+            InteractionType::ApplicationCommand,
+            "ping",
+            vec![],
+        ))
+        .await;
+    assert_eq!(result, Ok("pong 1".to_string()));
+
+    let options = vec![CommandDataOption {
+        name: "message".into(),
+        value: CommandOptionValue::Focused("wor".into(), CommandOptionType::String),
+    }];
+    let result = router
+        .handle(interaction(
+            InteractionType::ApplicationCommandAutocomplete,
+            "hello",
+            options,
+        ))
+        .await;
+    assert_eq!(result, Ok("autocomplete Focused(\"wor\")".to_string()));
+
+    let result = router
+        .handle(interaction(
+            InteractionType::ApplicationCommandAutocomplete,
+            "ping",
+            vec![],
+        ))
+        .await;
+    assert_eq!(result, Err(DispatchError::UnknownCommand("ping".into())));
+
+    let mut component = interaction(InteractionType::MessageComponent, "", vec![]);
+    component.data = Some(InteractionData::MessageComponent(Box::new(
+        MessageComponentInteractionData {
+            custom_id: "button".into(),
+            component_type: ComponentType::Button,
+            resolved: None,
+            values: Vec::new(),
+        },
+    )));
+    let result = router.handle(component).await;
+    assert_eq!(result, Ok("component button".to_string()));
+
+    let result = router
+        .handle(interaction(InteractionType::ModalSubmit, "", vec![]))
+        .await;
+    assert_eq!(
+        result,
+        Err(DispatchError::UnsupportedInteraction(
+            InteractionType::ModalSubmit
+        ))
+    );
+}
+
+#[tokio::test]
+async fn test_interaction_kind_router_catch_panics() {
+    let registry = CommandRegistry::<String>::new().catch_panics(|_: &HandlerPanic| ());
+    let router = InteractionKindRouter::new(registry)
+        .autocomplete("hello", |_: HelloAutocomplete, _| async move {
+            panic!("autocomplete failed")
+        });
+
+    let options = vec![CommandDataOption {
+        name: "message".into(),
+        value: CommandOptionValue::Focused("wor".into(), CommandOptionType::String),
+    }];
+    let result = router
+        .handle(interaction(
+            InteractionType::ApplicationCommandAutocomplete,
+            "hello",
+            options,
+        ))
+        .await;
+    let expected = HandlerPanic {
+        command: "hello".into(),
+        interaction_id: Id::new(1),
+        message: "autocomplete failed".into(),
+    };
+    assert_eq!(result, Err(DispatchError::Panicked(expected)));
+}
+
+#[cfg(feature = "tokio")]
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "slow", desc = "Slow command", timeout = "10s")]
+struct SlowCommand {
+    /// Duration of the command
+    seconds: i64,
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test(start_paused = true)]
+async fn test_registry_timeout() {
+    use std::time::Duration;
+
+    use twilight_interactions::{error::HandlerTimeout, registry::InteractionToken};
+
+    let deferred = Arc::new(Mutex::new(Vec::new()));
+    let hook_deferred = deferred.clone();
+    let timed_out = Arc::new(Mutex::new(Vec::new()));
+    let hook_timed_out = timed_out.clone();
+
+    let registry = CommandRegistry::new()
+        .register(|command: SlowCommand, _| async move {
+            tokio::time::sleep(Duration::from_secs(command.seconds as u64)).await;
+        })
+        .on_defer(move |token: InteractionToken| {
+            hook_deferred.lock().unwrap().push(token);
+            async {}
+        })
+        .on_timeout(move |token: InteractionToken, timeout: HandlerTimeout| {
+            hook_timed_out.lock().unwrap().push((token.token, timeout));
+            async {}
+        });
+
+    let slow = |seconds| {
+        let options = vec![CommandDataOption {
+            name: "seconds".into(),
+            value: CommandOptionValue::Integer(seconds),
+        }];
+
+        interaction(InteractionType::ApplicationCommand, "slow", options)
+    };
+
+    let result = registry.handle(slow(1)).await;
+    assert_eq!(result, Ok(()));
+    assert!(deferred.lock().unwrap().is_empty());
+
+    let start = tokio::time::Instant::now().into_std();
+    let result = registry.handle(slow(5)).await;
+    assert_eq!(result, Ok(()));
+    assert_eq!(
+        *deferred.lock().unwrap(),
+        vec![InteractionToken {
+            application_id: Id::new(1),
+            interaction_id: Id::new(1),
+            token: "token".into(),
+            received_at: start,
+        }]
+    );
+
+    let result = registry.handle(slow(20)).await;
+    let expected = HandlerTimeout {
+        command: "slow".into(),
+        interaction_id: Id::new(1),
+        timeout: Duration::from_secs(10),
+        deferred: true,
+    };
+    assert_eq!(result, Err(DispatchError::TimedOut(expected.clone())));
+    assert_eq!(deferred.lock().unwrap().len(), 2);
+
+    // The timeout hook is only called for handlers that timed out
+    assert_eq!(
+        *timed_out.lock().unwrap(),
+        vec![("token".to_string(), expected)]
+    );
+}
+
+#[cfg(all(feature = "tokio", feature = "testing"))]
+#[tokio::test]
+async fn test_registry_mock_clock() {
+    use std::time::Duration;
+
+    use twilight_interactions::{
+        error::HandlerTimeout,
+        registry::{Clock, InteractionToken, RESPONSE_DEADLINE, TOKEN_LIFETIME},
+        testing::MockClock,
+    };
+
+    let clock = MockClock::new();
+    let deferred = Arc::new(Mutex::new(Vec::new()));
+    let hook_deferred = deferred.clone();
+
+    let registry = CommandRegistry::new()
+        .register(|_: SlowCommand, _| std::future::pending::<()>())
+        .on_defer(move |token: InteractionToken| {
+            hook_deferred.lock().unwrap().push(token);
+            async {}
+        })
+        .clock(clock.clone());
+
+    let options = vec![CommandDataOption {
+        name: "seconds".into(),
+        value: CommandOptionValue::Integer(0),
+    }];
+    let slow = interaction(InteractionType::ApplicationCommand, "slow", options);
+
+    let start = clock.now();
+    let advance = async {
+        tokio::task::yield_now().await;
+        clock.advance(RESPONSE_DEADLINE);
+        assert_eq!(deferred.lock().unwrap().len(), 0);
+
+        tokio::task::yield_now().await;
+        assert_eq!(deferred.lock().unwrap().len(), 1);
+        clock.advance(Duration::from_secs(10));
+    };
+
+    let (result, ()) = tokio::join!(registry.handle(slow), advance);
+    let expected = HandlerTimeout {
+        command: "slow".into(),
+        interaction_id: Id::new(1),
+        timeout: Duration::from_secs(10),
+        deferred: true,
+    };
+    assert_eq!(result, Err(DispatchError::TimedOut(expected)));
+
+    let token = deferred.lock().unwrap().pop().unwrap();
+    assert_eq!(token.received_at, start);
+    assert!(!token.is_expired(&clock));
+
+    clock.advance(TOKEN_LIFETIME);
+    assert!(token.is_expired(&clock));
+}
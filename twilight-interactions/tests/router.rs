@@ -0,0 +1,104 @@
+use twilight_interactions::{
+    command::{CommandInputData, CommandModel, CommandRouter, CreateCommand},
+    error::ParseError,
+};
+use twilight_model::{
+    application::{
+        command::CommandType,
+        interaction::application_command::{CommandData, CommandDataOption, CommandOptionValue},
+    },
+    id::Id,
+};
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "hello", desc = "Say hello")]
+struct HelloCommand {
+    /// Message to send
+    message: String,
+}
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+struct PingCommand;
+
+#[derive(CommandRouter, Debug, PartialEq, Eq)]
+enum Commands {
+    Hello(HelloCommand),
+    #[command(name = "ping")]
+    Ping(PingCommand),
+}
+
+fn command_data(name: &str, options: Vec<CommandDataOption>) -> CommandData {
+    CommandData {
+        guild_id: None,
+        id: Id::new(1),
+        name: name.into(),
+        kind: CommandType::ChatInput,
+        options,
+        resolved: None,
+        target_id: None,
+    }
+}
+
+#[test]
+fn test_command_router() {
+    let options = vec![CommandDataOption {
+        name: "message".into(),
+        value: CommandOptionValue::String("world".into()),
+    }];
+
+    let result = Commands::route(command_data("hello", options));
+    assert_eq!(
+        result,
+        Ok(Commands::Hello(HelloCommand {
+            message: "world".into()
+        }))
+    );
+
+    let result = Commands::route(command_data("ping", vec![]));
+    assert_eq!(result, Ok(Commands::Ping(PingCommand)));
+}
+
+#[test]
+fn test_command_router_unknown() {
+    let result = Commands::route(command_data("unknown", vec![]));
+
+    assert_eq!(result, Err(ParseError::UnknownCommand("unknown".into())));
+}
+
+#[derive(CommandRouter, Debug, PartialEq)]
+enum FallbackCommands {
+    Hello(HelloCommand),
+    #[command(fallback)]
+    Other(Vec<CommandDataOption>),
+}
+
+#[derive(CommandRouter, Debug, PartialEq)]
+enum InputFallbackCommands {
+    #[command(name = "ping")]
+    Ping(PingCommand),
+    #[command(fallback)]
+    Other(Box<CommandInputData<'static>>),
+}
+
+#[test]
+fn test_command_router_fallback() {
+    let options = vec![CommandDataOption {
+        name: "target".into(),
+        value: CommandOptionValue::String("everyone".into()),
+    }];
+
+    let result = FallbackCommands::route(command_data("wave", options.clone()));
+    assert_eq!(result, Ok(FallbackCommands::Other(options.clone())));
+
+    let result = InputFallbackCommands::route(command_data("wave", options.clone()));
+    assert_eq!(
+        result,
+        Ok(InputFallbackCommands::Other(Box::new(CommandInputData {
+            options,
+            resolved: None,
+        })))
+    );
+
+    let result = InputFallbackCommands::route(command_data("ping", vec![]));
+    assert_eq!(result, Ok(InputFallbackCommands::Ping(PingCommand)));
+}
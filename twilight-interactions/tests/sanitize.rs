@@ -0,0 +1,51 @@
+use std::borrow::Cow;
+
+use twilight_interactions::sanitize::{
+    escape, escape_markdown, escape_mentions, sanitize, truncate, ELLIPSIS,
+};
+
+#[test]
+fn test_escape() {
+    assert_eq!(
+        escape_markdown("**bold** _it_ ~~no~~ `code` ||spoiler|| [link](url)"),
+        r"\*\*bold\*\* \_it\_ \~\~no\~\~ \`code\` \|\|spoiler\|\| \[link\](url)"
+    );
+    assert_eq!(
+        escape_markdown("# title\n- item\n> quote\na - b # c"),
+        "\\# title\n\\- item\n\\> quote\na - b # c"
+    );
+    assert_eq!(escape_markdown(r"\*"), r"\\\*");
+
+    assert_eq!(
+        escape_mentions("@everyone <@&123> <@456>"),
+        "@\u{200B}everyone <@\u{200B}&123> <@\u{200B}456>"
+    );
+    assert_eq!(escape("*@here*"), "\\*@\u{200B}here\\*");
+}
+
+#[test]
+fn test_truncate() {
+    assert_eq!(truncate("hello", 5), Cow::Borrowed("hello"));
+    assert_eq!(truncate("hello world", 5), "hell…");
+    assert_eq!(truncate("héllo wörld", 8), "héllo w…");
+    assert_eq!(truncate("hello", 0), "");
+    assert_eq!(truncate("", 0), "");
+}
+
+#[test]
+fn test_sanitize() {
+    assert_eq!(sanitize("**hi**", 10), r"\*\*hi\*\*");
+
+    // Escape sequences are not split by the truncation
+    let sanitized = sanitize("ab*cd", 4);
+    assert_eq!(sanitized, format!("ab{ELLIPSIS}"));
+
+    let sanitized = sanitize("a@everyone", 3);
+    assert_eq!(sanitized, format!("a{ELLIPSIS}"));
+
+    let sanitized = sanitize(&"*".repeat(3000), 2000);
+    assert_eq!(sanitized.chars().count(), 1999);
+    assert!(sanitized.ends_with(&format!("\\*{ELLIPSIS}")));
+
+    assert_eq!(sanitize("hello", 0), "");
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "serde")]
+
+use std::time::Duration;
+
+use twilight_interactions::command::{ApplicationCommandData, CreateCommand};
+
+#[derive(CreateCommand)]
+#[allow(dead_code)]
+#[command(name = "remind", desc = "Set a reminder", timeout = "30s")]
+struct RemindCommand {
+    /// Message of the reminder
+    #[command(max_length = 200)]
+    message: String,
+    /// Delay in minutes
+    #[command(min_value = 1, max_value = 1440)]
+    delay: Option<i64>,
+}
+
+#[test]
+fn test_serde_roundtrip() {
+    let command = RemindCommand::create_command();
+
+    let json = serde_json::to_string(&command).unwrap();
+    let deserialized: ApplicationCommandData = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized, command);
+    assert_eq!(deserialized.timeout, Some(Duration::from_secs(30)));
+}
+
+#[test]
+fn test_serde_missing_metadata() {
+    let mut value = serde_json::to_value(RemindCommand::create_command()).unwrap();
+    value.as_object_mut().unwrap().remove("metadata");
+
+    let command: ApplicationCommandData = serde_json::from_value(value).unwrap();
+    assert!(command.metadata.is_empty());
+}
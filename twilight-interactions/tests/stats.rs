@@ -0,0 +1,157 @@
+use std::{sync::Arc, time::Duration};
+
+use twilight_interactions::{
+    command::{CommandModel, CreateCommand},
+    error::{DispatchError, ParseError, ParseOptionError, ParseOptionErrorType},
+    registry::{CommandRegistry, DispatchEvent},
+    stats::{CommandUsage, Latency, UsageStats},
+};
+use twilight_model::{
+    application::{
+        command::CommandType,
+        interaction::{
+            application_command::{CommandData, CommandDataOption, CommandOptionValue},
+            Interaction, InteractionData, InteractionType,
+        },
+    },
+    id::Id,
+    oauth::ApplicationIntegrationMap,
+};
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "config", desc = "Configure the bot")]
+enum ConfigCommand {
+    #[command(name = "show")]
+    Show(ConfigShowCommand),
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "show", desc = "Show the configuration")]
+struct ConfigShowCommand {
+    /// Key to show
+    key: i64,
+}
+
+fn interaction(name: &str, options: Vec<CommandDataOption>) -> Interaction {
+    let data = CommandData {
+        guild_id: None,
+        id: Id::new(1),
+        name: name.into(),
+        kind: CommandType::ChatInput,
+        options,
+        resolved: None,
+        target_id: None,
+    };
+
+    #[allow(deprecated)]
+    Interaction {
+        app_permissions: None,
+        application_id: Id::new(1),
+        authorizing_integration_owners: ApplicationIntegrationMap {
+            guild: None,
+            user: None,
+        },
+        channel: None,
+        channel_id: None,
+        context: None,
+        data: Some(InteractionData::ApplicationCommand(Box::new(data))),
+        entitlements: Vec::new(),
+        guild: None,
+        guild_id: None,
+        guild_locale: None,
+        id: Id::new(1),
+        kind: InteractionType::ApplicationCommand,
+        locale: None,
+        member: None,
+        message: None,
+        token: "token".into(),
+        user: None,
+    }
+}
+
+fn show(key: CommandOptionValue) -> Vec<CommandDataOption> {
+    vec![CommandDataOption {
+        name: "show".into(),
+        value: CommandOptionValue::SubCommand(vec![CommandDataOption {
+            name: "key".into(),
+            value: key,
+        }]),
+    }]
+}
+
+#[tokio::test]
+async fn test_usage_stats_registry() {
+    let stats = Arc::new(UsageStats::new());
+    let registry = CommandRegistry::new()
+        .register(|command: ConfigCommand, _| async move {
+            let ConfigCommand::Show(show) = command;
+            show.key
+        })
+        .on_dispatch({
+            let stats = Arc::clone(&stats);
+            move |event| stats.record(event)
+        });
+
+    let result = registry
+        .handle(interaction("config", show(CommandOptionValue::Integer(1))))
+        .await;
+    assert_eq!(result, Ok(1));
+
+    let result = registry
+        .handle(interaction(
+            "config",
+            show(CommandOptionValue::Boolean(true)),
+        ))
+        .await;
+    assert!(matches!(result, Err(DispatchError::Parse(_))));
+
+    // Unknown commands are not recorded
+    let result = registry.handle(interaction("unknown", Vec::new())).await;
+    assert!(result.is_err());
+
+    let usage = stats.export();
+    assert_eq!(usage.len(), 1);
+    assert_eq!(usage[0].command, "config show");
+    assert_eq!(usage[0].count, 2);
+    assert_eq!(usage[0].errors, 1);
+    assert_eq!(usage[0].error_rate(), 0.5);
+
+    stats.reset();
+    assert!(stats.export().is_empty());
+}
+
+#[test]
+fn test_usage_stats_latency() {
+    let stats = UsageStats::new();
+    let error = DispatchError::Parse(ParseError::Option(ParseOptionError {
+        field: "key".into(),
+        kind: ParseOptionErrorType::RequiredField,
+        path: Vec::new(),
+    }));
+
+    for millis in 1..=100 {
+        stats.record(&DispatchEvent {
+            command: "ping",
+            parse_duration: Duration::from_micros(millis),
+            handle_duration: Duration::from_millis(millis),
+            error: (millis % 4 == 0).then_some(&error),
+        });
+    }
+
+    assert_eq!(
+        stats.export(),
+        vec![CommandUsage {
+            command: "ping".into(),
+            count: 100,
+            errors: 25,
+            parse_latency: Latency {
+                p50: Duration::from_micros(50),
+                p99: Duration::from_micros(99),
+            },
+            handle_latency: Latency {
+                p50: Duration::from_millis(50),
+                p99: Duration::from_millis(99),
+            },
+        }]
+    );
+}
@@ -1,7 +1,10 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, rc::Rc, sync::Arc};
 
-use twilight_interactions::command::{
-    ApplicationCommandData, CommandInputData, CommandModel, CreateCommand, DescLocalizations,
+use twilight_interactions::{
+    command::{
+        ApplicationCommandData, CommandInputData, CommandModel, CreateCommand, DescLocalizations,
+    },
+    error::{ParseError, ParseOptionError, ParseOptionErrorType},
 };
 use twilight_model::{
     application::{
@@ -121,6 +124,105 @@ fn test_subcommand_group_model() {
     );
 }
 
+#[test]
+fn test_subcommand_error_path() {
+    let subcommand_group_options = vec![CommandDataOption {
+        name: "three".into(),
+        value: CommandOptionValue::SubCommand(Vec::new()),
+    }];
+
+    let command_options = vec![CommandDataOption {
+        name: "group".into(),
+        value: CommandOptionValue::SubCommandGroup(subcommand_group_options),
+    }];
+
+    let data = CommandInputData {
+        options: command_options,
+        resolved: None,
+    };
+
+    let error = SubCommand::from_interaction(data).unwrap_err();
+
+    assert_eq!(
+        error,
+        ParseError::Option(ParseOptionError {
+            field: "option".into(),
+            kind: ParseOptionErrorType::RequiredField,
+            path: vec!["group".into(), "three".into()],
+        })
+    );
+    assert_eq!(
+        error.to_string(),
+        "failed to parse option `group three option`: missing required field"
+    );
+}
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+struct EmptyCommand;
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+enum ConfigCommand {
+    #[command(name = "get")]
+    Get(EmptyCommand),
+    #[command(name = "set")]
+    Set(EmptyCommand),
+    #[command(name = "list")]
+    List(EmptyCommand),
+    #[command(name = "reset")]
+    Reset(EmptyCommand),
+    #[command(name = "prefix")]
+    Prefix(EmptyCommand),
+    #[command(name = "locale")]
+    Locale(EmptyCommand),
+    #[command(name = "logging")]
+    Logging(EmptyCommand),
+    #[command(name = "channels")]
+    Channels(EmptyCommand),
+    #[command(name = "moderation")]
+    Moderation(EmptyCommand),
+}
+
+#[test]
+fn test_subcommand_many_variants() {
+    let data = |name: &str| CommandInputData {
+        options: vec![CommandDataOption {
+            name: name.into(),
+            value: CommandOptionValue::SubCommand(Vec::new()),
+        }],
+        resolved: None,
+    };
+
+    let cases = [
+        ("get", ConfigCommand::Get(EmptyCommand)),
+        ("set", ConfigCommand::Set(EmptyCommand)),
+        ("prefix", ConfigCommand::Prefix(EmptyCommand)),
+        ("locale", ConfigCommand::Locale(EmptyCommand)),
+        ("moderation", ConfigCommand::Moderation(EmptyCommand)),
+    ];
+
+    for (name, expected) in cases {
+        assert_eq!(ConfigCommand::from_interaction(data(name)), Ok(expected));
+    }
+
+    for name in ["put", "unknown", "a"] {
+        assert_eq!(
+            ConfigCommand::from_interaction(data(name)),
+            Err(ParseError::Option(ParseOptionError {
+                field: name.into(),
+                kind: ParseOptionErrorType::UnknownSubcommand,
+                path: Vec::new(),
+            }))
+        );
+    }
+}
+
+#[test]
+fn test_subcommand_depth() {
+    assert_eq!(CommandOne::SUBCOMMAND_DEPTH, 0);
+    assert_eq!(SubCommandGroup::SUBCOMMAND_DEPTH, 1);
+    assert_eq!(SubCommand::SUBCOMMAND_DEPTH, 2);
+}
+
 #[test]
 fn test_create_subcommand() {
     let command_options = vec![CommandOption {
@@ -223,7 +325,126 @@ fn test_create_subcommand() {
         nsfw: None,
         contexts: None,
         integration_types: None,
+        timeout: None,
+        cooldown: None,
+        guild_only: false,
+        owner_only: false,
+        category: None,
+        metadata: HashMap::new(),
     };
 
     assert_eq!(SubCommand::create_command(), expected);
+
+    // The description is returned by the `desc_localizations` function
+    assert_eq!(SubCommand::DESCRIPTION, "");
+    const { assert!(SubCommand::GROUP) };
+    assert_eq!(SubCommandGroup::DESCRIPTION, "Command group");
+    const { assert!(SubCommandGroup::GROUP) };
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "user-info", desc = "Show user info", rename_all = "kebab-case")]
+struct UserInfoCommand {
+    /// User ID
+    user_id: i64,
+    /// Whether to show roles
+    #[command(rename = "roles")]
+    show_roles: Option<bool>,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "admin", desc = "Admin commands", rename_all = "kebab-case")]
+enum AdminCommand {
+    UserInfo(UserInfoCommand),
+    #[command(name = "one")]
+    First(CommandOne),
+}
+
+#[test]
+fn test_rename_all() {
+    let command = AdminCommand::create_command();
+    let names: Vec<_> = command.options.iter().map(|option| &*option.name).collect();
+    assert_eq!(names, ["user-info", "one"]);
+
+    let options: Vec<_> = command.options[0]
+        .options
+        .iter()
+        .flatten()
+        .map(|option| &*option.name)
+        .collect();
+    assert_eq!(options, ["user-id", "roles"]);
+
+    let data = CommandInputData {
+        options: vec![CommandDataOption {
+            name: "user-info".into(),
+            value: CommandOptionValue::SubCommand(vec![
+                CommandDataOption {
+                    name: "user-id".into(),
+                    value: CommandOptionValue::Integer(42),
+                },
+                CommandDataOption {
+                    name: "roles".into(),
+                    value: CommandOptionValue::Boolean(true),
+                },
+            ]),
+        }],
+        resolved: None,
+    };
+
+    assert_eq!(
+        AdminCommand::from_interaction(data),
+        Ok(AdminCommand::UserInfo(UserInfoCommand {
+            user_id: 42,
+            show_roles: Some(true),
+        }))
+    );
+}
+
+#[test]
+fn test_subcommand_tree() {
+    assert_eq!(
+        SubCommand::create_command().to_string(),
+        "/command (permissions: none): fallback
+├── one (subcommand): Command one
+│   └── option (string, required): An option
+└── group (subcommand group): Command group
+    ├── two (subcommand): Command two
+    │   └── option (string, required): An option
+    └── three (subcommand): Command three
+        └── option (string, required): An option"
+    );
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "shared", desc = "Command with shared subcommands")]
+enum SharedCommand {
+    #[command(name = "one")]
+    One(Arc<CommandOne>),
+    #[command(name = "group")]
+    Group(Rc<SubCommandGroup>),
+}
+
+#[test]
+fn test_smart_pointer_variants() {
+    let options = vec![CommandDataOption {
+        name: "one".into(),
+        value: CommandOptionValue::SubCommand(vec![CommandDataOption {
+            name: "option".into(),
+            value: CommandOptionValue::String("test".into()),
+        }]),
+    }];
+    let data = CommandInputData {
+        options,
+        resolved: None,
+    };
+
+    let expected = SharedCommand::One(Arc::new(CommandOne {
+        option: "test".into(),
+    }));
+    assert_eq!(SharedCommand::from_interaction(data).unwrap(), expected);
+
+    let command = SharedCommand::create_command();
+    assert_eq!(command.options[0].kind, CommandOptionType::SubCommand);
+    assert_eq!(command.options[1].kind, CommandOptionType::SubCommandGroup);
+    assert_eq!(command.options[1].options.as_ref().unwrap().len(), 2);
 }
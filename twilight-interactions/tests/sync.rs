@@ -0,0 +1,213 @@
+use twilight_interactions::{
+    command::{ApplicationCommandData, CreateCommand, DescLocalizations},
+    sync::{
+        diff, diff_command, diff_with, hash, CommandChange, CommandField, FieldChange,
+        LocalizedField, SyncOperation,
+    },
+};
+use twilight_model::{
+    application::{
+        command::{Command, CommandType},
+        interaction::InteractionContextType,
+    },
+    id::Id,
+};
+
+#[derive(CreateCommand, Debug, PartialEq)]
+#[command(name = "hello", desc = "Say hello")]
+struct HelloCommand {
+    /// Message to send
+    message: String,
+    /// Whether to ping the user
+    ping: Option<bool>,
+}
+
+#[derive(CreateCommand)]
+#[command(name = "ping", desc_localizations = "ping_desc")]
+struct PingCommand;
+
+fn ping_desc() -> DescLocalizations {
+    DescLocalizations::new("Ping the bot", [("fr", "Ping le bot")])
+}
+
+/// Simulate a command returned by Discord.
+fn remote(mut command: Command, id: u64) -> Command {
+    command.id = Some(Id::new(id));
+    command.application_id = Some(Id::new(1));
+    command.version = Id::new(id + 100);
+    command.nsfw = Some(false);
+
+    for option in &mut command.options {
+        // Discord omits these fields when false
+        option.autocomplete = None;
+        if option.required == Some(false) {
+            option.required = None;
+        }
+    }
+
+    command
+}
+
+#[test]
+fn test_sync_up_to_date() {
+    let local = [
+        HelloCommand::create_command(),
+        PingCommand::create_command(),
+    ];
+    let remote = [
+        remote(PingCommand::create_command().into(), 2),
+        remote(HelloCommand::create_command().into(), 1),
+    ];
+
+    let plan = diff(&local, &remote);
+
+    assert!(plan.is_up_to_date());
+    assert_eq!(plan.operations().count(), 0);
+}
+
+#[test]
+fn test_sync_changes() {
+    let local = [
+        HelloCommand::create_command(),
+        PingCommand::create_command(),
+    ];
+
+    let mut ping: Command = remote(PingCommand::create_command().into(), 2);
+    ping.description = "Old description".into();
+    ping.description_localizations = None;
+
+    let mut user_command = remote(PingCommand::create_command().into(), 3);
+    user_command.kind = CommandType::User;
+
+    let old = remote(HelloCommand::create_command().into(), 4);
+    let old = Command {
+        name: "old".into(),
+        ..old
+    };
+
+    let plan = diff(&local, &[ping, old, user_command]);
+
+    assert!(!plan.is_up_to_date());
+    assert!(matches!(&plan.changes[0], CommandChange::Created(command) if command.name == "hello"));
+    assert!(matches!(
+        &plan.changes[1],
+        CommandChange::Updated { id, fields, .. }
+            if *id == Some(Id::new(2))
+                && fields == &[CommandField::Description, CommandField::DescriptionLocalizations]
+    ));
+    assert_eq!(
+        plan.changes[2],
+        CommandChange::Deleted {
+            id: Some(Id::new(4)),
+            name: "old".into()
+        }
+    );
+    assert_eq!(plan.changes.len(), 3);
+
+    let operations = plan.operations().collect::<Vec<_>>();
+    assert!(matches!(operations[0], SyncOperation::Create(_)));
+    assert!(matches!(operations[1], SyncOperation::Update { .. }));
+    assert!(matches!(
+        operations[2],
+        SyncOperation::Delete { name: "old", .. }
+    ));
+}
+
+#[test]
+fn test_sync_policy() {
+    let policy = |command: &mut ApplicationCommandData| {
+        command.contexts = Some(vec![InteractionContextType::Guild]);
+    };
+
+    let local = [HelloCommand::create_command()];
+    let remote = [remote(HelloCommand::create_command().into(), 1)];
+
+    let plan = diff(&local, &remote);
+    assert!(plan.is_up_to_date());
+
+    let plan = diff_with(&local, &remote, &policy);
+    assert!(matches!(
+        &plan.changes[0],
+        CommandChange::Updated { command, fields, .. }
+            if fields == &[CommandField::Contexts]
+                && command.contexts == Some(vec![InteractionContextType::Guild])
+    ));
+}
+
+#[test]
+fn test_diff_command() {
+    let local = HelloCommand::create_command();
+    assert!(diff_command(&local, &remote(local.clone().into(), 1)).is_empty());
+
+    let mut command = remote(local.clone().into(), 1);
+    command.options.swap(0, 1);
+    command.options[0].description = "Ping the user".into();
+    command.options[1].name = "content".into();
+    command.description_localizations = Some([("fr".into(), "Dire bonjour".into())].into());
+
+    let changes = diff_command(&local, &command);
+    assert_eq!(
+        changes,
+        [
+            FieldChange::Localization {
+                path: vec![],
+                field: LocalizedField::Description,
+                locale: "fr".into(),
+                local: None,
+                remote: Some("Dire bonjour".into()),
+            },
+            FieldChange::OptionAdded {
+                path: vec!["message".into()],
+            },
+            FieldChange::Value {
+                path: vec!["ping".into()],
+                field: "description",
+                local: r#""Whether to ping the user""#.into(),
+                remote: r#""Ping the user""#.into(),
+            },
+            FieldChange::OptionRemoved {
+                path: vec!["content".into()],
+            },
+        ]
+    );
+    assert_eq!(
+        changes.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        [
+            "removed `fr` description localization",
+            "added option `message`",
+            r#"option `ping`: `description` changed from "Ping the user" to "Whether to ping the user""#,
+            "removed option `content`",
+        ]
+    );
+
+    let mut command = remote(local.clone().into(), 1);
+    command.options.swap(0, 1);
+    let plan = diff(&[local], &[command]);
+    assert!(matches!(
+        &plan.changes[0],
+        CommandChange::Updated { fields, changes, .. }
+            if fields == &[CommandField::Options]
+                && changes == &[FieldChange::OptionsReordered { path: vec![] }]
+    ));
+}
+
+#[test]
+fn test_hash() {
+    let hello = HelloCommand::create_command();
+    let ping = PingCommand::create_command();
+    let stored = hash(&[hello.clone(), ping.clone()]);
+
+    // Order of commands and fields not sent to Discord are ignored
+    let mut other = hello.clone();
+    other.category = Some("fun".into());
+    other.metadata.insert("tier".into(), "2".into());
+    assert_eq!(hash(&[ping.clone(), other]), stored);
+
+    let mut other = hello.clone();
+    other.description = "Say hi".into();
+    assert_ne!(hash(&[other, ping.clone()]), stored);
+    assert_ne!(hash(&[hello]), stored);
+
+    // The hash is the same across runs
+    assert_eq!(hash(&[ping]), 17480937980192421431);
+}
@@ -0,0 +1,450 @@
+#![cfg(feature = "testing")]
+
+use std::net::IpAddr;
+
+use twilight_interactions::{
+    command::{
+        ApplicationCommandData, CommandModel, CommandOption, CreateCommand, CreateOption,
+        DescLocalizations, NameLocalizations, Parsed, ResolvedMentionable, ResolvedUser,
+    },
+    error::{DispatchError, FakeDiscordError},
+    testing::{
+        assert_create_parse_symmetry, assert_limits, assert_localizations, FakeDiscord,
+        RecordedStep, Recorder,
+    },
+};
+use twilight_model::{
+    application::{
+        command::{CommandOptionType, CommandOptionValue, CommandType},
+        interaction::{
+            application_command::{
+                CommandData, CommandDataOption, CommandOptionValue as InteractionOptionValue,
+            },
+            message_component::MessageComponentInteractionData,
+            modal::ModalInteractionData,
+            Interaction, InteractionChannel, InteractionData, InteractionType,
+        },
+    },
+    channel::{message::component::ComponentType, Attachment},
+    guild::Role,
+    http::interaction::{InteractionResponse, InteractionResponseType},
+    id::Id,
+    oauth::ApplicationIntegrationMap,
+};
+
+#[derive(CommandOption, CreateOption, Debug, PartialEq)]
+enum Animal {
+    #[option(name = "Dog", value = "dog")]
+    Dog,
+    #[option(name = "Cat", value = "cat")]
+    Cat,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "demo", desc = "Demo command", generate_tests)]
+struct DemoCommand {
+    /// A user
+    user: ResolvedUser,
+    /// A voice channel
+    #[command(channel_types = "guild_voice")]
+    channel: InteractionChannel,
+    /// A role
+    role: Role,
+    /// A mentionable
+    mentionable: ResolvedMentionable,
+    /// An attachment
+    attachment: Attachment,
+    /// An animal
+    animal: Animal,
+    /// A number
+    #[command(min_value = 5, max_value = 10)]
+    number: i64,
+    /// Some text
+    #[command(min_length = 3)]
+    text: String,
+    /// An optional IP address
+    address: Option<Parsed<IpAddr>>,
+    /// An optional number
+    optional: Option<f64>,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(
+    name = "config",
+    desc_localizations = "config_desc",
+    name_localizations = "config_name",
+    generate_tests
+)]
+enum ConfigCommand {
+    #[command(name = "show")]
+    Show(ConfigShowCommand),
+}
+
+fn config_name() -> NameLocalizations {
+    NameLocalizations::new([("fr", "configuration")])
+}
+
+fn config_desc() -> DescLocalizations {
+    DescLocalizations::new("Configure the bot", [("fr", "Configurer le bot")])
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(
+    name = "show",
+    name_localizations = "show_name",
+    desc_localizations = "show_desc"
+)]
+struct ConfigShowCommand {
+    /// Key to show
+    #[command(name_localizations = "key_name", desc_localizations = "key_desc")]
+    key: i64,
+}
+
+fn show_name() -> NameLocalizations {
+    NameLocalizations::new([("fr", "afficher")])
+}
+
+fn show_desc() -> DescLocalizations {
+    DescLocalizations::new(
+        "Show the configuration",
+        [("fr", "Afficher la configuration")],
+    )
+}
+
+fn key_name() -> NameLocalizations {
+    NameLocalizations::new([("fr", "clé")])
+}
+
+fn key_desc() -> DescLocalizations {
+    DescLocalizations::new("Key to show", [("fr", "Clé à afficher")])
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "range", desc = "Range")]
+struct RangeCommand {
+    /// A number
+    #[command(min_value = 1, max_value = 10)]
+    number: i64,
+}
+
+/// Command whose option bounds are inverted, which the derive rejects.
+#[derive(CommandModel, Debug, PartialEq)]
+struct InvalidRangeCommand {
+    number: i64,
+}
+
+impl CreateCommand for InvalidRangeCommand {
+    const NAME: &'static str = "range";
+
+    fn create_command() -> ApplicationCommandData {
+        let mut command = RangeCommand::create_command();
+        command.options[0].min_value = Some(CommandOptionValue::Integer(10));
+        command.options[0].max_value = Some(CommandOptionValue::Integer(1));
+
+        command
+    }
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "partial", desc_localizations = "partial_desc")]
+struct PartialCommand {
+    /// Not localized
+    value: bool,
+}
+
+fn partial_desc() -> DescLocalizations {
+    DescLocalizations::new("Partially localized", [("fr", "Partiellement traduit")])
+}
+
+/// Command whose created options do not match the parsed model.
+#[derive(CommandModel, Debug, PartialEq)]
+struct MismatchCommand {
+    count: i64,
+}
+
+impl CreateCommand for MismatchCommand {
+    const NAME: &'static str = "mismatch";
+
+    fn create_command() -> ApplicationCommandData {
+        let mut command = RangeCommand::create_command();
+        command.name = Self::NAME.into();
+        command.options[0].name = "count".into();
+        command.options[0].kind = CommandOptionType::String;
+        command.options[0].min_value = None;
+        command.options[0].max_value = None;
+
+        command
+    }
+}
+
+#[test]
+fn test_assertions_pass() {
+    assert_create_parse_symmetry::<DemoCommand>();
+    assert_limits::<DemoCommand>();
+    assert_localizations::<DemoCommand>();
+    assert_localizations::<ConfigCommand>();
+}
+
+#[test]
+#[should_panic(expected = "minimum value is greater than maximum value")]
+fn test_assert_limits() {
+    assert_limits::<InvalidRangeCommand>();
+}
+
+#[test]
+#[should_panic(expected = "`partial value (description)`: fr")]
+fn test_assert_localizations() {
+    assert_localizations::<PartialCommand>();
+}
+
+#[test]
+#[should_panic(expected = "failed to parse sample input for `mismatch`")]
+fn test_assert_create_parse_symmetry() {
+    assert_create_parse_symmetry::<MismatchCommand>();
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "ping", desc = "Ping an address")]
+struct PingCommand {
+    /// Address to ping
+    address: Parsed<IpAddr>,
+}
+
+#[test]
+#[should_panic(
+    expected = "failed to parse sample input for `ping`: failed to parse option `address`"
+)]
+fn test_assert_create_parse_symmetry_invalid_value() {
+    assert_create_parse_symmetry::<PingCommand>();
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "feedback", desc = "Send feedback")]
+struct FeedbackCommand {
+    /// Topic of the feedback
+    topic: String,
+}
+
+fn interaction(kind: InteractionType, data: InteractionData) -> Interaction {
+    #[allow(deprecated)]
+    Interaction {
+        app_permissions: None,
+        application_id: Id::new(1),
+        authorizing_integration_owners: ApplicationIntegrationMap {
+            guild: None,
+            user: None,
+        },
+        channel: None,
+        channel_id: None,
+        context: None,
+        data: Some(data),
+        entitlements: Vec::new(),
+        guild: None,
+        guild_id: None,
+        guild_locale: None,
+        id: Id::new(1),
+        kind,
+        locale: None,
+        member: None,
+        message: None,
+        token: "token".into(),
+        user: None,
+    }
+}
+
+fn response(kind: InteractionResponseType) -> InteractionResponse {
+    InteractionResponse { kind, data: None }
+}
+
+#[tokio::test]
+async fn test_recorder() {
+    let recorder = Recorder::new(|response: &InteractionResponse| format!("{:?}", response.kind))
+        .command(|_: FeedbackCommand, _| async move { response(InteractionResponseType::Modal) })
+        .modal(|_, _| async move { response(InteractionResponseType::ChannelMessageWithSource) })
+        .component(|_, _| async move { response(InteractionResponseType::UpdateMessage) });
+
+    let command = InteractionData::ApplicationCommand(Box::new(CommandData {
+        guild_id: None,
+        id: Id::new(1),
+        name: "feedback".into(),
+        kind: CommandType::ChatInput,
+        options: vec![CommandDataOption {
+            name: "topic".into(),
+            value: InteractionOptionValue::String("docs".into()),
+        }],
+        resolved: None,
+        target_id: None,
+    }));
+    let modal = InteractionData::ModalSubmit(ModalInteractionData {
+        components: Vec::new(),
+        custom_id: "feedback-form".into(),
+    });
+    let component = InteractionData::MessageComponent(Box::new(MessageComponentInteractionData {
+        custom_id: "close".into(),
+        component_type: ComponentType::Button,
+        resolved: None,
+        values: Vec::new(),
+    }));
+
+    for (kind, data) in [
+        (InteractionType::ApplicationCommand, command),
+        (InteractionType::ModalSubmit, modal),
+        (InteractionType::MessageComponent, component),
+        (
+            InteractionType::ApplicationCommandAutocomplete,
+            InteractionData::ModalSubmit(ModalInteractionData {
+                components: Vec::new(),
+                custom_id: "invalid".into(),
+            }),
+        ),
+    ] {
+        let _ = recorder.handle(interaction(kind, data)).await;
+    }
+
+    recorder.assert_script(&[
+        RecordedStep {
+            kind: InteractionType::ApplicationCommand,
+            name: "feedback".into(),
+            model: Some(r#"FeedbackCommand { topic: "docs" }"#.into()),
+            response: Ok("Modal".into()),
+        },
+        RecordedStep {
+            kind: InteractionType::ModalSubmit,
+            name: "feedback-form".into(),
+            model: None,
+            response: Ok("ChannelMessageWithSource".into()),
+        },
+        RecordedStep {
+            kind: InteractionType::MessageComponent,
+            name: "close".into(),
+            model: None,
+            response: Ok("UpdateMessage".into()),
+        },
+        RecordedStep {
+            kind: InteractionType::ApplicationCommandAutocomplete,
+            name: "invalid".into(),
+            model: None,
+            response: Err(DispatchError::MissingData),
+        },
+    ]);
+}
+
+#[tokio::test]
+#[should_panic(expected = "recorded 0 steps, expected 1")]
+async fn test_recorder_missing_step() {
+    let recorder = Recorder::new(|response: &String| response.clone());
+
+    recorder.assert_script(&[RecordedStep {
+        kind: InteractionType::ApplicationCommand,
+        name: "feedback".into(),
+        model: None,
+        response: Ok("Modal".into()),
+    }]);
+}
+
+#[test]
+fn test_fake_discord() {
+    let mut discord = FakeDiscord::new();
+    let commands = [
+        DemoCommand::create_command().into(),
+        ConfigCommand::create_command().into(),
+    ];
+
+    let registered = discord.set_commands(&commands).unwrap();
+    let config_id = registered[1].id;
+    assert!(registered.iter().all(|command| command.id.is_some()));
+
+    // Commands keep their ID when registered again
+    let registered = discord.set_commands(&commands[1..]).unwrap();
+    assert_eq!(registered.len(), 1);
+    assert_eq!(registered[0].id, config_id);
+
+    let interactions = discord.sample_interactions("config").unwrap();
+    assert_eq!(interactions.len(), 1);
+
+    let Some(InteractionData::ApplicationCommand(data)) = interactions[0].data.clone() else {
+        panic!("expected command data");
+    };
+    assert_eq!(
+        ConfigCommand::from_interaction((*data).into()),
+        Ok(ConfigCommand::Show(ConfigShowCommand { key: 1 }))
+    );
+
+    let interaction = discord.interaction(
+        "config",
+        vec![CommandDataOption {
+            name: "show".into(),
+            value: InteractionOptionValue::SubCommand(vec![CommandDataOption {
+                name: "key".into(),
+                value: InteractionOptionValue::Integer(42),
+            }]),
+        }],
+    );
+    assert!(interaction.is_ok());
+}
+
+#[test]
+fn test_fake_discord_errors() {
+    let mut discord = FakeDiscord::new();
+
+    let result = discord.set_commands(&[InvalidRangeCommand::create_command().into()]);
+    assert_eq!(
+        result,
+        Err(FakeDiscordError::InvalidCommand {
+            name: "range".into(),
+            violations: vec!["`range number`: minimum value is greater than maximum value".into()],
+        })
+    );
+
+    let command = RangeCommand::create_command();
+    let result = discord.set_commands(&[command.clone().into(), command.into()]);
+    assert_eq!(
+        result,
+        Err(FakeDiscordError::DuplicateCommand("range".into()))
+    );
+    assert!(discord.commands().is_empty());
+
+    discord
+        .set_commands(&[ConfigCommand::create_command().into()])
+        .unwrap();
+
+    let option = |name: &str, value| CommandDataOption {
+        name: name.into(),
+        value,
+    };
+    let show = |options| vec![option("show", InteractionOptionValue::SubCommand(options))];
+
+    assert_eq!(
+        discord.interaction("range", Vec::new()).unwrap_err(),
+        FakeDiscordError::UnknownCommand("range".into())
+    );
+    assert_eq!(
+        discord
+            .interaction(
+                "config",
+                vec![option(
+                    "hide",
+                    InteractionOptionValue::SubCommand(Vec::new())
+                )]
+            )
+            .unwrap_err(),
+        FakeDiscordError::UnknownOption("config hide".into())
+    );
+    assert_eq!(
+        discord
+            .interaction(
+                "config",
+                show(vec![option("key", InteractionOptionValue::Boolean(true))])
+            )
+            .unwrap_err(),
+        FakeDiscordError::InvalidOptionType {
+            option: "config show key".into(),
+            expected: CommandOptionType::Integer,
+            found: CommandOptionType::Boolean,
+        }
+    );
+    assert_eq!(
+        discord.interaction("config", show(Vec::new())).unwrap_err(),
+        FakeDiscordError::MissingOption("config show key".into())
+    );
+}
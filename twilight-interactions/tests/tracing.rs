@@ -0,0 +1,125 @@
+#![cfg(feature = "tracing")]
+
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
+use twilight_interactions::command::{CommandInputData, CommandModel};
+use twilight_model::application::interaction::application_command::{
+    CommandDataOption, CommandOptionValue,
+};
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+#[command(name = "ban")]
+struct BanCommand {
+    days: i64,
+}
+
+#[derive(CommandModel, Debug, PartialEq, Eq)]
+#[command(name = "moderation")]
+enum ModerationCommand {
+    #[command(name = "ban")]
+    Ban(BanCommand),
+}
+
+type Fields = Vec<(String, String)>;
+
+/// Subscriber collecting the fields of emitted events.
+#[derive(Clone, Default)]
+struct CollectEvents(Arc<Mutex<Vec<Fields>>>);
+
+impl Subscriber for CollectEvents {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+        span::Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = FieldVisitor(Vec::new());
+        event.record(&mut fields);
+        self.0.lock().unwrap().push(fields.0);
+    }
+
+    fn enter(&self, _span: &span::Id) {}
+
+    fn exit(&self, _span: &span::Id) {}
+}
+
+struct FieldVisitor(Fields);
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push((field.name().into(), value.into()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.push((field.name().into(), format!("{value:?}")));
+    }
+}
+
+fn field<'a>(fields: &'a Fields, name: &str) -> Option<&'a str> {
+    fields
+        .iter()
+        .find(|(field, _)| field == name)
+        .map(|(_, value)| &**value)
+}
+
+#[test]
+fn test_parse_error_event() {
+    let data = CommandInputData {
+        options: vec![CommandDataOption {
+            name: "ban".into(),
+            value: CommandOptionValue::SubCommand(vec![CommandDataOption {
+                name: "days".into(),
+                value: CommandOptionValue::String("seven".into()),
+            }]),
+        }],
+        resolved: None,
+    };
+
+    let subscriber = CollectEvents::default();
+    let result = tracing::subscriber::with_default(subscriber.clone(), || {
+        ModerationCommand::from_interaction(data)
+    });
+    assert!(result.is_err());
+
+    let events = subscriber.0.lock().unwrap();
+    assert_eq!(events.len(), 1);
+
+    let fields = &events[0];
+    assert_eq!(field(fields, "command"), Some("moderation"));
+    assert_eq!(field(fields, "option"), Some("ban days"));
+    assert_eq!(field(fields, "kind"), Some("InvalidType"));
+    assert_eq!(field(fields, "message"), Some("failed to parse command"));
+}
+
+#[test]
+fn test_parse_success_no_event() {
+    let data = CommandInputData {
+        options: vec![CommandDataOption {
+            name: "days".into(),
+            value: CommandOptionValue::Integer(7),
+        }],
+        resolved: None,
+    };
+
+    let subscriber = CollectEvents::default();
+    let result = tracing::subscriber::with_default(subscriber.clone(), || {
+        BanCommand::from_interaction(data)
+    });
+
+    assert_eq!(result, Ok(BanCommand { days: 7 }));
+    assert!(subscriber.0.lock().unwrap().is_empty());
+}